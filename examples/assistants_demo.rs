@@ -450,6 +450,9 @@ async fn demo_error_handling(api: &AssistantsApi) -> Result<()> {
         tools: Vec::new(),
         file_ids: Vec::new(),
         metadata: HashMap::new(),
+        response_format: None,
+        temperature: None,
+        top_p: None,
     };
 
     match api.create_assistant(invalid_request).await {
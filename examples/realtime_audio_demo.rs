@@ -43,6 +43,7 @@ fn create_api_client() -> Result<RealtimeAudioApi, Box<dyn std::error::Error>> {
             threshold: 0.6,
             prefix_padding_ms: 300,
             silence_duration_ms: 500,
+            ..Default::default()
         },
         connection_timeout: Duration::from_secs(30),
         max_reconnect_attempts: 3,
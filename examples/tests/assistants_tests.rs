@@ -42,6 +42,9 @@ fn create_assistant_request() -> AssistantRequest {
         tools: vec![],
         file_ids: vec![],
         metadata: HashMap::new(),
+        response_format: None,
+        temperature: None,
+        top_p: None,
     }
 }
 
@@ -67,6 +67,9 @@ fn create_test_assistant_request() -> AssistantRequest {
         tools: vec![],
         file_ids: vec![],
         metadata: HashMap::new(),
+        response_format: None,
+        temperature: None,
+        top_p: None,
     }
 }
 
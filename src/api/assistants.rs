@@ -60,6 +60,7 @@ use crate::constants::endpoints;
 use crate::error::{OpenAIError, Result};
 use crate::models::assistants::{
     Assistant, AssistantRequest, DeletionStatus, ListAssistantsParams, ListAssistantsResponse,
+    ModifyAssistantRequest,
 };
 use crate::{http_delete_beta, http_get_beta};
 
@@ -84,9 +85,7 @@ impl AssistantsApi {
     /// * `api_key` - Your `OpenAI` API key
     /// * `base_url` - Custom base URL for the API
     pub fn new_with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
     }
 
     /// Creates a new assistant
@@ -136,6 +135,9 @@ impl AssistantsApi {
 
     /// Modifies an existing assistant
     ///
+    /// Fields left as `None` are not sent, so they cannot be cleared here; use
+    /// [`AssistantsApi::modify_assistant_partial`] to remove a value.
+    ///
     /// # Arguments
     ///
     /// * `assistant_id` - The ID of the assistant to modify
@@ -177,6 +179,39 @@ impl AssistantsApi {
         self.http_client.post_with_beta(&path, &request).await
     }
 
+    /// Modifies only the fields set in a [`ModifyAssistantRequest`]
+    ///
+    /// Fields left unchanged keep their current values, and cleared fields are
+    /// sent as `null`. Build the request with [`Assistant::to_modify_request`]
+    /// to send only what changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::api::{assistants::AssistantsApi, common::ApiClientConstructors};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = AssistantsApi::new("your-api-key")?;
+    ///
+    /// let assistant = api.retrieve_assistant("asst_abc123").await?;
+    /// let request = assistant.to_modify_request().metadata_pair("tier", "gold").build();
+    /// let assistant = api.modify_assistant_partial(&assistant.id, request).await?;
+    /// println!("Modified assistant: {}", assistant.id);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn modify_assistant_partial(
+        &self,
+        assistant_id: impl Into<String>,
+        request: ModifyAssistantRequest,
+    ) -> Result<Assistant> {
+        validate_request(&request)?;
+
+        let assistant_id = assistant_id.into();
+        let path = endpoints::assistants::by_id(&assistant_id);
+        self.http_client.post_with_beta(&path, &request).await
+    }
+
     http_delete_beta!(delete_assistant, "/v1/assistants/{}", assistant_id: impl Into<String>, DeletionStatus);
 
     /// Lists assistants
@@ -289,6 +324,9 @@ mod tests {
             tools: Vec::new(),
             file_ids: Vec::new(),
             metadata: HashMap::new(),
+            response_format: None,
+            temperature: None,
+            top_p: None,
         };
 
         let result = api.create_assistant(invalid_request).await;
@@ -31,7 +31,7 @@ pub mod utilities;
 
 // Re-export the main client and utility types for public API
 pub use client::AudioApi;
-pub use utilities::AudioUtils;
+pub use utilities::{AudioUtils, MAX_SPEECH_INPUT_CHARS, SPEECH_PCM_SAMPLE_RATE};
 
 // Re-export all types from the models module for convenience
 pub use types::{
@@ -7,17 +7,22 @@ use super::types::{
     Bytes, Path, Result, StreamExt, Voice,
 };
 use crate::api::audio::AudioApi;
+use crate::error::OpenAIError;
 
 impl AudioApi {
     /// Create speech from text using text-to-speech
+    ///
+    /// When the server does not report a specific MIME type, the response
+    /// carries the MIME type of the requested format.
     pub async fn create_speech(&self, request: &AudioSpeechRequest) -> Result<AudioSpeechResponse> {
+        request.validate().map_err(OpenAIError::InvalidRequest)?;
         let (audio_data, content_type) = self
             .http_client()
             .post_bytes_with_content_type("/v1/audio/speech", request)
             .await?;
 
         let content_type = if content_type == "application/octet-stream" {
-            "audio/mpeg".to_string()
+            request.format().content_type().to_string()
         } else {
             content_type
         };
@@ -30,6 +35,9 @@ impl AudioApi {
         &self,
         request: &AudioSpeechRequest,
     ) -> Result<impl tokio_stream::Stream<Item = Result<Bytes>>> {
+        request
+            .validate_for_streaming()
+            .map_err(OpenAIError::InvalidRequest)?;
         let response = self
             .http_client()
             .post_stream("/v1/audio/speech", request)
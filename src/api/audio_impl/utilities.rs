@@ -2,8 +2,12 @@
 //!
 //! Helper functions and utilities for the Audio API.
 
-use super::types::{AudioFormat, Path, Voice};
+use super::types::{
+    AudioFormat, AudioModels, AudioSpeechRequest, AudioSpeechResponse, OpenAIError, Path, Result,
+    Voice,
+};
 use crate::api::audio::AudioApi;
+use futures::{StreamExt, TryStreamExt};
 
 impl AudioApi {
     /// Get supported audio formats for input
@@ -51,6 +55,15 @@ impl AudioApi {
     }
 }
 
+/// Maximum number of characters the speech endpoint accepts in one request
+pub const MAX_SPEECH_INPUT_CHARS: usize = 4096;
+
+/// Sample rate of the PCM audio returned by the speech endpoint
+pub const SPEECH_PCM_SAMPLE_RATE: u32 = 24_000;
+
+/// Number of chunks [`AudioUtils::synthesize_long`] synthesizes at once
+const SYNTHESIZE_LONG_CONCURRENCY: usize = 4;
+
 /// Audio utilities
 pub struct AudioUtils;
 
@@ -90,6 +103,143 @@ impl AudioUtils {
         minutes * 0.006 // $0.006 per minute
     }
 
+    /// Split text into chunks of at most `max_chars` characters
+    ///
+    /// Chunks end on sentence boundaries where possible. A sentence longer than
+    /// the limit is split between words, and a word longer than the limit is
+    /// split wherever it has to be.
+    #[must_use]
+    pub fn split_for_speech(text: &str, max_chars: usize) -> Vec<String> {
+        let max_chars = max_chars.max(1);
+        let mut chunks = Vec::new();
+        let mut current = String::new();
+
+        for sentence in text.split_inclusive(['.', '!', '?', '\n']) {
+            if sentence.chars().count() > max_chars {
+                for word in sentence.split_inclusive(char::is_whitespace) {
+                    Self::push_speech_piece(&mut chunks, &mut current, word, max_chars);
+                }
+            } else {
+                Self::push_speech_piece(&mut chunks, &mut current, sentence, max_chars);
+            }
+        }
+        Self::flush_speech_chunk(&mut chunks, &mut current);
+        chunks
+    }
+
+    /// Append a piece to the current chunk, starting a new chunk when it would overflow
+    fn push_speech_piece(
+        chunks: &mut Vec<String>,
+        current: &mut String,
+        piece: &str,
+        max_chars: usize,
+    ) {
+        let piece_chars = piece.chars().count();
+        if current.chars().count() + piece_chars > max_chars {
+            Self::flush_speech_chunk(chunks, current);
+        }
+        if piece_chars > max_chars {
+            let chars: Vec<char> = piece.chars().collect();
+            for part in chars.chunks(max_chars) {
+                current.extend(part);
+                Self::flush_speech_chunk(chunks, current);
+            }
+        } else {
+            current.push_str(piece);
+        }
+    }
+
+    /// Move the current chunk into `chunks` unless it is blank
+    fn flush_speech_chunk(chunks: &mut Vec<String>, current: &mut String) {
+        let trimmed = current.trim();
+        if !trimmed.is_empty() {
+            chunks.push(trimmed.to_string());
+        }
+        current.clear();
+    }
+
+    /// Wrap 16-bit mono little-endian PCM samples in a WAV header
+    #[must_use]
+    pub fn pcm_to_wav(pcm: &[u8], sample_rate: u32) -> Vec<u8> {
+        const CHANNELS: u16 = 1;
+        const BITS_PER_SAMPLE: u16 = 16;
+        let block_align = CHANNELS * BITS_PER_SAMPLE / 8;
+        let byte_rate = sample_rate * u32::from(block_align);
+        let data_len = u32::try_from(pcm.len()).unwrap_or(u32::MAX - 36);
+
+        let mut wav = Vec::with_capacity(44 + pcm.len());
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&CHANNELS.to_le_bytes());
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&byte_rate.to_le_bytes());
+        wav.extend_from_slice(&block_align.to_le_bytes());
+        wav.extend_from_slice(&BITS_PER_SAMPLE.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        wav.extend_from_slice(pcm);
+        wav
+    }
+
+    /// Synthesize text longer than the speech endpoint's input limit
+    ///
+    /// The text is split on sentence boundaries into chunks of at most
+    /// [`MAX_SPEECH_INPUT_CHARS`] characters, the chunks are synthesized
+    /// concurrently with the standard TTS model, and the audio is joined in
+    /// order. MP3, AAC and PCM are joined byte for byte. WAV, Opus and FLAC files
+    /// cannot be joined that way, so for those formats the chunks are requested
+    /// as PCM and returned as a single WAV file; check the response's content
+    /// type for the format actually produced.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from any chunk's request.
+    pub async fn synthesize_long(
+        api: &AudioApi,
+        text: &str,
+        voice: Voice,
+        format: AudioFormat,
+    ) -> Result<AudioSpeechResponse> {
+        let as_wav = !matches!(
+            format,
+            AudioFormat::Mp3 | AudioFormat::Aac | AudioFormat::Pcm
+        );
+        let request_format = if as_wav { AudioFormat::Pcm } else { format };
+
+        let chunks = Self::split_for_speech(text, MAX_SPEECH_INPUT_CHARS);
+        if chunks.is_empty() {
+            return Err(OpenAIError::InvalidRequest(
+                "Cannot synthesize empty text".to_string(),
+            ));
+        }
+
+        let parts: Vec<AudioSpeechResponse> = futures::stream::iter(chunks)
+            .map(|chunk| {
+                let request = AudioSpeechRequest::new(AudioModels::TTS_1, chunk, voice.clone())
+                    .with_format(request_format.clone());
+                async move { api.create_speech(&request).await }
+            })
+            .buffered(SYNTHESIZE_LONG_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        let audio: Vec<u8> = parts
+            .iter()
+            .flat_map(|part| part.data().iter().copied())
+            .collect();
+        Ok(if as_wav {
+            AudioSpeechResponse::new(
+                Self::pcm_to_wav(&audio, SPEECH_PCM_SAMPLE_RATE),
+                AudioFormat::Wav.content_type().to_string(),
+            )
+        } else {
+            AudioSpeechResponse::new(audio, request_format.content_type().to_string())
+        })
+    }
+
     /// Get recommended voice for different use cases
     #[must_use]
     pub fn recommend_voice(use_case: &str) -> Voice {
@@ -108,6 +258,80 @@ impl AudioUtils {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_for_speech_respects_sentences_and_limit() {
+        let text = "First sentence. Second one is here! Third? Fourth.";
+        let chunks = AudioUtils::split_for_speech(text, 40);
+        assert_eq!(
+            chunks,
+            vec!["First sentence. Second one is here!", "Third? Fourth."]
+        );
+
+        let long_sentence = "word ".repeat(30);
+        let chunks = AudioUtils::split_for_speech(&long_sentence, 24);
+        assert!(chunks.iter().all(|chunk| chunk.chars().count() <= 24));
+        assert_eq!(chunks.join(" "), long_sentence.trim());
+
+        let chunks = AudioUtils::split_for_speech(&"x".repeat(10), 4);
+        assert_eq!(chunks, vec!["xxxx", "xxxx", "xx"]);
+        assert!(AudioUtils::split_for_speech("   ", 10).is_empty());
+    }
+
+    #[test]
+    fn test_pcm_to_wav_header() {
+        let wav = AudioUtils::pcm_to_wav(&[1, 2, 3, 4], SPEECH_PCM_SAMPLE_RATE);
+        assert_eq!(wav.len(), 48);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(wav[4..8].try_into().unwrap()), 40);
+        assert_eq!(u32::from_le_bytes(wav[24..28].try_into().unwrap()), 24_000);
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 4);
+        assert_eq!(&wav[44..], &[1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_synthesize_long_joins_chunks() {
+        use crate::api::common::ApiClientConstructors;
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let mp3 = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/audio/speech")
+                    .body_includes(r#""response_format":"mp3""#);
+                then.status(200)
+                    .header("Content-Type", "audio/mpeg")
+                    .body("ab");
+            })
+            .await;
+        let pcm = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/audio/speech")
+                    .body_includes(r#""response_format":"pcm""#);
+                then.status(200)
+                    .header("Content-Type", "application/octet-stream")
+                    .body([0u8, 1]);
+            })
+            .await;
+        let api = AudioApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let text = format!("{} {}", "a".repeat(4000) + ".", "b".repeat(200) + ".");
+
+        let response = AudioUtils::synthesize_long(&api, &text, Voice::Alloy, AudioFormat::Mp3)
+            .await
+            .unwrap();
+        assert_eq!(response.data(), b"abab");
+        assert_eq!(response.content_type(), "audio/mpeg");
+        mp3.assert_calls_async(2).await;
+
+        let response = AudioUtils::synthesize_long(&api, &text, Voice::Alloy, AudioFormat::Opus)
+            .await
+            .unwrap();
+        assert_eq!(response.content_type(), "audio/wav");
+        assert_eq!(&response.data()[44..], &[0, 1, 0, 1]);
+        pcm.assert_calls_async(2).await;
+    }
+
     #[test]
     fn test_supported_formats() {
         let formats = AudioApi::supported_input_formats();
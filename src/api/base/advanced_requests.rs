@@ -69,6 +69,21 @@ impl HttpClient {
         self.handle_response(response).await
     }
 
+    /// Make a GET request and return the response for streaming its body
+    pub async fn get_stream(&self, path: &str) -> Result<reqwest::Response> {
+        let url = self.build_simple_url(path);
+        let headers = self.build_auth_headers()?;
+
+        let response = self.client().get(&url).headers(headers).send().await?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(response)
+        } else {
+            self.handle_error_response(response, status).await
+        }
+    }
+
     /// Make a POST request with streaming response
     pub async fn post_stream<B>(&self, path: &str, body: &B) -> Result<reqwest::Response>
     where
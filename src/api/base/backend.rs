@@ -0,0 +1,233 @@
+//! Pluggable transport layer for [`HttpClient`](crate::api::base::HttpClient)
+//!
+//! Every buffered request made by the sub-APIs is reduced to a [`RequestParts`]
+//! value and handed to an [`HttpBackend`], which returns the complete
+//! [`RawResponse`]. The default [`ReqwestBackend`] uses `reqwest`; integrators
+//! that standardize on another HTTP stack can supply their own implementation
+//! through [`HttpClient::with_backend`](crate::api::base::HttpClient::with_backend).
+//!
+//! Server-sent event streams and multipart uploads still go through the
+//! underlying `reqwest` client, because they need incremental bodies that a
+//! buffered backend cannot express.
+
+use crate::error::Result;
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+
+/// A fully described HTTP request, ready to be sent by a backend
+#[derive(Debug, Clone)]
+pub struct RequestParts {
+    /// HTTP method
+    pub method: Method,
+    /// Absolute request URL, including any query string
+    pub url: String,
+    /// Request headers, including authorization
+    pub headers: HeaderMap,
+    /// Request body, if any
+    pub body: Option<Vec<u8>>,
+}
+
+impl RequestParts {
+    /// Create a request without headers or body
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            method,
+            url: url.into(),
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    /// Set the request headers
+    #[must_use]
+    pub fn with_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Set the request body
+    #[must_use]
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// A buffered HTTP response returned by a backend
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    /// HTTP status code
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Complete response body
+    pub body: Vec<u8>,
+}
+
+impl RawResponse {
+    /// Create a response with the given status and body and no headers
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Check whether the status code is in the 2xx range
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    /// Get the body as text, replacing invalid UTF-8 sequences
+    #[must_use]
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Get a header value as a string, if present and valid
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// Deserialize the body as JSON
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+/// Transport used by [`HttpClient`](crate::api::base::HttpClient) to send requests
+///
+/// Implementations only need to perform the exchange; status handling and
+/// error conversion are done by the client. A transport failure (connection
+/// refused, timeout, ...) should be reported as an error, while any response
+/// received from the server, including 4xx and 5xx, should be returned as a
+/// [`RawResponse`].
+pub trait HttpBackend: std::fmt::Debug + Send + Sync {
+    /// Send a request and return the buffered response
+    fn execute(&self, request: RequestParts) -> BoxFuture<'_, Result<RawResponse>>;
+}
+
+/// Default backend built on `reqwest`
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestBackend {
+    /// The underlying reqwest HTTP client
+    client: reqwest::Client,
+}
+
+impl ReqwestBackend {
+    /// Create a backend that sends requests through the given client
+    #[must_use]
+    pub const fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl HttpBackend for ReqwestBackend {
+    fn execute(&self, request: RequestParts) -> BoxFuture<'_, Result<RawResponse>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(RawResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::base::HttpClient;
+    use crate::error::OpenAIError;
+    use serde_json::{Value, json};
+    use std::sync::{Arc, Mutex};
+
+    /// Backend that records requests and replies with a fixed response
+    #[derive(Debug, Clone)]
+    struct RecordingBackend {
+        /// Requests seen so far
+        requests: Arc<Mutex<Vec<RequestParts>>>,
+        /// Response returned for every request
+        response: RawResponse,
+    }
+
+    impl RecordingBackend {
+        /// Create a backend answering every request with `response`
+        fn new(response: RawResponse) -> Self {
+            Self {
+                requests: Arc::new(Mutex::new(Vec::new())),
+                response,
+            }
+        }
+    }
+
+    impl HttpBackend for RecordingBackend {
+        fn execute(&self, request: RequestParts) -> BoxFuture<'_, Result<RawResponse>> {
+            self.requests.lock().unwrap().push(request);
+            let response = self.response.clone();
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_routes_json_requests_through_backend() {
+        let backend = RecordingBackend::new(RawResponse::new(StatusCode::OK, r#"{"ok":true}"#));
+        let client = HttpClient::new_with_base_url("test-key", "https://example.test")
+            .unwrap()
+            .with_backend(backend.clone());
+
+        let result: Value = client
+            .post("/v1/embeddings", &json!({"input": "hi"}))
+            .await
+            .unwrap();
+        assert_eq!(result, json!({"ok": true}));
+
+        let requests = backend.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(request.url, "https://example.test/v1/embeddings");
+        assert_eq!(request.headers["authorization"], "Bearer test-key");
+        let body: Value = serde_json::from_slice(request.body.as_deref().unwrap()).unwrap();
+        assert_eq!(body, json!({"input": "hi"}));
+    }
+
+    #[tokio::test]
+    async fn test_client_converts_backend_error_responses() {
+        let backend = RecordingBackend::new(RawResponse::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            r#"{"error":{"message":"slow down","type":"rate_limit","code":null}}"#,
+        ));
+        let client = HttpClient::new("test-key").unwrap().with_backend(backend);
+
+        let err = client.get::<Value>("/v1/models").await.unwrap_err();
+        match err {
+            OpenAIError::Api {
+                status_code,
+                message,
+            } => {
+                assert_eq!(status_code, 429);
+                assert_eq!(message, "slow down");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+}
@@ -1,18 +1,36 @@
 //! Basic HTTP request methods for the client
 
+use crate::api::base::backend::RequestParts;
 use crate::api::base::client::HttpClient;
 use crate::error::Result;
+use reqwest::Method;
 use reqwest::header::HeaderMap;
 use serde::de::DeserializeOwned;
 
 impl HttpClient {
+    /// Send a request through the backend and decode the JSON response
+    pub(crate) async fn execute_request<T>(
+        &self,
+        method: Method,
+        url: &str,
+        headers: HeaderMap,
+        body: Option<Vec<u8>>,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let mut request = RequestParts::new(method, url).with_headers(headers);
+        request.body = body;
+        let response = self.execute(request).await?;
+        self.handle_raw_response(response)
+    }
+
     /// Execute a GET request with the given headers
     pub(crate) async fn execute_get_request<T>(&self, url: &str, headers: HeaderMap) -> Result<T>
     where
         T: DeserializeOwned,
     {
-        let response = self.client().get(url).headers(headers).send().await?;
-        self.handle_response(response).await
+        self.execute_request(Method::GET, url, headers, None).await
     }
 
     /// Execute a POST request with JSON body and the given headers
@@ -26,14 +44,9 @@ impl HttpClient {
         T: DeserializeOwned,
         B: serde::Serialize,
     {
-        let response = self
-            .client()
-            .post(url)
-            .headers(headers)
-            .json(body)
-            .send()
-            .await?;
-        self.handle_response(response).await
+        let body = serde_json::to_vec(body)?;
+        self.execute_request(Method::POST, url, headers, Some(body))
+            .await
     }
 
     /// Execute a DELETE request with the given headers
@@ -41,8 +54,8 @@ impl HttpClient {
     where
         T: DeserializeOwned,
     {
-        let response = self.client().delete(url).headers(headers).send().await?;
-        self.handle_response(response).await
+        self.execute_request(Method::DELETE, url, headers, None)
+            .await
     }
 
     /// Internal GET request with configurable headers
@@ -139,4 +152,26 @@ impl HttpClient {
     {
         self.delete_internal(path, true).await
     }
+
+    /// Make a PATCH request with JSON body to the specified path
+    pub async fn patch<T, B>(&self, path: &str, body: &B) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: serde::Serialize,
+    {
+        let url = self.build_simple_url(path);
+        let headers = self.build_headers()?;
+        let body = serde_json::to_vec(body)?;
+        self.execute_request(Method::PATCH, &url, headers, Some(body))
+            .await
+    }
+
+    /// Make a request without a body whose response content is ignored
+    pub async fn send_empty(&self, method: Method, path: &str) -> Result<()> {
+        let url = self.build_simple_url(path);
+        let headers = self.build_headers()?;
+        let request = RequestParts::new(method, url).with_headers(headers);
+        self.check_raw_status(self.execute(request).await?)
+            .map(|_| ())
+    }
 }
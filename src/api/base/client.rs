@@ -1,15 +1,20 @@
 //! Core HTTP client implementation for OpenAI API
 
+use crate::api::base::backend::{HttpBackend, RawResponse, RequestParts, ReqwestBackend};
 use crate::api::base::config::ClientConfig;
+use crate::api::base::helpers::url::encode_query;
 use crate::error::{OpenAIError, Result};
 use reqwest::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
 use serde::de::DeserializeOwned;
+use std::sync::Arc;
 
 /// Common HTTP client for all OpenAI API operations
 #[derive(Debug, Clone)]
 pub struct HttpClient {
-    /// The underlying reqwest HTTP client
+    /// The underlying reqwest HTTP client, used for streaming and multipart requests
     client: reqwest::Client,
+    /// Transport used for buffered requests
+    backend: Arc<dyn HttpBackend>,
     /// Client configuration
     config: ClientConfig,
 }
@@ -17,22 +22,83 @@ pub struct HttpClient {
 impl HttpClient {
     /// Create a new HTTP client with the given API key
     pub fn new<S: Into<String>>(api_key: S) -> Result<Self> {
-        let config = ClientConfig::new(api_key)?;
-        Ok(Self {
-            client: reqwest::Client::new(),
-            config,
-        })
+        Self::from_config(ClientConfig::new(api_key)?)
     }
 
     /// Create a new HTTP client with custom base URL
     pub fn new_with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        let config = ClientConfig::new_with_base_url(api_key, base_url)?;
+        Self::from_config(ClientConfig::new_with_base_url(api_key, base_url)?)
+    }
+
+    /// Create a new HTTP client from an existing configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `reqwest` client cannot be built
+    /// with the configured user agent and HTTP version.
+    pub fn from_config(config: ClientConfig) -> Result<Self> {
+        let client = config
+            .client_builder()
+            .build()
+            .map_err(crate::network_err!("Failed to create HTTP client: {}"))?;
+        let backend = Arc::new(ReqwestBackend::new(client.clone()));
         Ok(Self {
-            client: reqwest::Client::new(),
+            client,
+            backend,
             config,
         })
     }
 
+    /// Send buffered requests through a custom transport instead of `reqwest`
+    #[must_use]
+    pub fn with_backend(mut self, backend: impl HttpBackend + 'static) -> Self {
+        self.backend = Arc::new(backend);
+        self
+    }
+
+    /// Get the transport used for buffered requests
+    #[must_use]
+    pub fn backend(&self) -> &dyn HttpBackend {
+        self.backend.as_ref()
+    }
+
+    /// Send a request through the configured backend
+    ///
+    /// At debug log level the exchange is logged according to the
+    /// configured [`LogRedaction`](crate::api::base::LogRedaction) policy.
+    pub async fn execute(&self, request: RequestParts) -> Result<RawResponse> {
+        if !log::log_enabled!(log::Level::Debug) {
+            return self.backend.execute(request).await;
+        }
+
+        let redaction = self.config.log_redaction();
+        let method = request.method.clone();
+        let url = request.url.clone();
+        log::debug!(
+            "{method} {url} headers=[{}] body={}",
+            redaction.redact_headers(&request.headers),
+            request
+                .body
+                .as_deref()
+                .and_then(|body| redaction.redact_body(body))
+                .unwrap_or_default()
+        );
+
+        let response = self.backend.execute(request).await?;
+        log::debug!(
+            "{method} {url} -> {} body={}",
+            response.status,
+            redaction.redact_body(&response.body).unwrap_or_default()
+        );
+        Ok(response)
+    }
+
+    /// Get the client configuration
+    #[must_use]
+    pub const fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
     /// Get the API key
     #[must_use]
     pub fn api_key(&self) -> &str {
@@ -79,26 +145,85 @@ impl HttpClient {
         Ok(headers)
     }
 
-    /// Build a URL from the base URL and path
+    /// Build a URL from the base URL and path, honoring the configured path prefix
     pub fn build_simple_url(&self, path: &str) -> String {
-        format!("{}{}", self.config.base_url(), path)
+        self.config.url_for(path)
     }
 
-    /// Build URL with path and optional query parameters
+    /// Build URL with path and optional percent-encoded query parameters
     #[must_use]
     pub fn build_url(&self, path: &str, query_params: &[(String, String)]) -> String {
-        let mut url = format!("{}{}", self.config.base_url(), path);
+        let mut url = self.config.url_for(path);
 
         if !query_params.is_empty() {
             url.push('?');
-            let query_string = query_params
-                .iter()
-                .map(|(k, v)| format!("{k}={v}"))
-                .collect::<Vec<_>>()
-                .join("&");
-            url.push_str(&query_string);
+            url.push_str(&encode_query(query_params));
         }
 
         url
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::base::config::HttpVersionPreference;
+    use httpmock::prelude::*;
+
+    #[tokio::test]
+    async fn test_requests_send_sdk_user_agent() {
+        let server = MockServer::start();
+        let expected = format!("openai_rust_sdk/{} my-app/1.0", env!("CARGO_PKG_VERSION"));
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/v1/models")
+                .header("user-agent", expected.as_str());
+            then.status(200);
+        });
+
+        let config = ClientConfig::new_with_base_url("test-key", &server.base_url())
+            .unwrap()
+            .with_user_agent_suffix("my-app/1.0");
+        let client = HttpClient::from_config(config).unwrap();
+        let response = client
+            .client()
+            .get(client.build_simple_url("/v1/models"))
+            .headers(client.build_headers().unwrap())
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 200);
+        mock.assert();
+    }
+
+    #[tokio::test]
+    async fn test_http_version_preference_is_applied() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/v1/models");
+            then.status(200);
+        });
+
+        for (preference, version) in [
+            (HttpVersionPreference::Http1Only, reqwest::Version::HTTP_11),
+            (
+                HttpVersionPreference::Http2PriorKnowledge,
+                reqwest::Version::HTTP_2,
+            ),
+        ] {
+            let config = ClientConfig::new_with_base_url("test-key", &server.base_url())
+                .unwrap()
+                .with_http_version(preference);
+            let client = HttpClient::from_config(config).unwrap();
+            let response = client
+                .client()
+                .get(client.build_simple_url("/v1/models"))
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(response.version(), version);
+        }
+        mock.assert_calls(2);
+    }
+}
@@ -1,10 +1,30 @@
 //! Configuration utilities for the HTTP client
 
+use crate::api::base::helpers::url::join_path;
+use crate::api::base::redaction::LogRedaction;
+use crate::constants::{API_VERSION_PREFIX, sdk};
 use crate::error::{OpenAIError, Result};
+pub use crate::models::shared_traits::Validate;
 
 /// Default OpenAI API base URL
 pub const DEFAULT_BASE_URL: &str = "https://api.openai.com";
 
+/// HTTP protocol version used to talk to the API
+///
+/// `Auto` lets the connection negotiate HTTP/2 or HTTP/1.1. Some proxies and
+/// load balancers break that negotiation; force HTTP/1.1 with `Http1Only`, or
+/// skip it with `Http2PriorKnowledge` when the server is known to speak HTTP/2.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HttpVersionPreference {
+    /// Negotiate the protocol version
+    #[default]
+    Auto,
+    /// Only use HTTP/1.1
+    Http1Only,
+    /// Use HTTP/2 without negotiation
+    Http2PriorKnowledge,
+}
+
 /// Configuration builder for HTTP client
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
@@ -12,6 +32,17 @@ pub struct ClientConfig {
     pub api_key: String,
     /// Base URL for API requests
     pub base_url: String,
+    /// Path prefix that replaces the `/v1` segment of endpoint paths
+    pub path_prefix: String,
+    /// Text appended to the default `User-Agent` header
+    pub user_agent_suffix: Option<String>,
+    /// What request and response debug logging may reveal
+    pub log_redaction: LogRedaction,
+    /// HTTP protocol version used for connections
+    pub http_version: HttpVersionPreference,
+    /// Whether non-streaming chat requests that overflow the context window
+    /// are trimmed and retried
+    pub auto_trim_on_overflow: bool,
 }
 
 impl ClientConfig {
@@ -21,6 +52,11 @@ impl ClientConfig {
         Ok(Self {
             api_key,
             base_url: DEFAULT_BASE_URL.to_string(),
+            path_prefix: API_VERSION_PREFIX.to_string(),
+            user_agent_suffix: None,
+            log_redaction: LogRedaction::default(),
+            http_version: HttpVersionPreference::default(),
+            auto_trim_on_overflow: false,
         })
     }
 
@@ -30,16 +66,17 @@ impl ClientConfig {
         Ok(Self {
             api_key,
             base_url: base_url.into(),
+            path_prefix: API_VERSION_PREFIX.to_string(),
+            user_agent_suffix: None,
+            log_redaction: LogRedaction::default(),
+            http_version: HttpVersionPreference::default(),
+            auto_trim_on_overflow: false,
         })
     }
 
     /// Validate API key and return it if valid
     fn validate_api_key<S: Into<String>>(api_key: S) -> Result<String> {
-        let api_key = api_key.into();
-        if api_key.trim().is_empty() {
-            return Err(OpenAIError::authentication("API key cannot be empty"));
-        }
-        Ok(api_key)
+        crate::api::common::validate_api_key(api_key)
     }
 
     /// Set a custom base URL
@@ -48,6 +85,74 @@ impl ClientConfig {
         self
     }
 
+    /// Set the path prefix used in place of `/v1`
+    ///
+    /// Gateways that serve the API under a different path (for example
+    /// `/openai/v1`) can set it here. Leading and trailing slashes are
+    /// normalized; an empty prefix removes the version segment entirely.
+    pub fn with_path_prefix<S: Into<String>>(mut self, path_prefix: S) -> Self {
+        let path_prefix = path_prefix.into();
+        let trimmed = path_prefix.trim_matches('/');
+        self.path_prefix = if trimmed.is_empty() {
+            String::new()
+        } else {
+            format!("/{trimmed}")
+        };
+        self
+    }
+
+    /// Append custom text to the `User-Agent` header
+    ///
+    /// Requests are sent as `openai_rust_sdk/<version> <suffix>`, which makes
+    /// an application's traffic identifiable in server-side logs. Control
+    /// characters are stripped so the header value is always valid; a blank
+    /// suffix restores the default.
+    pub fn with_user_agent_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        let suffix: String = suffix.into().chars().filter(|c| !c.is_control()).collect();
+        let suffix = suffix.trim();
+        self.user_agent_suffix = (!suffix.is_empty()).then(|| suffix.to_string());
+        self
+    }
+
+    /// Set the policy applied to requests and responses written to debug logs
+    ///
+    /// The `Authorization` header is always masked. By default bodies are not
+    /// logged; enable them with [`LogRedaction::with_bodies`].
+    #[must_use]
+    pub const fn with_log_redaction(mut self, log_redaction: LogRedaction) -> Self {
+        self.log_redaction = log_redaction;
+        self
+    }
+
+    /// Set the HTTP protocol version used for connections
+    #[must_use]
+    pub const fn with_http_version(mut self, http_version: HttpVersionPreference) -> Self {
+        self.http_version = http_version;
+        self
+    }
+
+    /// Trim and retry chat requests that exceed the model's context window
+    ///
+    /// When enabled, a request made through `OpenAIClient::chat`,
+    /// `chat_detailed` or `create_chat_completion` that is rejected with
+    /// `context_length_exceeded` has its oldest turns removed and is retried
+    /// once. `chat_detailed` reports how many messages were dropped. Streaming
+    /// chat requests are not retried.
+    #[must_use]
+    pub const fn auto_trim_on_overflow(mut self, enabled: bool) -> Self {
+        self.auto_trim_on_overflow = enabled;
+        self
+    }
+
+    /// Get the `User-Agent` header value sent with every request
+    #[must_use]
+    pub fn user_agent(&self) -> String {
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("{} {suffix}", sdk::USER_AGENT),
+            None => sdk::USER_AGENT.to_string(),
+        }
+    }
+
     /// Get the API key
     #[must_use]
     pub fn api_key(&self) -> &str {
@@ -59,12 +164,59 @@ impl ClientConfig {
     pub fn base_url(&self) -> &str {
         &self.base_url
     }
-}
 
-/// Trait for request types that can be validated
-pub trait Validate {
-    /// Validates the request and returns an error message if invalid
-    fn validate(&self) -> std::result::Result<(), String>;
+    /// Get the policy applied to debug logging
+    #[must_use]
+    pub const fn log_redaction(&self) -> &LogRedaction {
+        &self.log_redaction
+    }
+
+    /// Get the HTTP protocol version used for connections
+    #[must_use]
+    pub const fn http_version(&self) -> HttpVersionPreference {
+        self.http_version
+    }
+
+    /// Check whether overflowing chat requests are trimmed and retried
+    #[must_use]
+    pub const fn trims_on_overflow(&self) -> bool {
+        self.auto_trim_on_overflow
+    }
+
+    /// Start a `reqwest` client builder with the user agent and protocol version
+    pub(crate) fn client_builder(&self) -> reqwest::ClientBuilder {
+        let builder = reqwest::Client::builder().user_agent(self.user_agent());
+        match self.http_version {
+            HttpVersionPreference::Auto => builder,
+            HttpVersionPreference::Http1Only => builder.http1_only(),
+            HttpVersionPreference::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+        }
+    }
+
+    /// Get the path prefix used in place of `/v1`
+    #[must_use]
+    pub fn path_prefix(&self) -> &str {
+        &self.path_prefix
+    }
+
+    /// Rewrite an endpoint path so its `/v1` segment uses the configured prefix
+    ///
+    /// Paths that do not start with `/v1` are returned unchanged.
+    #[must_use]
+    pub fn resolve_path(&self, path: &str) -> String {
+        match path.strip_prefix(API_VERSION_PREFIX) {
+            Some(rest) if rest.is_empty() || rest.starts_with(['/', '?']) => {
+                format!("{}{rest}", self.path_prefix)
+            }
+            _ => path.to_string(),
+        }
+    }
+
+    /// Build the full URL for an endpoint path
+    #[must_use]
+    pub fn url_for(&self, path: &str) -> String {
+        join_path(&self.base_url, &[&self.resolve_path(path)])
+    }
 }
 
 /// Validates a request object that implements a validate method
@@ -110,6 +262,59 @@ mod tests {
         assert_eq!(config.base_url(), "https://custom.api.com");
     }
 
+    #[test]
+    fn test_client_config_path_prefix() {
+        let config = ClientConfig::new_with_base_url("test-key", "https://gateway.test")
+            .unwrap()
+            .with_path_prefix("openai/v1/");
+        assert_eq!(config.path_prefix(), "/openai/v1");
+        assert_eq!(
+            config.url_for("/v1/chat/completions"),
+            "https://gateway.test/openai/v1/chat/completions"
+        );
+        assert_eq!(config.resolve_path("/v1"), "/openai/v1");
+        assert_eq!(config.resolve_path("/v1?limit=1"), "/openai/v1?limit=1");
+        assert_eq!(config.resolve_path("/v10/other"), "/v10/other");
+        assert_eq!(
+            config.resolve_path("/realtime/sessions"),
+            "/realtime/sessions"
+        );
+
+        let unversioned = config.with_path_prefix("/");
+        assert_eq!(
+            unversioned.url_for("/v1/models"),
+            "https://gateway.test/models"
+        );
+    }
+
+    #[test]
+    fn test_client_config_default_path_prefix() {
+        let config = ClientConfig::new("test-key").unwrap();
+        assert_eq!(config.path_prefix(), "/v1");
+        assert_eq!(
+            config.url_for("/v1/models"),
+            "https://api.openai.com/v1/models"
+        );
+    }
+
+    #[test]
+    fn test_client_config_user_agent() {
+        let config = ClientConfig::new("test-key").unwrap();
+        assert_eq!(
+            config.user_agent(),
+            format!("openai_rust_sdk/{}", env!("CARGO_PKG_VERSION"))
+        );
+
+        let config = config.with_user_agent_suffix(" my-app/1.2\r\n");
+        assert_eq!(
+            config.user_agent(),
+            format!("openai_rust_sdk/{} my-app/1.2", env!("CARGO_PKG_VERSION"))
+        );
+
+        let config = config.with_user_agent_suffix("  ");
+        assert_eq!(config.user_agent_suffix, None);
+    }
+
     #[test]
     fn test_client_config_empty_api_key() {
         let result = ClientConfig::new("");
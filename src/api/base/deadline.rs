@@ -0,0 +1,159 @@
+//! Overall time budgets for multi-step operations
+//!
+//! Polling helpers make many HTTP calls, each limited only by the client's
+//! per-request timeout, so their total running time could exceed the caller's
+//! budget. A [`Deadline`] is shared across those calls: every call and every
+//! sleep between polls is cut short once the budget is spent, and the helper
+//! fails with [`OpenAIError::DeadlineExceeded`] rather than a request timeout.
+
+use crate::error::{OpenAIError, Result};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+/// A point in time by which a multi-step operation must finish
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use openai_rust_sdk::api::base::Deadline;
+/// use openai_rust_sdk::api::{common::ApiClientConstructors, BatchApi};
+/// use std::time::Duration;
+///
+/// # tokio_test::block_on(async {
+/// let api = BatchApi::new("your-api-key")?;
+/// let deadline = Deadline::after(Duration::from_secs(3600));
+///
+/// // Both waits draw from the same one-hour budget
+/// let first = api.wait_for_completion_until("batch_1", Duration::from_secs(30), &deadline).await?;
+/// let second = api.wait_for_completion_until("batch_2", Duration::from_secs(30), &deadline).await?;
+/// # Ok::<(), openai_rust_sdk::OpenAIError>(())
+/// # });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Deadline {
+    /// When the budget runs out
+    expires_at: Instant,
+    /// The total budget the deadline was created with
+    budget: Duration,
+}
+
+impl Deadline {
+    /// Create a deadline `budget` from now
+    #[must_use]
+    pub fn after(budget: Duration) -> Self {
+        Self {
+            expires_at: Instant::now() + budget,
+            budget,
+        }
+    }
+
+    /// Get the total budget the deadline was created with
+    #[must_use]
+    pub const fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Get the time left before the deadline, or zero once it has passed
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.expires_at.saturating_duration_since(Instant::now())
+    }
+
+    /// Check whether the deadline has passed
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Limit a timeout to the time left before the deadline
+    #[must_use]
+    pub fn clamp(&self, timeout: Duration) -> Duration {
+        timeout.min(self.remaining())
+    }
+
+    /// Build the error returned when `operation` runs past the deadline
+    #[must_use]
+    pub fn exceeded(&self, operation: impl Into<String>) -> OpenAIError {
+        OpenAIError::DeadlineExceeded {
+            operation: operation.into(),
+            budget: self.budget,
+        }
+    }
+
+    /// Run one step of `operation`, failing if the deadline passes first
+    ///
+    /// # Errors
+    ///
+    /// Returns the step's own error, or [`OpenAIError::DeadlineExceeded`] if
+    /// the deadline has passed or passes before the step finishes.
+    pub async fn run<T, F>(&self, operation: &str, step: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        if self.is_expired() {
+            return Err(self.exceeded(operation));
+        }
+        tokio::time::timeout(self.remaining(), step)
+            .await
+            .map_err(|_| self.exceeded(operation))?
+    }
+
+    /// Sleep between polls without sleeping past the deadline
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenAIError::DeadlineExceeded`] if the deadline passes before
+    /// `interval` has elapsed.
+    pub async fn sleep(&self, operation: &str, interval: Duration) -> Result<()> {
+        let remaining = self.remaining();
+        if interval >= remaining {
+            tokio::time::sleep(remaining).await;
+            return Err(self.exceeded(operation));
+        }
+        tokio::time::sleep(interval).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_cuts_steps_off_at_the_deadline() {
+        let deadline = Deadline::after(Duration::from_millis(20));
+        assert!(deadline.clamp(Duration::from_secs(60)) <= Duration::from_millis(20));
+
+        let err = deadline
+            .run("waiting for batch_1", async {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                Ok(())
+            })
+            .await
+            .unwrap_err();
+        assert!(err.is_deadline_exceeded());
+        assert!(!err.is_request_timeout());
+        assert!(err.to_string().contains("waiting for batch_1"));
+        assert!(deadline.is_expired());
+    }
+
+    #[tokio::test]
+    async fn test_sleep_stops_at_the_deadline() {
+        let deadline = Deadline::after(Duration::from_secs(60));
+        assert!(
+            deadline
+                .sleep("polling", Duration::from_millis(1))
+                .await
+                .is_ok()
+        );
+
+        let deadline = Deadline::after(Duration::from_millis(5));
+        let started = Instant::now();
+        let err = deadline
+            .sleep("polling", Duration::from_secs(60))
+            .await
+            .unwrap_err();
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(matches!(err, OpenAIError::DeadlineExceeded { .. }));
+    }
+}
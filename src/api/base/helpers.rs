@@ -9,10 +9,80 @@ pub use crate::api::base::error::{
 
 /// URL building utilities
 pub mod url {
+    use crate::error::{OpenAIError, Result};
+
+    /// Validate a custom base URL and strip its trailing slashes
+    ///
+    /// The URL must be an absolute `http` or `https` URL with a host and no
+    /// query string or fragment, since endpoint paths are appended to it.
+    pub fn normalize_base_url(base_url: &str) -> Result<String> {
+        let trimmed = base_url.trim();
+        let invalid = |reason: &str| {
+            OpenAIError::invalid_request(format!("Invalid base URL '{trimmed}': {reason}"))
+        };
+
+        let parsed = ::url::Url::parse(trimmed).map_err(|e| match e {
+            ::url::ParseError::RelativeUrlWithoutBase => {
+                invalid("missing scheme, expected e.g. https://api.openai.com")
+            }
+            other => invalid(&other.to_string()),
+        })?;
+
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(invalid(&format!(
+                "unsupported scheme '{}', expected http or https",
+                parsed.scheme()
+            )));
+        }
+        if parsed.host_str().is_none_or(str::is_empty) {
+            return Err(invalid("missing host"));
+        }
+        if parsed.query().is_some() || parsed.fragment().is_some() {
+            return Err(invalid("query strings and fragments are not allowed"));
+        }
+
+        Ok(trimmed.trim_end_matches('/').to_string())
+    }
+
+    /// Join a base URL and path segments with exactly one `/` between each part
+    ///
+    /// Leading and trailing slashes on the base and on every segment are
+    /// collapsed, and empty segments are skipped, so `("https://host/", "/v1/", "/files")`
+    /// and `("https://host", "v1", "files")` both yield `https://host/v1/files`.
+    /// A query string on the last segment is preserved as-is.
+    #[must_use]
+    pub fn join_path(base: &str, segments: &[&str]) -> String {
+        let mut url = base.trim_end_matches('/').to_string();
+        let mut query = None;
+
+        for (index, segment) in segments.iter().enumerate() {
+            let mut segment = *segment;
+            if index + 1 == segments.len()
+                && let Some((path, query_string)) = segment.split_once('?')
+            {
+                segment = path;
+                query = Some(query_string);
+            }
+
+            let segment = segment.trim_matches('/');
+            if !segment.is_empty() {
+                url.push('/');
+                url.push_str(segment);
+            }
+        }
+
+        if let Some(query) = query.filter(|query| !query.is_empty()) {
+            url.push('?');
+            url.push_str(query);
+        }
+
+        url
+    }
+
     /// Build a URL from the base URL and path
     #[must_use]
     pub fn build_simple_url(base_url: &str, path: &str) -> String {
-        format!("{}{}", base_url, path)
+        join_path(base_url, &[path])
     }
 
     /// Build URL with path and optional query parameters
@@ -22,26 +92,56 @@ pub mod url {
         path: &str,
         query_params: &[(String, String)],
     ) -> String {
-        let mut url = format!("{}{}", base_url, path);
+        let mut url = join_path(base_url, &[path]);
 
         if !query_params.is_empty() {
             url.push('?');
-            let query_string = query_params
-                .iter()
-                .map(|(k, v)| format!("{k}={v}"))
-                .collect::<Vec<_>>()
-                .join("&");
-            url.push_str(&query_string);
+            url.push_str(&encode_query(query_params));
         }
 
         url
     }
+
+    /// Encode query parameters as an `application/x-www-form-urlencoded` string
+    ///
+    /// Keys and values are percent-encoded, so cursors containing characters
+    /// such as `+`, `/`, `&` or spaces survive the round trip to the server.
+    #[must_use]
+    pub fn encode_query(query_params: &[(String, String)]) -> String {
+        ::url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(query_params)
+            .finish()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::url::*;
 
+    #[test]
+    fn test_normalize_base_url() {
+        assert_eq!(normalize_base_url("https://host/").unwrap(), "https://host");
+        assert_eq!(
+            normalize_base_url(" http://localhost:8080/proxy/v1// ").unwrap(),
+            "http://localhost:8080/proxy/v1"
+        );
+
+        let err = normalize_base_url("api.openai.com")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'api.openai.com'"));
+        assert!(err.contains("missing scheme"));
+
+        let err = normalize_base_url("localhost:8080")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unsupported scheme 'localhost'"));
+
+        assert!(normalize_base_url("ftp://host").is_err());
+        assert!(normalize_base_url("https://host/?x=1").is_err());
+        assert!(normalize_base_url("").is_err());
+    }
+
     #[test]
     fn test_build_simple_url() {
         let result = build_simple_url("https://api.example.com", "/v1/test");
@@ -61,6 +161,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_join_path_slash_permutations() {
+        let expected = "https://api.example.com/v1/files";
+        for base in ["https://api.example.com", "https://api.example.com/"] {
+            for version in ["v1", "/v1", "v1/", "/v1/"] {
+                for path in ["files", "/files", "files/", "/files/"] {
+                    assert_eq!(join_path(base, &[version, path]), expected);
+                }
+            }
+        }
+        assert_eq!(
+            join_path("https://api.example.com//", &["//v1//files"]),
+            "https://api.example.com/v1//files"
+        );
+    }
+
+    #[test]
+    fn test_join_path_skips_empty_segments() {
+        assert_eq!(
+            join_path("https://api.example.com/", &["", "/", "v1"]),
+            "https://api.example.com/v1"
+        );
+        assert_eq!(
+            join_path("https://api.example.com/", &[]),
+            "https://api.example.com"
+        );
+    }
+
+    #[test]
+    fn test_join_path_preserves_query_string() {
+        assert_eq!(
+            join_path(
+                "https://gateway.test/openai/",
+                &["/v1/", "/files/?purpose=batch&limit=5"]
+            ),
+            "https://gateway.test/openai/v1/files?purpose=batch&limit=5"
+        );
+        assert_eq!(
+            join_path("https://api.example.com", &["/v1/models?"]),
+            "https://api.example.com/v1/models"
+        );
+    }
+
+    #[test]
+    fn test_build_simple_url_with_trailing_slash_base() {
+        let result = build_simple_url("https://api.example.com/", "/v1/test");
+        assert_eq!(result, "https://api.example.com/v1/test");
+    }
+
+    #[test]
+    fn test_build_url_with_query_encodes_values() {
+        let params = vec![
+            ("after".to_string(), "file+abc/def ghi".to_string()),
+            ("order".to_string(), "desc".to_string()),
+        ];
+        let result = build_url_with_query("https://api.example.com", "/v1/files", &params);
+        assert_eq!(
+            result,
+            "https://api.example.com/v1/files?after=file%2Babc%2Fdef+ghi&order=desc"
+        );
+    }
+
+    #[test]
+    fn test_encode_query_escapes_reserved_characters() {
+        let params = vec![("filter".to_string(), "a&b=c?d#e".to_string())];
+        assert_eq!(encode_query(&params), "filter=a%26b%3Dc%3Fd%23e");
+        assert_eq!(encode_query(&[]), "");
+    }
+
     #[test]
     fn test_build_url_with_empty_query() {
         let result = build_url_with_query("https://api.example.com", "/v1/test", &[]);
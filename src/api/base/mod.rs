@@ -2,15 +2,19 @@
 //!
 //! This module provides common HTTP client functionality organized into focused modules:
 //! - Core HTTP client implementation and configuration
+//! - Pluggable transport backend
 //! - Request builders split into basic and advanced methods
 //! - Response handlers with error conversion
 //! - Error handling utilities
+//! - Redaction of debug logs
 //! - Utility functions and validation
 //! - Legacy utilities for backward compatibility
 
 // Core modules
+pub mod backend;
 pub mod client;
 pub mod config;
+pub mod deadline;
 pub mod error;
 
 // Request handling modules
@@ -20,12 +24,19 @@ pub mod response_handlers;
 
 // Utility modules
 pub mod helpers;
+pub mod redaction;
 pub mod utilities; // Legacy module for backward compatibility
 
 // Re-export the main client and commonly used items
+pub use backend::{HttpBackend, RawResponse, RequestParts, ReqwestBackend};
 pub use client::HttpClient;
-pub use config::{ClientConfig, DEFAULT_BASE_URL, Validate, validate_request};
+pub use config::{
+    ClientConfig, DEFAULT_BASE_URL, HttpVersionPreference, Validate, validate_request,
+};
+pub use deadline::Deadline;
 pub use error::{map_parse_error, map_request_error};
+pub use redaction::{ContentRedaction, LogRedaction};
+pub use response_handlers::WithRaw;
 
 // Re-export for backward compatibility
 pub use utilities::{handle_error_response_with_json, handle_simple_error_response};
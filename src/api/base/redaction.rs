@@ -0,0 +1,287 @@
+//! Redaction of requests and responses written to debug logs
+//!
+//! When the `log` crate is enabled at debug level, [`HttpClient`] logs every
+//! buffered request and response. The `Authorization` header is always
+//! masked; bodies are only logged when [`LogRedaction::log_bodies`] is set,
+//! and the text of prompts and outputs inside them is rewritten according to
+//! the [`ContentRedaction`] policy.
+//!
+//! [`HttpClient`]: crate::api::base::HttpClient
+
+use reqwest::header::{AUTHORIZATION, HeaderMap};
+use serde_json::Value;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+use std::sync::OnceLock;
+
+/// JSON keys whose string values hold user or model content
+const CONTENT_KEYS: &[&str] = &[
+    "arguments",
+    "b64_json",
+    "content",
+    "delta",
+    "input",
+    "instructions",
+    "output",
+    "output_text",
+    "prompt",
+    "text",
+    "transcript",
+];
+
+/// Randomly keyed hasher shared by the whole process
+///
+/// The key keeps short secrets from being recovered from logged hashes by
+/// brute force, while equal values still hash alike within one process.
+fn content_hasher() -> &'static RandomState {
+    static HASHER: OnceLock<RandomState> = OnceLock::new();
+    HASHER.get_or_init(RandomState::new)
+}
+
+/// Header names whose values are never written to logs
+const SENSITIVE_HEADERS: &[&str] = &["api-key", "openai-organization", "openai-project"];
+
+/// How prompt and output text is written to debug logs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentRedaction {
+    /// Log content unchanged
+    Full,
+    /// Keep at most this many characters of each value
+    Truncate(usize),
+    /// Replace each value with a short hash, so equal values can be correlated
+    ///
+    /// The hash is keyed per process, so hashes cannot be compared across
+    /// runs or brute-forced back to short values.
+    #[default]
+    Hash,
+    /// Replace each value with a placeholder
+    Omit,
+}
+
+impl ContentRedaction {
+    /// Apply the policy to a single content value
+    #[must_use]
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Self::Full => text.to_string(),
+            Self::Truncate(max) => {
+                let total = text.chars().count();
+                if total <= max {
+                    text.to_string()
+                } else {
+                    let kept: String = text.chars().take(max).collect();
+                    format!("{kept}...[{} chars truncated]", total - max)
+                }
+            }
+            Self::Hash => format!("[hash:{:016x}]", content_hasher().hash_one(text)),
+            Self::Omit => format!("[{} chars omitted]", text.chars().count()),
+        }
+    }
+}
+
+/// Policy controlling what request and response logging may reveal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LogRedaction {
+    /// Whether request and response bodies are logged at all
+    pub log_bodies: bool,
+    /// How content inside logged bodies is rewritten
+    pub content: ContentRedaction,
+}
+
+impl LogRedaction {
+    /// Log bodies with content rewritten by the given policy
+    #[must_use]
+    pub const fn with_bodies(content: ContentRedaction) -> Self {
+        Self {
+            log_bodies: true,
+            content,
+        }
+    }
+
+    /// Render headers for logging, masking credentials
+    #[must_use]
+    pub fn redact_headers(&self, headers: &HeaderMap) -> String {
+        let rendered: Vec<String> = headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if *name == AUTHORIZATION {
+                    mask_authorization(value.to_str().unwrap_or_default())
+                } else if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                    "[redacted]".to_string()
+                } else {
+                    value.to_str().unwrap_or("[binary]").to_string()
+                };
+                format!("{name}: {value}")
+            })
+            .collect();
+        rendered.join(", ")
+    }
+
+    /// Render a body for logging, or `None` when bodies are not logged
+    ///
+    /// JSON bodies have their content fields rewritten; other bodies are
+    /// treated as a single content value.
+    #[must_use]
+    pub fn redact_body(&self, body: &[u8]) -> Option<String> {
+        if !self.log_bodies {
+            return None;
+        }
+        match serde_json::from_slice::<Value>(body) {
+            Ok(mut value) => {
+                self.redact_value(&mut value, false);
+                Some(value.to_string())
+            }
+            Err(_) => Some(self.content.apply(&String::from_utf8_lossy(body))),
+        }
+    }
+
+    /// Rewrite content strings in a JSON value in place
+    ///
+    /// Strings are rewritten when their closest key is a content key; nested
+    /// objects are inspected key by key so fields such as `role` stay readable.
+    /// `data:` URLs, such as inline images, are rewritten wherever they occur.
+    fn redact_value(&self, value: &mut Value, in_content: bool) {
+        match value {
+            Value::String(text) if self.content != ContentRedaction::Full && is_data_url(text) => {
+                *text = redact_data_url(text);
+            }
+            Value::String(text) if in_content => *text = self.content.apply(text),
+            Value::Array(items) => {
+                for item in items {
+                    self.redact_value(item, in_content);
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    self.redact_value(item, CONTENT_KEYS.contains(&key.as_str()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Check whether a string is a `data:` URL
+fn is_data_url(text: &str) -> bool {
+    text.get(..5)
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("data:"))
+}
+
+/// Replace a `data:` URL with its media type and length
+fn redact_data_url(text: &str) -> String {
+    let media_type = text[5..]
+        .split([';', ','])
+        .next()
+        .filter(|media_type| !media_type.is_empty())
+        .unwrap_or("text/plain");
+    format!(
+        "[data:{media_type}, {} chars omitted]",
+        text.chars().count()
+    )
+}
+
+/// Mask an `Authorization` header, keeping only the scheme and last characters
+fn mask_authorization(value: &str) -> String {
+    let (scheme, token) = value.split_once(' ').unwrap_or(("", value));
+    let visible: String = token
+        .chars()
+        .rev()
+        .take(4)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    let masked = if token.chars().count() > 8 {
+        format!("***{visible}")
+    } else {
+        "***".to_string()
+    };
+    if scheme.is_empty() {
+        masked
+    } else {
+        format!("{scheme} {masked}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{CONTENT_TYPE, HeaderValue};
+    use serde_json::json;
+
+    #[test]
+    fn test_headers_mask_api_key() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("Bearer sk-secret-key-1234"),
+        );
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let rendered = LogRedaction::default().redact_headers(&headers);
+        assert!(rendered.contains("authorization: Bearer ***1234"));
+        assert!(rendered.contains("content-type: application/json"));
+        assert!(!rendered.contains("sk-secret"));
+    }
+
+    #[test]
+    fn test_body_content_is_redacted_by_policy() {
+        let body = serde_json::to_vec(&json!({
+            "model": "gpt-4o",
+            "input": [{"role": "user", "content": "my card is 4111 1111"}]
+        }))
+        .unwrap();
+
+        assert!(LogRedaction::default().redact_body(&body).is_none());
+
+        let hashed = LogRedaction::with_bodies(ContentRedaction::Hash)
+            .redact_body(&body)
+            .unwrap();
+        assert!(hashed.contains("gpt-4o"));
+        assert!(hashed.contains("\"role\":\"user\""));
+        assert!(hashed.contains("\"content\":\"[hash:"));
+        assert!(!hashed.contains("4111"));
+
+        let truncated = LogRedaction::with_bodies(ContentRedaction::Truncate(5))
+            .redact_body(&body)
+            .unwrap();
+        assert!(truncated.contains("my ca...[15 chars truncated]"));
+
+        assert_eq!(ContentRedaction::Omit.apply("abc"), "[3 chars omitted]");
+        assert_eq!(ContentRedaction::Full.apply("abc"), "abc");
+    }
+
+    #[test]
+    fn test_hash_is_keyed_and_stable_within_process() {
+        let hashed = ContentRedaction::Hash.apply("1234");
+        assert_eq!(hashed, ContentRedaction::Hash.apply("1234"));
+        assert_ne!(hashed, ContentRedaction::Hash.apply("1235"));
+
+        let mut unkeyed = std::collections::hash_map::DefaultHasher::new();
+        std::hash::Hash::hash("1234", &mut unkeyed);
+        let unkeyed = format!("[hash:{:016x}]", std::hash::Hasher::finish(&unkeyed));
+        assert_ne!(hashed, unkeyed);
+    }
+
+    #[test]
+    fn test_tool_arguments_outputs_and_data_urls_are_redacted() {
+        let body = serde_json::to_vec(&json!({
+            "input": [
+                {"type": "function_call", "name": "lookup", "arguments": "{\"ssn\":\"123\"}"},
+                {"type": "function_call_output", "call_id": "c1", "output": "ssn ok"},
+                {"type": "input_image", "image_url": "data:image/png;base64,iVBORw0KGgo="}
+            ],
+            "data": [{"b64_json": "aGVsbG8="}]
+        }))
+        .unwrap();
+
+        let redacted = LogRedaction::with_bodies(ContentRedaction::Omit)
+            .redact_body(&body)
+            .unwrap();
+        assert!(redacted.contains("\"name\":\"lookup\""));
+        assert!(redacted.contains("[data:image/png, 34 chars omitted]"));
+        for secret in ["ssn", "iVBOR", "aGVsbG8"] {
+            assert!(!redacted.contains(secret), "{secret} leaked: {redacted}");
+        }
+    }
+}
@@ -1,8 +1,63 @@
 //! Response handling and error conversion functionality
 
+use crate::api::base::backend::{RawResponse, RequestParts};
 use crate::api::base::client::HttpClient;
 use crate::error::{OpenAIError, Result};
+use reqwest::Method;
+use serde::Deserialize;
 use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::ops::Deref;
+
+/// A typed response together with the JSON it was decoded from
+///
+/// Lets callers read fields the crate does not model yet while still using
+/// the typed struct for everything it does.
+#[derive(Debug, Clone)]
+pub struct WithRaw<T> {
+    /// The decoded response
+    pub value: T,
+    /// The response body exactly as returned by the API
+    pub raw: Value,
+}
+
+impl<T> WithRaw<T> {
+    /// Get the raw JSON body
+    #[must_use]
+    pub const fn raw(&self) -> &Value {
+        &self.raw
+    }
+
+    /// Discard the raw JSON and keep the typed value
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Split into the typed value and the raw JSON
+    pub fn into_parts(self) -> (T, Value) {
+        (self.value, self.raw)
+    }
+}
+
+impl<T> Deref for WithRaw<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Convert an error response body into an `OpenAIError`
+fn error_from_body(status: u16, error_text: String) -> OpenAIError {
+    // Try to parse as API error response
+    serde_json::from_str::<crate::error::ApiErrorResponse>(&error_text).map_or_else(
+        |_| OpenAIError::ApiError {
+            status,
+            message: error_text,
+        },
+        |api_error| OpenAIError::from_api_response(status, api_error),
+    )
+}
 
 impl HttpClient {
     /// Handle error response by extracting text and parsing as API error
@@ -15,17 +70,7 @@ impl HttpClient {
             .text()
             .await
             .unwrap_or_else(|_| "Unknown error".to_string());
-
-        // Try to parse as API error response
-        serde_json::from_str::<crate::error::ApiErrorResponse>(&error_text).map_or_else(
-            |_| {
-                Err(OpenAIError::ApiError {
-                    status: status.as_u16(),
-                    message: error_text,
-                })
-            },
-            |api_error| Err(OpenAIError::from_api_response(status.as_u16(), api_error)),
-        )
+        Err(error_from_body(status.as_u16(), error_text))
     }
 
     /// Handle API response and convert to the desired type
@@ -45,64 +90,92 @@ impl HttpClient {
         }
     }
 
-    /// Extract raw content from a successful response
-    async fn extract_raw_content<F, R>(
-        &self,
-        response: reqwest::Response,
-        status: reqwest::StatusCode,
-        extractor: F,
-        error_context: &str,
-    ) -> Result<R>
+    /// Handle a backend response and convert it to the desired type
+    pub fn handle_raw_response<T>(&self, response: RawResponse) -> Result<T>
     where
-        F: FnOnce(
-            reqwest::Response,
-        )
-            -> futures::future::BoxFuture<'static, std::result::Result<R, reqwest::Error>>,
-        R: 'static,
+        T: DeserializeOwned,
     {
-        if status.is_success() {
-            extractor(response).await.map_err(crate::map_err!(
-                RequestError,
-                error_context,
-                to_string
+        let response = self.check_raw_status(response)?;
+        serde_json::from_slice(&response.body).map_err(|e| {
+            OpenAIError::ParseError(format!(
+                "Failed to parse response: {e}. Response: {}",
+                response.text()
             ))
+        })
+    }
+
+    /// Handle a backend response, keeping the raw JSON next to the typed value
+    pub fn handle_raw_response_with_raw<T>(&self, response: RawResponse) -> Result<WithRaw<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self.check_raw_status(response)?;
+        let parse_error = |e: serde_json::Error| {
+            OpenAIError::ParseError(format!(
+                "Failed to parse response: {e}. Response: {}",
+                response.text()
+            ))
+        };
+        let raw: Value = serde_json::from_slice(&response.body).map_err(parse_error)?;
+        let value = T::deserialize(&raw).map_err(parse_error)?;
+        Ok(WithRaw { value, raw })
+    }
+
+    /// Pass through a successful backend response, converting failures into errors
+    pub fn check_raw_status(&self, response: RawResponse) -> Result<RawResponse> {
+        if response.is_success() {
+            Ok(response)
         } else {
-            self.handle_error_response(response, status).await
+            Err(error_from_body(response.status.as_u16(), response.text()))
         }
     }
 
-    /// Make a GET request and return raw text content
-    pub async fn get_text(&self, path: &str) -> Result<String> {
+    /// Make a GET request and return the successful backend response
+    async fn get_raw(&self, path: &str) -> Result<RawResponse> {
         let url = self.build_simple_url(path);
         let headers = self.build_headers()?;
+        let request = RequestParts::new(Method::GET, url).with_headers(headers);
+        self.check_raw_status(self.execute(request).await?)
+    }
 
-        let response = self.client().get(&url).headers(headers).send().await?;
-        let status = response.status();
-
-        self.extract_raw_content(
-            response,
-            status,
-            |r| Box::pin(async move { r.text().await }),
-            "Failed to read response text",
-        )
-        .await
+    /// Make a GET request and return the typed response along with its raw JSON
+    pub async fn get_with_raw<T>(&self, path: &str) -> Result<WithRaw<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let url = self.build_simple_url(path);
+        let headers = self.build_headers()?;
+        let request = RequestParts::new(Method::GET, url).with_headers(headers);
+        self.handle_raw_response_with_raw(self.execute(request).await?)
     }
 
-    /// Make a GET request and return raw bytes
-    pub async fn get_bytes(&self, path: &str) -> Result<Vec<u8>> {
+    /// Make a POST request and return the typed response along with its raw JSON
+    pub async fn post_with_raw<T, B>(&self, path: &str, body: &B) -> Result<WithRaw<T>>
+    where
+        T: DeserializeOwned,
+        B: serde::Serialize,
+    {
         let url = self.build_simple_url(path);
         let headers = self.build_headers()?;
+        let request = RequestParts::new(Method::POST, url)
+            .with_headers(headers)
+            .with_body(serde_json::to_vec(body)?);
+        self.handle_raw_response_with_raw(self.execute(request).await?)
+    }
 
-        let response = self.client().get(&url).headers(headers).send().await?;
-        let status = response.status();
+    /// Make a GET request and return raw text content
+    pub async fn get_text(&self, path: &str) -> Result<String> {
+        let response = self.get_raw(path).await?;
+        String::from_utf8(response.body).map_err(crate::map_err!(
+            RequestError,
+            "Failed to read response text",
+            to_string
+        ))
+    }
 
-        self.extract_raw_content(
-            response,
-            status,
-            |r| Box::pin(async move { r.bytes().await.map(|b| b.to_vec()) }),
-            "Failed to read response bytes",
-        )
-        .await
+    /// Make a GET request and return raw bytes
+    pub async fn get_bytes(&self, path: &str) -> Result<Vec<u8>> {
+        Ok(self.get_raw(path).await?.body)
     }
 
     /// Make a POST request and return raw bytes with content type
@@ -116,31 +189,51 @@ impl HttpClient {
     {
         let url = self.build_simple_url(path);
         let headers = self.build_headers()?;
+        let request = RequestParts::new(Method::POST, url)
+            .with_headers(headers)
+            .with_body(serde_json::to_vec(body)?);
 
-        let response = self
-            .client()
-            .post(&url)
-            .headers(headers)
-            .json(body)
-            .send()
-            .await?;
+        let response = self.check_raw_status(self.execute(request).await?)?;
+        let content_type = response
+            .header("content-type")
+            .unwrap_or("application/octet-stream")
+            .to_string();
 
-        let status = response.status();
-        if status.is_success() {
-            let content_type = response
-                .headers()
-                .get("content-type")
-                .and_then(|v| v.to_str().ok())
-                .unwrap_or("application/octet-stream")
-                .to_string();
-
-            let bytes = response.bytes().await.map_err(|e| {
-                OpenAIError::RequestError(format!("Failed to read response bytes: {e}"))
-            })?;
-
-            Ok((bytes.to_vec(), content_type))
-        } else {
-            self.handle_error_response(response, status).await
-        }
+        Ok((response.body, content_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::models::Model;
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_with_raw_keeps_unmodeled_fields() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/v1/models/gpt-4o");
+            then.status(200).json_body(json!({
+                "id": "gpt-4o",
+                "object": "model",
+                "created": 1_715_367_049,
+                "owned_by": "system",
+                "context_window": {"input": 128_000}
+            }));
+        });
+
+        let client = HttpClient::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let response = client
+            .get_with_raw::<Model>("/v1/models/gpt-4o")
+            .await
+            .unwrap();
+
+        assert_eq!(response.id, "gpt-4o");
+        assert_eq!(response.raw()["context_window"]["input"], 128_000);
+        let (model, raw) = response.into_parts();
+        assert_eq!(model.owned_by, "system");
+        assert_eq!(raw["object"], "model");
     }
 }
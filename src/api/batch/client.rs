@@ -1,15 +1,21 @@
 //! Main BatchApi client for OpenAI batch processing operations
 
-use crate::api::base::HttpClient;
+use crate::api::base::{Deadline, HttpClient};
 use crate::api::common::ApiClientConstructors;
 use crate::error::Result;
 use std::path::Path;
+use std::time::Duration;
 
 use super::file_ops::FileOperations;
 use super::helpers::BatchHelpers;
 use super::models::{Batch, BatchList, FileUploadResponse};
 use super::operations::BatchOperations;
 use super::reports::BatchReport;
+#[cfg(feature = "yara")]
+use super::reports::{YaraQualityReport, YaraRuleExpectation};
+use super::validation::BatchValidationReport;
+#[cfg(feature = "yara")]
+use std::collections::HashMap;
 
 /// `OpenAI` Batch API client for asynchronous batch processing
 #[derive(Debug, Clone)]
@@ -75,8 +81,32 @@ impl BatchApi {
             .await
     }
 
+    /// Waits for a batch to complete without running past `deadline`
+    ///
+    /// Share one [`Deadline`] between several waits to bound their total time.
+    pub async fn wait_for_completion_until(
+        &self,
+        batch_id: &str,
+        poll_interval: Duration,
+        deadline: &Deadline,
+    ) -> Result<Batch> {
+        let ops = BatchOperations::new(&self.http_client);
+        ops.wait_for_completion_until(batch_id, poll_interval, deadline)
+            .await
+    }
+
     // File Operations
 
+    /// Validates a batch JSONL file locally without uploading it
+    ///
+    /// Reports per-line problems with line numbers and any `custom_id` used
+    /// more than once. Check [`BatchValidationReport::is_valid`] before
+    /// calling [`Self::upload_batch_file`].
+    pub async fn validate_jsonl(&self, file_path: &Path) -> Result<BatchValidationReport> {
+        let content = crate::helpers::read_string(file_path).await?;
+        Ok(BatchValidationReport::from_jsonl(&content))
+    }
+
     /// Uploads a JSONL file for batch processing
     pub async fn upload_batch_file(&self, file_path: &Path) -> Result<FileUploadResponse> {
         let file_ops = FileOperations::new(&self.http_client);
@@ -147,6 +177,22 @@ impl BatchApi {
             .generate_batch_report(results_file, errors_file, report_path)
             .await
     }
+
+    /// Checks the YARA rules in batch results against expected behaviour
+    ///
+    /// Each extracted rule is compiled with the validator and scanned against
+    /// the samples in the expectation keyed by its request's `custom_id`.
+    #[cfg(feature = "yara")]
+    pub async fn generate_yara_quality_report(
+        &self,
+        results_file: &Path,
+        expectations: &HashMap<String, YaraRuleExpectation>,
+    ) -> Result<YaraQualityReport> {
+        let helpers = BatchHelpers::new(&self.http_client);
+        helpers
+            .generate_yara_quality_report(results_file, expectations)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -160,6 +206,54 @@ mod tests {
         assert_eq!(api.http_client.base_url(), "https://api.openai.com");
     }
 
+    #[tokio::test]
+    async fn test_validate_jsonl_reads_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("batch.jsonl");
+        std::fs::write(
+            &path,
+            "{\"custom_id\":\"a\",\"method\":\"POST\",\"url\":\"/v1/embeddings\",\"body\":{}}\n{}\n",
+        )
+        .unwrap();
+
+        let api = BatchApi::new("test-key").unwrap();
+        let report = api.validate_jsonl(&path).await.unwrap();
+        assert_eq!(report.valid_requests, 1);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].line, 2);
+
+        assert!(
+            api.validate_jsonl(&dir.path().join("missing.jsonl"))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_until_clamps_requests_to_deadline() {
+        let server = httpmock::MockServer::start();
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/v1/batches/batch_1");
+            then.status(200).delay(Duration::from_secs(5)).body("{}");
+        });
+
+        let api = BatchApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let started = std::time::Instant::now();
+        let err = api
+            .wait_for_completion_until(
+                "batch_1",
+                Duration::from_secs(1),
+                &Deadline::after(Duration::from_millis(100)),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(err.is_deadline_exceeded(), "{err}");
+        assert!(err.to_string().contains("batch batch_1"));
+    }
+
     #[test]
     fn test_batch_api_custom_base_url() {
         let api = BatchApi::new_with_base_url("test-key", "https://custom.api.com").unwrap();
@@ -9,8 +9,12 @@ use std::path::Path;
 use tokio::fs;
 
 use super::reports::BatchReport;
+#[cfg(feature = "yara")]
+use super::reports::{YaraQualityReport, YaraRuleExpectation};
 use super::types::YaraRuleInfo;
 use super::yara::YaraProcessor;
+#[cfg(feature = "yara")]
+use std::collections::HashMap;
 
 /// Helper operations for batch processing
 pub struct BatchHelpers<'a> {
@@ -62,6 +66,25 @@ impl<'a> BatchHelpers<'a> {
         Ok(report)
     }
 
+    /// Extracts YARA rules from batch results and evaluates them against expectations
+    #[cfg(feature = "yara")]
+    pub async fn generate_yara_quality_report(
+        &self,
+        results_file: &Path,
+        expectations: &HashMap<String, YaraRuleExpectation>,
+    ) -> Result<YaraQualityReport> {
+        let content = crate::helpers::read_string(results_file).await?;
+
+        let mut rules = Vec::new();
+        for line in content.lines() {
+            if let Some(rule_info) = self.parse_yara_response_line(line)? {
+                rules.push(rule_info);
+            }
+        }
+
+        Ok(YaraQualityReport::evaluate(&rules, expectations))
+    }
+
     /// Ensures the output directory exists
     async fn ensure_output_directory(&self, output_dir: &Path) -> Result<()> {
         fs::create_dir_all(output_dir).await.map_err(|e| {
@@ -5,6 +5,7 @@
 //!
 //! ## Features
 //!
+//! - **Local Validation**: Check JSONL batch files for malformed requests before upload
 //! - **File Upload**: Upload JSONL batch files to OpenAI
 //! - **Batch Creation**: Create and submit batch processing jobs  
 //! - **Status Monitoring**: Check batch progress and completion status
@@ -12,6 +13,7 @@
 //! - **Batch Management**: List, cancel, and manage batch operations
 //! - **YARA Processing**: Extract and process YARA rules from batch results
 //! - **Comprehensive Reporting**: Generate detailed analysis reports
+//! - **Rule Quality Checks**: Compile generated YARA rules and scan them against expected samples
 //!
 //! ## Example
 //!
@@ -49,13 +51,18 @@ pub mod models;
 pub mod operations;
 pub mod reports;
 pub mod types;
+pub mod validation;
 pub mod yara;
 
 // Re-export main types and functions for convenience
 pub use client::BatchApi;
 pub use models::{Batch, BatchList, CreateBatchRequest, FileUploadResponse};
-pub use reports::BatchReport;
+pub use reports::{
+    BatchReport, YaraQualityReport, YaraRuleDiagnostic, YaraRuleExpectation, YaraSample,
+    YaraSampleResult,
+};
 pub use types::{BatchRequestCounts, BatchStatus, YaraRuleInfo};
+pub use validation::{BatchLineError, BatchValidationReport, DuplicateCustomId};
 pub use yara::YaraProcessor;
 
 // Keep legacy re-exports for API compatibility
@@ -2,6 +2,8 @@
 
 use crate::{De, Ser};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
 use super::types::{BatchRequestCounts, BatchStatus};
 
@@ -59,8 +61,13 @@ pub struct Batch {
     pub request_counts: BatchRequestCounts,
     /// Optional metadata for the batch
     pub metadata: Option<serde_json::Value>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(Batch);
+
 /// Request to create a new batch
 #[derive(Debug, Clone, Ser)]
 pub struct CreateBatchRequest {
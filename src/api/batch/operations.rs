@@ -3,13 +3,13 @@
 //! This module contains the main batch operations including creation, status checking,
 //! cancellation, listing, and completion waiting.
 
-use crate::api::base::HttpClient;
+use crate::api::base::{Deadline, HttpClient};
 use crate::constants::endpoints;
 use crate::error::{OpenAIError, Result};
+use crate::helpers::backoff::Backoff;
 use tokio::time;
 
 use super::models::{Batch, BatchList, CreateBatchRequest};
-use super::types::BatchStatus;
 
 /// Core batch operations implementation
 pub struct BatchOperations<'a> {
@@ -87,34 +87,36 @@ impl<'a> BatchOperations<'a> {
     ) -> Result<Batch> {
         let poll_interval = time::Duration::from_secs(poll_interval_secs.unwrap_or(30));
         let max_wait = time::Duration::from_secs(max_wait_secs.unwrap_or(24 * 60 * 60)); // 24 hours
-        let start_time = std::time::Instant::now();
+        self.wait_for_completion_until(batch_id, poll_interval, &Deadline::after(max_wait))
+            .await
+    }
+
+    /// Waits for a batch to complete without running past `deadline`
+    ///
+    /// Each status request is cut short when the deadline passes, so the
+    /// total wait never exceeds the deadline's budget. Poll intervals are
+    /// jittered by up to 10% so many waiters do not poll in lockstep.
+    pub async fn wait_for_completion_until(
+        &self,
+        batch_id: &str,
+        poll_interval: time::Duration,
+        deadline: &Deadline,
+    ) -> Result<Batch> {
+        let operation = format!("waiting for batch {batch_id} to complete");
+        let mut intervals = Backoff::polling(poll_interval);
 
         loop {
-            let batch = self.get_batch_status(batch_id).await?;
+            let batch = deadline
+                .run(&operation, self.get_batch_status(batch_id))
+                .await?;
 
             // Check if batch has reached a terminal state
-            match batch.status {
-                BatchStatus::Completed
-                | BatchStatus::Failed
-                | BatchStatus::Expired
-                | BatchStatus::Cancelled => {
-                    return Ok(batch);
-                }
-                _ => {
-                    // Continue polling
-                }
-            }
-
-            // Check if we've exceeded the maximum wait time
-            if start_time.elapsed() > max_wait {
-                return Err(OpenAIError::RequestError(format!(
-                    "Timeout waiting for batch {} to complete after {} seconds",
-                    batch_id,
-                    max_wait.as_secs()
-                )));
+            if batch.status.is_terminal() {
+                return Ok(batch);
             }
 
-            time::sleep(poll_interval).await;
+            let interval = intervals.next().unwrap_or(poll_interval);
+            deadline.sleep(&operation, interval).await?;
         }
     }
 }
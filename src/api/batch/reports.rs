@@ -4,6 +4,11 @@ use crate::{De, Ser};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+#[cfg(feature = "yara")]
+use super::types::YaraRuleInfo;
+#[cfg(feature = "yara")]
+use crate::testing::{yara_scanner::YaraScanner, yara_validator::YaraValidator};
+
 /// Comprehensive report generated from batch processing results
 #[derive(Debug, Clone, Ser, De)]
 pub struct BatchReport {
@@ -203,6 +208,297 @@ impl Default for BatchReport {
     }
 }
 
+/// Sample data a generated rule is checked against
+#[derive(Debug, Clone, Ser, De)]
+pub struct YaraSample {
+    /// Name used in diagnostics
+    pub name: String,
+    /// Sample contents
+    pub data: Vec<u8>,
+    /// Whether the rule is expected to match the sample
+    pub should_match: bool,
+}
+
+/// Expected behaviour of the rule generated for one batch request
+#[derive(Debug, Clone, Default, Ser, De)]
+pub struct YaraRuleExpectation {
+    /// Samples the rule is scanned against
+    pub samples: Vec<YaraSample>,
+}
+
+impl YaraRuleExpectation {
+    /// Creates an expectation that only requires the rule to compile
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sample the rule must match
+    #[must_use]
+    pub fn matching(mut self, name: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        self.samples.push(YaraSample {
+            name: name.into(),
+            data: data.into(),
+            should_match: true,
+        });
+        self
+    }
+
+    /// Adds a sample the rule must not match
+    #[must_use]
+    pub fn not_matching(mut self, name: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        self.samples.push(YaraSample {
+            name: name.into(),
+            data: data.into(),
+            should_match: false,
+        });
+        self
+    }
+}
+
+/// Outcome of scanning one sample with a generated rule
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct YaraSampleResult {
+    /// Name of the sample
+    pub name: String,
+    /// Whether the rule was expected to match
+    pub expected_match: bool,
+    /// Whether the rule actually matched
+    pub matched: bool,
+}
+
+impl YaraSampleResult {
+    /// Returns true if the scan outcome met the expectation
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.expected_match == self.matched
+    }
+}
+
+/// Quality diagnostics for a single generated rule
+#[derive(Debug, Clone, Default, Ser, De)]
+pub struct YaraRuleDiagnostic {
+    /// Custom ID of the batch request that produced the rule
+    pub custom_id: String,
+    /// Rule name, if one could be extracted
+    pub rule_name: Option<String>,
+    /// Whether the rule compiled
+    pub compiled: bool,
+    /// Compilation and scan errors
+    pub errors: Vec<String>,
+    /// Non-fatal validator warnings
+    pub warnings: Vec<String>,
+    /// Results of the sample scans, in expectation order
+    pub sample_results: Vec<YaraSampleResult>,
+}
+
+impl YaraRuleDiagnostic {
+    /// Creates an empty diagnostic for the given request
+    #[must_use]
+    pub fn new(custom_id: impl Into<String>) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns true if the rule compiled, raised no errors and met every sample expectation
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.compiled
+            && self.errors.is_empty()
+            && self.sample_results.iter().all(YaraSampleResult::passed)
+    }
+}
+
+/// Quality report comparing generated YARA rules against expectations
+#[derive(Debug, Clone, Default, Ser, De)]
+pub struct YaraQualityReport {
+    /// Number of rules evaluated
+    pub total_rules: usize,
+    /// Number of rules that passed every check
+    pub passed_rules: usize,
+    /// Number of rules that failed at least one check
+    pub failed_rules: usize,
+    /// Number of rules that did not compile
+    pub compile_failures: usize,
+    /// Number of compiled rules with at least one unexpected sample result
+    pub sample_failures: usize,
+    /// Custom IDs that had an expectation but no extracted rule
+    pub missing_rules: Vec<String>,
+    /// Per-rule diagnostics, in evaluation order
+    pub rules: Vec<YaraRuleDiagnostic>,
+}
+
+impl YaraQualityReport {
+    /// Creates an empty quality report
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validates each rule and scans the samples from its expectation
+    ///
+    /// Rules without an expectation only need to compile. Expectations whose
+    /// custom ID has no matching rule are listed in `missing_rules`.
+    #[cfg(feature = "yara")]
+    #[must_use]
+    pub fn evaluate(
+        rules: &[YaraRuleInfo],
+        expectations: &HashMap<String, YaraRuleExpectation>,
+    ) -> Self {
+        let validator = YaraValidator::new();
+        let mut report = Self::new();
+
+        for rule in rules {
+            report.add_diagnostic(Self::diagnose_rule(
+                &validator,
+                rule,
+                expectations.get(&rule.custom_id),
+            ));
+        }
+
+        report.missing_rules = expectations
+            .keys()
+            .filter(|id| !rules.iter().any(|rule| &rule.custom_id == *id))
+            .cloned()
+            .collect();
+        report.missing_rules.sort();
+        report
+    }
+
+    /// Compiles a single rule and scans its expected samples
+    #[cfg(feature = "yara")]
+    fn diagnose_rule(
+        validator: &YaraValidator,
+        rule: &YaraRuleInfo,
+        expectation: Option<&YaraRuleExpectation>,
+    ) -> YaraRuleDiagnostic {
+        let mut diagnostic = YaraRuleDiagnostic::new(rule.custom_id.clone());
+
+        let validation = match validator.validate_rule(&rule.rule_content) {
+            Ok(validation) => validation,
+            Err(e) => {
+                diagnostic.errors.push(format!("{e:#}"));
+                return diagnostic;
+            }
+        };
+        diagnostic.compiled = validation.is_valid;
+        diagnostic
+            .errors
+            .extend(validation.errors.iter().map(ToString::to_string));
+        diagnostic.rule_name = validation.rule_name;
+        diagnostic.warnings = validation.warnings;
+
+        let Some(expectation) =
+            expectation.filter(|e| diagnostic.compiled && !e.samples.is_empty())
+        else {
+            return diagnostic;
+        };
+
+        let scanner = match YaraScanner::from_source(&rule.rule_content) {
+            Ok(scanner) => scanner,
+            Err(e) => {
+                diagnostic.errors.push(format!("{e:#}"));
+                return diagnostic;
+            }
+        };
+
+        for sample in &expectation.samples {
+            match scanner.scan_bytes(&sample.data) {
+                Ok(matches) => diagnostic.sample_results.push(YaraSampleResult {
+                    name: sample.name.clone(),
+                    expected_match: sample.should_match,
+                    matched: !matches.is_empty(),
+                }),
+                Err(e) => diagnostic
+                    .errors
+                    .push(format!("Failed to scan sample '{}': {e:#}", sample.name)),
+            }
+        }
+
+        diagnostic
+    }
+
+    /// Adds a rule diagnostic and updates the pass/fail counts
+    pub fn add_diagnostic(&mut self, diagnostic: YaraRuleDiagnostic) {
+        self.total_rules += 1;
+        if diagnostic.passed() {
+            self.passed_rules += 1;
+        } else {
+            self.failed_rules += 1;
+            if !diagnostic.compiled {
+                self.compile_failures += 1;
+            } else if !diagnostic
+                .sample_results
+                .iter()
+                .all(YaraSampleResult::passed)
+            {
+                self.sample_failures += 1;
+            }
+        }
+        self.rules.push(diagnostic);
+    }
+
+    /// Calculates the pass rate as a percentage
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pass_rate(&self) -> f64 {
+        if self.total_rules == 0 {
+            0.0
+        } else {
+            (self.passed_rules as f64 / self.total_rules as f64) * 100.0
+        }
+    }
+
+    /// Generates a formatted report text
+    #[must_use]
+    pub fn generate_report_text(&self) -> String {
+        use std::fmt::Write;
+
+        let mut report = String::from("# YARA Rule Quality Report\n\n");
+        report.push_str("## Summary\n\n");
+        let _ = writeln!(report, "- **Rules Evaluated**: {}", self.total_rules);
+        let _ = writeln!(report, "- **Passed**: {}", self.passed_rules);
+        let _ = writeln!(report, "- **Failed**: {}", self.failed_rules);
+        let _ = writeln!(report, "- **Compile Failures**: {}", self.compile_failures);
+        let _ = writeln!(report, "- **Sample Failures**: {}", self.sample_failures);
+        let _ = writeln!(report, "- **Pass Rate**: {:.1}%\n", self.pass_rate());
+
+        if !self.missing_rules.is_empty() {
+            report.push_str("## Missing Rules\n\n");
+            for custom_id in &self.missing_rules {
+                let _ = writeln!(report, "- {custom_id}");
+            }
+            report.push('\n');
+        }
+
+        let failures: Vec<&YaraRuleDiagnostic> =
+            self.rules.iter().filter(|rule| !rule.passed()).collect();
+        if !failures.is_empty() {
+            report.push_str("## Failed Rules\n\n");
+            for rule in failures {
+                let name = rule.rule_name.as_deref().unwrap_or("<unnamed>");
+                let _ = writeln!(report, "### {} ({name})\n", rule.custom_id);
+                for error in &rule.errors {
+                    let _ = writeln!(report, "- Error: {error}");
+                }
+                for sample in rule.sample_results.iter().filter(|s| !s.passed()) {
+                    let expected = if sample.expected_match {
+                        "match"
+                    } else {
+                        "no match"
+                    };
+                    let _ = writeln!(report, "- Sample '{}': expected {expected}", sample.name);
+                }
+                report.push('\n');
+            }
+        }
+
+        report
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,6 +565,75 @@ mod tests {
         assert_eq!(report.error_types.get("timeout"), Some(&1));
     }
 
+    fn compiled_rule(custom_id: &str, samples: &[(bool, bool)]) -> YaraRuleDiagnostic {
+        let mut diagnostic = YaraRuleDiagnostic::new(custom_id);
+        diagnostic.compiled = true;
+        diagnostic.sample_results = samples
+            .iter()
+            .enumerate()
+            .map(|(i, (expected_match, matched))| YaraSampleResult {
+                name: format!("sample-{i}"),
+                expected_match: *expected_match,
+                matched: *matched,
+            })
+            .collect();
+        diagnostic
+    }
+
+    #[test]
+    fn test_yara_quality_report_counts() {
+        let mut report = YaraQualityReport::new();
+        report.add_diagnostic(compiled_rule("ok", &[(true, true), (false, false)]));
+        report.add_diagnostic(compiled_rule("missed", &[(true, false)]));
+        let mut broken = YaraRuleDiagnostic::new("broken");
+        broken.errors.push("syntax error".to_string());
+        report.add_diagnostic(broken);
+        report.missing_rules.push("absent".to_string());
+
+        assert_eq!(report.total_rules, 3);
+        assert_eq!(report.passed_rules, 1);
+        assert_eq!(report.failed_rules, 2);
+        assert_eq!(report.compile_failures, 1);
+        assert_eq!(report.sample_failures, 1);
+        assert!((report.pass_rate() - 100.0 / 3.0).abs() < 0.01);
+
+        let text = report.generate_report_text();
+        assert!(text.contains("## Missing Rules"));
+        assert!(text.contains("- absent"));
+        assert!(text.contains("### missed (<unnamed>)"));
+        assert!(text.contains("Sample 'sample-0': expected match"));
+        assert!(text.contains("- Error: syntax error"));
+        assert!(!text.contains("### ok"));
+    }
+
+    #[cfg(feature = "yara")]
+    #[test]
+    fn test_yara_quality_report_evaluate() {
+        let rules = vec![
+            YaraRuleInfo::new(
+                "pe".to_string(),
+                "rule detect_pe { strings: $mz = { 4D 5A } condition: $mz at 0 }".to_string(),
+            ),
+            YaraRuleInfo::new("bad".to_string(), "rule broken { condition: }".to_string()),
+        ];
+        let mut expectations = HashMap::new();
+        expectations.insert(
+            "pe".to_string(),
+            YaraRuleExpectation::new()
+                .matching("pe", b"MZ\x90\x00".to_vec())
+                .not_matching("text", b"hello".to_vec()),
+        );
+        expectations.insert("absent".to_string(), YaraRuleExpectation::new());
+
+        let report = YaraQualityReport::evaluate(&rules, &expectations);
+        assert_eq!(report.passed_rules, 1);
+        assert_eq!(report.compile_failures, 1);
+        assert_eq!(report.missing_rules, vec!["absent".to_string()]);
+        assert_eq!(report.rules[0].rule_name.as_deref(), Some("detect_pe"));
+        assert_eq!(report.rules[0].sample_results.len(), 2);
+        assert!(!report.rules[1].errors.is_empty());
+    }
+
     #[test]
     fn test_report_generation() {
         let mut report = BatchReport::new();
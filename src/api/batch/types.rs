@@ -25,6 +25,17 @@ pub enum BatchStatus {
     Cancelled,
 }
 
+impl BatchStatus {
+    /// Returns true if the batch can no longer change state
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed | Self::Failed | Self::Expired | Self::Cancelled
+        )
+    }
+}
+
 impl std::fmt::Display for BatchStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let status = match self {
@@ -82,6 +93,18 @@ mod tests {
         assert_eq!(BatchStatus::Completed.to_string(), "completed");
     }
 
+    #[test]
+    fn test_batch_status_is_terminal() {
+        assert!(BatchStatus::Completed.is_terminal());
+        assert!(BatchStatus::Failed.is_terminal());
+        assert!(BatchStatus::Expired.is_terminal());
+        assert!(BatchStatus::Cancelled.is_terminal());
+        assert!(!BatchStatus::Validating.is_terminal());
+        assert!(!BatchStatus::InProgress.is_terminal());
+        assert!(!BatchStatus::Finalizing.is_terminal());
+        assert!(!BatchStatus::Cancelling.is_terminal());
+    }
+
     #[test]
     fn test_yara_rule_info() {
         let rule_info = YaraRuleInfo::new(
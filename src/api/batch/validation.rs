@@ -0,0 +1,205 @@
+//! Local validation of batch input files
+//!
+//! Checks a JSONL batch file line by line before it is uploaded, so malformed
+//! requests and duplicate `custom_id` values are caught without waiting for
+//! the server-side validation step.
+
+use crate::{De, Ser};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A problem found on a single line of a batch input file
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct BatchLineError {
+    /// 1-based line number in the file
+    pub line: usize,
+    /// Description of the problem
+    pub message: String,
+}
+
+/// A `custom_id` that appears on more than one line
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct DuplicateCustomId {
+    /// The repeated identifier
+    pub custom_id: String,
+    /// 1-based line numbers on which it appears
+    pub lines: Vec<usize>,
+}
+
+/// Result of validating a batch input file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Ser, De)]
+pub struct BatchValidationReport {
+    /// Number of non-empty lines checked
+    pub total_requests: usize,
+    /// Number of lines that are well-formed requests
+    pub valid_requests: usize,
+    /// Per-line problems, in line order
+    pub errors: Vec<BatchLineError>,
+    /// Identifiers used by more than one request
+    pub duplicate_custom_ids: Vec<DuplicateCustomId>,
+}
+
+impl BatchValidationReport {
+    /// Validate the contents of a batch JSONL file
+    ///
+    /// Every line must be a JSON object with a non-empty string `custom_id`,
+    /// `method` set to `POST`, a `url` starting with `/v1/` and an object
+    /// `body`. Blank lines are reported as errors, except for a trailing
+    /// newline at the end of the file.
+    #[must_use]
+    pub fn from_jsonl(content: &str) -> Self {
+        let mut report = Self::default();
+        let mut custom_ids: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() {
+                report.push_error(line_number, "Line is empty");
+                continue;
+            }
+            report.total_requests += 1;
+
+            let request: Value = match serde_json::from_str(line) {
+                Ok(value) => value,
+                Err(e) => {
+                    report.push_error(line_number, format!("Invalid JSON: {e}"));
+                    continue;
+                }
+            };
+
+            match check_request(&request) {
+                Ok(custom_id) => {
+                    report.valid_requests += 1;
+                    custom_ids
+                        .entry(custom_id.to_string())
+                        .or_default()
+                        .push(line_number);
+                }
+                Err(message) => report.push_error(line_number, message),
+            }
+        }
+
+        report.duplicate_custom_ids = custom_ids
+            .into_iter()
+            .filter(|(_, lines)| lines.len() > 1)
+            .map(|(custom_id, lines)| DuplicateCustomId { custom_id, lines })
+            .collect();
+        report
+    }
+
+    /// Check whether the file can be uploaded as-is
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty() && self.duplicate_custom_ids.is_empty()
+    }
+
+    /// Record a problem on a line
+    fn push_error(&mut self, line: usize, message: impl Into<String>) {
+        self.errors.push(BatchLineError {
+            line,
+            message: message.into(),
+        });
+    }
+}
+
+/// Check the required fields of one request and return its `custom_id`
+fn check_request(request: &Value) -> std::result::Result<&str, String> {
+    let object = request
+        .as_object()
+        .ok_or_else(|| "Request must be a JSON object".to_string())?;
+
+    let custom_id = match object.get("custom_id") {
+        Some(Value::String(id)) if !id.trim().is_empty() => id.as_str(),
+        Some(Value::String(_)) => return Err("Field 'custom_id' must not be empty".to_string()),
+        Some(_) => return Err("Field 'custom_id' must be a string".to_string()),
+        None => return Err("Missing required field 'custom_id'".to_string()),
+    };
+
+    match object.get("method") {
+        Some(Value::String(method)) if method == "POST" => {}
+        Some(Value::String(method)) => {
+            return Err(format!("Field 'method' must be \"POST\", got \"{method}\""));
+        }
+        Some(_) => return Err("Field 'method' must be a string".to_string()),
+        None => return Err("Missing required field 'method'".to_string()),
+    }
+
+    match object.get("url") {
+        Some(Value::String(url)) if url.starts_with("/v1/") => {}
+        Some(Value::String(url)) => {
+            return Err(format!(
+                "Field 'url' must start with \"/v1/\", got \"{url}\""
+            ));
+        }
+        Some(_) => return Err("Field 'url' must be a string".to_string()),
+        None => return Err("Missing required field 'url'".to_string()),
+    }
+
+    match object.get("body") {
+        Some(Value::Object(_)) => Ok(custom_id),
+        Some(_) => Err("Field 'body' must be a JSON object".to_string()),
+        None => Err("Missing required field 'body'".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_LINE: &str = r#"{"custom_id":"req-1","method":"POST","url":"/v1/chat/completions","body":{"model":"gpt-4o"}}"#;
+
+    #[test]
+    fn test_valid_file() {
+        let content = format!("{VALID_LINE}\n{}\n", VALID_LINE.replace("req-1", "req-2"));
+        let report = BatchValidationReport::from_jsonl(&content);
+        assert!(report.is_valid());
+        assert_eq!(report.total_requests, 2);
+        assert_eq!(report.valid_requests, 2);
+    }
+
+    #[test]
+    fn test_reports_errors_with_line_numbers() {
+        let content = [
+            VALID_LINE,
+            "not json",
+            r#"{"method":"POST","url":"/v1/embeddings","body":{}}"#,
+            "",
+            r#"{"custom_id":"req-5","method":"GET","url":"/v1/embeddings","body":{}}"#,
+            r#"{"custom_id":"req-6","method":"POST","url":"/v1/embeddings","body":"text"}"#,
+        ]
+        .join("\n");
+
+        let report = BatchValidationReport::from_jsonl(&content);
+        assert!(!report.is_valid());
+        assert_eq!(report.total_requests, 5);
+        assert_eq!(report.valid_requests, 1);
+
+        let lines: Vec<usize> = report.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2, 3, 4, 5, 6]);
+        assert!(report.errors[0].message.starts_with("Invalid JSON"));
+        assert!(report.errors[1].message.contains("custom_id"));
+        assert!(report.errors[3].message.contains("\"GET\""));
+        assert!(report.errors[4].message.contains("body"));
+    }
+
+    #[test]
+    fn test_reports_duplicate_custom_ids() {
+        let content = [
+            VALID_LINE,
+            &VALID_LINE.replace("req-1", "req-2"),
+            VALID_LINE,
+        ]
+        .join("\n");
+
+        let report = BatchValidationReport::from_jsonl(&content);
+        assert!(report.errors.is_empty());
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.duplicate_custom_ids,
+            vec![DuplicateCustomId {
+                custom_id: "req-1".to_string(),
+                lines: vec![1, 3],
+            }]
+        );
+    }
+}
@@ -1,7 +1,8 @@
 //! Common utilities for API clients to reduce code duplication
 
 use crate::api::base::HttpClient;
-use crate::error::Result;
+use crate::error::{OpenAIError, Result};
+pub use crate::models::shared_traits::ListQueryParams;
 
 /// Common trait for API clients with standard constructors
 pub trait ApiClientConstructors: Sized {
@@ -18,6 +19,7 @@ pub trait ApiClientConstructors: Sized {
     ///
     /// Returns an error if the API key is empty or invalid
     fn new<S: Into<String>>(api_key: S) -> Result<Self> {
+        let api_key = validate_api_key(api_key)?;
         Ok(Self::from_http_client(HttpClient::new(api_key)?))
     }
 
@@ -32,12 +34,30 @@ pub trait ApiClientConstructors: Sized {
     ///
     /// Returns an error if the API key is empty or invalid
     fn new_with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
+        let api_key = validate_api_key(api_key)?;
         Ok(Self::from_http_client(HttpClient::new_with_base_url(
-            api_key, base_url,
+            api_key,
+            base_url.into(),
         )?))
     }
 }
 
+/// Reject an empty or whitespace-only API key
+///
+/// Shared by every client constructor so a blank key fails early with the
+/// same [`OpenAIError::Authentication`] error whichever API is created first.
+///
+/// # Errors
+///
+/// Returns an authentication error if the key is empty or only whitespace
+pub fn validate_api_key<S: Into<String>>(api_key: S) -> Result<String> {
+    let api_key = api_key.into();
+    if api_key.trim().is_empty() {
+        return Err(OpenAIError::authentication("API key cannot be empty"));
+    }
+    Ok(api_key)
+}
+
 /// Helper function to build query parameters for list operations
 pub fn build_list_query_params<T>(params: &T) -> Vec<(String, String)>
 where
@@ -61,18 +81,6 @@ where
     query_params
 }
 
-/// Common trait for list query parameters
-pub trait ListQueryParams {
-    /// Get the limit parameter
-    fn limit(&self) -> Option<u32>;
-    /// Get the order parameter as string
-    fn order_str(&self) -> Option<&str>;
-    /// Get the after cursor parameter
-    fn after(&self) -> Option<&String>;
-    /// Get the before cursor parameter
-    fn before(&self) -> Option<&String>;
-}
-
 /// Standard implementation for common list parameters
 #[derive(Debug, Clone, Default)]
 pub struct StandardListParams {
@@ -6,19 +6,52 @@
 
 use crate::models::containers::{
     CodeExecutionRequest, CodeExecutionResult, Container, ContainerConfig, ContainerFile,
-    ContainerFileList, ContainerList, ListContainersParams,
+    ContainerFileList, ContainerList, ContainerStatus, ListContainersParams,
 };
 use crate::{
-    api::{base::HttpClient, common::ApiClientConstructors, shared_utilities::FormBuilder},
+    api::{
+        base::{Deadline, HttpClient},
+        common::ApiClientConstructors,
+        shared_utilities::FormBuilder,
+    },
     constants::endpoints,
     error::{OpenAIError, Result},
+    helpers::backoff::Backoff,
 };
-use reqwest::multipart;
+use futures::StreamExt;
+use reqwest::{Method, multipart};
 use serde_json::json;
+use std::ops::Deref;
 use std::path::Path;
+use std::time::{Duration, Instant};
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+/// How long and how often to poll while waiting for a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollConfig {
+    /// Delay between status checks, jittered by up to 10%
+    pub interval: Duration,
+    /// Maximum time to wait before giving up
+    pub timeout: Duration,
+}
+
+impl PollConfig {
+    /// Create a poll configuration
+    #[must_use]
+    pub const fn new(interval: Duration, timeout: Duration) -> Self {
+        Self { interval, timeout }
+    }
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), Duration::from_secs(60))
+    }
+}
 
 /// Container Management API client
+#[derive(Clone)]
 pub struct ContainersApi {
     /// Shared HTTP client for making requests
     client: HttpClient,
@@ -38,6 +71,76 @@ impl ContainersApi {
         self.client.post("/v1/containers", &config).await
     }
 
+    /// Create a container and wait until it is ready for use
+    ///
+    /// Polls the container until it is `active` or `idle`. If it fails, expires
+    /// or does not become ready within `poll.timeout`, the container is deleted
+    /// before the error is returned so it does not keep accruing cost.
+    pub async fn create_and_wait(
+        &self,
+        config: ContainerConfig,
+        poll: PollConfig,
+    ) -> Result<Container> {
+        let container = self.create_container(config).await?;
+        match self.wait_until_ready(container, poll).await {
+            Ok(container) => Ok(container),
+            Err((container_id, error)) => {
+                let _ = self.delete_container(&container_id).await;
+                Err(error)
+            }
+        }
+    }
+
+    /// Create a container, wait until it is ready and tie its lifetime to a guard
+    ///
+    /// The container is deleted when the returned [`ContainerGuard`] is dropped.
+    pub async fn create_guarded(
+        &self,
+        config: ContainerConfig,
+        poll: PollConfig,
+    ) -> Result<ContainerGuard> {
+        let container = self.create_and_wait(config, poll).await?;
+        Ok(ContainerGuard::new(self.clone(), container))
+    }
+
+    /// Poll a container until it is ready, returning its ID with any error
+    async fn wait_until_ready(
+        &self,
+        mut container: Container,
+        poll: PollConfig,
+    ) -> std::result::Result<Container, (String, OpenAIError)> {
+        let deadline = Deadline::after(poll.timeout);
+        let mut intervals = Backoff::polling(poll.interval);
+
+        loop {
+            match container.status {
+                ContainerStatus::Active | ContainerStatus::Idle => return Ok(container),
+                ContainerStatus::Expired | ContainerStatus::Deleting | ContainerStatus::Error => {
+                    let message = format!(
+                        "Container {} entered status {:?} before becoming ready",
+                        container.id, container.status
+                    );
+                    return Err((container.id, OpenAIError::RequestError(message)));
+                }
+                ContainerStatus::Creating => {}
+            }
+
+            let operation = format!("waiting for container {} to become ready", container.id);
+            let polled = async {
+                let interval = intervals.next().unwrap_or(poll.interval);
+                deadline.sleep(&operation, interval).await?;
+                deadline
+                    .run(&operation, self.get_container(&container.id))
+                    .await
+            }
+            .await;
+            container = match polled {
+                Ok(container) => container,
+                Err(error) => return Err((container.id, error)),
+            };
+        }
+    }
+
     /// Get container details
     pub async fn get_container(&self, container_id: &str) -> Result<Container> {
         let path = endpoints::containers::by_id(container_id);
@@ -81,21 +184,7 @@ impl ContainersApi {
         let path = endpoints::containers::by_id(container_id);
         let body = json!({ "metadata": metadata });
 
-        // Use reqwest client directly for PATCH since HttpClient doesn't have patch method yet
-        let url = format!("{}{path}", self.client.base_url());
-        let headers = self.client.build_headers()?;
-
-        let response = self
-            .client
-            .client()
-            .patch(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await
-            .map_err(crate::request_err!(to_string))?;
-
-        self.client.handle_response(response).await
+        self.client.patch(&path, &body).await
     }
 
     /// Upload a file to a container
@@ -141,42 +230,57 @@ impl ContainersApi {
         self.client.get_bytes(&path).await
     }
 
+    /// Get metadata for a file in a container
+    pub async fn get_file(&self, container_id: &str, file_id: &str) -> Result<ContainerFile> {
+        let path = endpoints::containers::file_by_id(container_id, file_id);
+        self.client.get(&path).await
+    }
+
     /// Download a file and save it to disk
+    ///
+    /// The content is streamed chunk by chunk into a temporary file next to
+    /// `output_path`, so large generated outputs are never held in memory. The
+    /// temporary file is renamed over `output_path` only once the download
+    /// completes, and removed if it fails, so a partial file is never left at
+    /// `output_path`.
     pub async fn download_file_to_path(
         &self,
         container_id: &str,
         file_id: &str,
         output_path: &Path,
     ) -> Result<()> {
-        let content = self.download_file(container_id, file_id).await?;
+        let path = endpoints::containers::file_content(container_id, file_id);
+        let response = self.client.get_stream(&path).await?;
 
-        crate::helpers::write_bytes(output_path, &content).await?;
+        if let Some(parent) = output_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).await.map_err(|e| {
+                OpenAIError::FileError(format!("Failed to create {}: {e}", parent.display()))
+            })?;
+        }
+        write_stream_to_path(response.bytes_stream(), output_path).await
+    }
 
-        Ok(())
+    /// Download a file to disk and return its metadata
+    ///
+    /// Same as [`download_file_to_path`](Self::download_file_to_path), but
+    /// also fetches the file's [`ContainerFile`] metadata first.
+    pub async fn download_file_with_metadata(
+        &self,
+        container_id: &str,
+        file_id: &str,
+        output_path: &Path,
+    ) -> Result<ContainerFile> {
+        let metadata = self.get_file(container_id, file_id).await?;
+        self.download_file_to_path(container_id, file_id, output_path)
+            .await?;
+        Ok(metadata)
     }
 
     /// Delete a file from a container
     pub async fn delete_file(&self, container_id: &str, file_id: &str) -> Result<()> {
         let path = endpoints::containers::file_by_id(container_id, file_id);
 
-        // Use reqwest client directly for DELETE with () response since HttpClient doesn't handle () yet
-        let url = format!("{}{path}", self.client.base_url());
-        let headers = self.client.build_headers()?;
-
-        let response = self
-            .client
-            .client()
-            .delete(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(crate::request_err!(to_string))?;
-
-        if response.status().is_success() {
-            Ok(())
-        } else {
-            self.client.handle_response::<()>(response).await
-        }
+        self.client.send_empty(Method::DELETE, &path).await
     }
 
     /// Execute Python code in a container
@@ -218,49 +322,138 @@ impl ContainersApi {
     pub async fn delete_container(&self, container_id: &str) -> Result<()> {
         let path = endpoints::containers::by_id(container_id);
 
-        // Use reqwest client directly for DELETE with () response since HttpClient doesn't handle () yet
-        let url = format!("{}{path}", self.client.base_url());
-        let headers = self.client.build_headers()?;
-
-        let response = self
-            .client
-            .client()
-            .delete(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(crate::request_err!(to_string))?;
-
-        if response.status().is_success() {
-            Ok(())
-        } else {
-            self.client.handle_response::<()>(response).await
-        }
+        self.client.send_empty(Method::DELETE, &path).await
     }
 
     /// Keep a container alive by updating its last activity
     pub async fn keep_alive(&self, container_id: &str) -> Result<()> {
         let path = endpoints::containers::keep_alive(container_id);
 
-        // Use reqwest client directly for POST with () response since HttpClient doesn't handle () well yet
-        let url = format!("{}{path}", self.client.base_url());
-        let headers = self.client.build_headers()?;
+        self.client.send_empty(Method::POST, &path).await
+    }
+}
 
-        let response = self
-            .client
-            .client()
-            .post(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(crate::request_err!(to_string))?;
+/// Deletes its container when dropped
+///
+/// Dropping the guard spawns the delete request on the current Tokio runtime;
+/// use [`ContainerGuard::delete`] to await it and observe errors, or
+/// [`ContainerGuard::into_inner`] to keep the container alive.
+pub struct ContainerGuard {
+    /// API client used to delete the container
+    api: ContainersApi,
+    /// The guarded container, `None` once released or deleted
+    container: Option<Container>,
+}
+
+impl ContainerGuard {
+    /// Guard an existing container
+    #[must_use]
+    pub fn new(api: ContainersApi, container: Container) -> Self {
+        Self {
+            api,
+            container: Some(container),
+        }
+    }
+
+    /// Get the guarded container
+    #[must_use]
+    pub fn container(&self) -> &Container {
+        self.container
+            .as_ref()
+            .expect("container is only taken when the guard is consumed")
+    }
+
+    /// Release the container without deleting it
+    #[must_use]
+    pub fn into_inner(mut self) -> Container {
+        self.container
+            .take()
+            .expect("container is only taken when the guard is consumed")
+    }
+
+    /// Delete the container now and wait for the result
+    pub async fn delete(mut self) -> Result<()> {
+        match self.container.take() {
+            Some(container) => self.api.delete_container(&container.id).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Deref for ContainerGuard {
+    type Target = Container;
+
+    fn deref(&self) -> &Container {
+        self.container()
+    }
+}
+
+impl Drop for ContainerGuard {
+    fn drop(&mut self) {
+        let Some(container) = self.container.take() else {
+            return;
+        };
+
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                let api = self.api.clone();
+                handle.spawn(async move {
+                    if let Err(e) = api.delete_container(&container.id).await {
+                        log::warn!("Failed to delete container {}: {e}", container.id);
+                    }
+                });
+            }
+            Err(_) => log::warn!(
+                "No Tokio runtime available; container {} was not deleted",
+                container.id
+            ),
+        }
+    }
+}
 
-        if response.status().is_success() {
-            Ok(())
-        } else {
-            self.client.handle_response::<()>(response).await
+/// Write a byte stream to `output_path` via a temporary sibling file
+///
+/// The temporary file is renamed into place after the last chunk is written
+/// and removed if the stream or a write fails.
+async fn write_stream_to_path<S, B, E>(mut chunks: S, output_path: &Path) -> Result<()>
+where
+    S: futures::Stream<Item = std::result::Result<B, E>> + Unpin,
+    B: AsRef<[u8]>,
+    E: Into<OpenAIError>,
+{
+    let file_name = output_path
+        .file_name()
+        .ok_or_else(|| {
+            OpenAIError::FileError(format!("{} is not a file path", output_path.display()))
+        })?
+        .to_string_lossy();
+    let temp_path = output_path.with_file_name(format!(".{file_name}.part"));
+
+    let written = async {
+        let mut file = fs::File::create(&temp_path).await.map_err(|e| {
+            OpenAIError::FileError(format!("Failed to create {}: {e}", temp_path.display()))
+        })?;
+        while let Some(chunk) = chunks.next().await {
+            file.write_all(chunk.map_err(Into::into)?.as_ref())
+                .await
+                .map_err(|e| {
+                    OpenAIError::FileError(format!("Failed to write {}: {e}", temp_path.display()))
+                })?;
         }
+        file.flush().await.map_err(|e| {
+            OpenAIError::FileError(format!("Failed to write {}: {e}", temp_path.display()))
+        })?;
+        fs::rename(&temp_path, output_path).await.map_err(|e| {
+            OpenAIError::FileError(format!("Failed to move {}: {e}", output_path.display()))
+        })
+    }
+    .await;
+
+    if written.is_err() {
+        // Best effort: the original error is more useful than a cleanup failure
+        let _ = fs::remove_file(&temp_path).await;
     }
+    written
 }
 
 #[cfg(test)]
@@ -273,4 +466,171 @@ mod tests {
         let api = ContainersApi::new("test_key").unwrap();
         assert_eq!(api.client.base_url(), "https://api.openai.com");
     }
+
+    fn container_json(status: &str) -> serde_json::Value {
+        json!({
+            "id": "cntr_123",
+            "object": "container",
+            "status": status,
+            "created_at": 1,
+            "last_activity_at": 1,
+            "expires_at": 100,
+            "python_version": "3.11"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_and_wait_polls_until_active_and_guard_deletes() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let create = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/containers");
+                then.status(200).json_body(container_json("creating"));
+            })
+            .await;
+        let get = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/containers/cntr_123");
+                then.status(200).json_body(container_json("active"));
+            })
+            .await;
+        let delete = server
+            .mock_async(|when, then| {
+                when.method(DELETE).path("/v1/containers/cntr_123");
+                then.status(200).json_body(json!({"deleted": true}));
+            })
+            .await;
+
+        let api = ContainersApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let poll = PollConfig::new(Duration::from_millis(10), Duration::from_secs(5));
+        let guard = api
+            .create_guarded(ContainerConfig::default(), poll)
+            .await
+            .unwrap();
+
+        assert_eq!(guard.status, ContainerStatus::Active);
+        create.assert_async().await;
+        get.assert_async().await;
+        assert_eq!(delete.calls_async().await, 0);
+
+        drop(guard);
+        for _ in 0..50 {
+            if delete.calls_async().await > 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        delete.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_and_wait_deletes_failed_container() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/containers");
+                then.status(200).json_body(container_json("error"));
+            })
+            .await;
+        let delete = server
+            .mock_async(|when, then| {
+                when.method(DELETE).path("/v1/containers/cntr_123");
+                then.status(200).json_body(json!({"deleted": true}));
+            })
+            .await;
+
+        let api = ContainersApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let result = api
+            .create_and_wait(ContainerConfig::default(), PollConfig::default())
+            .await;
+
+        assert!(matches!(result, Err(OpenAIError::RequestError(_))));
+        delete.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_and_download_file_round_trip() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let file_json = json!({
+            "id": "cfile_1",
+            "object": "container.file",
+            "filename": "data.csv",
+            "size": 8,
+            "created_at": 1,
+            "path": "/mnt/data/data.csv"
+        });
+        let upload = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/containers/cntr_123/files")
+                    .body_includes("a,b\n1,2\n");
+                then.status(200).json_body(file_json.clone());
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/containers/cntr_123/files/cfile_1");
+                then.status(200).json_body(file_json.clone());
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/containers/cntr_123/files/cfile_1/content");
+                then.status(200).body("a,b\n1,2\n");
+            })
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("data.csv");
+        std::fs::write(&input, "a,b\n1,2\n").unwrap();
+
+        let api = ContainersApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let uploaded = api.upload_file("cntr_123", &input).await.unwrap();
+        assert_eq!(uploaded.id, "cfile_1");
+        upload.assert_async().await;
+
+        let output = dir.path().join("out").join("data.csv");
+        let metadata = api
+            .download_file_with_metadata("cntr_123", "cfile_1", &output)
+            .await
+            .unwrap();
+        assert_eq!(metadata.filename, "data.csv");
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "a,b\n1,2\n");
+        let leftovers: Vec<_> = std::fs::read_dir(dir.path().join("out"))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(leftovers, vec!["data.csv"]);
+
+        let copy = dir.path().join("copy.csv");
+        api.download_file_to_path("cntr_123", "cfile_1", &copy)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&copy).unwrap(), "a,b\n1,2\n");
+    }
+
+    #[tokio::test]
+    async fn test_failed_download_leaves_existing_file_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join("data.csv");
+        std::fs::write(&output, "old").unwrap();
+
+        let chunks = futures::stream::iter(vec![
+            Ok(b"partial".to_vec()),
+            Err(OpenAIError::streaming("connection reset")),
+        ]);
+        let result = write_stream_to_path(chunks, &output).await;
+
+        assert!(result.is_err());
+        assert_eq!(std::fs::read_to_string(&output).unwrap(), "old");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
 }
@@ -8,8 +8,13 @@ use crate::api::common::ApiClientConstructors;
 use crate::error::{OpenAIError, Result};
 use crate::http_post;
 use crate::models::embeddings::{EmbeddingRequest, EmbeddingResponse};
+use futures::stream::{self, Stream, StreamExt};
+
+/// Maximum number of inputs the API accepts in a single embeddings request
+pub const MAX_EMBEDDING_BATCH_SIZE: usize = 2048;
 
 /// Embeddings API client
+#[derive(Debug, Clone)]
 pub struct EmbeddingsApi {
     /// HTTP client for making API requests
     http_client: HttpClient,
@@ -35,12 +40,86 @@ impl EmbeddingsApi {
             .ok_or_else(|| OpenAIError::ParseError("No embedding returned".to_string()))
     }
 
-    /// Create embeddings for multiple text inputs
+    /// Create embeddings for multiple text inputs, in input order
     pub async fn embed_texts(&self, model: &str, texts: Vec<String>) -> Result<Vec<Vec<f32>>> {
         let request = EmbeddingRequest::new_batch(model, texts);
         let response = self.create_embeddings(&request).await?;
 
-        Ok(response.get_embeddings())
+        response
+            .decode_embeddings_by_index()
+            .map_err(OpenAIError::ParseError)
+    }
+
+    /// Embed a stream of inputs in batches, yielding `(index, embedding)` pairs in input order
+    ///
+    /// Inputs are grouped into requests of at most `batch_size` texts (capped at
+    /// [`MAX_EMBEDDING_BATCH_SIZE`]) and at most `max_concurrency` requests are in
+    /// flight at once, so only a bounded number of inputs and vectors are held in
+    /// memory. `index` is the position of the input in the original stream.
+    ///
+    /// A failed batch yields a single `Err` in place of its embeddings and the
+    /// stream carries on with the next batch; the indices of the failed batch are
+    /// simply absent from the output.
+    ///
+    /// ```rust,no_run
+    /// use futures::{StreamExt, stream};
+    /// use openai_rust_sdk::api::{common::ApiClientConstructors, embeddings::EmbeddingsApi};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = EmbeddingsApi::new("your-api-key")?;
+    /// let documents = stream::iter(vec!["first".to_string(), "second".to_string()]);
+    ///
+    /// let mut embeddings = api.embed_stream("text-embedding-3-small", documents, 100, 4);
+    /// while let Some(result) = embeddings.next().await {
+    ///     let (index, vector) = result?;
+    ///     println!("document {index}: {} dimensions", vector.len());
+    /// }
+    /// # Ok::<(), openai_rust_sdk::OpenAIError>(())
+    /// # });
+    /// ```
+    pub fn embed_stream<'a, S>(
+        &'a self,
+        model: &'a str,
+        inputs: S,
+        batch_size: usize,
+        max_concurrency: usize,
+    ) -> impl Stream<Item = Result<(usize, Vec<f32>)>> + Send + 'a
+    where
+        S: Stream<Item = String> + Send + 'a,
+    {
+        let batch_size = batch_size.clamp(1, MAX_EMBEDDING_BATCH_SIZE);
+
+        inputs
+            .chunks(batch_size)
+            .enumerate()
+            .map(move |(batch_index, texts)| async move {
+                let offset = batch_index * batch_size;
+                let expected = texts.len();
+                let result = self.embed_texts(model, texts).await.and_then(|vectors| {
+                    if vectors.len() == expected {
+                        Ok(vectors)
+                    } else {
+                        Err(OpenAIError::ParseError(format!(
+                            "Expected {expected} embeddings for inputs {offset}..{}, got {}",
+                            offset + expected,
+                            vectors.len()
+                        )))
+                    }
+                });
+                (offset, result)
+            })
+            .buffered(max_concurrency.max(1))
+            .flat_map(|(offset, result)| {
+                let items: Vec<Result<(usize, Vec<f32>)>> = match result {
+                    Ok(vectors) => vectors
+                        .into_iter()
+                        .enumerate()
+                        .map(|(position, vector)| Ok((offset + position, vector)))
+                        .collect(),
+                    Err(error) => vec![Err(error)],
+                };
+                stream::iter(items)
+            })
     }
 
     /// Create embeddings with custom dimensions
@@ -210,6 +289,78 @@ mod tests {
         assert_eq!(EmbeddingsApi::cosine_similarity(&a, &d), -1.0);
     }
 
+    fn embedding_list(vectors: &[[f32; 2]]) -> serde_json::Value {
+        let data: Vec<serde_json::Value> = vectors
+            .iter()
+            .enumerate()
+            .map(|(index, vector)| {
+                serde_json::json!({ "object": "embedding", "index": index, "embedding": vector })
+            })
+            .collect();
+        serde_json::json!({
+            "object": "list",
+            "data": data,
+            "model": "text-embedding-3-small",
+            "usage": { "prompt_tokens": 4, "total_tokens": 4 }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_embed_stream_preserves_order_and_surfaces_batch_errors() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let first = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/embeddings")
+                    .body_includes(r#"["a","b"]"#);
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(embedding_list(&[[0.0, 1.0], [1.0, 1.0]]));
+            })
+            .await;
+        let failing = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/embeddings")
+                    .body_includes(r#"["c","d"]"#);
+                then.status(500)
+                    .header("Content-Type", "application/json")
+                    .json_body(serde_json::json!({
+                        "error": { "message": "boom", "type": "server_error" }
+                    }));
+            })
+            .await;
+        let last = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/embeddings")
+                    .body_includes(r#"["e"]"#);
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(embedding_list(&[[4.0, 1.0]]));
+            })
+            .await;
+
+        let api = EmbeddingsApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let inputs = stream::iter(["a", "b", "c", "d", "e"].map(String::from));
+        let results: Vec<_> = api
+            .embed_stream("text-embedding-3-small", inputs, 2, 3)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap(), &(0, vec![0.0, 1.0]));
+        assert_eq!(results[1].as_ref().unwrap(), &(1, vec![1.0, 1.0]));
+        assert!(results[2].is_err());
+        assert_eq!(results[3].as_ref().unwrap(), &(4, vec![4.0, 1.0]));
+
+        first.assert_async().await;
+        failing.assert_async().await;
+        last.assert_async().await;
+    }
+
     #[test]
     fn test_euclidean_distance() {
         let a = vec![0.0, 0.0];
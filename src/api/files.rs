@@ -55,6 +55,7 @@
 
 use crate::api::base::{HttpClient, validate_request};
 use crate::api::common::ApiClientConstructors;
+use crate::api::resource::{BulkDeleteSummary, DEFAULT_BULK_DELETE_CONCURRENCY, DeletableResource};
 use crate::api::shared_utilities::FormBuilder;
 use crate::constants::endpoints;
 use crate::error::{OpenAIError, Result};
@@ -64,6 +65,7 @@ use crate::models::files::{
 };
 use reqwest::multipart;
 use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// `OpenAI` Files API client for file management operations
 #[derive(Debug, Clone)]
@@ -86,9 +88,7 @@ impl FilesApi {
     /// * `api_key` - Your `OpenAI` API key
     /// * `base_url` - Custom base URL for the API
     pub fn new_with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
     }
 
     /// Uploads a file to `OpenAI`
@@ -527,6 +527,55 @@ impl FilesApi {
 
         results
     }
+
+    /// Deletes every file with the given purpose that is older than `older_than`
+    ///
+    /// All pages of files are listed and the matching files are deleted with
+    /// bounded concurrency. Individual failures are counted rather than
+    /// aborting the cleanup.
+    ///
+    /// # Arguments
+    ///
+    /// * `purpose` - Only files uploaded with this purpose are deleted
+    /// * `older_than` - Minimum age of a file for it to be deleted
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of files deleted and the number that failed
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::api::{files::FilesApi, common::ApiClientConstructors};
+    /// use openai_rust_sdk::models::files::FilePurpose;
+    /// use std::time::Duration;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = FilesApi::new("your-api-key")?;
+    /// let week = Duration::from_secs(7 * 24 * 60 * 60);
+    /// let summary = api.delete_by_purpose(FilePurpose::Batch, week).await?;
+    /// println!("Deleted {} files ({} failed)", summary.deleted, summary.failed);
+    /// # Ok::<(), openai_rust_sdk::OpenAIError>(())
+    /// # });
+    /// ```
+    pub async fn delete_by_purpose(
+        &self,
+        purpose: FilePurpose,
+        older_than: Duration,
+    ) -> Result<BulkDeleteSummary> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let cutoff = now.saturating_sub(older_than.as_secs());
+        let purpose = purpose.to_string();
+
+        self.delete_where(
+            |file| file.purpose == purpose && file.created_at < cutoff,
+            DEFAULT_BULK_DELETE_CONCURRENCY,
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -583,6 +632,38 @@ mod tests {
         assert!(query_params.contains(&("order".to_string(), "desc".to_string())));
     }
 
+    #[tokio::test]
+    async fn test_list_files_encodes_cursor_with_special_characters() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let cursor = "file+abc/def ghi==";
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/files")
+                    .query_param("after", cursor)
+                    .query_param("limit", "2");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(serde_json::json!({
+                        "object": "list",
+                        "data": [],
+                        "has_more": false
+                    }));
+            })
+            .await;
+
+        let api = FilesApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let params = ListFilesParams::new()
+            .with_after(cursor.to_string())
+            .with_limit(2);
+        let response = api.list_files(Some(params)).await.unwrap();
+
+        assert!(response.data.is_empty());
+        mock.assert_async().await;
+    }
+
     #[test]
     fn test_file_purpose_validation() {
         // Test that different file types are validated correctly for different purposes
@@ -9,6 +9,7 @@
 //! - **Event Monitoring**: Stream and list training events for progress tracking
 //! - **Checkpoint Access**: List and retrieve training checkpoints
 //! - **Hyperparameter Tuning**: Customize training parameters for optimal results
+//! - **Local Validation**: Check training JSONL files for malformed examples before upload
 //! - **Error Handling**: Comprehensive error handling with detailed messages
 //!
 //! ## Fine-tuning Workflow
@@ -66,13 +67,20 @@
 
 use crate::api::base::HttpClient;
 use crate::api::common::ApiClientConstructors;
+use crate::api::resource::collect_pages;
 use crate::constants::endpoints;
-use crate::error::Result;
+use crate::error::{OpenAIError, Result};
+use crate::helpers::backoff::Backoff;
 use crate::models::fine_tuning::{
-    FineTuningJob, FineTuningJobEvent, FineTuningJobRequest, ListFineTuningJobCheckpointsParams,
-    ListFineTuningJobCheckpointsResponse, ListFineTuningJobEventsParams,
-    ListFineTuningJobEventsResponse, ListFineTuningJobsParams, ListFineTuningJobsResponse,
+    FineTuningJob, FineTuningJobCheckpoint, FineTuningJobEvent, FineTuningJobRequest,
+    ListFineTuningJobCheckpointsParams, ListFineTuningJobCheckpointsResponse,
+    ListFineTuningJobEventsParams, ListFineTuningJobEventsResponse, ListFineTuningJobsParams,
+    ListFineTuningJobsResponse,
 };
+use crate::models::pricing::{PricingTable, TrainingCostEstimate};
+use crate::models::training_data::{TrainingFileReport, fine_tuning_context_limit};
+use futures::TryStreamExt;
+use std::path::Path;
 use std::time::Duration;
 use tokio::time;
 
@@ -109,9 +117,7 @@ impl FineTuningApi {
     /// # Ok::<(), openai_rust_sdk::error::OpenAIError>(())
     /// ```
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
     }
 
     /// Get the API key (for testing purposes)
@@ -120,6 +126,74 @@ impl FineTuningApi {
         self.http_client.api_key()
     }
 
+    /// Validates a training JSONL file locally without uploading it
+    ///
+    /// Reports the first problems with line numbers, counts the examples and
+    /// estimates each example's token count. Examples estimated to exceed the
+    /// fine-tuning limit of `model` are flagged, since the service would
+    /// truncate them; the length check is skipped for models without a known
+    /// limit. Check [`TrainingFileReport::is_valid`] before uploading.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use openai_rust_sdk::api::{fine_tuning::FineTuningApi, common::ApiClientConstructors};
+    /// # tokio_test::block_on(async {
+    /// let api = FineTuningApi::new("your-api-key")?;
+    ///
+    /// let report = api
+    ///     .validate_training_file("training.jsonl", "gpt-4o-mini-2024-07-18")
+    ///     .await?;
+    /// for error in &report.errors {
+    ///     println!("line {}: {}", error.line, error.message);
+    /// }
+    /// println!("{} examples, ~{} tokens", report.valid_examples, report.total_tokens);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn validate_training_file(
+        &self,
+        path: impl AsRef<Path>,
+        model: &str,
+    ) -> Result<TrainingFileReport> {
+        let content = crate::helpers::read_string(path.as_ref()).await?;
+        Ok(TrainingFileReport::from_jsonl(
+            &content,
+            fine_tuning_context_limit(model),
+        ))
+    }
+
+    /// Estimates the cost of fine-tuning `model` on a training file
+    ///
+    /// Multiplies the estimated tokens of the file's valid examples by
+    /// `n_epochs` and the model's training rate from the default
+    /// [`PricingTable`]. Token counts use the same rough estimate as
+    /// [`Self::validate_training_file`], so treat the result as a budget
+    /// figure. Returns `Ok(None)` when the model's training rate is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use openai_rust_sdk::api::{fine_tuning::FineTuningApi, common::ApiClientConstructors};
+    /// # tokio_test::block_on(async {
+    /// let api = FineTuningApi::new("your-api-key")?;
+    ///
+    /// if let Some(estimate) = api.estimate_cost("training.jsonl", "gpt-4o-mini", 3).await? {
+    ///     println!("~{} tokens, ~${:.2}", estimate.trained_tokens, estimate.cost);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn estimate_cost(
+        &self,
+        file_path: impl AsRef<Path>,
+        model: &str,
+        n_epochs: u32,
+    ) -> Result<Option<TrainingCostEstimate>> {
+        let report = self.validate_training_file(file_path, model).await?;
+        Ok(report.estimated_cost(model, n_epochs, &PricingTable::default()))
+    }
+
     /// Creates a job that fine-tunes a specified model from a given dataset
     ///
     /// # Arguments
@@ -236,13 +310,18 @@ impl FineTuningApi {
 
     /// Immediately cancel a fine-tuning job
     ///
+    /// Cancelling a job that has already finished is not an error: the
+    /// service rejects the request, so the job is retrieved and returned in
+    /// its terminal state instead.
+    ///
     /// # Arguments
     ///
     /// * `fine_tuning_job_id` - The ID of the fine-tuning job to cancel
     ///
     /// # Returns
     ///
-    /// The updated fine-tuning job object with status "cancelled"
+    /// The updated fine-tuning job object with status "cancelled", or the
+    /// job's current state if it had already succeeded, failed or been cancelled
     ///
     /// # Example
     ///
@@ -261,9 +340,20 @@ impl FineTuningApi {
         fine_tuning_job_id: impl Into<String>,
     ) -> Result<FineTuningJob> {
         let fine_tuning_job_id = fine_tuning_job_id.into();
-        self.http_client
+        let error = match self
+            .http_client
             .post(&endpoints::fine_tuning::cancel(&fine_tuning_job_id), &())
             .await
+        {
+            Ok(job) => return Ok(job),
+            Err(error) if matches!(error.status_code(), Some(400 | 409)) => error,
+            Err(error) => return Err(error),
+        };
+
+        match self.retrieve_fine_tuning_job(&fine_tuning_job_id).await {
+            Ok(job) if job.status.is_terminal() => Ok(job),
+            _ => Err(error),
+        }
     }
 
     /// Get status updates for a fine-tuning job
@@ -360,6 +450,44 @@ impl FineTuningApi {
             .await
     }
 
+    /// List every checkpoint of a fine-tuning job, following pagination transparently
+    ///
+    /// Pass the checkpoints to [`FineTuningJobCheckpoint::best`] to pick the
+    /// one with the lowest loss.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any page request fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::api::{fine_tuning::FineTuningApi, common::ApiClientConstructors};
+    /// use openai_rust_sdk::models::fine_tuning::FineTuningJobCheckpoint;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = FineTuningApi::new("your-api-key")?;
+    /// let checkpoints = api.list_all_checkpoints("ft-123").await?;
+    /// if let Some(best) = FineTuningJobCheckpoint::best(&checkpoints) {
+    ///     println!("Best checkpoint: {}", best.fine_tuned_model_checkpoint);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn list_all_checkpoints(
+        &self,
+        fine_tuning_job_id: &str,
+    ) -> Result<Vec<FineTuningJobCheckpoint>> {
+        collect_pages(|after| {
+            let params = ListFineTuningJobCheckpointsParams {
+                after,
+                limit: Some(100),
+            };
+            self.list_fine_tuning_checkpoints(fine_tuning_job_id, Some(params))
+        })
+        .await
+    }
+
     /// Monitor a fine-tuning job until completion or failure
     ///
     /// This is a convenience method that polls the job status and events
@@ -403,6 +531,7 @@ impl FineTuningApi {
     ) -> Result<FineTuningJob> {
         let fine_tuning_job_id = fine_tuning_job_id.into();
         let poll_interval = poll_interval.unwrap_or(Duration::from_secs(30));
+        let mut intervals = Backoff::polling(poll_interval);
         let mut last_event_id: Option<String> = None;
 
         loop {
@@ -433,7 +562,7 @@ impl FineTuningApi {
             }
 
             // Wait before next poll
-            time::sleep(poll_interval).await;
+            time::sleep(intervals.next().unwrap_or(poll_interval)).await;
         }
     }
 
@@ -481,26 +610,61 @@ impl FineTuningApi {
 
     /// Pause a fine-tuning job
     ///
-    /// Pauses a running fine-tuning job. The job can be resumed later.
+    /// Pauses a running fine-tuning job. The job can be resumed later with
+    /// [`Self::resume_fine_tuning_job`].
+    ///
+    /// # Returns
+    ///
+    /// The updated fine-tuning job object with status "paused"
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use openai_rust_sdk::api::{fine_tuning::FineTuningApi, common::ApiClientConstructors};
+    /// # tokio_test::block_on(async {
+    /// let api = FineTuningApi::new("your-api-key")?;
+    ///
+    /// let job = api.pause_fine_tuning_job("ft-123").await?;
+    /// println!("Job status: {:?}", job.status);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
     pub async fn pause_fine_tuning_job(
         &self,
         fine_tuning_job_id: impl Into<String>,
     ) -> Result<FineTuningJob> {
         let fine_tuning_job_id = fine_tuning_job_id.into();
-        let endpoint = format!("/v1/fine_tuning/jobs/{fine_tuning_job_id}/pause");
-        self.http_client.post(&endpoint, &()).await
+        self.http_client
+            .post(&endpoints::fine_tuning::pause(&fine_tuning_job_id), &())
+            .await
     }
 
     /// Resume a paused fine-tuning job
     ///
-    /// Resumes a previously paused fine-tuning job.
+    /// # Returns
+    ///
+    /// The updated fine-tuning job object, running again
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use openai_rust_sdk::api::{fine_tuning::FineTuningApi, common::ApiClientConstructors};
+    /// # tokio_test::block_on(async {
+    /// let api = FineTuningApi::new("your-api-key")?;
+    ///
+    /// let job = api.resume_fine_tuning_job("ft-123").await?;
+    /// println!("Job status: {:?}", job.status);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
     pub async fn resume_fine_tuning_job(
         &self,
         fine_tuning_job_id: impl Into<String>,
     ) -> Result<FineTuningJob> {
         let fine_tuning_job_id = fine_tuning_job_id.into();
-        let endpoint = format!("/v1/fine_tuning/jobs/{fine_tuning_job_id}/resume");
-        self.http_client.post(&endpoint, &()).await
+        self.http_client
+            .post(&endpoints::fine_tuning::resume(&fine_tuning_job_id), &())
+            .await
     }
 
     /// Create a fine-tuning job and wait for it to complete
@@ -592,4 +756,52 @@ mod tests {
                 .contains("API key cannot be empty")
         );
     }
+
+    #[tokio::test]
+    async fn test_validate_training_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("training.jsonl");
+        let content = [
+            r#"{"messages":[{"role":"user","content":"Hi"},{"role":"assistant","content":"Hello"}]}"#,
+            r#"{"messages":[{"role":"assistant","content":"Hello"}]}"#,
+        ]
+        .join("\n");
+        std::fs::write(&path, content).unwrap();
+
+        let api = FineTuningApi::new("test-key").unwrap();
+        let report = api
+            .validate_training_file(&path, "gpt-4o-mini")
+            .await
+            .unwrap();
+
+        assert_eq!(report.total_examples, 2);
+        assert_eq!(report.valid_examples, 1);
+        assert_eq!(report.errors[0].line, 2);
+        assert_eq!(report.context_limit, Some(65_536));
+        assert!(!report.is_valid());
+    }
+
+    #[tokio::test]
+    async fn test_estimate_cost() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("training.jsonl");
+        let line = format!(
+            r#"{{"messages":[{{"role":"user","content":"{}"}},{{"role":"assistant","content":"{}"}}]}}"#,
+            "q".repeat(400),
+            "a".repeat(400)
+        );
+        std::fs::write(&path, format!("{line}\n{line}\n")).unwrap();
+
+        let api = FineTuningApi::new("test-key").unwrap();
+        let estimate = api
+            .estimate_cost(&path, "gpt-4o-mini", 4)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(estimate.tokens_per_epoch, 400);
+        assert_eq!(estimate.trained_tokens, 1_600);
+        assert!((estimate.cost - 1_600.0 * 3.00 / 1_000_000.0).abs() < 1e-12);
+
+        assert!(api.estimate_cost(&path, "o3", 4).await.unwrap().is_none());
+    }
 }
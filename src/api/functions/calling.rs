@@ -36,17 +36,7 @@ impl FunctionsApi {
     ) -> Result<FunctionResponseResult> {
         // Mark function calls as completed
         for result in &results {
-            self.conversation_state
-                .completed_calls
-                .insert(result.call_id.clone(), result.clone());
-
-            self.conversation_state
-                .call_history
-                .push(FunctionCallEvent::CallCompleted {
-                    timestamp: self.current_timestamp(),
-                    call_id: result.call_id.clone(),
-                    output: result.clone(),
-                });
+            self.conversation_state.complete_call(result.clone());
         }
 
         // Create a new request with function results
@@ -67,13 +57,13 @@ impl FunctionsApi {
     /// Check if there are any pending function calls
     #[must_use]
     pub fn has_pending_calls(&self) -> bool {
-        !self.conversation_state.pending_calls.is_empty()
+        self.conversation_state.has_pending_calls()
     }
 
     /// Get the conversation history
     #[must_use]
     pub fn get_call_history(&self) -> &[FunctionCallEvent] {
-        &self.conversation_state.call_history
+        self.conversation_state.call_history()
     }
 
     /// Clear the conversation state
@@ -1,18 +1,19 @@
+use crate::api::base::ClientConfig;
 use crate::api::custom_tools::CustomToolsApi;
 use crate::error::{OpenAIError, Result};
 use crate::models::functions::Tool;
+use crate::models::functions::{FunctionCall, FunctionCallOutput};
 use reqwest::Client;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 /// Core function calling API for `OpenAI`
 #[derive(Debug, Clone)]
 pub struct FunctionsApi {
     /// HTTP client for API requests
     pub(crate) client: Client,
-    /// API key for authentication
-    pub(crate) api_key: String,
-    /// Base URL for API requests
-    pub(crate) base_url: String,
+    /// API key, base URL, and path prefix for requests
+    pub(crate) config: ClientConfig,
     /// Custom tools registry
     pub(crate) custom_tools: CustomToolsApi,
     /// Active conversation state
@@ -20,16 +21,148 @@ pub struct FunctionsApi {
 }
 
 /// State management for function calling conversations
+///
+/// This type is not synchronized. Agents that record and complete calls from
+/// several tasks should use [`SharedConversationState`] instead of wrapping
+/// it in their own lock.
 #[derive(Debug, Clone, Default)]
 pub struct ConversationState {
     /// Pending function calls awaiting results
-    pub(crate) pending_calls: HashMap<String, crate::models::functions::FunctionCall>,
+    pub(crate) pending_calls: HashMap<String, FunctionCall>,
     /// Completed function calls with results
-    pub(crate) completed_calls: HashMap<String, crate::models::functions::FunctionCallOutput>,
+    pub(crate) completed_calls: HashMap<String, FunctionCallOutput>,
     /// Function call history for context
     pub(crate) call_history: Vec<FunctionCallEvent>,
 }
 
+impl ConversationState {
+    /// Record a function call the model has requested
+    pub fn record_call(&mut self, call: FunctionCall) {
+        self.call_history.push(FunctionCallEvent::CallInitiated {
+            timestamp: unix_timestamp(),
+            call: call.clone(),
+        });
+        self.pending_calls.insert(call.call_id.clone(), call);
+    }
+
+    /// Move a call from pending to completed with its output
+    ///
+    /// Returns `false` if no pending call had the output's `call_id`; the
+    /// output is still recorded so late or unknown results are not lost.
+    pub fn complete_call(&mut self, output: FunctionCallOutput) -> bool {
+        let was_pending = self.pending_calls.remove(&output.call_id).is_some();
+        self.call_history.push(FunctionCallEvent::CallCompleted {
+            timestamp: unix_timestamp(),
+            call_id: output.call_id.clone(),
+            output: output.clone(),
+        });
+        self.completed_calls.insert(output.call_id.clone(), output);
+        was_pending
+    }
+
+    /// IDs of the calls still awaiting results, sorted
+    #[must_use]
+    pub fn pending_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.pending_calls.keys().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Pending call with the given ID
+    #[must_use]
+    pub fn pending_call(&self, call_id: &str) -> Option<&FunctionCall> {
+        self.pending_calls.get(call_id)
+    }
+
+    /// Output recorded for a completed call
+    #[must_use]
+    pub fn completed_output(&self, call_id: &str) -> Option<&FunctionCallOutput> {
+        self.completed_calls.get(call_id)
+    }
+
+    /// Check if any calls are awaiting results
+    #[must_use]
+    pub fn has_pending_calls(&self) -> bool {
+        !self.pending_calls.is_empty()
+    }
+
+    /// Function call history in the order events were recorded
+    #[must_use]
+    pub fn call_history(&self) -> &[FunctionCallEvent] {
+        &self.call_history
+    }
+}
+
+/// Thread-safe handle to a [`ConversationState`]
+///
+/// Clones share the same state. Every method takes the lock once, so
+/// completing a call removes it from the pending set and records its output
+/// in a single step even when results arrive out of order from several tasks.
+#[derive(Debug, Clone, Default)]
+pub struct SharedConversationState {
+    /// Shared state behind a mutex
+    inner: Arc<Mutex<ConversationState>>,
+}
+
+impl SharedConversationState {
+    /// Create an empty shared state
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a function call the model has requested
+    pub fn record_call(&self, call: FunctionCall) {
+        self.lock().record_call(call);
+    }
+
+    /// Move a call from pending to completed with its output
+    ///
+    /// Returns `false` if no pending call had the output's `call_id`.
+    pub fn complete_call(&self, output: FunctionCallOutput) -> bool {
+        self.lock().complete_call(output)
+    }
+
+    /// IDs of the calls still awaiting results, sorted
+    #[must_use]
+    pub fn pending_ids(&self) -> Vec<String> {
+        self.lock().pending_ids()
+    }
+
+    /// Check if any calls are awaiting results
+    #[must_use]
+    pub fn has_pending_calls(&self) -> bool {
+        self.lock().has_pending_calls()
+    }
+
+    /// Copy of the current state
+    #[must_use]
+    pub fn snapshot(&self) -> ConversationState {
+        self.lock().clone()
+    }
+
+    /// Lock the state, recovering it if another holder panicked
+    fn lock(&self) -> MutexGuard<'_, ConversationState> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<ConversationState> for SharedConversationState {
+    fn from(state: ConversationState) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(state)),
+        }
+    }
+}
+
+/// Current Unix time in seconds
+fn unix_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
 /// Events in the function calling lifecycle
 #[derive(Debug, Clone)]
 #[allow(clippy::enum_variant_names)]
@@ -69,19 +202,20 @@ impl FunctionsApi {
 
     /// Create a new functions API with custom base URL
     pub fn with_base_url(api_key: &str, base_url: &str) -> Result<Self> {
-        if api_key.trim().is_empty() {
-            return Err(OpenAIError::authentication("API key cannot be empty"));
-        }
+        Self::from_config(ClientConfig::new_with_base_url(api_key, base_url)?)
+    }
 
-        let client = Client::builder()
+    /// Create a new functions API from an existing client configuration
+    pub fn from_config(config: ClientConfig) -> Result<Self> {
+        let client = config
+            .client_builder()
             .timeout(std::time::Duration::from_mins(2))
             .build()
             .map_err(crate::network_err!("Failed to create HTTP client: {}"))?;
 
         Ok(Self {
             client,
-            api_key: api_key.to_string(),
-            base_url: base_url.to_string(),
+            config,
             custom_tools: CustomToolsApi::new(),
             conversation_state: ConversationState::default(),
         })
@@ -90,7 +224,7 @@ impl FunctionsApi {
     /// Get API key
     #[must_use]
     pub fn api_key(&self) -> &str {
-        &self.api_key
+        self.config.api_key()
     }
 }
 
@@ -103,7 +237,7 @@ pub struct FunctionConfig {
     pub tool_choice: Option<crate::models::functions::ToolChoice>,
     /// Whether to allow parallel function calls
     pub parallel_function_calls: Option<bool>,
-    /// Whether to use strict mode for schema adherence
+    /// Whether to use strict mode for schema adherence on every function tool
     pub strict_mode: Option<bool>,
     /// Maximum number of function calls per response
     pub max_function_calls: Option<u32>,
@@ -138,12 +272,25 @@ impl FunctionConfig {
     }
 
     /// Enable/disable strict mode
+    ///
+    /// When enabled, every function tool is sent with `strict: true`; see
+    /// [`FunctionConfig::force_strict`].
     #[must_use]
     pub const fn with_strict_mode(mut self, enabled: bool) -> Self {
         self.strict_mode = Some(enabled);
         self
     }
 
+    /// Force strict schema adherence on every function tool
+    ///
+    /// Tools are serialized with `strict: true` regardless of their own
+    /// setting, and a tool whose parameter schema is not strict-compatible
+    /// makes the request fail before it is sent.
+    #[must_use]
+    pub const fn force_strict(self, enabled: bool) -> Self {
+        self.with_strict_mode(enabled)
+    }
+
     /// Set maximum function calls per response
     #[must_use]
     pub const fn with_max_calls(mut self, max: u32) -> Self {
@@ -19,19 +19,8 @@ pub struct FunctionResponseResult {
 impl FunctionsApi {
     /// Update conversation state with function calls
     pub(crate) fn update_conversation_state(&mut self, result: &FunctionResponseResult) {
-        let timestamp = self.current_timestamp();
-
         for call in &result.function_calls {
-            self.conversation_state
-                .pending_calls
-                .insert(call.call_id.clone(), call.clone());
-
-            self.conversation_state
-                .call_history
-                .push(FunctionCallEvent::CallInitiated {
-                    timestamp,
-                    call: call.clone(),
-                });
+            self.conversation_state.record_call(call.clone());
         }
     }
 
@@ -77,13 +66,4 @@ impl FunctionsApi {
 
         Ok(())
     }
-
-    /// Get current timestamp
-    #[allow(clippy::unused_self)]
-    pub(crate) fn current_timestamp(&self) -> u64 {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs()
-    }
 }
@@ -12,7 +12,9 @@ pub mod parsing;
 pub mod tools;
 
 // Re-export all public types and functions
-pub use client::{ConversationState, FunctionCallEvent, FunctionConfig, FunctionsApi};
+pub use client::{
+    ConversationState, FunctionCallEvent, FunctionConfig, FunctionsApi, SharedConversationState,
+};
 pub use helpers::FunctionResponseResult;
 
 // Re-export extraction utilities that might be useful publicly
@@ -21,8 +23,10 @@ pub(crate) use extraction::ToolCallExtractor;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::OpenAIError;
     use crate::models::functions::FunctionTool;
     use crate::models::functions::Tool;
+    use crate::models::functions::{FunctionCall, FunctionCallOutput};
     use serde_json::json;
 
     #[test]
@@ -49,6 +53,46 @@ mod tests {
         assert_eq!(state.pending_calls.len(), 1);
     }
 
+    #[test]
+    fn test_conversation_state_completes_out_of_order() {
+        let mut state = ConversationState::default();
+        state.record_call(FunctionCall::new("call-1", "first", "{}"));
+        state.record_call(FunctionCall::new("call-2", "second", "{}"));
+        assert_eq!(state.pending_ids(), vec!["call-1", "call-2"]);
+
+        assert!(state.complete_call(FunctionCallOutput::new("call-2", "two")));
+        assert_eq!(state.pending_ids(), vec!["call-1"]);
+        assert_eq!(state.completed_output("call-2").unwrap().output, "two");
+        assert!(state.pending_call("call-2").is_none());
+
+        assert!(!state.complete_call(FunctionCallOutput::new("call-9", "late")));
+        assert!(state.complete_call(FunctionCallOutput::new("call-1", "one")));
+        assert!(!state.has_pending_calls());
+        assert_eq!(state.call_history().len(), 5);
+    }
+
+    #[test]
+    fn test_shared_conversation_state_across_threads() {
+        let shared = SharedConversationState::new();
+        for i in 0..8 {
+            shared.record_call(FunctionCall::new(format!("call-{i}"), "fn", "{}"));
+        }
+
+        let handles: Vec<_> = (0..8)
+            .rev()
+            .map(|i| {
+                let shared = shared.clone();
+                std::thread::spawn(move || {
+                    shared.complete_call(FunctionCallOutput::new(format!("call-{i}"), "ok"))
+                })
+            })
+            .collect();
+        assert!(handles.into_iter().all(|h| h.join().unwrap()));
+
+        assert!(shared.pending_ids().is_empty());
+        assert_eq!(shared.snapshot().completed_calls.len(), 8);
+    }
+
     #[test]
     fn test_function_call_validation() {
         let api = FunctionsApi::new("test-key").unwrap();
@@ -76,12 +120,70 @@ mod tests {
             }),
         ))];
 
-        let serialized = api.serialize_tools(&tools).unwrap();
+        let serialized = api.serialize_tools(&tools, false).unwrap();
         assert_eq!(serialized.len(), 1);
         assert_eq!(serialized[0]["type"], "function");
         assert_eq!(serialized[0]["function"]["name"], "get_weather");
     }
 
+    #[test]
+    fn test_serialize_tools_rejects_invalid_name() {
+        let api = FunctionsApi::new("test-key").unwrap();
+        let tools = vec![Tool::function(FunctionTool::simple(
+            "get weather",
+            "Weather",
+        ))];
+
+        match api.serialize_tools(&tools, false) {
+            Err(OpenAIError::InvalidRequest(message)) => {
+                assert!(message.contains("tools[0]"));
+                assert!(message.contains("'get weather'"));
+            }
+            other => panic!("expected InvalidRequest, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_force_strict_applies_to_every_function() {
+        let api = FunctionsApi::new("test-key").unwrap();
+        let config = FunctionConfig::new()
+            .with_tools(vec![
+                Tool::function(FunctionTool::simple("a", "A")),
+                Tool::function(FunctionTool::simple("b", "B").with_strict(false)),
+            ])
+            .force_strict(true);
+
+        let mut payload = json!({});
+        api.add_function_config(&mut payload, &config).unwrap();
+        assert_eq!(payload["tools"][0]["function"]["strict"], true);
+        assert_eq!(payload["tools"][1]["function"]["strict"], true);
+
+        let loose = FunctionConfig::new()
+            .with_tools(vec![Tool::function(FunctionTool::new(
+                "c",
+                "C",
+                json!({"type": "object", "properties": {"x": {"type": "string"}}}),
+            ))])
+            .force_strict(true);
+        api.add_function_config(&mut payload, &loose).unwrap();
+        let parameters = &payload["tools"][0]["function"]["parameters"];
+        assert_eq!(parameters["additionalProperties"], false);
+        assert_eq!(parameters["required"], json!(["x"]));
+
+        let not_object = FunctionConfig::new()
+            .with_tools(vec![Tool::function(FunctionTool::new(
+                "c",
+                "C",
+                json!({"type": "string"}),
+            ))])
+            .force_strict(true);
+        let err = api
+            .add_function_config(&mut payload, &not_object)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("function 'c'"));
+    }
+
     #[test]
     fn test_parse_tool_calls() {
         use extraction::parse_tool_calls;
@@ -37,7 +37,8 @@ impl FunctionsApi {
         config: &FunctionConfig,
     ) -> Result<()> {
         if !config.tools.is_empty() {
-            payload["tools"] = json!(self.serialize_tools(&config.tools)?);
+            let force_strict = config.strict_mode == Some(true);
+            payload["tools"] = json!(self.serialize_tools(&config.tools, force_strict)?);
         }
 
         if let Some(ref tool_choice) = config.tool_choice {
@@ -83,12 +84,12 @@ impl FunctionsApi {
 
     /// Send the API request
     pub(crate) async fn send_request(&self, payload: &Value) -> Result<Value> {
-        let url = format!("{}{}", self.base_url, endpoints::CHAT_COMPLETIONS);
+        let url = self.config.url_for(endpoints::CHAT_COMPLETIONS);
 
         let response = self
             .client
             .post(&url)
-            .header("Authorization", format!("Bearer {}", self.api_key))
+            .header("Authorization", format!("Bearer {}", self.config.api_key()))
             .header("Content-Type", "application/json")
             .json(payload)
             .send()
@@ -1,5 +1,5 @@
 use crate::error::{OpenAIError, Result};
-use crate::models::functions::{FunctionCall, FunctionCallOutput, Tool};
+use crate::models::functions::{FunctionCall, FunctionCallOutput, Tool, validate_tools};
 use serde_json::{Value, json};
 
 use super::client::FunctionsApi;
@@ -83,22 +83,41 @@ impl FunctionsApi {
         }
     }
 
-    /// Convert tools to API format
+    /// Convert tools to API format, optionally forcing strict mode on every function
+    ///
+    /// Function tools that end up strict have their parameter schema sanitized
+    /// with [`sanitize_for_strict`](crate::models::functions::sanitize_for_strict)
+    /// and then checked for strict compatibility. The tool list is first checked
+    /// against `OpenAI`'s limits with [`validate_tools`].
     #[allow(clippy::unused_self)]
-    #[allow(clippy::unnecessary_wraps)]
-    pub(crate) fn serialize_tools(&self, tools: &[Tool]) -> Result<Vec<Value>> {
+    pub(crate) fn serialize_tools(&self, tools: &[Tool], force_strict: bool) -> Result<Vec<Value>> {
+        validate_tools(tools).map_err(OpenAIError::invalid_request)?;
         let mut serialized = Vec::new();
 
         for tool in tools {
             match tool {
                 Tool::Function { function } => {
+                    let strict = if force_strict {
+                        Some(true)
+                    } else {
+                        function.strict
+                    };
+                    let parameters = if strict == Some(true) {
+                        let sanitized = function.clone().sanitized_for_strict();
+                        sanitized
+                            .check_strict_compatible()
+                            .map_err(OpenAIError::validation)?;
+                        sanitized.parameters
+                    } else {
+                        function.parameters.clone()
+                    };
                     serialized.push(json!({
                         "type": "function",
                         "function": {
                             "name": function.name,
                             "description": function.description,
-                            "parameters": function.parameters,
-                            "strict": function.strict
+                            "parameters": parameters,
+                            "strict": strict
                         }
                     }));
                 }
@@ -8,6 +8,7 @@ use crate::{
     models::{
         functions::{Tool, ToolChoice},
         gpt5::{ReasoningConfig, ReasoningEffort, TextConfig, Verbosity, models},
+        models::{ModelCapabilities, ModelFamily},
         responses::{ResponseInput, ResponseRequest, ResponseResult},
     },
 };
@@ -210,6 +211,11 @@ impl GPT5Api {
 }
 
 /// Builder for GPT-5 requests with fluent API
+///
+/// [`build_checked`](Self::build_checked) also checks the settings against
+/// the model's [`ModelCapabilities`], so a combination the service would
+/// reject with a 400, such as `temperature` on a reasoning model, fails
+/// before sending.
 #[allow(clippy::missing_docs_in_private_items)]
 pub struct GPT5RequestBuilder {
     model: String,
@@ -221,6 +227,7 @@ pub struct GPT5RequestBuilder {
     tools: Option<Vec<Tool>>,
     tool_choice: Option<ToolChoice>,
     temperature: Option<f32>,
+    top_p: Option<f32>,
     max_tokens: Option<u32>,
 }
 
@@ -244,6 +251,7 @@ impl GPT5RequestBuilder {
             tools: None,
             tool_choice: None,
             temperature: None,
+            top_p: None,
             max_tokens: None,
         }
     }
@@ -373,12 +381,25 @@ impl GPT5RequestBuilder {
     }
 
     /// Set temperature
+    ///
+    /// Only non-reasoning models such as `gpt-5-chat-latest` accept a value
+    /// other than the default of 1.
     #[must_use]
     pub fn temperature(mut self, temp: f32) -> Self {
         self.temperature = Some(temp);
         self
     }
 
+    /// Set nucleus sampling
+    ///
+    /// Only non-reasoning models such as `gpt-5-chat-latest` accept a value
+    /// other than the default of 1.
+    #[must_use]
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
+
     /// Set max tokens
     #[must_use]
     pub fn max_tokens(mut self, tokens: u32) -> Self {
@@ -387,6 +408,10 @@ impl GPT5RequestBuilder {
     }
 
     /// Build the request
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenAIError::InvalidRequest`] if no input is set.
     pub fn build(self) -> Result<ResponseRequest> {
         let input = self
             .input
@@ -402,8 +427,76 @@ impl GPT5RequestBuilder {
             tools: self.tools,
             tool_choice: self.tool_choice,
             temperature: self.temperature,
+            top_p: self.top_p,
             max_tokens: self.max_tokens,
             ..Default::default()
         })
     }
+
+    /// Build the request after checking it against the model's capabilities
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenAIError::InvalidRequest`] if no input is set, if
+    /// `temperature` or `top_p` is out of range, or if the model does not
+    /// accept the requested reasoning effort, verbosity or sampling
+    /// controls. Models the crate does not recognize are not checked.
+    pub fn build_checked(self) -> Result<ResponseRequest> {
+        self.validate()?;
+        self.build()
+    }
+
+    /// Check the settings against their ranges and the model's capabilities
+    fn validate(&self) -> Result<()> {
+        if let Some(temperature) = self.temperature
+            && !(0.0..=2.0).contains(&temperature)
+        {
+            return Err(OpenAIError::InvalidRequest(format!(
+                "temperature must be between 0 and 2, got {temperature}"
+            )));
+        }
+        if let Some(top_p) = self.top_p
+            && !(0.0..=1.0).contains(&top_p)
+        {
+            return Err(OpenAIError::InvalidRequest(format!(
+                "top_p must be between 0 and 1, got {top_p}"
+            )));
+        }
+
+        let capabilities = ModelCapabilities::from_model_id(&self.model);
+        if capabilities.family == ModelFamily::Unknown {
+            return Ok(());
+        }
+        let model = &self.model;
+
+        if let Some(effort) = self.reasoning_effort
+            && !capabilities.reasoning_efforts.contains(&effort)
+        {
+            return Err(OpenAIError::InvalidRequest(
+                if capabilities.supports_reasoning() {
+                    format!("{model} does not support reasoning effort {effort:?}")
+                } else {
+                    format!("{model} does not accept a reasoning effort")
+                },
+            ));
+        }
+        if self.verbosity.is_some() && !capabilities.supports_verbosity {
+            return Err(OpenAIError::InvalidRequest(format!(
+                "{model} does not support the verbosity setting"
+            )));
+        }
+        if !capabilities.supports_sampling {
+            let sampling = [("temperature", self.temperature), ("top_p", self.top_p)];
+            if let Some((name, _)) = sampling
+                .iter()
+                .find(|(_, value)| value.is_some_and(|value| value != 1.0))
+            {
+                return Err(OpenAIError::InvalidRequest(format!(
+                    "{model} only supports the default {name} of 1; use a non-reasoning model such as {} to set it",
+                    models::GPT_5_CHAT_LATEST
+                )));
+            }
+        }
+        Ok(())
+    }
 }
@@ -14,6 +14,7 @@ pub use utilities::*;
 use crate::api::base::HttpClient;
 
 /// Images API client
+#[derive(Debug, Clone)]
 pub struct ImagesApi {
     /// HTTP client for making API requests
     http_client: HttpClient,
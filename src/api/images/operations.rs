@@ -1,11 +1,13 @@
 //! Core image operations and API methods
 
 use super::{ImageFormBuilder, ImageRequestBuilder, ImagesApi};
+use crate::api::base::RequestParts;
 use crate::error::{OpenAIError, Result};
 use crate::models::images::{
     ImageEditRequest, ImageGenerationRequest, ImageModels, ImageQuality, ImageResponse,
-    ImageResponseFormat, ImageSize, ImageVariationRequest,
+    ImageResponseFormat, ImageSize, ImageVariationRequest, validate_prompt_length,
 };
+use reqwest::Method;
 use std::path::Path;
 use tokio::fs;
 
@@ -15,8 +17,18 @@ impl ImagesApi {
         // Validate request before sending
         request.validate().map_err(OpenAIError::invalid_request)?;
 
+        let trimmed = request.prompt.trim_end();
+        if trimmed.len() == request.prompt.len() {
+            return self
+                .http_client
+                .post("/v1/images/generations", request)
+                .await;
+        }
+
+        let mut request = request.clone();
+        request.prompt.truncate(trimmed.len());
         self.http_client
-            .post("/v1/images/generations", request)
+            .post("/v1/images/generations", &request)
             .await
     }
 
@@ -27,6 +39,9 @@ impl ImagesApi {
         image_data: Vec<u8>,
         mask_data: Option<Vec<u8>>,
     ) -> Result<ImageResponse> {
+        validate_prompt_length(&request.model, &request.prompt)
+            .map_err(OpenAIError::invalid_request)?;
+
         // Create multipart form
         let mut form = ImageFormBuilder::create_image_multipart_form(
             "image",
@@ -34,7 +49,7 @@ impl ImagesApi {
             &request.image,
             &request.model,
         )?
-        .text("prompt", request.prompt.clone());
+        .text("prompt", request.prompt.trim_end().to_string());
 
         // Add mask if provided
         if let (Some(mask_bytes), Some(mask_name)) = (mask_data, &request.mask) {
@@ -179,25 +194,18 @@ impl ImagesApi {
     pub async fn download_image(&self, url: &str, output_path: impl AsRef<Path>) -> Result<()> {
         let response = self
             .http_client
-            .client()
-            .get(url)
-            .send()
+            .execute(RequestParts::new(Method::GET, url))
             .await
-            .map_err(crate::request_err!("Failed to download image: {}"))?;
+            .map_err(|e| OpenAIError::RequestError(format!("Failed to download image: {e}")))?;
 
-        if !response.status().is_success() {
+        if !response.is_success() {
             return Err(OpenAIError::RequestError(format!(
                 "Failed to download image: HTTP {}",
-                response.status()
+                response.status
             )));
         }
 
-        let bytes = response
-            .bytes()
-            .await
-            .map_err(crate::request_err!("Failed to read image data: {}"))?;
-
-        crate::helpers::write_bytes(output_path, &bytes).await?;
+        crate::helpers::write_bytes(output_path, &response.body).await?;
 
         Ok(())
     }
@@ -35,14 +35,20 @@ pub mod models;
 pub mod moderations;
 /// Real-time Audio API for WebRTC-based audio streaming
 pub mod realtime_audio;
+/// Generic resource traits shared by CRUD-shaped sub-APIs
+pub mod resource;
 /// Response API implementation
 pub mod responses;
 /// Helper functions for responses API
 mod responses_helpers;
 /// Modern Responses API implementation for /v1/responses
 pub mod responses_v2;
+/// JSONL recording of streamed assistant run events
+pub mod run_recorder;
 /// Runs API for assistant execution and run steps management
 pub mod runs;
+/// Replay of recorded JSONL streams for offline testing
+pub mod stream_replay;
 /// Streaming API implementation
 pub mod streaming;
 /// Helper functions for streaming API
@@ -79,6 +85,7 @@ pub use fine_tuning::*;
 // Functions exports - be explicit to avoid conflicts
 pub use functions::{
     ConversationState, FunctionCallEvent, FunctionConfig, FunctionResponseResult, FunctionsApi,
+    SharedConversationState,
 };
 pub use gpt5::*;
 pub use images::*;
@@ -91,7 +98,12 @@ pub use responses_v2::{
     DeleteResponseAck, ListResponsesParams as ResponsesListParams, ResponseInputItemList,
     ResponseList as ResponsesList, ResponsesApiV2,
 };
+pub use run_recorder::{RecordedRunEvent, RunEventRecorder};
 pub use runs::*;
+pub use stream_replay::{
+    ReplayTiming, replay_response_stream, replay_response_stream_file, replay_run_events,
+    replay_run_events_file,
+};
 pub use streaming::{FunctionStream, ResponseStream, ResponseStreamExt, StreamingApi};
 // Threads exports - explicitly list to avoid conflict with audio::types
 pub use threads::types as thread_types;
@@ -24,16 +24,12 @@ impl ApiClientConstructors for ModelsApi {
 impl ModelsApi {
     /// Create a new Models API client
     pub fn new<S: Into<String>>(api_key: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new(api_key)?,
-        })
+        <Self as ApiClientConstructors>::new(api_key)
     }
 
     /// Create a new client with custom base URL
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
     }
 
     /// Get the API key (for testing purposes)
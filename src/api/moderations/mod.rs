@@ -59,4 +59,87 @@ mod tests {
         let request = ModerationRequest::new("test").with_model("text-moderation-stable");
         assert_eq!(request.model, Some("text-moderation-stable".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_moderate_with_models_reports_each_model() {
+        use crate::models::moderations::ModerationModels;
+        use httpmock::prelude::*;
+        use serde_json::json;
+
+        let categories: serde_json::Map<String, serde_json::Value> = [
+            "hate",
+            "hate/threatening",
+            "self-harm",
+            "self-harm/intent",
+            "self-harm/instructions",
+            "sexual",
+            "sexual/minors",
+            "violence",
+            "violence/graphic",
+            "harassment",
+            "harassment/threatening",
+        ]
+        .into_iter()
+        .map(|name| (name.to_string(), json!(name == "violence")))
+        .collect();
+        let scores: serde_json::Map<String, serde_json::Value> = categories
+            .iter()
+            .map(|(name, flagged)| {
+                (
+                    name.clone(),
+                    json!(if flagged == true { 0.9 } else { 0.01 }),
+                )
+            })
+            .collect();
+
+        let server = MockServer::start_async().await;
+        let omni = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/moderations")
+                    .body_includes(r#""model":"omni-moderation-latest""#);
+                then.status(200).json_body(json!({
+                    "id": "modr-1",
+                    "model": "omni-moderation-latest",
+                    "results": [{
+                        "flagged": true,
+                        "categories": categories,
+                        "category_scores": scores,
+                    }]
+                }));
+            })
+            .await;
+        let stable = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/moderations")
+                    .body_includes(r#""model":"text-moderation-stable""#);
+                then.status(400).json_body(json!({
+                    "error": {"message": "model retired", "type": "invalid_request_error", "code": null}
+                }));
+            })
+            .await;
+
+        let api = ModerationsApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let results = api
+            .moderate_with_models(
+                "some text",
+                &[
+                    ModerationModels::OMNI_LATEST,
+                    ModerationModels::STABLE,
+                    ModerationModels::OMNI_LATEST,
+                ],
+            )
+            .await;
+
+        assert_eq!(results.len(), 2);
+        let omni_result = results[ModerationModels::OMNI_LATEST].as_ref().unwrap();
+        assert!(omni_result.flagged);
+        assert_eq!(omni_result.violated_categories(), vec!["violence"]);
+        let err = results[ModerationModels::STABLE].as_ref().unwrap_err();
+        assert!(err.to_string().contains("model retired"));
+
+        omni.assert_calls_async(1).await;
+        stable.assert_calls_async(1).await;
+    }
 }
@@ -3,6 +3,8 @@
 use super::client::ModerationsApi;
 use crate::error::{OpenAIError, Result};
 use crate::models::moderations::{ModerationRequest, ModerationResult};
+use futures::future::join_all;
+use std::collections::HashMap;
 
 impl ModerationsApi {
     /// Moderate a single text input (convenience method)
@@ -33,6 +35,37 @@ impl ModerationsApi {
             .ok_or_else(|| OpenAIError::ParseError("No moderation result returned".to_string()))
     }
 
+    /// Moderate a single text input with several models concurrently
+    ///
+    /// Sends one request per distinct model and returns the outcome keyed by
+    /// model name, so a failure for one model does not hide the results of
+    /// the others.
+    pub async fn moderate_with_models(
+        &self,
+        text: &str,
+        models: &[&str],
+    ) -> HashMap<String, Result<ModerationResult>> {
+        let mut unique: Vec<&str> = Vec::with_capacity(models.len());
+        for model in models {
+            if !unique.contains(model) {
+                unique.push(model);
+            }
+        }
+
+        let results = join_all(
+            unique
+                .iter()
+                .map(|model| self.moderate_text_with_model(text, model)),
+        )
+        .await;
+
+        unique
+            .into_iter()
+            .map(str::to_string)
+            .zip(results)
+            .collect()
+    }
+
     /// Moderate multiple text inputs (batch processing)
     pub async fn moderate_texts(&self, texts: Vec<String>) -> Result<Vec<ModerationResult>> {
         let request = ModerationRequest::new_batch(texts);
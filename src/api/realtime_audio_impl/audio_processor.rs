@@ -5,23 +5,54 @@
 // Re-export AudioBuffer from the models module
 pub use crate::models::realtime_audio::AudioBuffer;
 
-/// Audio processor for real-time audio effects
-pub struct AudioProcessor {
-    /// Audio sample rate (for future use)
-    _sample_rate: u32,
-    /// Number of audio channels (for future use)
-    _channels: u16,
+/// Tuning for [`AudioProcessor`]
+#[derive(Debug, Clone)]
+pub struct AudioProcessorConfig {
+    /// Audio sample rate
+    pub sample_rate: u32,
+    /// Number of audio channels
+    pub channels: u16,
     /// Whether acoustic echo cancellation is enabled
-    enable_aec: bool,
+    pub enable_echo_cancellation: bool,
     /// Whether noise suppression is enabled
-    enable_noise_suppression: bool,
+    pub enable_noise_suppression: bool,
     /// Whether automatic gain control is enabled
-    enable_agc: bool,
+    pub enable_automatic_gain_control: bool,
+    /// Samples quieter than this amplitude are silenced by the noise gate
+    pub noise_gate_threshold: i16,
+    /// RMS level automatic gain control aims for
+    pub agc_target_rms: f32,
+    /// Largest gain automatic gain control applies
+    pub agc_max_gain: f32,
+}
+
+impl Default for AudioProcessorConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: 24000,
+            channels: 1,
+            enable_echo_cancellation: true,
+            enable_noise_suppression: true,
+            enable_automatic_gain_control: true,
+            noise_gate_threshold: 100,
+            agc_target_rms: 1000.0,
+            agc_max_gain: 10.0,
+        }
+    }
+}
+
+/// Audio processor for real-time audio effects
+pub struct AudioProcessor {
+    /// Processing configuration
+    config: AudioProcessorConfig,
     // Audio processing state would be maintained here
 }
 
 impl AudioProcessor {
-    /// Create a new audio processor
+    /// Smallest gain automatic gain control applies
+    const AGC_MIN_GAIN: f32 = 0.1;
+
+    /// Create a new audio processor with the default tuning
     #[must_use]
     pub fn new(
         sample_rate: u32,
@@ -30,26 +61,39 @@ impl AudioProcessor {
         enable_noise_suppression: bool,
         enable_automatic_gain_control: bool,
     ) -> Self {
-        Self {
-            _sample_rate: sample_rate,
-            _channels: channels,
-            enable_aec: enable_echo_cancellation,
+        Self::with_config(AudioProcessorConfig {
+            sample_rate,
+            channels,
+            enable_echo_cancellation,
             enable_noise_suppression,
-            enable_agc: enable_automatic_gain_control,
-        }
+            enable_automatic_gain_control,
+            ..Default::default()
+        })
+    }
+
+    /// Create a new audio processor from a configuration
+    #[must_use]
+    pub fn with_config(config: AudioProcessorConfig) -> Self {
+        Self { config }
+    }
+
+    /// Get the processing configuration
+    #[must_use]
+    pub fn config(&self) -> &AudioProcessorConfig {
+        &self.config
     }
 
     /// Process audio buffer with effects
     pub fn process(&mut self, audio_buffer: &mut AudioBuffer) {
-        if self.enable_noise_suppression {
+        if self.config.enable_noise_suppression {
             self.apply_noise_suppression(audio_buffer);
         }
 
-        if self.enable_agc {
+        if self.config.enable_automatic_gain_control {
             self.apply_automatic_gain_control(audio_buffer);
         }
 
-        if self.enable_aec {
+        if self.config.enable_echo_cancellation {
             self.apply_echo_cancellation(audio_buffer);
         }
     }
@@ -57,7 +101,7 @@ impl AudioProcessor {
     /// Apply noise suppression (simplified implementation)
     fn apply_noise_suppression(&self, audio_buffer: &mut AudioBuffer) {
         // Simple noise gate - remove samples below threshold
-        let threshold = 100; // Adjust based on requirements
+        let threshold = self.config.noise_gate_threshold;
 
         for sample in &mut audio_buffer.samples {
             if sample.abs() < threshold {
@@ -74,11 +118,12 @@ impl AudioProcessor {
 
         // Calculate current RMS level
         let rms = audio_buffer.rms_energy();
-        let target_rms = 1000.0; // Target RMS level
+        let target_rms = self.config.agc_target_rms;
 
         if rms > 0.0 {
             let gain: f32 = target_rms / rms;
-            let clamped_gain = gain.clamp(0.1, 10.0); // Limit gain range
+            let max_gain = self.config.agc_max_gain.max(Self::AGC_MIN_GAIN);
+            let clamped_gain = gain.clamp(Self::AGC_MIN_GAIN, max_gain); // Limit gain range
 
             for sample in &mut audio_buffer.samples {
                 *sample = (f32::from(*sample) * clamped_gain) as i16;
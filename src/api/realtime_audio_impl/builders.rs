@@ -3,7 +3,8 @@
 //! Builder patterns for creating real-time audio sessions.
 
 use crate::models::realtime_audio::{
-    RealtimeAudioModels, RealtimeSessionConfig, RealtimeSessionRequest, RealtimeVoice,
+    RealtimeAudioModels, RealtimeSessionConfig, RealtimeSessionRequest, RealtimeTool,
+    RealtimeVoice, TurnDetectionConfig,
 };
 
 /// Builder for creating real-time audio sessions
@@ -46,6 +47,31 @@ impl RealtimeSessionBuilder {
         self
     }
 
+    /// Set turn detection, `None` disables it for push-to-talk
+    ///
+    /// Starts from the default session configuration when none was set.
+    #[must_use]
+    pub fn turn_detection(mut self, turn_detection: Option<TurnDetectionConfig>) -> Self {
+        let config = self.request.config.take().unwrap_or_default();
+        self.request.config = Some(config.with_turn_detection(turn_detection));
+        self
+    }
+
+    /// Set the tools the model may call
+    ///
+    /// Accepts [`RealtimeTool`]s or function tools. Starts from the default
+    /// session configuration when none was set.
+    #[must_use]
+    pub fn tools<I, T>(mut self, tools: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<RealtimeTool>,
+    {
+        let config = self.request.config.take().unwrap_or_default();
+        self.request.config = Some(config.with_tools(tools));
+        self
+    }
+
     /// Set instructions
     pub fn instructions(mut self, instructions: impl Into<String>) -> Self {
         self.request.instructions = Some(instructions.into());
@@ -3,9 +3,11 @@
 //! Core client functionality for the Real-time Audio API.
 
 use super::{config::RealtimeAudioConfig, session::RealtimeSession};
-use crate::api::base::HttpClient;
+use crate::api::base::{HttpClient, RequestParts};
+use crate::api::common::ApiClientConstructors;
 use crate::error::{OpenAIError, Result};
 use crate::models::realtime_audio::{RealtimeSessionRequest, RealtimeSessionResponse};
+use reqwest::Method;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -20,14 +22,20 @@ pub struct RealtimeAudioApi {
     pub config: RealtimeAudioConfig,
 }
 
+impl ApiClientConstructors for RealtimeAudioApi {
+    fn from_http_client(http_client: HttpClient) -> Self {
+        Self {
+            http_client,
+            sessions: Arc::new(RwLock::new(HashMap::new())),
+            config: RealtimeAudioConfig::default(),
+        }
+    }
+}
+
 impl RealtimeAudioApi {
     /// Create a new Real-time Audio API client
     pub fn new<S: Into<String>>(api_key: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new(api_key)?,
-            sessions: Arc::new(RwLock::new(HashMap::new())),
-            config: RealtimeAudioConfig::default(),
-        })
+        <Self as ApiClientConstructors>::new(api_key)
     }
 
     /// Create a new client with custom configuration
@@ -36,19 +44,14 @@ impl RealtimeAudioApi {
         config: RealtimeAudioConfig,
     ) -> Result<Self> {
         Ok(Self {
-            http_client: HttpClient::new(api_key)?,
-            sessions: Arc::new(RwLock::new(HashMap::new())),
             config,
+            ..<Self as ApiClientConstructors>::new(api_key)?
         })
     }
 
     /// Create a new client with custom base URL
     pub fn new_with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-            sessions: Arc::new(RwLock::new(HashMap::new())),
-            config: RealtimeAudioConfig::default(),
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
     }
 
     /// Create a new real-time audio session
@@ -56,35 +59,27 @@ impl RealtimeAudioApi {
         &self,
         request: &RealtimeSessionRequest,
     ) -> Result<Arc<RealtimeSession>> {
-        let url = format!("{}/realtime/sessions", self.http_client.base_url());
+        let url = self.http_client.build_simple_url("/realtime/sessions");
         let headers = self.http_client.build_headers()?;
 
+        let http_request = RequestParts::new(Method::POST, url)
+            .with_headers(headers)
+            .with_body(serde_json::to_vec(request)?);
         let response = self
             .http_client
-            .client()
-            .post(&url)
-            .headers(headers)
-            .json(request)
-            .send()
+            .execute(http_request)
             .await
             .map_err(crate::request_err!(to_string))?;
 
-        let status = response.status();
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
+        if !response.is_success() {
             return Err(OpenAIError::ApiError {
-                status: status.as_u16(),
-                message: error_text,
+                status: response.status.as_u16(),
+                message: response.text(),
             });
         }
 
-        let session_response: RealtimeSessionResponse = response
-            .json()
-            .await
-            .map_err(crate::parse_err!(to_string))?;
+        let session_response: RealtimeSessionResponse =
+            response.json().map_err(crate::parse_err!(to_string))?;
 
         let session = self
             .create_webrtc_session(session_response, request.config.clone())
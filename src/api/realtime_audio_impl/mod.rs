@@ -30,10 +30,13 @@ pub use webrtc::*;
 
 #[cfg(test)]
 mod tests {
+    use super::session::types::ResponseAudioProgress;
     use super::*;
     use crate::models::realtime_audio::{
-        RealtimeEvent, RealtimeSessionConfig, RealtimeVoice, VoiceActivityDetectionConfig,
+        RealtimeAudioFormat, RealtimeEvent, RealtimeSessionConfig, RealtimeVoice,
+        VoiceActivityDetectionConfig,
     };
+    use std::time::Duration;
     use tokio::sync::mpsc;
 
     #[test]
@@ -98,6 +101,66 @@ mod tests {
         assert!(result.confidence > 0.0);
     }
 
+    /// Count speech/non-speech flips while feeding 20ms frames at each level
+    fn count_vad_transitions(config: VoiceActivityDetectionConfig, levels: &[i16]) -> usize {
+        let mut vad = VoiceActivityDetector::new(config, 24000);
+        let mut speaking = false;
+        let mut transitions = 0;
+        for &level in levels {
+            let frame = crate::models::realtime_audio::AudioBuffer::new(vec![level; 480], 24000, 1);
+            let result = vad.process(&frame);
+            if result.is_speech != speaking {
+                transitions += 1;
+                speaking = result.is_speech;
+            }
+        }
+        transitions
+    }
+
+    #[test]
+    fn test_voice_activity_detector_hysteresis() {
+        // Silence, then a signal that ramps up past the start threshold and
+        // ripples around it before fading out
+        let mut levels = vec![0; 5];
+        levels.extend([400, 800, 1100, 900, 1100, 850, 1050, 900, 1000, 700, 300, 0]);
+        let config = VoiceActivityDetectionConfig {
+            threshold: 1000.0,
+            prefix_padding_ms: 0,
+            silence_duration_ms: 0,
+            ..Default::default()
+        };
+
+        // A single threshold flickers on every ripple
+        assert!(count_vad_transitions(config.clone(), &levels) > 2);
+
+        // A lower end threshold gives one start and one end
+        let config = VoiceActivityDetectionConfig {
+            speech_end_threshold: Some(500.0),
+            ..config
+        };
+        assert_eq!(count_vad_transitions(config, &levels), 2);
+    }
+
+    #[test]
+    fn test_voice_activity_detector_release_smoothing() {
+        let levels = [0, 0, 0, 5000, 0, 5000, 0];
+        let config = VoiceActivityDetectionConfig {
+            threshold: 1000.0,
+            prefix_padding_ms: 0,
+            silence_duration_ms: 0,
+            ..Default::default()
+        };
+        assert!(count_vad_transitions(config.clone(), &levels) > 2);
+
+        // A slow release carries the level over the silent frames
+        let config = VoiceActivityDetectionConfig {
+            attack: Some(1.0),
+            release: Some(0.2),
+            ..config
+        };
+        assert_eq!(count_vad_transitions(config, &levels), 1);
+    }
+
     #[test]
     fn test_audio_processor() {
         let mut processor = AudioProcessor::new(24000, 1, true, true, true);
@@ -111,6 +174,103 @@ mod tests {
         assert_ne!(audio.samples[1], 2000); // Should be modified by AGC
     }
 
+    #[test]
+    fn test_audio_processor_config_tuning() {
+        let config = AudioProcessorConfig {
+            enable_echo_cancellation: false,
+            enable_automatic_gain_control: false,
+            noise_gate_threshold: 500,
+            ..Default::default()
+        };
+        let mut processor = AudioProcessor::with_config(config);
+        let mut audio = crate::models::realtime_audio::AudioBuffer::new(
+            vec![100, 499, -499, 500, -600],
+            24000,
+            1,
+        );
+
+        processor.process(&mut audio);
+        assert_eq!(audio.samples, vec![0, 0, 0, 500, -600]);
+
+        // Gain is limited by the configured maximum
+        let mut processor = AudioProcessor::with_config(AudioProcessorConfig {
+            enable_noise_suppression: false,
+            agc_target_rms: 8000.0,
+            agc_max_gain: 2.0,
+            ..Default::default()
+        });
+        let mut audio = crate::models::realtime_audio::AudioBuffer::new(vec![1000; 4], 24000, 1);
+        processor.process(&mut audio);
+        assert_eq!(audio.samples, vec![2000; 4]);
+
+        // The boolean constructor keeps the default tuning
+        let processor = AudioProcessor::new(16000, 2, false, true, true);
+        assert_eq!(processor.config().sample_rate, 16000);
+        assert_eq!(processor.config().noise_gate_threshold, 100);
+    }
+
+    #[test]
+    fn test_response_audio_progress_tracking() {
+        let delta = |item_id: &str, delta: &str| RealtimeEvent::ResponseAudioDelta {
+            event_id: "event".to_string(),
+            response_id: "resp_1".to_string(),
+            item_id: item_id.to_string(),
+            output_index: 0,
+            content_index: 0,
+            delta: delta.to_string(),
+        };
+        let pcm16 = RealtimeAudioFormat::Pcm16.bytes_per_second();
+        // 4800 bytes of PCM16 is 100ms at 24 kHz
+        let chunk =
+            base64::Engine::encode(&base64::engine::general_purpose::STANDARD, vec![0u8; 4800]);
+
+        let mut progress = None;
+        ResponseAudioProgress::track(&mut progress, &delta("item_1", &chunk), pcm16);
+        ResponseAudioProgress::track(&mut progress, &delta("item_1", &chunk), pcm16);
+        let current = progress.clone().unwrap();
+        assert_eq!(current.item_id, "item_1");
+        assert_eq!(current.audio_ms(), 200);
+
+        // Other events leave the progress alone
+        let cancel = RealtimeEvent::ResponseCancel {
+            event_id: "event".to_string(),
+        };
+        ResponseAudioProgress::track(&mut progress, &cancel, pcm16);
+        assert_eq!(progress.as_ref(), Some(&current));
+
+        // A new item restarts the count; G.711 is one byte per 8 kHz sample
+        let g711 = RealtimeAudioFormat::G711Ulaw.bytes_per_second();
+        ResponseAudioProgress::track(&mut progress, &delta("item_2", &chunk), g711);
+        let current = progress.unwrap();
+        assert_eq!(current.item_id, "item_2");
+        assert_eq!(current.audio_ms(), 600);
+    }
+
+    #[test]
+    fn test_response_audio_progress_from_media_track() {
+        // Over WebRTC the data channel only names the item
+        let transcript = RealtimeEvent::ResponseAudioTranscriptDelta {
+            event_id: "event".to_string(),
+            response_id: "resp_1".to_string(),
+            item_id: "item_1".to_string(),
+            output_index: 0,
+            content_index: 0,
+            delta: "Hel".to_string(),
+        };
+        let mut progress = None;
+        ResponseAudioProgress::add_track_audio(&mut progress, Duration::from_millis(20));
+        assert!(progress.is_none());
+
+        ResponseAudioProgress::track(&mut progress, &transcript, 48_000);
+        for _ in 0..15 {
+            ResponseAudioProgress::add_track_audio(&mut progress, Duration::from_millis(20));
+        }
+        ResponseAudioProgress::track(&mut progress, &transcript, 48_000);
+        let current = progress.unwrap();
+        assert_eq!(current.item_id, "item_1");
+        assert_eq!(current.audio_ms(), 300);
+    }
+
     #[tokio::test]
     async fn test_session_lifecycle() {
         let _session_id = "test-session".to_string();
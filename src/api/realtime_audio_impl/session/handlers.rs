@@ -3,7 +3,10 @@
 //! Event and audio handling functionality for real-time sessions.
 
 use crate::error::{OpenAIError, Result};
-use crate::models::realtime_audio::{AudioBuffer, RealtimeEvent};
+use crate::models::realtime_audio::{
+    AudioBuffer, ConversationItem, MAX_AUDIO_APPEND_BYTES, REALTIME_PCM16_SAMPLE_RATE,
+    RealtimeEvent,
+};
 use log::warn;
 use std::sync::Arc;
 use std::time::Duration;
@@ -12,7 +15,7 @@ use webrtc::data_channel::data_channel_message::DataChannelMessage;
 use webrtc::media::Sample;
 use webrtc::track::track_remote::TrackRemote;
 
-use super::types::RealtimeSession;
+use super::types::{RealtimeSession, ResponseAudioProgress};
 
 impl RealtimeSession {
     /// Send an event to the server
@@ -33,6 +36,109 @@ impl RealtimeSession {
         Ok(())
     }
 
+    /// Append audio to the server's input audio buffer
+    ///
+    /// The buffer must be PCM16 at 24 kHz; stereo is downmixed to mono.
+    /// Large buffers are split across several `input_audio_buffer.append`
+    /// events. Call [`RealtimeSession::commit_audio`] to turn the appended
+    /// audio into a user message when turn detection is disabled.
+    pub async fn append_audio(&self, audio_buffer: &AudioBuffer) -> Result<()> {
+        if audio_buffer.sample_rate != REALTIME_PCM16_SAMPLE_RATE {
+            return Err(OpenAIError::InvalidRequest(format!(
+                "Realtime input audio must be {REALTIME_PCM16_SAMPLE_RATE} Hz PCM16, got {} Hz",
+                audio_buffer.sample_rate
+            )));
+        }
+
+        for audio in audio_buffer.to_base64_chunks(MAX_AUDIO_APPEND_BYTES) {
+            self.send_event(RealtimeEvent::InputAudioBufferAppend {
+                event_id: uuid::Uuid::new_v4().to_string(),
+                audio,
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Commit the input audio buffer as a user message
+    pub async fn commit_audio(&self) -> Result<()> {
+        self.send_event(RealtimeEvent::InputAudioBufferCommit {
+            event_id: uuid::Uuid::new_v4().to_string(),
+        })
+        .await
+    }
+
+    /// Discard the audio appended since the last commit
+    pub async fn clear_audio(&self) -> Result<()> {
+        self.send_event(RealtimeEvent::InputAudioBufferClear {
+            event_id: uuid::Uuid::new_v4().to_string(),
+        })
+        .await
+    }
+
+    /// Interrupt the assistant, for example when the user starts speaking
+    ///
+    /// Sends `response.cancel`, then truncates the assistant's latest audio
+    /// item to the audio received so far so the conversation does not keep
+    /// audio the user never heard. Received audio is counted from
+    /// `response.audio.delta` events and from the remote media track. Use
+    /// [`RealtimeSession::cancel_response_at`] when playback lags behind and
+    /// the played position is known.
+    pub async fn cancel_response(&self) -> Result<()> {
+        let audio_end_ms = self
+            .response_audio()
+            .lock()
+            .await
+            .as_ref()
+            .map(ResponseAudioProgress::audio_ms);
+        self.cancel_response_at(audio_end_ms.unwrap_or(0)).await
+    }
+
+    /// Interrupt the assistant and truncate its audio at `audio_end_ms`
+    ///
+    /// `audio_end_ms` is capped at the audio received for the item. Only
+    /// `response.cancel` is sent when no assistant audio item is in
+    /// progress.
+    pub async fn cancel_response_at(&self, audio_end_ms: u32) -> Result<()> {
+        self.send_event(RealtimeEvent::ResponseCancel {
+            event_id: uuid::Uuid::new_v4().to_string(),
+        })
+        .await?;
+
+        let progress = self.response_audio().lock().await.take();
+        if let Some(progress) = progress {
+            let audio_end_ms = audio_end_ms.min(progress.audio_ms());
+            self.send_event(RealtimeEvent::ConversationItemTruncate {
+                event_id: uuid::Uuid::new_v4().to_string(),
+                item_id: progress.item_id,
+                content_index: progress.content_index,
+                audio_end_ms,
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Return the result of a function call to the model
+    ///
+    /// Sends `conversation.item.create` with a `function_call_output` item
+    /// for `call_id`. Send `response.create` afterwards to have the model
+    /// respond to the result.
+    pub async fn send_function_call_output(
+        &self,
+        call_id: impl Into<String>,
+        output: impl Into<String>,
+    ) -> Result<()> {
+        self.send_event(RealtimeEvent::ConversationItemCreate {
+            event_id: uuid::Uuid::new_v4().to_string(),
+            previous_item_id: None,
+            item: ConversationItem::new_function_call_output(call_id.into(), output.into()),
+        })
+        .await
+    }
+
     /// Send audio data
     pub async fn send_audio(&self, audio_buffer: AudioBuffer) -> Result<()> {
         if let Some(audio_track) = self.audio_track().await {
@@ -80,6 +186,11 @@ impl RealtimeSession {
         if let Ok(text) = String::from_utf8(msg.data.to_vec()) {
             match serde_json::from_str::<RealtimeEvent>(&text) {
                 Ok(event) => {
+                    ResponseAudioProgress::track(
+                        &mut *self.response_audio().lock().await,
+                        &event,
+                        self.output_bytes_per_second(),
+                    );
                     if let Err(e) = self.event_sender().send(event) {
                         warn!("Failed to send event to handler: {e}");
                     }
@@ -94,14 +205,35 @@ impl RealtimeSession {
     }
 
     /// Handle incoming audio track
+    ///
+    /// The duration of each RTP packet, taken from its timestamp, is added to
+    /// the progress of the assistant's current audio item.
     pub async fn handle_incoming_track(&self, track: Arc<TrackRemote>) {
         let audio_sender = self.audio_sender().clone();
+        let response_audio = self.response_audio().clone();
         let sample_rate = 24000; // Default sample rate for real-time audio
+        let clock_rate = track.codec().capability.clock_rate;
 
         tokio::spawn(async move {
+            let mut last_timestamp = None;
             loop {
                 match track.read_rtp().await {
                     Ok((rtp_packet, _)) => {
+                        let timestamp = rtp_packet.header.timestamp;
+                        if let Some(previous) = last_timestamp.replace(timestamp) {
+                            let ticks = timestamp.wrapping_sub(previous);
+                            // Skip jumps of a second or more, such as after a pause
+                            if clock_rate > 0 && ticks < clock_rate {
+                                let played = Duration::from_secs_f64(
+                                    f64::from(ticks) / f64::from(clock_rate),
+                                );
+                                ResponseAudioProgress::add_track_audio(
+                                    &mut *response_audio.lock().await,
+                                    played,
+                                );
+                            }
+                        }
+
                         // Convert RTP packet to audio buffer
                         // This is a simplified conversion - in reality you'd need
                         // to handle Opus decoding properly
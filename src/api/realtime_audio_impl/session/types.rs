@@ -4,11 +4,13 @@
 
 use crate::error::Result;
 use crate::models::realtime_audio::{
-    AudioBuffer, RealtimeEvent, RealtimeSessionConfig, WebRtcConnectionState, WebRtcStats,
+    AudioBuffer, ContentPart, RealtimeAudioFormat, RealtimeEvent, RealtimeSessionConfig,
+    WebRtcConnectionState, WebRtcStats,
 };
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 use tokio::sync::{Mutex, mpsc};
 use webrtc::data_channel::RTCDataChannel;
 use webrtc::peer_connection::RTCPeerConnection;
@@ -16,6 +18,87 @@ use webrtc::track::track_local::track_local_static_sample::TrackLocalStaticSampl
 
 use super::super::vad::VoiceActivityDetector;
 
+/// Audio received so far for the assistant's latest audio item
+///
+/// Over the data channel the audio arrives as `response.audio.delta`
+/// events; over WebRTC it arrives on the remote media track while the data
+/// channel only carries the item's other events. Both paths add to the same
+/// count.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ResponseAudioProgress {
+    /// Item carrying the audio
+    pub(crate) item_id: String,
+    /// Content part carrying the audio
+    pub(crate) content_index: u32,
+    /// Audio received for the item, in microseconds
+    pub(crate) audio_us: u64,
+}
+
+impl ResponseAudioProgress {
+    /// Update the progress from an incoming server event
+    ///
+    /// `bytes_per_second` is the byte rate of the session's output audio
+    /// format, used to measure `response.audio.delta` payloads. An audio
+    /// event for a different item starts tracking afresh.
+    pub(crate) fn track(progress: &mut Option<Self>, event: &RealtimeEvent, bytes_per_second: u32) {
+        let (item_id, content_index, bytes) = match event {
+            RealtimeEvent::ResponseAudioDelta {
+                item_id,
+                content_index,
+                delta,
+                ..
+            } => (item_id, *content_index, base64_decoded_len(delta)),
+            RealtimeEvent::ResponseContentPartAdded {
+                item_id,
+                content_index,
+                part: ContentPart::Audio { .. },
+                ..
+            }
+            | RealtimeEvent::ResponseAudioTranscriptDelta {
+                item_id,
+                content_index,
+                ..
+            } => (item_id, *content_index, 0),
+            _ => return,
+        };
+        let received_us = (bytes as u64 * 1_000_000)
+            .checked_div(u64::from(bytes_per_second))
+            .unwrap_or(0);
+        match progress {
+            Some(current)
+                if current.item_id == *item_id && current.content_index == content_index =>
+            {
+                current.audio_us += received_us;
+            }
+            _ => {
+                *progress = Some(Self {
+                    item_id: item_id.clone(),
+                    content_index,
+                    audio_us: received_us,
+                });
+            }
+        }
+    }
+
+    /// Add audio received on the remote media track to the current item
+    pub(crate) fn add_track_audio(progress: &mut Option<Self>, duration: Duration) {
+        if let Some(current) = progress {
+            current.audio_us += u64::try_from(duration.as_micros()).unwrap_or(u64::MAX);
+        }
+    }
+
+    /// Milliseconds of audio received
+    pub(crate) fn audio_ms(&self) -> u32 {
+        u32::try_from(self.audio_us / 1000).unwrap_or(u32::MAX)
+    }
+}
+
+/// Length of the data a padded base64 string decodes to
+fn base64_decoded_len(encoded: &str) -> usize {
+    let padding = encoded.bytes().rev().take_while(|&b| b == b'=').count();
+    (encoded.len() / 4 * 3).saturating_sub(padding)
+}
+
 /// Real-time audio session
 pub struct RealtimeSession {
     /// Session ID
@@ -43,7 +126,7 @@ pub struct RealtimeSession {
     audio_receiver: Arc<Mutex<Option<mpsc::UnboundedReceiver<AudioBuffer>>>>,
 
     /// Session configuration
-    _config: RealtimeSessionConfig,
+    config: RealtimeSessionConfig,
 
     /// Connection state
     connection_state: Arc<Mutex<WebRtcConnectionState>>,
@@ -62,6 +145,9 @@ pub struct RealtimeSession {
 
     /// Reconnection handler
     reconnect_handler: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+
+    /// Audio received for the assistant's latest audio item
+    response_audio: Arc<Mutex<Option<ResponseAudioProgress>>>,
 }
 
 impl RealtimeSession {
@@ -86,13 +172,14 @@ impl RealtimeSession {
             event_receiver: Arc::new(Mutex::new(Some(event_receiver))),
             audio_sender,
             audio_receiver: Arc::new(Mutex::new(Some(audio_receiver))),
-            _config: config,
+            config,
             connection_state: Arc::new(Mutex::new(WebRtcConnectionState::New)),
             stats: Arc::new(Mutex::new(WebRtcStats::default())),
             _vad: Arc::new(Mutex::new(vad)),
             started_at: Utc::now(),
             is_active: Arc::new(AtomicBool::new(true)),
             reconnect_handler: Arc::new(Mutex::new(None)),
+            response_audio: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -188,6 +275,20 @@ impl RealtimeSession {
         &self.event_sender
     }
 
+    /// Get the response audio progress (for internal use)
+    pub(crate) fn response_audio(&self) -> &Arc<Mutex<Option<ResponseAudioProgress>>> {
+        &self.response_audio
+    }
+
+    /// Byte rate of the session's output audio format (for internal use)
+    pub(crate) fn output_bytes_per_second(&self) -> u32 {
+        self.config
+            .output_audio_format
+            .as_ref()
+            .unwrap_or(&RealtimeAudioFormat::Pcm16)
+            .bytes_per_second()
+    }
+
     /// Get the audio sender (for internal use)
     pub(crate) fn audio_sender(&self) -> &mpsc::UnboundedSender<AudioBuffer> {
         &self.audio_sender
@@ -6,7 +6,6 @@ use crate::models::realtime_audio::{
     AudioBuffer, VoiceActivityDetectionConfig, VoiceActivityResult,
 };
 use chrono::Utc;
-use std::time::Instant;
 
 /// Voice Activity Detector
 pub struct VoiceActivityDetector {
@@ -14,8 +13,12 @@ pub struct VoiceActivityDetector {
     config: VoiceActivityDetectionConfig,
     /// History of audio energy levels for smoothing
     energy_history: Vec<f32>,
-    /// Timestamp of last detected speech
-    last_speech_time: Option<Instant>,
+    /// Energy after attack/release smoothing
+    smoothed_energy: f32,
+    /// Audio time since the last speech frame, in milliseconds
+    silence_ms: u32,
+    /// Whether the last frame was above the active threshold
+    in_speech_frame: bool,
     /// Whether speech is currently being detected
     is_speaking: bool,
     /// Audio sample rate (for future use)
@@ -29,15 +32,22 @@ impl VoiceActivityDetector {
         Self {
             config,
             energy_history: Vec::new(),
-            last_speech_time: None,
+            smoothed_energy: 0.0,
+            silence_ms: 0,
+            in_speech_frame: false,
             is_speaking: false,
             _sample_rate: sample_rate,
         }
     }
 
     /// Process audio buffer and detect voice activity
+    ///
+    /// Speech starts when the smoothed energy exceeds `threshold` and is well
+    /// above the recent average, and continues while it stays above
+    /// `speech_end_threshold`. Speaking ends once `silence_duration_ms` of
+    /// audio has passed without a speech frame.
     pub fn process(&mut self, audio_buffer: &AudioBuffer) -> VoiceActivityResult {
-        let energy = audio_buffer.rms_energy();
+        let energy = self.smooth(audio_buffer.rms_energy());
         self.energy_history.push(energy);
 
         // Keep only recent history (last 100 frames)
@@ -52,8 +62,18 @@ impl VoiceActivityDetector {
             self.energy_history.iter().sum::<f32>() / self.energy_history.len() as f32
         };
 
-        // Determine if speech is detected
-        let is_speech = energy > self.config.threshold && energy > avg_energy * 2.0;
+        // Determine if speech is detected, holding on to speech until the
+        // energy drops below the lower end threshold
+        let end_threshold = self
+            .config
+            .speech_end_threshold
+            .unwrap_or(self.config.threshold);
+        let is_speech = if self.in_speech_frame {
+            energy > end_threshold
+        } else {
+            energy > self.config.threshold && energy > avg_energy * 2.0
+        };
+        self.in_speech_frame = is_speech;
         let confidence = if is_speech {
             (energy / self.config.threshold).min(1.0)
         } else {
@@ -61,12 +81,13 @@ impl VoiceActivityDetector {
         };
 
         if is_speech {
-            self.last_speech_time = Some(Instant::now());
+            self.silence_ms = 0;
             self.is_speaking = true;
-        } else if let Some(last_speech) = self.last_speech_time
-            && last_speech.elapsed().as_millis() > u128::from(self.config.silence_duration_ms)
-        {
-            self.is_speaking = false;
+        } else {
+            self.silence_ms = self.silence_ms.saturating_add(audio_buffer.duration_ms());
+            if self.silence_ms >= self.config.silence_duration_ms {
+                self.is_speaking = false;
+            }
         }
 
         VoiceActivityResult {
@@ -77,6 +98,19 @@ impl VoiceActivityDetector {
         }
     }
 
+    /// Apply attack/release smoothing to a frame's energy
+    fn smooth(&mut self, energy: f32) -> f32 {
+        let factor = if energy > self.smoothed_energy {
+            self.config.attack
+        } else {
+            self.config.release
+        }
+        .unwrap_or(1.0)
+        .clamp(0.0, 1.0);
+        self.smoothed_energy += (energy - self.smoothed_energy) * factor;
+        self.smoothed_energy
+    }
+
     /// Check if currently speaking
     #[must_use]
     pub fn is_speaking(&self) -> bool {
@@ -0,0 +1,581 @@
+//! # Generic Resource Trait
+//!
+//! Several `OpenAI` endpoints share the same create/retrieve/list/delete shape.
+//! This module captures that shape in the [`Resource`] and [`DeletableResource`]
+//! traits so that admin tooling can be written once and run against any
+//! resource type.
+//!
+//! ## Implemented for
+//!
+//! - [`FilesApi`] (deletable)
+//! - [`AssistantsApi`] (deletable)
+//! - [`VectorStoresApi`] (deletable)
+//! - [`BatchApi`]
+//! - [`FineTuningApi`]
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use openai_rust_sdk::api::common::ApiClientConstructors;
+//! use openai_rust_sdk::api::files::FilesApi;
+//! use openai_rust_sdk::api::resource::{DeletableResource, Resource, ResourceObject};
+//!
+//! async fn delete_created_before<R: DeletableResource>(
+//!     api: &R,
+//!     cutoff: i64,
+//! ) -> openai_rust_sdk::error::Result<usize> {
+//!     let mut deleted = 0;
+//!     for object in api.list_all().await? {
+//!         if object.created_at() < cutoff {
+//!             api.delete(object.id()).await?;
+//!             deleted += 1;
+//!         }
+//!     }
+//!     Ok(deleted)
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let api = FilesApi::new("your-api-key")?;
+//! let deleted = delete_created_before(&api, 1_700_000_000).await?;
+//! println!("Deleted {deleted} files");
+//! # Ok::<(), openai_rust_sdk::OpenAIError>(())
+//! # });
+//! ```
+
+use crate::api::assistants::AssistantsApi;
+use crate::api::batch::{Batch, BatchApi, BatchList, CreateBatchRequest};
+use crate::api::common::StandardListParams;
+use crate::api::files::FilesApi;
+use crate::api::fine_tuning::FineTuningApi;
+use crate::api::vector_stores::VectorStoresApi;
+use crate::error::Result;
+use crate::models::assistants::{
+    Assistant, AssistantRequest, DeletionStatus, ListAssistantsParams, ListAssistantsResponse,
+};
+use crate::models::files::{
+    File, FileDeleteResponse, FileUploadRequest, ListFilesParams, ListFilesResponse,
+};
+use crate::models::fine_tuning::{
+    FineTuningJob, FineTuningJobCheckpoint, FineTuningJobRequest,
+    ListFineTuningJobCheckpointsResponse, ListFineTuningJobsParams, ListFineTuningJobsResponse,
+};
+use crate::models::shared_traits::DeleteResponse;
+use crate::models::vector_stores::{
+    ListVectorStoresParams, ListVectorStoresResponse, VectorStore, VectorStoreDeleteResponse,
+    VectorStoreRequest,
+};
+use futures::stream::{self, StreamExt};
+use std::future::Future;
+
+/// An object returned by a [`Resource`] that has an ID and a creation time
+pub trait ResourceObject {
+    /// The unique identifier of the object
+    fn id(&self) -> &str;
+
+    /// Unix timestamp (in seconds) of when the object was created
+    fn created_at(&self) -> i64;
+}
+
+/// A single page returned by a [`Resource`] list call
+pub trait ResourcePage {
+    /// The object type contained in the page
+    type Item;
+
+    /// Consumes the page and returns its objects
+    fn into_items(self) -> Vec<Self::Item>;
+
+    /// Whether more objects are available after this page
+    fn has_more(&self) -> bool;
+}
+
+/// A sub-API exposing create, retrieve, and list operations for one object type
+pub trait Resource: Send + Sync {
+    /// The object managed by this resource
+    type Object: ResourceObject + Send;
+    /// The request used to create a new object
+    type CreateRequest: Send;
+    /// Parameters accepted by the list endpoint
+    type ListParams: Send;
+    /// A page of objects returned by the list endpoint
+    type ListResponse: ResourcePage<Item = Self::Object> + Send;
+
+    /// Creates a new object
+    fn create(
+        &self,
+        request: Self::CreateRequest,
+    ) -> impl Future<Output = Result<Self::Object>> + Send;
+
+    /// Retrieves an object by ID
+    fn retrieve(&self, id: &str) -> impl Future<Output = Result<Self::Object>> + Send;
+
+    /// Lists a single page of objects
+    fn list(
+        &self,
+        params: Option<Self::ListParams>,
+    ) -> impl Future<Output = Result<Self::ListResponse>> + Send;
+
+    /// Builds list parameters requesting the page after the given cursor
+    fn page_params(after: Option<String>) -> Self::ListParams;
+
+    /// Lists every object by following the pagination cursor until exhausted
+    fn list_all(&self) -> impl Future<Output = Result<Vec<Self::Object>>> + Send {
+        collect_pages(|after| self.list(Some(Self::page_params(after))))
+    }
+}
+
+/// Collects every object of a paginated list by following the `after` cursor
+///
+/// `fetch_page` is called with the ID of the last object seen so far,
+/// starting from `None`, until a page reports that no more objects follow.
+pub(crate) async fn collect_pages<P, F, Fut>(mut fetch_page: F) -> Result<Vec<P::Item>>
+where
+    P: ResourcePage,
+    P::Item: ResourceObject,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    let mut objects = Vec::new();
+    let mut after = None;
+
+    loop {
+        let page = fetch_page(after).await?;
+        let has_more = page.has_more();
+        let items = page.into_items();
+        after = items.last().map(|object| object.id().to_string());
+        objects.extend(items);
+
+        if !has_more || after.is_none() {
+            return Ok(objects);
+        }
+    }
+}
+
+/// Default number of concurrent delete requests used by bulk cleanup helpers
+pub const DEFAULT_BULK_DELETE_CONCURRENCY: usize = 5;
+
+/// Outcome of a bulk delete operation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkDeleteSummary {
+    /// Number of objects the API confirmed as deleted
+    pub deleted: usize,
+    /// Number of objects whose deletion failed or was not confirmed
+    pub failed: usize,
+}
+
+/// A [`Resource`] whose objects can be deleted
+pub trait DeletableResource: Resource {
+    /// The response returned by the delete endpoint
+    type DeleteResponse: DeleteResponse + Send;
+
+    /// Deletes an object by ID
+    fn delete(&self, id: &str) -> impl Future<Output = Result<Self::DeleteResponse>> + Send;
+
+    /// Deletes every object matching `predicate`
+    ///
+    /// All pages are listed first, then matching objects are deleted with at
+    /// most `max_concurrent` requests in flight. Individual failures are
+    /// counted rather than aborting the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if listing the objects fails
+    fn delete_where<F>(
+        &self,
+        predicate: F,
+        max_concurrent: usize,
+    ) -> impl Future<Output = Result<BulkDeleteSummary>> + Send
+    where
+        F: Fn(&Self::Object) -> bool + Send,
+    {
+        async move {
+            let ids: Vec<String> = self
+                .list_all()
+                .await?
+                .iter()
+                .filter(|object| predicate(object))
+                .map(|object| object.id().to_string())
+                .collect();
+
+            let outcomes: Vec<bool> = stream::iter(ids)
+                .map(|id| async move {
+                    self.delete(&id)
+                        .await
+                        .is_ok_and(|response| response.is_deleted())
+                })
+                .buffer_unordered(max_concurrent.max(1))
+                .collect()
+                .await;
+
+            let deleted = outcomes.iter().filter(|deleted| **deleted).count();
+            Ok(BulkDeleteSummary {
+                deleted,
+                failed: outcomes.len() - deleted,
+            })
+        }
+    }
+}
+
+/// Implements [`ResourceObject`] for a model with `id` and `created_at` fields
+macro_rules! impl_resource_object {
+    ($type:ty) => {
+        impl ResourceObject for $type {
+            fn id(&self) -> &str {
+                &self.id
+            }
+
+            fn created_at(&self) -> i64 {
+                i64::try_from(self.created_at).unwrap_or(i64::MAX)
+            }
+        }
+    };
+}
+
+/// Implements [`ResourcePage`] for a list response with `data` and `has_more` fields
+macro_rules! impl_resource_page {
+    ($type:ty, $item:ty) => {
+        impl ResourcePage for $type {
+            type Item = $item;
+
+            fn into_items(self) -> Vec<Self::Item> {
+                self.data
+            }
+
+            fn has_more(&self) -> bool {
+                self.has_more
+            }
+        }
+    };
+}
+
+impl_resource_object!(File);
+impl_resource_object!(Assistant);
+impl_resource_object!(VectorStore);
+impl_resource_object!(Batch);
+impl_resource_object!(FineTuningJob);
+impl_resource_object!(FineTuningJobCheckpoint);
+
+impl_resource_page!(ListFilesResponse, File);
+impl_resource_page!(ListAssistantsResponse, Assistant);
+impl_resource_page!(ListVectorStoresResponse, VectorStore);
+impl_resource_page!(BatchList, Batch);
+impl_resource_page!(ListFineTuningJobsResponse, FineTuningJob);
+impl_resource_page!(
+    ListFineTuningJobCheckpointsResponse,
+    FineTuningJobCheckpoint
+);
+
+impl Resource for FilesApi {
+    type Object = File;
+    type CreateRequest = FileUploadRequest;
+    type ListParams = ListFilesParams;
+    type ListResponse = ListFilesResponse;
+
+    async fn create(&self, request: FileUploadRequest) -> Result<File> {
+        self.upload_file(request).await
+    }
+
+    async fn retrieve(&self, id: &str) -> Result<File> {
+        self.retrieve_file(id).await
+    }
+
+    async fn list(&self, params: Option<ListFilesParams>) -> Result<ListFilesResponse> {
+        self.list_files(params).await
+    }
+
+    fn page_params(after: Option<String>) -> ListFilesParams {
+        ListFilesParams {
+            after,
+            ..ListFilesParams::default()
+        }
+    }
+}
+
+impl DeletableResource for FilesApi {
+    type DeleteResponse = FileDeleteResponse;
+
+    async fn delete(&self, id: &str) -> Result<FileDeleteResponse> {
+        self.delete_file(id).await
+    }
+}
+
+impl Resource for AssistantsApi {
+    type Object = Assistant;
+    type CreateRequest = AssistantRequest;
+    type ListParams = ListAssistantsParams;
+    type ListResponse = ListAssistantsResponse;
+
+    async fn create(&self, request: AssistantRequest) -> Result<Assistant> {
+        self.create_assistant(request).await
+    }
+
+    async fn retrieve(&self, id: &str) -> Result<Assistant> {
+        self.retrieve_assistant(id).await
+    }
+
+    async fn list(&self, params: Option<ListAssistantsParams>) -> Result<ListAssistantsResponse> {
+        self.list_assistants(params).await
+    }
+
+    fn page_params(after: Option<String>) -> ListAssistantsParams {
+        ListAssistantsParams {
+            after,
+            ..ListAssistantsParams::default()
+        }
+    }
+}
+
+impl DeletableResource for AssistantsApi {
+    type DeleteResponse = DeletionStatus;
+
+    async fn delete(&self, id: &str) -> Result<DeletionStatus> {
+        self.delete_assistant(id).await
+    }
+}
+
+impl Resource for VectorStoresApi {
+    type Object = VectorStore;
+    type CreateRequest = VectorStoreRequest;
+    type ListParams = ListVectorStoresParams;
+    type ListResponse = ListVectorStoresResponse;
+
+    async fn create(&self, request: VectorStoreRequest) -> Result<VectorStore> {
+        self.create_vector_store(request).await
+    }
+
+    async fn retrieve(&self, id: &str) -> Result<VectorStore> {
+        self.retrieve_vector_store(id).await
+    }
+
+    async fn list(
+        &self,
+        params: Option<ListVectorStoresParams>,
+    ) -> Result<ListVectorStoresResponse> {
+        self.list_vector_stores(params).await
+    }
+
+    fn page_params(after: Option<String>) -> ListVectorStoresParams {
+        ListVectorStoresParams {
+            after,
+            ..ListVectorStoresParams::default()
+        }
+    }
+}
+
+impl DeletableResource for VectorStoresApi {
+    type DeleteResponse = VectorStoreDeleteResponse;
+
+    async fn delete(&self, id: &str) -> Result<VectorStoreDeleteResponse> {
+        self.delete_vector_store(id).await
+    }
+}
+
+impl Resource for BatchApi {
+    type Object = Batch;
+    type CreateRequest = CreateBatchRequest;
+    type ListParams = StandardListParams;
+    type ListResponse = BatchList;
+
+    async fn create(&self, request: CreateBatchRequest) -> Result<Batch> {
+        self.create_batch_with_metadata(&request.input_file_id, &request.endpoint, request.metadata)
+            .await
+    }
+
+    async fn retrieve(&self, id: &str) -> Result<Batch> {
+        self.get_batch_status(id).await
+    }
+
+    async fn list(&self, params: Option<StandardListParams>) -> Result<BatchList> {
+        let params = params.unwrap_or_default();
+        self.list_batches(params.limit, params.after.as_deref())
+            .await
+    }
+
+    fn page_params(after: Option<String>) -> StandardListParams {
+        StandardListParams {
+            after,
+            ..StandardListParams::default()
+        }
+    }
+}
+
+impl Resource for FineTuningApi {
+    type Object = FineTuningJob;
+    type CreateRequest = FineTuningJobRequest;
+    type ListParams = ListFineTuningJobsParams;
+    type ListResponse = ListFineTuningJobsResponse;
+
+    async fn create(&self, request: FineTuningJobRequest) -> Result<FineTuningJob> {
+        self.create_fine_tuning_job(request).await
+    }
+
+    async fn retrieve(&self, id: &str) -> Result<FineTuningJob> {
+        self.retrieve_fine_tuning_job(id).await
+    }
+
+    async fn list(
+        &self,
+        params: Option<ListFineTuningJobsParams>,
+    ) -> Result<ListFineTuningJobsResponse> {
+        self.list_fine_tuning_jobs(params).await
+    }
+
+    fn page_params(after: Option<String>) -> ListFineTuningJobsParams {
+        ListFineTuningJobsParams {
+            after,
+            ..ListFineTuningJobsParams::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::common::ApiClientConstructors;
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    fn file_json(id: &str, created_at: u64) -> serde_json::Value {
+        file_json_with_purpose(id, created_at, "batch")
+    }
+
+    fn file_json_with_purpose(id: &str, created_at: u64, purpose: &str) -> serde_json::Value {
+        json!({
+            "id": id,
+            "object": "file",
+            "bytes": 120,
+            "created_at": created_at,
+            "filename": format!("{id}.jsonl"),
+            "purpose": purpose
+        })
+    }
+
+    #[test]
+    fn test_resource_page_for_file_list() {
+        let page: ListFilesResponse = serde_json::from_value(json!({
+            "object": "list",
+            "data": [file_json("file-1", 100), file_json("file-2", 200)],
+            "has_more": true
+        }))
+        .unwrap();
+
+        assert!(page.has_more());
+        let items = page.into_items();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].id(), "file-2");
+        assert_eq!(items[1].created_at(), 200);
+    }
+
+    #[test]
+    fn test_negative_created_at_is_kept() {
+        let assistant: Assistant = serde_json::from_value(json!({
+            "id": "asst_1",
+            "created_at": -5,
+            "model": "gpt-4o"
+        }))
+        .unwrap();
+        assert_eq!(assistant.created_at(), -5);
+    }
+
+    #[tokio::test]
+    async fn test_list_all_follows_pagination_cursor() {
+        let server = MockServer::start_async().await;
+
+        let second_page = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/files")
+                    .query_param("after", "file-2");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(json!({
+                        "object": "list",
+                        "data": [file_json("file-3", 300)],
+                        "has_more": false
+                    }));
+            })
+            .await;
+
+        let first_page = server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/files");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(json!({
+                        "object": "list",
+                        "data": [file_json("file-1", 100), file_json("file-2", 200)],
+                        "has_more": true
+                    }));
+            })
+            .await;
+
+        let api = FilesApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let files = api.list_all().await.unwrap();
+
+        let ids: Vec<&str> = files.iter().map(ResourceObject::id).collect();
+        assert_eq!(ids, vec!["file-1", "file-2", "file-3"]);
+        first_page.assert_async().await;
+        second_page.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_delete_by_purpose_counts_deleted_and_failed() {
+        let server = MockServer::start_async().await;
+        let now = crate::models::vector_stores::common_types::utils::current_timestamp();
+        let old = now - 10 * 24 * 60 * 60;
+
+        server
+            .mock_async(|when, then| {
+                when.method(GET).path("/v1/files");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(json!({
+                        "object": "list",
+                        "data": [
+                            file_json("file-old", old),
+                            file_json("file-new", now),
+                            file_json_with_purpose("file-tune", old, "fine-tune"),
+                            file_json("file-broken", old)
+                        ],
+                        "has_more": false
+                    }));
+            })
+            .await;
+
+        let delete_old = server
+            .mock_async(|when, then| {
+                when.method(DELETE).path("/v1/files/file-old");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(json!({"id": "file-old", "object": "file", "deleted": true}));
+            })
+            .await;
+
+        let delete_broken = server
+            .mock_async(|when, then| {
+                when.method(DELETE).path("/v1/files/file-broken");
+                then.status(500)
+                    .header("Content-Type", "application/json")
+                    .json_body(json!({"error": {"message": "boom", "type": "server_error"}}));
+            })
+            .await;
+
+        let api = FilesApi::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let summary = api
+            .delete_by_purpose(
+                crate::models::files::FilePurpose::Batch,
+                std::time::Duration::from_secs(7 * 24 * 60 * 60),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary,
+            BulkDeleteSummary {
+                deleted: 1,
+                failed: 1
+            }
+        );
+        delete_old.assert_async().await;
+        delete_broken.assert_async().await;
+    }
+}
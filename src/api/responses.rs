@@ -1,9 +1,11 @@
-use crate::api::base::HttpClient;
+use crate::api::base::{HttpClient, validate_request};
 use crate::api::common::ApiClientConstructors;
 use crate::api::shared_utilities::EnumConverter;
 use crate::error::{OpenAIError, Result};
+use crate::models::models::{ModelCapabilities, ModelFamily};
 use crate::models::responses::{
-    Message, MessageRole, ResponseInput, ResponseRequest, ResponseResult,
+    ContinuedResponse, Message, MessageRole, ParameterCheckMode, ResponseInput, ResponseRequest,
+    ResponseResult, validate_against_model,
 };
 
 /// `OpenAI` Responses API client
@@ -11,24 +13,45 @@ use crate::models::responses::{
 pub struct ResponsesApi {
     /// HTTP client for making API requests
     http_client: HttpClient,
+    /// How requests are checked against the model's capabilities, if at all
+    parameter_check: Option<ParameterCheckMode>,
 }
 
 impl ApiClientConstructors for ResponsesApi {
     fn from_http_client(http_client: HttpClient) -> Self {
-        Self { http_client }
+        Self {
+            http_client,
+            parameter_check: None,
+        }
     }
 }
 
 impl ResponsesApi {
     /// Create a new `ResponsesApi` client with custom base URL
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
+    }
+
+    /// Check requests against the model's capabilities before sending them
+    ///
+    /// In [`ParameterCheckMode::Warn`] incompatible parameters are logged and
+    /// the request is sent unchanged; in [`ParameterCheckMode::Error`] the
+    /// request is rejected locally. Models the SDK does not recognize are
+    /// never checked. The check covers every request sent through this
+    /// client, including streams started from a [`StreamingApi`] built on
+    /// it.
+    ///
+    /// [`StreamingApi`]: crate::api::streaming::StreamingApi
+    #[must_use]
+    pub fn with_parameter_check(mut self, mode: ParameterCheckMode) -> Self {
+        self.parameter_check = Some(mode);
+        self
     }
 
     /// Create a response using the /v1/chat/completions endpoint
     pub async fn create_response(&self, request: &ResponseRequest) -> Result<ResponseResult> {
+        self.check_parameters(request)?;
+
         // Convert our request format to OpenAI's chat completions format
         let openai_request = self.to_openai_format(request)?;
 
@@ -37,6 +60,12 @@ impl ResponsesApi {
             .post("/v1/chat/completions", &openai_request)
             .await?;
 
+        if let Some(stop) = &request.stop {
+            for choice in &mut result.choices {
+                choice.apply_stop_sequences(stop);
+            }
+        }
+
         // Post-process response for structured outputs
         if let Some(response_format) = &request.response_format {
             self.process_structured_response(&mut result, response_format)?;
@@ -45,10 +74,86 @@ impl ResponsesApi {
         Ok(result)
     }
 
+    /// Run the configured parameter check on a request
+    pub(crate) fn check_parameters(&self, request: &ResponseRequest) -> Result<()> {
+        let Some(mode) = self.parameter_check else {
+            return Ok(());
+        };
+        let capabilities = ModelCapabilities::from_model_id(&request.model);
+        if capabilities.family == ModelFamily::Unknown {
+            return Ok(());
+        }
+        for warning in validate_against_model(request, &capabilities, mode)? {
+            log::warn!("{}: {warning}", request.model);
+        }
+        Ok(())
+    }
+
+    /// Create a response, continuing it while the output is cut off by the token limit
+    ///
+    /// Whenever a response finishes with `length`, the request is re-issued
+    /// with the output so far appended as an assistant message, up to
+    /// `max_continuations` extra requests. The text of every request is
+    /// concatenated and the usage summed.
+    pub async fn create_with_continuation(
+        &self,
+        request: &ResponseRequest,
+        max_continuations: u32,
+    ) -> Result<ContinuedResponse> {
+        let mut messages = match &request.input {
+            ResponseInput::Text(text) => vec![Message::user(text.clone())],
+            ResponseInput::Messages(messages) => messages.clone(),
+        };
+        if let (ResponseInput::Text(_), Some(instructions)) =
+            (&request.input, &request.instructions)
+        {
+            messages.insert(0, Message::system(instructions.clone()));
+        }
+
+        let mut current = request.clone();
+        let mut continued = ContinuedResponse {
+            text: String::new(),
+            usage: None,
+            continuations: 0,
+            finish_reason: None,
+        };
+
+        loop {
+            let response = self.create_response(&current).await?;
+            let choice = response
+                .choices
+                .first()
+                .ok_or_else(|| OpenAIError::invalid_request("No choices returned in response"))?;
+
+            continued
+                .text
+                .push_str(choice.message.content.as_deref().unwrap_or_default());
+            continued.finish_reason = choice.finish_reason_kind();
+            if let Some(usage) = &response.usage {
+                match &mut continued.usage {
+                    Some(total) => total.accumulate(usage),
+                    None => continued.usage = Some(usage.clone()),
+                }
+            }
+
+            if !continued.is_truncated() || continued.continuations >= max_continuations {
+                return Ok(continued);
+            }
+
+            let mut context = messages.clone();
+            context.push(Message::assistant(continued.text.clone()));
+            current.input = ResponseInput::Messages(context);
+            current.instructions = None;
+            continued.continuations += 1;
+        }
+    }
+
     /// Convert our internal request format to `OpenAI`'s chat completions format
     pub fn to_openai_format(&self, request: &ResponseRequest) -> Result<serde_json::Value> {
         use serde_json::json;
 
+        validate_request(request)?;
+
         let messages =
             self.convert_input_to_messages(&request.input, request.instructions.as_ref());
 
@@ -194,6 +299,12 @@ impl ResponsesApi {
         if let Some(max_tokens) = request.max_tokens {
             openai_request["max_tokens"] = json!(max_tokens);
         }
+        if let Some(n) = request.n {
+            openai_request["n"] = json!(n);
+        }
+        if let Some(stop) = &request.stop {
+            openai_request["stop"] = json!(stop);
+        }
         if let Some(top_p) = request.top_p {
             openai_request["top_p"] = json!(top_p);
         }
@@ -203,8 +314,14 @@ impl ResponsesApi {
         if let Some(pres_penalty) = request.presence_penalty {
             openai_request["presence_penalty"] = json!(pres_penalty);
         }
+        if let Some(cache_key) = &request.prompt_cache_key {
+            openai_request["prompt_cache_key"] = json!(cache_key);
+        }
         if request.stream == Some(true) {
             openai_request["stream"] = json!(true);
+            if let Some(options) = &request.stream_options {
+                openai_request["stream_options"] = json!(options);
+            }
         }
     }
 
@@ -329,11 +446,17 @@ impl ResponsesApi {
         self.http_client.api_key()
     }
 
-    /// Get the base URL (for internal use)
+    /// Get the base URL (for testing purposes)
+    #[cfg(test)]
     pub(crate) fn base_url(&self) -> &str {
         self.http_client.base_url()
     }
 
+    /// Build the full URL for an endpoint path (for internal use)
+    pub(crate) fn url_for(&self, path: &str) -> String {
+        self.http_client.build_simple_url(path)
+    }
+
     /// Get the HTTP client (for internal use)
     pub(crate) fn client(&self) -> &reqwest::Client {
         self.http_client.client()
@@ -456,6 +579,67 @@ impl ResponsesApi {
         }
     }
 
+    /// Send a request and deserialize the JSON output into `T`
+    ///
+    /// The JSON is taken from the validated structured data when present and
+    /// otherwise from the first choice's text, with any Markdown code fence
+    /// stripped. Use [`Self::create_typed_with_schema`] to attach `T`'s schema
+    /// to the request automatically.
+    pub async fn create_typed<T>(&self, request: &ResponseRequest) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let result = self.create_response(request).await?;
+        Self::parse_typed_output(&result)
+    }
+
+    /// Send a request with `T`'s schema attached as a strict response format
+    ///
+    /// A response format already set on the request is left untouched.
+    pub async fn create_typed_with_schema<T>(&self, request: &ResponseRequest) -> Result<T>
+    where
+        T: crate::schema::StructuredOutput,
+    {
+        let mut request = request.clone();
+        if request.response_format.is_none() {
+            request = request.with_strict_json_schema(T::schema_name(), T::schema().build().schema);
+        }
+        self.create_typed(&request).await
+    }
+
+    /// Deserialize the JSON output of a response into `T`
+    fn parse_typed_output<T>(result: &ResponseResult) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let type_name = std::any::type_name::<T>();
+
+        if let Some(refusal) = result.refusal() {
+            return Err(OpenAIError::Refusal(refusal.to_string()));
+        }
+
+        if let Some(structured_data) = result.structured_data() {
+            return serde_json::from_value(structured_data.clone()).map_err(|e| {
+                OpenAIError::ParseError(format!(
+                    "Structured output does not match {type_name}: {e}"
+                ))
+            });
+        }
+
+        let text = result.text().ok_or_else(|| {
+            OpenAIError::ParseError(format!(
+                "Response has no content to deserialize into {type_name}"
+            ))
+        })?;
+        let json = strip_code_fence(text);
+        serde_json::from_str(json).map_err(|e| {
+            let preview: String = json.chars().take(200).collect();
+            OpenAIError::ParseError(format!(
+                "Response JSON does not match {type_name}: {e} (content: {preview})"
+            ))
+        })
+    }
+
     /// Create a response with JSON object format
     pub async fn create_json_response(
         &self,
@@ -521,6 +705,25 @@ impl ResponsesApi {
     }
 }
 
+/// Strip a surrounding Markdown code fence (e.g. "```json ... ```") from model output
+pub(crate) fn strip_code_fence(text: &str) -> &str {
+    let trimmed = text.trim();
+    trimmed
+        .strip_prefix("```")
+        .and_then(|rest| rest.strip_suffix("```"))
+        .map_or(trimmed, |body| {
+            body.split_once('\n')
+                .map_or(body, |(language, code)| {
+                    if language.trim().chars().all(char::is_alphanumeric) {
+                        code
+                    } else {
+                        body
+                    }
+                })
+                .trim()
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -551,6 +754,303 @@ mod tests {
         assert_eq!(request.max_tokens, Some(100));
     }
 
+    #[test]
+    fn test_n_is_forwarded_and_validated() {
+        let api = ResponsesApi::new("test-key").unwrap();
+
+        let request = ResponseRequest::new_text("gpt-4", "Hello").with_n(3);
+        let payload = api.to_openai_format(&request).unwrap();
+        assert_eq!(payload["n"], 3);
+
+        let streaming = request.clone().with_streaming(true);
+        assert!(matches!(
+            api.to_openai_format(&streaming),
+            Err(OpenAIError::InvalidRequest(_))
+        ));
+
+        let zero = ResponseRequest::new_text("gpt-4", "Hello").with_n(0);
+        assert!(api.to_openai_format(&zero).is_err());
+
+        let single_stream = ResponseRequest::new_text("gpt-4", "Hello")
+            .with_n(1)
+            .with_streaming(true);
+        assert!(api.to_openai_format(&single_stream).is_ok());
+    }
+
+    #[test]
+    fn test_prompt_cache_key_is_forwarded() {
+        let api = ResponsesApi::new("test-key").unwrap();
+
+        let request = ResponseRequest::new_text("gpt-4", "Hello");
+        let payload = api.to_openai_format(&request).unwrap();
+        assert!(payload.get("prompt_cache_key").is_none());
+
+        let request = request.with_prompt_cache_key("user-42");
+        let payload = api.to_openai_format(&request).unwrap();
+        assert_eq!(payload["prompt_cache_key"], "user-42");
+    }
+
+    #[test]
+    fn test_stop_sequences_are_limited() {
+        let api = ResponsesApi::new("test-key").unwrap();
+
+        let four = ResponseRequest::new_text("gpt-4", "Hello").with_stop(["a", "b", "c", "d"]);
+        assert!(api.to_openai_format(&four).is_ok());
+
+        let five = four.clone().with_stop(["a", "b", "c", "d", "e"]);
+        assert!(matches!(
+            api.to_openai_format(&five),
+            Err(OpenAIError::InvalidRequest(message)) if message.contains("at most 4")
+        ));
+    }
+
+    #[test]
+    fn test_validate_all_reports_every_error() {
+        use crate::models::shared_traits::Validate;
+
+        let request = ResponseRequest::new_text("gpt-4", "Hello")
+            .with_stop(["a", "b", "c", "d", "e"])
+            .with_n(0);
+        let errors = Validate::validate_all(&request).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("at most 4"));
+        assert_eq!(errors[1], "n must be at least 1");
+    }
+
+    #[test]
+    fn test_stream_options_are_sent_only_when_streaming() {
+        let api = ResponsesApi::new("test-key").unwrap();
+
+        let request = ResponseRequest::new_text("gpt-4", "Hello").with_stream_usage(true);
+        let payload = api.to_openai_format(&request).unwrap();
+        assert!(payload.get("stream_options").is_none());
+
+        let payload = api.to_openai_format(&request.with_streaming(true)).unwrap();
+        assert_eq!(
+            payload["stream_options"],
+            serde_json::json!({"include_usage": true})
+        );
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Weather {
+        city: String,
+        celsius: i32,
+    }
+
+    impl crate::schema::StructuredOutput for Weather {
+        fn schema_name() -> &'static str {
+            "weather"
+        }
+
+        fn schema() -> crate::schema::SchemaBuilder {
+            crate::schema::SchemaBuilder::object()
+                .property("city", crate::schema::SchemaBuilder::string())
+                .property("celsius", crate::schema::SchemaBuilder::integer())
+                .required(&["city", "celsius"])
+        }
+    }
+
+    fn chat_completion(content: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 0,
+            "model": "gpt-4o",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": content },
+                "finish_reason": "stop"
+            }]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_typed_deserializes_output() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/chat/completions")
+                    .body_includes(r#""name":"weather""#);
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(chat_completion(r#"{"city":"Oslo","celsius":-3}"#));
+            })
+            .await;
+
+        let api = ResponsesApi::with_base_url("test-key", &server.base_url()).unwrap();
+        let request = ResponseRequest::new_text("gpt-4o", "Weather in Oslo?");
+        let weather: Weather = api.create_typed_with_schema(&request).await.unwrap();
+
+        assert_eq!(
+            weather,
+            Weather {
+                city: "Oslo".to_string(),
+                celsius: -3
+            }
+        );
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_create_with_continuation_stitches_truncated_output() {
+        use httpmock::prelude::*;
+
+        let completion = |content: &str, finish_reason: &str| {
+            serde_json::json!({
+                "id": "chatcmpl-1",
+                "object": "chat.completion",
+                "created": 0,
+                "model": "gpt-4o",
+                "choices": [{
+                    "index": 0,
+                    "message": {"role": "assistant", "content": content},
+                    "finish_reason": finish_reason
+                }],
+                "usage": {"prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15}
+            })
+        };
+
+        let server = MockServer::start_async().await;
+        let continuation = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/chat/completions")
+                    .body_includes("Once upon");
+                then.status(200).json_body(completion(" a time.", "stop"));
+            })
+            .await;
+        let first = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/chat/completions")
+                    .body_excludes("Once upon");
+                then.status(200)
+                    .json_body(completion("Once upon", "length"));
+            })
+            .await;
+
+        let api = ResponsesApi::with_base_url("test-key", &server.base_url()).unwrap();
+        let request = ResponseRequest::new_text("gpt-4o", "Tell a story")
+            .with_instructions("Be brief")
+            .with_max_tokens(5);
+
+        let result = api.create_with_continuation(&request, 3).await.unwrap();
+        assert_eq!(result.text, "Once upon a time.");
+        assert_eq!(result.continuations, 1);
+        assert!(!result.is_truncated());
+        assert_eq!(result.usage.unwrap().total_tokens, 30);
+        first.assert_async().await;
+        continuation.assert_async().await;
+
+        let capped = api.create_with_continuation(&request, 0).await.unwrap();
+        assert_eq!(capped.text, "Once upon");
+        assert!(capped.is_truncated());
+    }
+
+    #[tokio::test]
+    async fn test_create_typed_reports_mismatched_json() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/chat/completions");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(chat_completion("```json\n{\"city\":\"Oslo\"}\n```"));
+            })
+            .await;
+
+        let api = ResponsesApi::with_base_url("test-key", &server.base_url()).unwrap();
+        let request = ResponseRequest::new_text("gpt-4o", "Weather in Oslo?");
+        let error = api.create_typed::<Weather>(&request).await.unwrap_err();
+
+        match error {
+            OpenAIError::ParseError(message) => {
+                assert!(message.contains("Weather"), "{message}");
+                assert!(message.contains("celsius"), "{message}");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_typed_surfaces_refusal() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/chat/completions");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(serde_json::json!({
+                        "choices": [{
+                            "index": 0,
+                            "message": {
+                                "role": "assistant",
+                                "content": null,
+                                "refusal": "I can't help with that."
+                            },
+                            "finish_reason": "stop"
+                        }]
+                    }));
+            })
+            .await;
+
+        let api = ResponsesApi::with_base_url("test-key", &server.base_url()).unwrap();
+        let request = ResponseRequest::new_text("gpt-4o", "Something unsafe");
+        let error = api
+            .create_typed_with_schema::<Weather>(&request)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, OpenAIError::Refusal(message) if message.contains("can't help")));
+    }
+
+    #[tokio::test]
+    async fn test_parameter_check_modes() {
+        use httpmock::prelude::*;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/chat/completions");
+                then.status(200)
+                    .header("Content-Type", "application/json")
+                    .json_body(chat_completion("Hello"));
+            })
+            .await;
+
+        let request = ResponseRequest::new_text("o3", "Hi").with_temperature(0.2);
+        let api = ResponsesApi::with_base_url("test-key", &server.base_url()).unwrap();
+
+        let strict = api.clone().with_parameter_check(ParameterCheckMode::Error);
+        let error = strict.create_response(&request).await.unwrap_err();
+        assert!(
+            matches!(error, OpenAIError::InvalidRequest(message) if message.contains("temperature"))
+        );
+        mock.assert_calls_async(0).await;
+
+        let unknown = ResponseRequest::new_text("my-custom-model", "Hi").with_temperature(0.2);
+        strict.create_response(&unknown).await.unwrap();
+
+        let lenient = api.with_parameter_check(ParameterCheckMode::Warn);
+        lenient.create_response(&request).await.unwrap();
+        mock.assert_calls_async(2).await;
+    }
+
+    #[test]
+    fn test_strip_code_fence() {
+        assert_eq!(strip_code_fence("```json\n{\"a\":1}\n```"), "{\"a\":1}");
+        assert_eq!(strip_code_fence("```\n[1]\n```"), "[1]");
+        assert_eq!(strip_code_fence("  {\"a\":1} "), "{\"a\":1}");
+    }
+
     #[test]
     fn test_message_builders() {
         let user_msg = Message::user("Hello");
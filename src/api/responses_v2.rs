@@ -5,8 +5,8 @@ use crate::api::common::{
 use crate::api::shared_utilities::EnumConverter;
 use crate::error::{ApiErrorResponse, OpenAIError, Result};
 use crate::models::responses_v2::{
-    ContentPart, CreateResponseRequest, ResponseInput, ResponseItem, ResponseObject,
-    ResponseStreamEvent,
+    ContentPart, CreateResponseRequest, ResponseError, ResponseInput, ResponseItem, ResponseObject,
+    ResponseStreamAssembler, ResponseStreamEvent,
 };
 use crate::{De, Ser};
 use eventsource_stream::Eventsource;
@@ -50,7 +50,7 @@ impl ResponsesApiV2 {
         streaming_request.stream = Some(true);
         let payload = streaming_request.to_payload()?;
 
-        let url = format!("{}{}", self.http_client.base_url(), "/v1/responses");
+        let url = self.http_client.build_simple_url("/v1/responses");
         let response = self
             .http_client
             .client()
@@ -82,6 +82,18 @@ impl ResponsesApiV2 {
         Ok(Box::pin(stream))
     }
 
+    /// Stream a response and assemble its output items
+    ///
+    /// Text and annotations are collected per content part, as described on
+    /// [`assemble_response_stream`].
+    pub async fn stream_response_items(
+        &self,
+        request: &CreateResponseRequest,
+    ) -> Result<Vec<ResponseItem>> {
+        let stream = self.stream_response(request).await?;
+        assemble_response_stream(stream).await
+    }
+
     /// Retrieve a response by ID
     pub async fn retrieve_response(
         &self,
@@ -98,15 +110,7 @@ impl ResponsesApiV2 {
             .http_client
             .build_url(&format!("/v1/responses/{}", response_id.as_ref()), &query);
         let headers = self.http_client.build_headers()?;
-        self.http_client
-            .client()
-            .get(url)
-            .headers(headers)
-            .send()
-            .await?
-            .json()
-            .await
-            .map_err(OpenAIError::from)
+        self.http_client.execute_get_request(&url, headers).await
     }
 
     /// Delete a stored response
@@ -132,15 +136,7 @@ impl ResponsesApiV2 {
         }
         let url = self.http_client.build_url("/v1/responses", &query);
         let headers = self.http_client.build_headers()?;
-        self.http_client
-            .client()
-            .get(url)
-            .headers(headers)
-            .send()
-            .await?
-            .json()
-            .await
-            .map_err(OpenAIError::from)
+        self.http_client.execute_get_request(&url, headers).await
     }
 
     /// Compact a response (server-side context compaction)
@@ -179,15 +175,7 @@ impl ResponsesApiV2 {
             &query,
         );
         let headers = self.http_client.build_headers()?;
-        self.http_client
-            .client()
-            .get(url)
-            .headers(headers)
-            .send()
-            .await?
-            .json()
-            .await
-            .map_err(OpenAIError::from)
+        self.http_client.execute_get_request(&url, headers).await
     }
 }
 
@@ -265,6 +253,41 @@ impl ListQueryParams for ListResponsesParams {
     }
 }
 
+/// Drain a response event stream into complete output items
+///
+/// Events are fed to a [`ResponseStreamAssembler`], so text deltas and
+/// annotations end up in the content part they belong to. An `error` event or
+/// a failed response ends the stream with an error.
+pub async fn assemble_response_stream(
+    mut stream: ResponsesEventStream,
+) -> Result<Vec<ResponseItem>> {
+    let mut assembler = ResponseStreamAssembler::new();
+    while let Some(event) = stream.next().await {
+        let event = event?;
+        match &event {
+            ResponseStreamEvent::StreamError { error, .. } => return Err(stream_error(error)),
+            ResponseStreamEvent::ResponseFailed { response, .. } => {
+                return Err(response
+                    .error
+                    .as_ref()
+                    .map_or_else(|| OpenAIError::streaming("Response failed"), stream_error));
+            }
+            _ => assembler.push(&event),
+        }
+    }
+    Ok(assembler.finish())
+}
+
+/// Convert an error reported inside a response stream
+fn stream_error(error: &ResponseError) -> OpenAIError {
+    OpenAIError::streaming(
+        error
+            .message
+            .clone()
+            .unwrap_or_else(|| "Response stream reported an error".to_string()),
+    )
+}
+
 /// Parse an SSE event into a ResponseStreamEvent
 ///
 /// Returns None for ping events (keep-alive), and Some(Result) for data events
@@ -568,4 +591,76 @@ event: response.completed\ndata: {}\n\n",
         list_mock.assert_async().await;
         input_items_mock.assert_async().await;
     }
+
+    /// Render events as an SSE body
+    fn sse_body(events: &[serde_json::Value]) -> String {
+        events
+            .iter()
+            .map(|event| {
+                format!(
+                    "event: {}\ndata: {event}\n\n",
+                    event["type"].as_str().unwrap()
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn stream_response_items_keeps_content_parts_apart() {
+        let delta = |content_index: u32, delta: &str| {
+            json!({
+                "type": "response.output_text.delta",
+                "response_id": "resp_1",
+                "output_index": 0,
+                "content_index": content_index,
+                "delta": delta
+            })
+        };
+        let body = sse_body(&[delta(0, "One"), delta(1, "Two"), delta(0, " more")]);
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/responses");
+                then.status(200)
+                    .header("Content-Type", "text/event-stream")
+                    .body(&body);
+            })
+            .await;
+
+        let api = ResponsesApiV2::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let request = CreateResponseRequest::new_text("gpt-4o-mini", "Hello");
+        let items = api.stream_response_items(&request).await.unwrap();
+
+        assert_eq!(items.len(), 1);
+        let texts: Vec<_> = items[0]
+            .content
+            .iter()
+            .map(|part| part.text.as_deref())
+            .collect();
+        assert_eq!(texts, vec![Some("One more"), Some("Two")]);
+    }
+
+    #[tokio::test]
+    async fn stream_response_items_reports_stream_errors() {
+        let body = sse_body(&[json!({
+            "type": "error",
+            "error": {"code": "server_error", "message": "boom"}
+        })]);
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/responses");
+                then.status(200)
+                    .header("Content-Type", "text/event-stream")
+                    .body(&body);
+            })
+            .await;
+
+        let api = ResponsesApiV2::new_with_base_url("test-key", &server.base_url()).unwrap();
+        let request = CreateResponseRequest::new_text("gpt-4o-mini", "Hello");
+        let error = api.stream_response_items(&request).await.unwrap_err();
+        assert!(error.to_string().contains("boom"));
+    }
 }
@@ -0,0 +1,298 @@
+//! Recording streamed assistant runs as JSON Lines
+//!
+//! [`RunEventRecorder`] sits between a [`AssistantEventStream`] and its
+//! consumer, appending every event to a log with a timestamp and the thread
+//! and run it belongs to. The log is an audit trail of what the assistant did
+//! and can be inspected or replayed when debugging or evaluating agents.
+
+use crate::api::runs::AssistantEventStream;
+use crate::error::{OpenAIError, Result};
+use crate::models::runs::AssistantStreamEvent;
+use crate::{De, Ser};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+/// One line of a run event log
+#[derive(Debug, Clone, PartialEq, Ser, De)]
+pub struct RecordedRunEvent {
+    /// When the event was received
+    pub timestamp: DateTime<Utc>,
+    /// The thread the run belongs to, once known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+    /// The run that produced the event, once known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<String>,
+    /// The event as it was passed to the consumer
+    pub event: AssistantStreamEvent,
+}
+
+/// Appends assistant stream events to a JSONL log
+///
+/// Thread and run IDs are taken from run and run step events and attached to
+/// every later event, so deltas can be attributed to their run. Set the
+/// thread ID up front with [`with_thread_id`](Self::with_thread_id) to have
+/// it on events that arrive before the run is created.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use openai_rust_sdk::api::{common::ApiClientConstructors, runs::RunsApi, RunEventRecorder};
+/// use openai_rust_sdk::models::runs::RunRequest;
+///
+/// # tokio_test::block_on(async {
+/// let api = RunsApi::new("your-api-key")?;
+/// let request = RunRequest::builder().assistant_id("asst_abc123").build()?;
+///
+/// let events = api.stream_run("thread_abc123", request).await?;
+/// let mut events = RunEventRecorder::create("run_events.jsonl")?
+///     .with_thread_id("thread_abc123")
+///     .record_stream(events);
+/// while let Some(event) = events.next().await {
+///     println!("{:?}", event?);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct RunEventRecorder<W> {
+    /// Destination of the log lines
+    writer: W,
+    /// Most recently seen thread ID
+    thread_id: Option<String>,
+    /// Most recently seen run ID
+    run_id: Option<String>,
+}
+
+impl RunEventRecorder<File> {
+    /// Open a log file for appending, creating it if needed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| {
+                OpenAIError::FileError(format!(
+                    "Failed to open run event log {}: {e}",
+                    path.display()
+                ))
+            })?;
+        Ok(Self::new(file))
+    }
+}
+
+impl<W: Write> RunEventRecorder<W> {
+    /// Record events to the given writer
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            thread_id: None,
+            run_id: None,
+        }
+    }
+
+    /// Attach a thread ID to events recorded before the run reports one
+    #[must_use]
+    pub fn with_thread_id(mut self, thread_id: impl Into<String>) -> Self {
+        self.thread_id = Some(thread_id.into());
+        self
+    }
+
+    /// Attach a run ID to events recorded before the run reports one
+    #[must_use]
+    pub fn with_run_id(mut self, run_id: impl Into<String>) -> Self {
+        self.run_id = Some(run_id.into());
+        self
+    }
+
+    /// Append one event to the log and flush it
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line cannot be serialized or written.
+    pub fn record(&mut self, event: &AssistantStreamEvent) -> Result<()> {
+        match event {
+            AssistantStreamEvent::Run { run, .. } => {
+                self.thread_id = Some(run.thread_id.clone());
+                self.run_id = Some(run.id.clone());
+            }
+            AssistantStreamEvent::RunStep { step, .. } => {
+                self.thread_id = Some(step.thread_id.clone());
+                self.run_id = Some(step.run_id.clone());
+            }
+            _ => {}
+        }
+
+        let line = serde_json::to_string(&RecordedRunEvent {
+            timestamp: Utc::now(),
+            thread_id: self.thread_id.clone(),
+            run_id: self.run_id.clone(),
+            event: event.clone(),
+        })?;
+        writeln!(self.writer, "{line}")
+            .and_then(|()| self.writer.flush())
+            .map_err(|e| OpenAIError::FileError(format!("Failed to write run event log: {e}")))
+    }
+
+    /// Consume the recorder and return the writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write + Send + 'static> RunEventRecorder<W> {
+    /// Record every event of a stream while passing it through unchanged
+    ///
+    /// Stream errors are passed through without being recorded. If writing
+    /// the log fails, the event is still yielded and followed by the error.
+    pub fn record_stream(mut self, stream: AssistantEventStream) -> AssistantEventStream {
+        let stream = stream.flat_map(move |item| {
+            let items = match item {
+                Ok(event) => match self.record(&event) {
+                    Ok(()) => vec![Ok(event)],
+                    Err(e) => vec![Ok(event), Err(e)],
+                },
+                Err(e) => vec![Err(e)],
+            };
+            futures::stream::iter(items)
+        });
+        Box::pin(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn run_event(status: &str) -> AssistantStreamEvent {
+        AssistantStreamEvent::parse(
+            &format!("thread.run.{status}"),
+            &json!({
+                "id": "run_1",
+                "object": "thread.run",
+                "created_at": 1,
+                "thread_id": "thread_1",
+                "assistant_id": "asst_1",
+                "status": status,
+                "model": "gpt-4o",
+                "instructions": "",
+                "tools": [],
+                "metadata": {}
+            })
+            .to_string(),
+        )
+        .unwrap()
+        .remove(0)
+    }
+
+    #[tokio::test]
+    async fn test_record_stream_passes_events_through() {
+        let events = vec![
+            AssistantStreamEvent::MessageDelta {
+                message_id: "msg_0".to_string(),
+                text: "early".to_string(),
+            },
+            run_event("in_progress"),
+            AssistantStreamEvent::MessageDelta {
+                message_id: "msg_1".to_string(),
+                text: "Hello".to_string(),
+            },
+        ];
+        let source: AssistantEventStream =
+            Box::pin(futures::stream::iter(events.clone().into_iter().map(Ok)));
+
+        let log = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let writer = SharedBuffer(log.clone());
+        let passed: Vec<_> = RunEventRecorder::new(writer)
+            .with_thread_id("thread_1")
+            .record_stream(source)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(passed, events);
+
+        let log = String::from_utf8(log.lock().unwrap().clone()).unwrap();
+        let lines: Vec<RecordedRunEvent> = log
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].thread_id.as_deref(), Some("thread_1"));
+        assert_eq!(lines[0].run_id, None);
+        assert_eq!(lines[2].run_id.as_deref(), Some("run_1"));
+        assert_eq!(lines[2].event, events[2]);
+        assert!(log.contains(r#""type":"message_delta""#));
+    }
+
+    #[test]
+    fn test_non_code_interpreter_step_deltas_are_recorded() {
+        let function_delta = json!({
+            "id": "step_1",
+            "object": "thread.run.step.delta",
+            "delta": {"step_details": {"type": "tool_calls", "tool_calls": [
+                {"index": 0, "id": "call_1", "type": "function",
+                 "function": {"name": "lookup", "arguments": "{\"q\":"}}
+            ]}}
+        });
+        let message_step_delta = json!({
+            "id": "step_2",
+            "object": "thread.run.step.delta",
+            "delta": {"step_details": {"type": "message_creation",
+                "message_creation": {"message_id": "msg_1"}}}
+        });
+        let mut events =
+            AssistantStreamEvent::parse("thread.run.step.delta", &function_delta.to_string())
+                .unwrap();
+        events.extend(
+            AssistantStreamEvent::parse("thread.run.step.delta", &message_step_delta.to_string())
+                .unwrap(),
+        );
+        assert!(matches!(
+            &events[0],
+            AssistantStreamEvent::ToolCallDelta { call_index: 0, call_id: Some(id), delta, .. }
+                if id == "call_1" && delta["function"]["name"] == "lookup"
+        ));
+        assert!(matches!(
+            &events[1],
+            AssistantStreamEvent::RunStepDelta { step_id, .. } if step_id == "step_2"
+        ));
+
+        let mut recorder = RunEventRecorder::new(Vec::new());
+        for event in &events {
+            recorder.record(event).unwrap();
+        }
+        let log = String::from_utf8(recorder.into_inner()).unwrap();
+        let recorded: Vec<AssistantStreamEvent> = log
+            .lines()
+            .map(|line| {
+                serde_json::from_str::<RecordedRunEvent>(line)
+                    .unwrap()
+                    .event
+            })
+            .collect();
+        assert_eq!(recorded, events);
+    }
+
+    /// Writer appending to a buffer the test can still read
+    struct SharedBuffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+}
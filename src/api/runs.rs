@@ -79,13 +79,25 @@
 
 use crate::api::base::HttpClient;
 use crate::api::common::ApiClientConstructors;
+use crate::api::responses::strip_code_fence;
 use crate::api::streaming::helpers::to_streaming_json;
 use crate::constants::endpoints;
 use crate::error::{OpenAIError, Result};
 use crate::models::runs::{
-    CreateThreadAndRunRequest, ListRunStepsParams, ListRunStepsResponse, ListRunsParams,
-    ListRunsResponse, ModifyRunRequest, Run, RunRequest, RunStep, SubmitToolOutputsRequest,
+    AssistantStreamEvent, CreateThreadAndRunRequest, ListRunStepsParams, ListRunStepsResponse,
+    ListRunsParams, ListRunsResponse, ModifyRunRequest, Run, RunRequest, RunStep,
+    SubmitToolOutputsRequest,
 };
+use crate::models::shared_traits::AsText;
+use crate::models::threads::{Message, MessageRole};
+use eventsource_stream::Eventsource;
+use futures::{StreamExt, TryStreamExt};
+use reqwest::header::{ACCEPT, HeaderValue};
+use std::pin::Pin;
+
+/// Stream of events from a streamed run
+pub type AssistantEventStream =
+    Pin<Box<dyn futures::Stream<Item = Result<AssistantStreamEvent>> + Send>>;
 
 /// `OpenAI` Runs API client for managing assistant run execution
 #[derive(Debug, Clone)]
@@ -117,9 +129,7 @@ impl RunsApi {
     /// # Ok::<(), openai_rust_sdk::OpenAIError>(())
     /// ```
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
     }
 
     /// Create a run
@@ -417,6 +427,63 @@ impl RunsApi {
             .await
     }
 
+    /// List every step of a run, following pagination transparently
+    ///
+    /// Steps are returned oldest first. A failed page request ends the stream
+    /// with that error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use openai_rust_sdk::api::{runs::RunsApi, common::ApiClientConstructors};
+    /// use openai_rust_sdk::models::runs::step_tool_calls;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = RunsApi::new("your-api-key")?;
+    /// let steps: Vec<_> = api.list_all_steps("thread_abc123", "run_abc123").try_collect().await?;
+    /// for call in step_tool_calls(&steps) {
+    ///     println!("tool call {}", call.id());
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub fn list_all_steps<S: AsRef<str>, R: AsRef<str>>(
+        &self,
+        thread_id: S,
+        run_id: R,
+    ) -> impl futures::Stream<Item = Result<RunStep>> + Send + '_ {
+        let thread_id = thread_id.as_ref().to_string();
+        let run_id = run_id.as_ref().to_string();
+
+        futures::stream::try_unfold(Some(None), move |cursor: Option<Option<String>>| {
+            let thread_id = thread_id.clone();
+            let run_id = run_id.clone();
+            async move {
+                let Some(after) = cursor else {
+                    return Ok(None);
+                };
+                let params = ListRunStepsParams {
+                    limit: Some(100),
+                    order: Some("asc".to_string()),
+                    after,
+                    before: None,
+                };
+                let page = self
+                    .list_run_steps(&thread_id, &run_id, Some(params))
+                    .await?;
+                let next = page
+                    .data
+                    .last()
+                    .map(|step| step.id.clone())
+                    .filter(|_| page.has_more);
+                let steps = futures::stream::iter(page.data.into_iter().map(Ok));
+                Ok::<_, OpenAIError>(Some((steps, next.map(Some))))
+            }
+        })
+        .try_flatten()
+    }
+
     /// Retrieve a run step
     ///
     /// # Arguments
@@ -568,4 +635,221 @@ impl RunsApi {
             .post("/v1/threads/runs", &request_json)
             .await
     }
+
+    /// Create a run and stream its events
+    ///
+    /// Unlike [`RunsApi::create_run_stream`], this reads the server-sent events
+    /// as they arrive, including message text and code interpreter deltas.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use openai_rust_sdk::api::{runs::RunsApi, common::ApiClientConstructors};
+    /// use openai_rust_sdk::models::runs::{AssistantStreamEvent, RunRequest};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = RunsApi::new("your-api-key")?;
+    /// let request = RunRequest::builder().assistant_id("asst_abc123").build()?;
+    ///
+    /// let mut events = api.stream_run("thread_abc123", request).await?;
+    /// while let Some(event) = events.next().await {
+    ///     match event? {
+    ///         AssistantStreamEvent::CodeInterpreterInputDelta { input, .. } => print!("{input}"),
+    ///         AssistantStreamEvent::CodeInterpreterOutputDelta { output, .. } => {
+    ///             if let Some(logs) = output.logs() {
+    ///                 println!("{logs}");
+    ///             }
+    ///         }
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn stream_run<S: AsRef<str>>(
+        &self,
+        thread_id: S,
+        request: RunRequest,
+    ) -> Result<AssistantEventStream> {
+        let endpoint = endpoints::threads::runs(thread_id.as_ref());
+        self.open_event_stream(&endpoint, &request).await
+    }
+
+    /// Create a thread and run and stream the run's events
+    pub async fn stream_thread_and_run(
+        &self,
+        request: CreateThreadAndRunRequest,
+    ) -> Result<AssistantEventStream> {
+        self.open_event_stream("/v1/threads/runs", &request).await
+    }
+
+    /// Submit tool outputs and stream the resumed run's events
+    pub async fn stream_submit_tool_outputs<S: AsRef<str>, R: AsRef<str>>(
+        &self,
+        thread_id: S,
+        run_id: R,
+        request: SubmitToolOutputsRequest,
+    ) -> Result<AssistantEventStream> {
+        let endpoint = endpoints::threads::submit_tool_outputs(thread_id.as_ref(), run_id.as_ref());
+        self.open_event_stream(&endpoint, &request).await
+    }
+
+    /// Run an assistant on a thread and deserialize its final reply into `T`
+    ///
+    /// The run is streamed to completion and the text of the last assistant
+    /// message, with any Markdown code fence stripped, is parsed as JSON.
+    /// Use [`RunsApi::run_typed_with`] to observe the events as they arrive.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::api::{runs::RunsApi, common::ApiClientConstructors};
+    /// use openai_rust_sdk::models::runs::RunRequest;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Verdict {
+    ///     approved: bool,
+    /// }
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = RunsApi::new("your-api-key")?;
+    /// let request = RunRequest::builder().assistant_id("asst_abc123").build()?;
+    ///
+    /// let verdict: Verdict = api.run_typed("thread_abc123", request).await?;
+    /// println!("Approved: {}", verdict.approved);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn run_typed<T, S>(&self, thread_id: S, request: RunRequest) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        S: AsRef<str>,
+    {
+        self.run_typed_with(thread_id, request, |_| {}).await
+    }
+
+    /// Like [`RunsApi::run_typed`], passing every stream event to `on_event`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OpenAIError::Refusal`] if the assistant declined to answer, and
+    /// [`OpenAIError::RequestError`] if the run failed, expired, was cancelled
+    /// or stopped to wait for tool outputs.
+    pub async fn run_typed_with<T, S, F>(
+        &self,
+        thread_id: S,
+        request: RunRequest,
+        mut on_event: F,
+    ) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+        S: AsRef<str>,
+        F: FnMut(&AssistantStreamEvent),
+    {
+        let mut events = self.stream_run(thread_id, request).await?;
+        let mut reply: Option<Message> = None;
+        let mut streamed_text = String::new();
+
+        while let Some(event) = events.next().await {
+            let event = event?;
+            on_event(&event);
+            match event {
+                AssistantStreamEvent::MessageDelta { text, .. } => streamed_text.push_str(&text),
+                AssistantStreamEvent::Other { event, data }
+                    if event == "thread.message.completed" =>
+                {
+                    let message: Message = serde_json::from_value(data)?;
+                    if message.role == MessageRole::Assistant {
+                        reply = Some(message);
+                    }
+                }
+                AssistantStreamEvent::Run { event, run } => Self::check_run_event(&event, &run)?,
+                AssistantStreamEvent::Error { message } => {
+                    return Err(OpenAIError::streaming(message));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(refusal) = reply.as_ref().and_then(Message::refusal) {
+            return Err(OpenAIError::Refusal(refusal.to_string()));
+        }
+        let text = reply.map_or(streamed_text, |message| message.text());
+        let type_name = std::any::type_name::<T>();
+        if text.trim().is_empty() {
+            return Err(OpenAIError::ParseError(format!(
+                "Run produced no assistant message to deserialize into {type_name}"
+            )));
+        }
+        let json = strip_code_fence(&text);
+        serde_json::from_str(json).map_err(|e| {
+            let preview: String = json.chars().take(200).collect();
+            OpenAIError::ParseError(format!(
+                "Run output does not match {type_name}: {e} (content: {preview})"
+            ))
+        })
+    }
+
+    /// Turn a terminal run event that did not complete into an error
+    fn check_run_event(event: &str, run: &Run) -> Result<()> {
+        let reason = match event {
+            "thread.run.failed" => run.last_error.as_ref().map_or_else(
+                || "failed".to_string(),
+                |error| format!("failed: {} ({})", error.message, error.code),
+            ),
+            "thread.run.expired" => "expired".to_string(),
+            "thread.run.cancelled" => "was cancelled".to_string(),
+            "thread.run.incomplete" => "ended incomplete".to_string(),
+            "thread.run.requires_action" => {
+                "requires tool outputs; use stream_run to handle tool calls".to_string()
+            }
+            _ => return Ok(()),
+        };
+        Err(OpenAIError::RequestError(format!(
+            "Run {} {reason}",
+            run.id
+        )))
+    }
+
+    /// POST a streaming request and parse the response as assistant events
+    async fn open_event_stream<B: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        request: &B,
+    ) -> Result<AssistantEventStream> {
+        let url = self.http_client.build_simple_url(endpoint);
+        let mut headers = self.http_client.build_headers_with_beta()?;
+        headers.insert(ACCEPT, HeaderValue::from_static("text/event-stream"));
+
+        let response = self
+            .http_client
+            .client()
+            .post(&url)
+            .headers(headers)
+            .json(&to_streaming_json(request)?)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return self
+                .http_client
+                .handle_error_response(response, status)
+                .await;
+        }
+
+        let stream = response.bytes_stream().eventsource().flat_map(|event| {
+            let events = match event {
+                Ok(event) => match AssistantStreamEvent::parse(&event.event, &event.data) {
+                    Ok(events) => events.into_iter().map(Ok).collect(),
+                    Err(e) => vec![Err(e)],
+                },
+                Err(e) => vec![Err(OpenAIError::streaming(format!("Stream error: {e}")))],
+            };
+            futures::stream::iter(events)
+        });
+
+        Ok(Box::pin(stream))
+    }
 }
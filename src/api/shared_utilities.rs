@@ -263,7 +263,7 @@ impl MultipartRequestExecutor {
         endpoint: &str,
         form: Form,
     ) -> Result<reqwest::Response> {
-        let url = format!("{}{}", http_client.base_url(), endpoint);
+        let url = http_client.build_simple_url(endpoint);
         let headers = http_client.build_auth_headers()?;
 
         let response = http_client
@@ -0,0 +1,277 @@
+//! Replaying recorded streams without a network connection
+//!
+//! Turns a JSONL log back into the stream types the SDK returns, so UI and
+//! agent code can be tested against realistic streamed data deterministically
+//! and at no cost. Logs written by [`RunEventRecorder`] replay as an
+//! [`AssistantEventStream`]; logs of [`StreamChunk`]s replay as a
+//! [`ResponseStream`].
+//!
+//! [`RunEventRecorder`]: crate::api::run_recorder::RunEventRecorder
+
+use crate::De;
+use crate::api::run_recorder::RecordedRunEvent;
+use crate::api::runs::AssistantEventStream;
+use crate::api::streaming::ResponseStream;
+use crate::error::{OpenAIError, Result};
+use crate::models::responses::StreamChunk;
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::pin::Pin;
+use std::time::Duration;
+
+/// How long to wait between replayed events
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ReplayTiming {
+    /// Yield every event as soon as it is polled
+    #[default]
+    Immediate,
+    /// Wait the gaps between the recorded timestamps
+    Recorded,
+    /// Wait the recorded gaps multiplied by a factor, e.g. `0.5` for double speed
+    ///
+    /// Negative, `NaN` and infinite factors, or products too large for a
+    /// [`Duration`], do not delay.
+    Scaled(f64),
+    /// Wait the same duration between every pair of events
+    Fixed(Duration),
+}
+
+impl ReplayTiming {
+    /// Delay before an event, given the recorded gap since the previous one
+    fn delay(self, gap: Option<Duration>) -> Duration {
+        match self {
+            Self::Immediate => Duration::ZERO,
+            Self::Recorded => gap.unwrap_or_default(),
+            Self::Scaled(factor) => gap
+                .and_then(|gap| Duration::try_from_secs_f64(gap.as_secs_f64() * factor).ok())
+                .unwrap_or_default(),
+            Self::Fixed(delay) => delay,
+        }
+    }
+}
+
+/// One line of a response stream log
+///
+/// Either a bare chunk or a chunk with the time it was received.
+#[derive(De)]
+#[serde(untagged)]
+enum ChunkLine {
+    /// A chunk with a receive timestamp
+    Recorded {
+        /// When the chunk was received
+        timestamp: DateTime<Utc>,
+        /// The chunk
+        chunk: StreamChunk,
+    },
+    /// A chunk without timing information
+    Bare(StreamChunk),
+}
+
+/// Replay a run event log written by a `RunEventRecorder`
+///
+/// # Errors
+///
+/// Returns an error naming the line if the log cannot be read or a line is
+/// not a recorded event.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::StreamExt;
+/// use openai_rust_sdk::api::{replay_run_events, ReplayTiming};
+///
+/// # tokio_test::block_on(async {
+/// let log = r#"{"timestamp":"2024-01-01T00:00:00Z","event":{"type":"message_delta","message_id":"msg_1","text":"Hi"}}"#;
+/// let mut events = replay_run_events(log.as_bytes(), ReplayTiming::Immediate)?;
+/// assert!(events.next().await.unwrap().is_ok());
+/// # Ok::<(), openai_rust_sdk::error::OpenAIError>(())
+/// # });
+/// ```
+pub fn replay_run_events<R: BufRead>(
+    reader: R,
+    timing: ReplayTiming,
+) -> Result<AssistantEventStream> {
+    let events = read_log::<RecordedRunEvent, _>(reader)?
+        .into_iter()
+        .map(|recorded| (Some(recorded.timestamp), recorded.event))
+        .collect();
+    Ok(timed_stream(events, timing))
+}
+
+/// Replay a run event log file written by a `RunEventRecorder`
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or parsed.
+pub fn replay_run_events_file(
+    path: impl AsRef<Path>,
+    timing: ReplayTiming,
+) -> Result<AssistantEventStream> {
+    replay_run_events(open_log(path.as_ref())?, timing)
+}
+
+/// Replay a log of stream chunks as a [`ResponseStream`]
+///
+/// Each line is either a chunk or `{"timestamp": ..., "chunk": ...}`. Lines
+/// without a timestamp have no recorded gap, so only
+/// [`ReplayTiming::Fixed`] delays them.
+///
+/// # Errors
+///
+/// Returns an error naming the line if the log cannot be read or a line is
+/// not a chunk.
+pub fn replay_response_stream<R: BufRead>(
+    reader: R,
+    timing: ReplayTiming,
+) -> Result<ResponseStream> {
+    let chunks = read_log::<ChunkLine, _>(reader)?
+        .into_iter()
+        .map(|line| match line {
+            ChunkLine::Recorded { timestamp, chunk } => (Some(timestamp), chunk),
+            ChunkLine::Bare(chunk) => (None, chunk),
+        })
+        .collect();
+    Ok(timed_stream(chunks, timing))
+}
+
+/// Replay a file of stream chunks as a [`ResponseStream`]
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or parsed.
+pub fn replay_response_stream_file(
+    path: impl AsRef<Path>,
+    timing: ReplayTiming,
+) -> Result<ResponseStream> {
+    replay_response_stream(open_log(path.as_ref())?, timing)
+}
+
+/// Open a log file for reading
+fn open_log(path: &Path) -> Result<BufReader<File>> {
+    File::open(path).map(BufReader::new).map_err(|e| {
+        OpenAIError::FileError(format!("Failed to open replay log {}: {e}", path.display()))
+    })
+}
+
+/// Parse every non-blank line of a JSONL log
+fn read_log<T: DeserializeOwned, R: BufRead>(reader: R) -> Result<Vec<T>> {
+    let mut items = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| {
+            OpenAIError::FileError(format!("Failed to read replay log line {}: {e}", index + 1))
+        })?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let item = serde_json::from_str(&line).map_err(|e| {
+            OpenAIError::parsing(format!("Invalid replay log line {}: {e}", index + 1))
+        })?;
+        items.push(item);
+    }
+    Ok(items)
+}
+
+/// Yield items in order, sleeping before each according to `timing`
+fn timed_stream<T: Send + 'static>(
+    items: Vec<(Option<DateTime<Utc>>, T)>,
+    timing: ReplayTiming,
+) -> Pin<Box<dyn Stream<Item = Result<T>> + Send>> {
+    let mut previous: Option<DateTime<Utc>> = None;
+    let delayed: Vec<(Duration, T)> = items
+        .into_iter()
+        .enumerate()
+        .map(|(index, (timestamp, item))| {
+            let gap = match (previous, timestamp) {
+                (Some(previous), Some(timestamp)) => (timestamp - previous).to_std().ok(),
+                _ => None,
+            };
+            previous = timestamp.or(previous);
+            let delay = if index == 0 {
+                Duration::ZERO
+            } else {
+                timing.delay(gap)
+            };
+            (delay, item)
+        })
+        .collect();
+
+    Box::pin(
+        futures::stream::iter(delayed).then(|(delay, item)| async move {
+            if !delay.is_zero() {
+                tokio::time::sleep(delay).await;
+            }
+            Ok(item)
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::runs::AssistantStreamEvent;
+
+    const RUN_LOG: &str = r#"{"timestamp":"2024-01-01T00:00:00Z","thread_id":"thread_1","event":{"type":"message_delta","message_id":"msg_1","text":"Hel"}}
+
+{"timestamp":"2024-01-01T00:00:00.030Z","thread_id":"thread_1","event":{"type":"message_delta","message_id":"msg_1","text":"lo"}}
+"#;
+
+    #[tokio::test]
+    async fn test_replay_run_events_with_recorded_timing() {
+        let started = std::time::Instant::now();
+        let events: Vec<_> = replay_run_events(RUN_LOG.as_bytes(), ReplayTiming::Recorded)
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert!(started.elapsed() >= Duration::from_millis(30));
+        assert_eq!(
+            events[1],
+            AssistantStreamEvent::MessageDelta {
+                message_id: "msg_1".to_string(),
+                text: "lo".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_replay_response_stream_accepts_bare_and_timed_chunks() {
+        let log = r#"{"id":"c1","object":"chat.completion.chunk","created":1,"model":"gpt-4o","choices":[]}
+{"timestamp":"2024-01-01T00:00:00Z","chunk":{"id":"c1","object":"chat.completion.chunk","created":1,"model":"gpt-4o","choices":[]}}"#;
+        let chunks: Vec<_> = replay_response_stream(log.as_bytes(), ReplayTiming::Immediate)
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 2);
+        assert!(
+            chunks
+                .iter()
+                .all(|chunk| chunk.as_ref().unwrap().id == "c1")
+        );
+    }
+
+    #[test]
+    fn test_invalid_line_is_reported() {
+        let log = format!("{RUN_LOG}not json\n");
+        let Err(err) = replay_run_events(log.as_bytes(), ReplayTiming::Immediate) else {
+            panic!("expected an error");
+        };
+        assert!(err.to_string().contains("line 4"), "{err}");
+    }
+
+    #[test]
+    fn test_scaled_timing_ignores_invalid_factors() {
+        let gap = Some(Duration::from_millis(100));
+        assert_eq!(
+            ReplayTiming::Scaled(0.5).delay(gap),
+            Duration::from_millis(50)
+        );
+        for factor in [f64::INFINITY, f64::NAN, -1.0, f64::MAX] {
+            assert_eq!(ReplayTiming::Scaled(factor).delay(gap), Duration::ZERO);
+        }
+    }
+}
@@ -6,50 +6,116 @@ use crate::api::responses::ResponsesApi;
 use crate::constants::endpoints;
 use crate::error::{ApiErrorResponse, OpenAIError, Result};
 use crate::models::functions::{Tool, ToolChoice};
-use crate::models::responses::{Message, ResponseRequest};
+use crate::models::responses::{Message, ParameterCheckMode, ResponseRequest};
 use eventsource_stream::Eventsource;
 use futures::StreamExt as FuturesStreamExt;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::UnboundedReceiverStream;
 
-use super::helpers::{collect_stream_response, process_stream_event};
+use super::helpers::{collect_stream_response, decode_stream_event, process_stream_event_with};
 use super::processor::FunctionStreamProcessor;
-use super::types::{FunctionStream, ResponseStream};
+use super::types::{EventStream, FunctionStream, ResponseStream, StreamConfig, StreamEventType};
 
 /// Streaming API client (extends `ResponsesApi`)
 #[derive(Clone)]
 pub struct StreamingApi {
     /// Underlying responses API client
     responses_api: ResponsesApi,
+    /// How stream frames are decoded
+    stream_config: StreamConfig,
 }
 
 impl StreamingApi {
     /// Create a new `StreamingApi` client
     pub fn new(api_key: impl Into<String>) -> Result<Self> {
-        let responses_api = ResponsesApi::new(api_key)?;
-        Ok(Self { responses_api })
+        <Self as ApiClientConstructors>::new(api_key)
     }
 
     /// Create a new `StreamingApi` client with custom base URL
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        let responses_api = ResponsesApi::with_base_url(api_key, base_url)?;
-        Ok(Self { responses_api })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
+    }
+
+    /// Set how stream frames are decoded
+    #[must_use]
+    pub fn with_stream_config(mut self, stream_config: StreamConfig) -> Self {
+        self.stream_config = stream_config;
+        self
+    }
+
+    /// Check requests against the model's capabilities before streaming them
+    ///
+    /// See [`ResponsesApi::with_parameter_check`].
+    #[must_use]
+    pub fn with_parameter_check(mut self, mode: ParameterCheckMode) -> Self {
+        self.responses_api = self.responses_api.with_parameter_check(mode);
+        self
+    }
+
+    /// Get the stream decoding configuration
+    #[must_use]
+    pub fn stream_config(&self) -> StreamConfig {
+        self.stream_config
     }
 
     /// Create a streaming response
+    ///
+    /// When [`StreamConfig::skip_malformed_events`] is set, frames with
+    /// malformed JSON are dropped instead of ending the stream with an error.
     pub async fn create_response_stream(
         &self,
         request: &ResponseRequest,
     ) -> Result<ResponseStream> {
+        let response = self.send_stream_request(request).await?;
+        let config = self.stream_config;
+
+        // Convert the response to a stream with event processing
+        let stream =
+            response
+                .bytes_stream()
+                .eventsource()
+                .filter_map(move |event_result| async move {
+                    process_stream_event_with(event_result, config)
+                });
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Create a streaming response decoded into [`StreamEventType`]s
+    ///
+    /// When [`StreamConfig::skip_malformed_events`] is set, a frame with
+    /// malformed JSON yields [`StreamEventType::ParseError`] and later frames
+    /// are still delivered.
+    pub async fn create_event_stream(&self, request: &ResponseRequest) -> Result<EventStream> {
+        let response = self.send_stream_request(request).await?;
+        let config = self.stream_config;
+
+        let stream = response
+            .bytes_stream()
+            .eventsource()
+            .filter_map(
+                move |event_result| async move { decode_stream_event(event_result, config) },
+            )
+            .flat_map(|decoded| {
+                let events: Vec<Result<StreamEventType>> = match decoded {
+                    Ok(events) => events.into_iter().map(Ok).collect(),
+                    Err(error) => vec![Err(error)],
+                };
+                futures::stream::iter(events)
+            });
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Send a request with streaming enabled and check the response status
+    async fn send_stream_request(&self, request: &ResponseRequest) -> Result<reqwest::Response> {
+        self.responses_api.check_parameters(request)?;
+
         // Create a streaming version of the request
         let mut streaming_request = request.clone();
         streaming_request.stream = Some(true);
 
-        let url = format!(
-            "{}{}",
-            self.responses_api.base_url(),
-            endpoints::CHAT_COMPLETIONS
-        );
+        let url = self.responses_api.url_for(endpoints::CHAT_COMPLETIONS);
 
         // Convert to OpenAI format and send request
         let mut openai_request = self.responses_api.to_openai_format(&streaming_request)?;
@@ -79,13 +145,7 @@ impl StreamingApi {
             ));
         }
 
-        // Convert the response to a stream with event processing
-        let stream = response
-            .bytes_stream()
-            .eventsource()
-            .filter_map(|event_result| async move { process_stream_event(event_result) });
-
-        Ok(Box::pin(stream))
+        Ok(response)
     }
 
     /// Create a simple text streaming response
@@ -186,7 +246,10 @@ impl StreamingApi {
 impl ApiClientConstructors for StreamingApi {
     fn from_http_client(http_client: HttpClient) -> Self {
         let responses_api = ResponsesApi::from_http_client(http_client);
-        Self { responses_api }
+        Self {
+            responses_api,
+            stream_config: StreamConfig::default(),
+        }
     }
 }
 
@@ -200,6 +263,99 @@ mod tests {
         assert_eq!(api.responses_api().api_key(), "test-key");
     }
 
+    fn chunk_frame(content: &str) -> String {
+        let chunk = serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 1,
+            "model": "gpt-4o-mini",
+            "choices": [{"index": 0, "delta": {"content": content}, "finish_reason": null}]
+        });
+        format!("data: {chunk}\n\n")
+    }
+
+    async fn api_with_malformed_frame(
+        config: StreamConfig,
+    ) -> (httpmock::MockServer, StreamingApi) {
+        use httpmock::prelude::*;
+
+        let body = [
+            chunk_frame("Hello"),
+            "data: {\"id\": \"chatcmpl-1\", \"choices\": [\n\n".to_string(),
+            chunk_frame(" world"),
+            "data: [DONE]\n\n".to_string(),
+        ]
+        .concat();
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/chat/completions");
+                then.status(200)
+                    .header("Content-Type", "text/event-stream")
+                    .body(body);
+            })
+            .await;
+        let api = StreamingApi::with_base_url("test-key".to_string(), server.base_url())
+            .unwrap()
+            .with_stream_config(config);
+        (server, api)
+    }
+
+    #[tokio::test]
+    async fn test_parameter_check_applies_to_streams() {
+        let (_server, api) = api_with_malformed_frame(StreamConfig::new()).await;
+        let api = api.with_parameter_check(ParameterCheckMode::Error);
+        let request = ResponseRequest::new_text("o3", "Hi").with_temperature(0.2);
+
+        let Err(error) = api.create_response_stream(&request).await else {
+            panic!("incompatible request was streamed");
+        };
+        assert!(matches!(error, OpenAIError::InvalidRequest(_)));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_frame_fails_by_default() {
+        let (_server, api) = api_with_malformed_frame(StreamConfig::new()).await;
+        let request = ResponseRequest::new_text("gpt-4o-mini", "Hi");
+        let chunks: Vec<_> = api
+            .create_response_stream(&request)
+            .await
+            .unwrap()
+            .collect()
+            .await;
+
+        assert_eq!(chunks.len(), 3);
+        assert!(matches!(&chunks[1], Err(OpenAIError::Streaming(_))));
+    }
+
+    #[tokio::test]
+    async fn test_skip_malformed_events_keeps_streaming() {
+        let config = StreamConfig::new().skip_malformed_events(true);
+        let (_server, api) = api_with_malformed_frame(config).await;
+        let request = ResponseRequest::new_text("gpt-4o-mini", "Hi");
+
+        let text = StreamingApi::collect_stream_response(
+            api.create_response_stream(&request).await.unwrap(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(text, "Hello world");
+
+        let events: Vec<StreamEventType> = api
+            .create_event_stream(&request)
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(events.len(), 3);
+        assert!(matches!(&events[0], StreamEventType::Delta { content } if content == "Hello"));
+        assert!(
+            matches!(&events[1], StreamEventType::ParseError { raw } if raw.starts_with("{\"id\""))
+        );
+        assert!(matches!(&events[2], StreamEventType::Delta { content } if content == " world"));
+    }
+
     #[tokio::test]
     async fn test_stream_request_configuration() {
         let request = ResponseRequest::new_text("gpt-4", "Hello").with_streaming(true);
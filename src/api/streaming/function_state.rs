@@ -82,17 +82,14 @@ impl FunctionStreamState {
 
         events.extend(FunctionStreamProcessor::process_content_delta(choice).unwrap_or_default());
         events.extend(
-            FunctionStreamProcessor::process_choice_tool_calls(
-                processor.function_calls_mut(),
-                choice,
-            )
-            .unwrap_or_default(),
+            FunctionStreamProcessor::process_choice_tool_calls(processor.tool_calls_mut(), choice)
+                .unwrap_or_default(),
         );
 
         if choice.finish_reason.is_some() {
             events.extend(
                 FunctionStreamProcessor::process_choice_completion(
-                    processor.function_calls_mut(),
+                    processor.tool_calls_mut(),
                     chunk,
                     choice,
                 )
@@ -1,9 +1,12 @@
 //! Helper functions and utilities for streaming operations
 
 // Re-export functions from other modules for backward compatibility
-pub use crate::api::streaming::stream_operations::{ResponseStreamExt, collect_stream_response};
+pub use crate::api::streaming::stream_operations::{
+    ResponseStreamExt, StreamHandlers, collect_stream_response, collect_stream_response_with_usage,
+};
 pub use crate::api::streaming::utilities::{
-    chunk_to_events, process_stream_event, to_streaming_json,
+    chunk_to_events, decode_stream_event, process_stream_event, process_stream_event_with,
+    to_streaming_json,
 };
 
 #[cfg(test)]
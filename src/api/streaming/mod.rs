@@ -48,12 +48,14 @@ pub mod utilities;
 // Re-export main types and functions for convenience
 pub use client::StreamingApi;
 pub use helpers::{
-    ResponseStreamExt, chunk_to_events, collect_stream_response, process_stream_event,
-    to_streaming_json,
+    ResponseStreamExt, StreamHandlers, chunk_to_events, collect_stream_response,
+    collect_stream_response_with_usage, decode_stream_event, process_stream_event,
+    process_stream_event_with, to_streaming_json,
 };
-pub use processor::FunctionStreamProcessor;
+pub use processor::{FunctionStreamProcessor, ToolCallAccumulator, UsageAccumulator};
 pub use types::{
-    FunctionStream, FunctionStreamEvent, ResponseStream, StreamEventType, StreamProcessingState,
+    EventStream, FunctionStream, FunctionStreamEvent, PartialToolCall, ResponseStream,
+    StreamConfig, StreamEventType, StreamProcessingState,
 };
 
 // StreamingApi already re-exported above in the main re-exports section
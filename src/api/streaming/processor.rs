@@ -1,35 +1,199 @@
 //! Function stream processing logic for handling tool calls and function execution
 
 use crate::error::Result;
-use crate::models::functions::FunctionCall;
 use crate::models::responses::{
-    ResponseChoice, ResponseOutput, ResponseResult, StreamChunk, Usage,
+    ResponseChoice, ResponseOutput, ResponseResult, StreamChunk, ToolCallDelta, Usage,
 };
 use futures::StreamExt as FuturesStreamExt;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use super::function_state::FunctionStreamState;
-use super::types::{FunctionCallBuilder, FunctionStream, FunctionStreamEvent, ResponseStream};
+use super::types::{FunctionStream, FunctionStreamEvent, PartialToolCall, ResponseStream};
+
+/// Accumulates streamed tool call fragments independently per index
+///
+/// Argument JSON arrives in pieces, possibly interleaved across parallel
+/// calls. A call is completed once its arguments parse as JSON, and
+/// completions are always emitted in index order: a finished call waits
+/// until every lower-indexed call has completed.
+#[derive(Debug, Clone, Default)]
+pub struct ToolCallAccumulator {
+    /// Partial calls keyed by their stream index
+    calls: BTreeMap<u32, PartialToolCall>,
+}
+
+/// Captures the token usage reported at the end of a stream
+///
+/// With `stream_options.include_usage` set, the API sends a final chunk with
+/// no choices and a `usage` object. Feed every chunk to [`observe`] and read
+/// the usage once the stream has ended.
+///
+/// [`observe`]: UsageAccumulator::observe
+#[derive(Debug, Clone, Default)]
+pub struct UsageAccumulator {
+    /// Most recent usage seen on the stream
+    usage: Option<Usage>,
+}
+
+impl UsageAccumulator {
+    /// Create an accumulator that has not seen any usage yet
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the usage carried by a chunk, if any
+    pub fn observe(&mut self, chunk: &StreamChunk) {
+        if let Some(usage) = &chunk.usage {
+            self.usage = Some(usage.clone());
+        }
+    }
+
+    /// Usage reported so far, if the stream included it
+    #[must_use]
+    pub fn usage(&self) -> Option<&Usage> {
+        self.usage.as_ref()
+    }
+
+    /// Consume the accumulator and return the reported usage
+    #[must_use]
+    pub fn into_usage(self) -> Option<Usage> {
+        self.usage
+    }
+}
+
+impl ToolCallAccumulator {
+    /// Create an empty accumulator
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accumulated state for the call at `index`
+    #[must_use]
+    pub fn get(&self, index: u32) -> Option<&PartialToolCall> {
+        self.calls.get(&index)
+    }
+
+    /// Iterate over accumulated calls in index order
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &PartialToolCall)> {
+        self.calls.iter().map(|(index, call)| (*index, call))
+    }
+
+    /// Number of calls seen so far
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Check whether no tool call deltas have been seen
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// Apply a tool call delta and return the events it produces
+    ///
+    /// Returns start and argument events for the delta, followed by any
+    /// calls that became complete.
+    pub fn push(&mut self, delta: ToolCallDelta) -> Vec<FunctionStreamEvent> {
+        let mut events = Vec::new();
+        let call = self.calls.entry(delta.index).or_default();
+
+        if let Some(id) = delta.id {
+            call.call_id = Some(id);
+        }
+
+        if let Some(function) = delta.function {
+            if let Some(name) = function.name {
+                call.name = Some(name);
+            }
+
+            if !call.started
+                && let (Some(call_id), Some(name)) = (&call.call_id, &call.name)
+            {
+                call.started = true;
+                events.push(FunctionStreamEvent::FunctionCallStarted {
+                    call_id: call_id.clone(),
+                    function_name: name.clone(),
+                });
+            }
+
+            if let Some(args_delta) = function.arguments {
+                call.arguments.push_str(&args_delta);
+
+                if let Some(call_id) = &call.call_id {
+                    events.push(FunctionStreamEvent::FunctionCallArgumentsDelta {
+                        call_id: call_id.clone(),
+                        arguments_delta: args_delta,
+                    });
+                }
+            }
+        }
+
+        events.extend(self.drain_ready());
+        events
+    }
+
+    /// Complete every remaining call in index order and reset the accumulator
+    pub fn finish(&mut self) -> Vec<FunctionStreamEvent> {
+        let events = self
+            .calls
+            .values()
+            .filter(|call| !call.completed)
+            .filter_map(PartialToolCall::to_function_call)
+            .map(|call| FunctionStreamEvent::FunctionCallCompleted { call })
+            .collect();
+        self.calls.clear();
+        events
+    }
+
+    /// Complete calls whose arguments parse, stopping at the first pending one
+    fn drain_ready(&mut self) -> Vec<FunctionStreamEvent> {
+        let mut events = Vec::new();
+
+        for call in self.calls.values_mut().filter(|call| !call.completed) {
+            if !call.arguments_complete() {
+                break;
+            }
+            let Some(function_call) = call.to_function_call() else {
+                break;
+            };
+            call.completed = true;
+            events.push(FunctionStreamEvent::FunctionCallCompleted {
+                call: function_call,
+            });
+        }
+
+        events
+    }
+}
 
 /// Function stream processor that accumulates function calls
 pub struct FunctionStreamProcessor {
     /// Raw stream
     stream: ResponseStream,
-    /// Accumulated function calls by index
-    function_calls: HashMap<u32, FunctionCallBuilder>,
+    /// Accumulated tool calls by index
+    tool_calls: ToolCallAccumulator,
 }
 
 impl FunctionStreamProcessor {
-    /// Get mutable reference to function calls
-    pub(crate) fn function_calls_mut(&mut self) -> &mut HashMap<u32, FunctionCallBuilder> {
-        &mut self.function_calls
+    /// Accumulated tool calls by index
+    #[must_use]
+    pub fn tool_calls(&self) -> &ToolCallAccumulator {
+        &self.tool_calls
+    }
+
+    /// Get mutable reference to the tool call accumulator
+    pub(crate) fn tool_calls_mut(&mut self) -> &mut ToolCallAccumulator {
+        &mut self.tool_calls
     }
     /// Create a new function stream from a response stream
     #[must_use]
     pub fn into_function_stream(stream: ResponseStream) -> FunctionStream {
         let processor = Self {
             stream,
-            function_calls: HashMap::new(),
+            tool_calls: ToolCallAccumulator::new(),
         };
 
         Box::pin(async_stream::stream! {
@@ -67,96 +231,37 @@ impl FunctionStreamProcessor {
 
     /// Process tool calls from choice
     pub(crate) fn process_choice_tool_calls(
-        function_calls: &mut HashMap<u32, FunctionCallBuilder>,
+        tool_calls: &mut ToolCallAccumulator,
         choice: &crate::models::responses::StreamChoice,
     ) -> Option<Vec<FunctionStreamEvent>> {
-        choice
-            .delta
-            .tool_calls
-            .as_ref()
-            .map(|tool_calls| Self::process_tool_calls(function_calls, tool_calls.clone()))
+        choice.delta.tool_calls.as_ref().map(|deltas| {
+            deltas
+                .iter()
+                .flat_map(|delta| tool_calls.push(delta.clone()))
+                .collect()
+        })
     }
 
     /// Process choice completion
     pub(crate) fn process_choice_completion(
-        function_calls: &mut HashMap<u32, FunctionCallBuilder>,
+        tool_calls: &mut ToolCallAccumulator,
         chunk: &StreamChunk,
         choice: &crate::models::responses::StreamChoice,
     ) -> Option<Vec<FunctionStreamEvent>> {
         if choice.finish_reason.is_some() {
-            Some(Self::handle_completion(function_calls, chunk, choice))
+            Some(Self::handle_completion(tool_calls, chunk, choice))
         } else {
             None
         }
     }
 
-    /// Process tool calls from stream delta
-    fn process_tool_calls(
-        function_calls: &mut HashMap<u32, FunctionCallBuilder>,
-        tool_calls: Vec<crate::models::responses::ToolCallDelta>,
-    ) -> Vec<FunctionStreamEvent> {
-        let mut events = Vec::new();
-
-        for tool_call in tool_calls {
-            let builder = function_calls.entry(tool_call.index).or_default();
-
-            if let Some(id) = tool_call.id {
-                builder.call_id = Some(id);
-            }
-
-            if let Some(function) = tool_call.function {
-                events.extend(Self::process_function_delta(builder, function));
-            }
-        }
-
-        events
-    }
-
-    /// Process function call delta from stream
-    fn process_function_delta(
-        builder: &mut FunctionCallBuilder,
-        function: crate::models::responses::FunctionCallDelta,
-    ) -> Vec<FunctionStreamEvent> {
-        let mut events = Vec::new();
-
-        if let Some(name) = function.name {
-            builder.name = Some(name.clone());
-
-            if let Some(call_id) = &builder.call_id {
-                events.push(FunctionStreamEvent::FunctionCallStarted {
-                    call_id: call_id.clone(),
-                    function_name: name,
-                });
-            }
-        }
-
-        if let Some(args_delta) = function.arguments {
-            builder.arguments.push_str(&args_delta);
-
-            if let Some(call_id) = &builder.call_id {
-                events.push(FunctionStreamEvent::FunctionCallArgumentsDelta {
-                    call_id: call_id.clone(),
-                    arguments_delta: args_delta,
-                });
-            }
-        }
-
-        events
-    }
-
     /// Handle stream completion
     fn handle_completion(
-        function_calls: &mut HashMap<u32, FunctionCallBuilder>,
+        tool_calls: &mut ToolCallAccumulator,
         chunk: &StreamChunk,
         choice: &crate::models::responses::StreamChoice,
     ) -> Vec<FunctionStreamEvent> {
-        let mut events = Vec::new();
-
-        for (_, builder) in function_calls.drain() {
-            if let Some(call) = builder.build() {
-                events.push(FunctionStreamEvent::FunctionCallCompleted { call });
-            }
-        }
+        let mut events = tool_calls.finish();
 
         let response = Self::create_completion_response(chunk, choice);
         events.push(FunctionStreamEvent::Completed { response });
@@ -182,8 +287,11 @@ impl FunctionStreamProcessor {
                     function_calls: None,
                     structured_data: None,
                     schema_validation: None,
+                    refusal: None,
+                    annotations: None,
                 },
                 finish_reason: choice.finish_reason.clone(),
+                stop_sequence: None,
             }],
             usage: Some(Usage {
                 prompt_tokens: 0,
@@ -192,6 +300,147 @@ impl FunctionStreamProcessor {
                 prompt_tokens_details: None,
                 completion_tokens_details: None,
             }),
+            extra: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::responses::{FunctionCallDelta, StreamChoice, StreamDelta};
+    use futures::stream;
+
+    fn fragment(index: u32, id: Option<&str>, name: Option<&str>, args: &str) -> ToolCallDelta {
+        ToolCallDelta {
+            index,
+            id: id.map(String::from),
+            r#type: id.map(|_| "function".to_string()),
+            function: Some(FunctionCallDelta {
+                name: name.map(String::from),
+                arguments: Some(args.to_string()),
+            }),
         }
     }
+
+    fn chunk(tool_calls: Vec<ToolCallDelta>, finish_reason: Option<&str>) -> StreamChunk {
+        StreamChunk {
+            id: "chunk".to_string(),
+            object: "chat.completion.chunk".to_string(),
+            created: 0,
+            model: "gpt-4o".to_string(),
+            choices: vec![StreamChoice {
+                index: 0,
+                delta: StreamDelta {
+                    content: None,
+                    role: None,
+                    tool_calls: (!tool_calls.is_empty()).then_some(tool_calls),
+                },
+                finish_reason: finish_reason.map(String::from),
+            }],
+            usage: None,
+        }
+    }
+
+    fn completed(events: &[FunctionStreamEvent]) -> Vec<(String, String)> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                FunctionStreamEvent::FunctionCallCompleted { call } => {
+                    Some((call.name.clone(), call.arguments.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_accumulator_tracks_interleaved_calls_independently() {
+        let mut acc = ToolCallAccumulator::new();
+        acc.push(fragment(
+            0,
+            Some("call_a"),
+            Some("get_weather"),
+            "{\"city\":",
+        ));
+        acc.push(fragment(1, Some("call_b"), Some("get_time"), "{\"tz\""));
+        acc.push(fragment(0, None, None, "\"Paris\""));
+
+        let first = acc.get(0).unwrap();
+        assert_eq!(first.name.as_deref(), Some("get_weather"));
+        assert_eq!(first.arguments, "{\"city\":\"Paris\"");
+        assert!(!first.is_completed());
+        assert_eq!(acc.get(1).unwrap().arguments, "{\"tz\"");
+        assert_eq!(acc.len(), 2);
+    }
+
+    #[test]
+    fn test_accumulator_completes_in_index_order() {
+        let mut acc = ToolCallAccumulator::new();
+        acc.push(fragment(0, Some("call_a"), Some("first"), "{\"a\":"));
+        let events = acc.push(fragment(1, Some("call_b"), Some("second"), "{}"));
+        assert!(
+            completed(&events).is_empty(),
+            "index 1 must wait for index 0"
+        );
+
+        let events = acc.push(fragment(0, None, None, "1}"));
+        assert_eq!(
+            completed(&events),
+            vec![
+                ("first".to_string(), "{\"a\":1}".to_string()),
+                ("second".to_string(), "{}".to_string()),
+            ]
+        );
+        assert!(acc.iter().all(|(_, call)| call.is_completed()));
+        assert!(completed(&acc.finish()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_function_stream_with_interleaved_fragments() {
+        let chunks = vec![
+            Ok(chunk(
+                vec![
+                    fragment(0, Some("call_a"), Some("get_weather"), ""),
+                    fragment(1, Some("call_b"), Some("get_time"), ""),
+                ],
+                None,
+            )),
+            Ok(chunk(vec![fragment(1, None, None, "{\"tz\":")], None)),
+            Ok(chunk(vec![fragment(0, None, None, "{\"city\":")], None)),
+            Ok(chunk(vec![fragment(1, None, None, "\"UTC\"}")], None)),
+            Ok(chunk(vec![fragment(0, None, None, "\"Paris\"}")], None)),
+            Ok(chunk(Vec::new(), Some("tool_calls"))),
+        ];
+        let stream: ResponseStream = Box::pin(stream::iter(chunks));
+
+        let events: Vec<FunctionStreamEvent> =
+            FunctionStreamProcessor::into_function_stream(stream)
+                .map(|event| event.unwrap())
+                .collect()
+                .await;
+
+        let started: Vec<&str> = events
+            .iter()
+            .filter_map(|event| match event {
+                FunctionStreamEvent::FunctionCallStarted { call_id, .. } => Some(call_id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(started, vec!["call_a", "call_b"]);
+        assert_eq!(
+            completed(&events),
+            vec![
+                (
+                    "get_weather".to_string(),
+                    "{\"city\":\"Paris\"}".to_string()
+                ),
+                ("get_time".to_string(), "{\"tz\":\"UTC\"}".to_string()),
+            ]
+        );
+        assert!(matches!(
+            events.last(),
+            Some(FunctionStreamEvent::Completed { .. })
+        ));
+    }
 }
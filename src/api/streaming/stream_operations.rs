@@ -1,22 +1,191 @@
 //! Stream operation functions for collecting and processing streams
 
-use crate::error::Result;
+use crate::error::{OpenAIError, Result};
+use crate::models::responses::{ToolCallDelta, Usage};
 use futures::Stream;
 use futures::StreamExt as FuturesStreamExt;
 use std::pin::Pin;
 
+use super::processor::UsageAccumulator;
 use super::types::ResponseStream;
 
+/// Future returned by [`ResponseStreamExt::collect_with_usage`]
+pub type UsageCollectFuture =
+    Pin<Box<dyn futures::Future<Output = Result<(String, Option<Usage>)>> + Send>>;
+
 /// Helper trait for streaming operations
 pub trait ResponseStreamExt {
     /// Collect content from stream chunks
     fn collect_content(self) -> Pin<Box<dyn futures::Future<Output = Result<String>> + Send>>;
+
+    /// Collect content from stream chunks along with the usage sent in the final chunk
+    ///
+    /// Usage is only reported when the request set `stream_options.include_usage`.
+    fn collect_with_usage(self) -> UsageCollectFuture;
+
+    /// Drive the stream to completion, invoking the matching handler for each event
+    ///
+    /// See [`StreamHandlers`] for when each callback fires.
+    fn for_each_event<'a>(
+        self,
+        handlers: StreamHandlers<'a>,
+    ) -> Pin<Box<dyn futures::Future<Output = Result<()>> + Send + 'a>>;
 }
 
 impl ResponseStreamExt for ResponseStream {
     fn collect_content(self) -> Pin<Box<dyn futures::Future<Output = Result<String>> + Send>> {
         Box::pin(collect_stream_response(self))
     }
+
+    fn collect_with_usage(self) -> UsageCollectFuture {
+        Box::pin(collect_stream_response_with_usage(self))
+    }
+
+    fn for_each_event<'a>(
+        self,
+        handlers: StreamHandlers<'a>,
+    ) -> Pin<Box<dyn futures::Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(dispatch_stream_events(self, handlers))
+    }
+}
+
+/// Handler invoked with each text fragment
+type TextDeltaHandler<'a> = Box<dyn FnMut(&str) + Send + 'a>;
+/// Handler invoked with each tool call delta
+type ToolCallHandler<'a> = Box<dyn FnMut(&ToolCallDelta) + Send + 'a>;
+/// Handler invoked once with the last finish reason seen
+type DoneHandler<'a> = Box<dyn FnOnce(Option<&str>) + Send + 'a>;
+/// Handler invoked with an error that ended the stream
+type ErrorHandler<'a> = Box<dyn FnOnce(&OpenAIError) + Send + 'a>;
+
+/// Optional callbacks for consuming a [`ResponseStream`] with
+/// [`ResponseStreamExt::for_each_event`]
+///
+/// - `on_text_delta` fires for every content fragment, in order.
+/// - `on_tool_call` fires for every streamed tool call delta.
+/// - `on_done` fires once when the stream ends cleanly, with the last finish reason.
+/// - `on_error` fires if the stream yields an error; the error is then treated as
+///   handled and `for_each_event` returns `Ok(())`. Without an error handler the
+///   error is returned instead.
+///
+/// ```rust,no_run
+/// use openai_rust_sdk::api::streaming::{ResponseStreamExt, StreamHandlers, StreamingApi};
+///
+/// # tokio_test::block_on(async {
+/// let api = StreamingApi::new("your-api-key")?;
+/// let stream = api.create_text_stream("gpt-4", "Tell me a story").await?;
+///
+/// stream
+///     .for_each_event(
+///         StreamHandlers::new()
+///             .on_text_delta(|text| print!("{text}"))
+///             .on_done(|reason| println!("\n[finished: {reason:?}]")),
+///     )
+///     .await?;
+/// # Ok::<(), openai_rust_sdk::OpenAIError>(())
+/// # });
+/// ```
+#[derive(Default)]
+pub struct StreamHandlers<'a> {
+    /// Called with each text delta
+    text_delta: Option<TextDeltaHandler<'a>>,
+    /// Called with each tool call delta
+    tool_call: Option<ToolCallHandler<'a>>,
+    /// Called once when the stream completes
+    done: Option<DoneHandler<'a>>,
+    /// Called when the stream yields an error
+    error: Option<ErrorHandler<'a>>,
+}
+
+impl<'a> StreamHandlers<'a> {
+    /// Create an empty set of handlers
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the handler for text deltas
+    #[must_use]
+    pub fn on_text_delta(mut self, handler: impl FnMut(&str) + Send + 'a) -> Self {
+        self.text_delta = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the handler for tool call deltas
+    #[must_use]
+    pub fn on_tool_call(mut self, handler: impl FnMut(&ToolCallDelta) + Send + 'a) -> Self {
+        self.tool_call = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the handler called once the stream completes
+    #[must_use]
+    pub fn on_done(mut self, handler: impl FnOnce(Option<&str>) + Send + 'a) -> Self {
+        self.done = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the handler called when the stream yields an error
+    #[must_use]
+    pub fn on_error(mut self, handler: impl FnOnce(&OpenAIError) + Send + 'a) -> Self {
+        self.error = Some(Box::new(handler));
+        self
+    }
+}
+
+impl std::fmt::Debug for StreamHandlers<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamHandlers")
+            .field("on_text_delta", &self.text_delta.is_some())
+            .field("on_tool_call", &self.tool_call.is_some())
+            .field("on_done", &self.done.is_some())
+            .field("on_error", &self.error.is_some())
+            .finish()
+    }
+}
+
+/// Consume a stream, dispatching each chunk to the given handlers
+async fn dispatch_stream_events(
+    mut stream: ResponseStream,
+    mut handlers: StreamHandlers<'_>,
+) -> Result<()> {
+    let mut finish_reason = None;
+
+    while let Some(chunk_result) = FuturesStreamExt::next(&mut stream).await {
+        let chunk = match chunk_result {
+            Ok(chunk) => chunk,
+            Err(error) => {
+                return match handlers.error.take() {
+                    Some(on_error) => {
+                        on_error(&error);
+                        Ok(())
+                    }
+                    None => Err(error),
+                };
+            }
+        };
+
+        for choice in chunk.choices {
+            if let (Some(content), Some(on_text_delta)) =
+                (&choice.delta.content, handlers.text_delta.as_mut())
+            {
+                on_text_delta(content);
+            }
+            if let (Some(tool_calls), Some(on_tool_call)) =
+                (&choice.delta.tool_calls, handlers.tool_call.as_mut())
+            {
+                tool_calls.iter().for_each(on_tool_call);
+            }
+            if choice.finish_reason.is_some() {
+                finish_reason = choice.finish_reason;
+            }
+        }
+    }
+
+    if let Some(on_done) = handlers.done.take() {
+        on_done(finish_reason.as_deref());
+    }
+    Ok(())
 }
 
 /// Collect all chunks from a stream into a single response
@@ -40,3 +209,164 @@ pub async fn collect_stream_response(mut stream: ResponseStream) -> Result<Strin
 
     Ok(content)
 }
+
+/// Collect all chunks from a stream into a single response and its reported usage
+pub async fn collect_stream_response_with_usage(
+    mut stream: ResponseStream,
+) -> Result<(String, Option<Usage>)> {
+    let mut content = String::new();
+    let mut usage = UsageAccumulator::new();
+
+    while let Some(chunk_result) = FuturesStreamExt::next(&mut stream).await {
+        let chunk = chunk_result?;
+        usage.observe(&chunk);
+
+        for choice in chunk.choices {
+            if let Some(delta_content) = &choice.delta.content {
+                content.push_str(delta_content);
+            }
+        }
+    }
+
+    Ok((content, usage.into_usage()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::responses::{FunctionCallDelta, StreamChoice, StreamChunk, StreamDelta};
+    use std::sync::{Arc, Mutex};
+
+    fn chunk(
+        content: Option<&str>,
+        tool_calls: Option<Vec<ToolCallDelta>>,
+        finish_reason: Option<&str>,
+    ) -> Result<StreamChunk> {
+        Ok(StreamChunk {
+            id: "chatcmpl-1".to_string(),
+            object: "chat.completion.chunk".to_string(),
+            created: 0,
+            model: "gpt-4".to_string(),
+            choices: vec![StreamChoice {
+                index: 0,
+                delta: StreamDelta {
+                    content: content.map(str::to_string),
+                    role: None,
+                    tool_calls,
+                },
+                finish_reason: finish_reason.map(str::to_string),
+            }],
+            usage: None,
+        })
+    }
+
+    fn stream_of(items: Vec<Result<StreamChunk>>) -> ResponseStream {
+        Box::pin(futures::stream::iter(items))
+    }
+
+    #[tokio::test]
+    async fn test_for_each_event_dispatches_handlers() {
+        let tool_delta = ToolCallDelta {
+            index: 0,
+            id: Some("call_1".to_string()),
+            r#type: Some("function".to_string()),
+            function: Some(FunctionCallDelta {
+                name: Some("lookup".to_string()),
+                arguments: Some("{}".to_string()),
+            }),
+        };
+        let stream = stream_of(vec![
+            chunk(Some("Hel"), None, None),
+            chunk(Some("lo"), None, None),
+            chunk(None, Some(vec![tool_delta]), None),
+            chunk(None, None, Some("tool_calls")),
+        ]);
+
+        let mut text = String::new();
+        let mut tool_names = Vec::new();
+        let done = Arc::new(Mutex::new(None));
+        let done_reason = Arc::clone(&done);
+
+        stream
+            .for_each_event(
+                StreamHandlers::new()
+                    .on_text_delta(|delta| text.push_str(delta))
+                    .on_tool_call(|call| {
+                        tool_names.push(call.function.as_ref().and_then(|f| f.name.clone()));
+                    })
+                    .on_done(move |reason| {
+                        *done_reason.lock().unwrap() = reason.map(str::to_string);
+                    }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(text, "Hello");
+        assert_eq!(tool_names, vec![Some("lookup".to_string())]);
+        assert_eq!(done.lock().unwrap().as_deref(), Some("tool_calls"));
+    }
+
+    #[tokio::test]
+    async fn test_collect_with_usage_reads_final_usage_chunk() {
+        let usage_chunk: StreamChunk = serde_json::from_value(serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion.chunk",
+            "created": 0,
+            "model": "gpt-4",
+            "choices": [],
+            "usage": {"prompt_tokens": 9, "completion_tokens": 3, "total_tokens": 12}
+        }))
+        .unwrap();
+
+        let (text, usage) = stream_of(vec![
+            chunk(Some("Hel"), None, None),
+            chunk(Some("lo"), None, Some("stop")),
+            Ok(usage_chunk),
+        ])
+        .collect_with_usage()
+        .await
+        .unwrap();
+
+        assert_eq!(text, "Hello");
+        let usage = usage.unwrap();
+        assert_eq!(usage.prompt_tokens, 9);
+        assert_eq!(usage.total_tokens, 12);
+
+        let (_, usage) = stream_of(vec![chunk(Some("Hi"), None, Some("stop"))])
+            .collect_with_usage()
+            .await
+            .unwrap();
+        assert!(usage.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_for_each_event_routes_errors() {
+        let failing = || {
+            stream_of(vec![
+                chunk(Some("partial"), None, None),
+                Err(OpenAIError::streaming("connection reset")),
+                chunk(Some("never seen"), None, None),
+            ])
+        };
+
+        let mut text = String::new();
+        let mut seen_error = None;
+        let mut done_called = false;
+        failing()
+            .for_each_event(
+                StreamHandlers::new()
+                    .on_text_delta(|delta| text.push_str(delta))
+                    .on_error(|error| seen_error = Some(error.to_string()))
+                    .on_done(|_| done_called = true),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(text, "partial");
+        assert!(seen_error.unwrap().contains("connection reset"));
+        assert!(!done_called);
+
+        let unhandled = failing().for_each_event(StreamHandlers::new()).await;
+        assert!(matches!(unhandled, Err(OpenAIError::Streaming(_))));
+    }
+}
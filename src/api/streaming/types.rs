@@ -9,6 +9,9 @@ use std::pin::Pin;
 /// Streaming response from `OpenAI` API
 pub type ResponseStream = Pin<Box<dyn Stream<Item = Result<StreamChunk>> + Send>>;
 
+/// Stream of decoded events from `OpenAI` API
+pub type EventStream = Pin<Box<dyn Stream<Item = Result<StreamEventType>> + Send>>;
+
 /// Streaming response with function calling support
 pub type FunctionStream = Pin<Box<dyn Stream<Item = Result<FunctionStreamEvent>> + Send>>;
 
@@ -35,6 +38,36 @@ pub enum StreamEventType {
         /// Error message describing what went wrong
         message: String,
     },
+    /// A frame whose payload could not be parsed and was skipped
+    ParseError {
+        /// The raw `data:` payload of the frame
+        raw: String,
+    },
+}
+
+/// Options controlling how stream frames are decoded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreamConfig {
+    /// Keep streaming past frames with malformed JSON
+    pub skip_malformed_events: bool,
+}
+
+impl StreamConfig {
+    /// Create the default configuration, which fails on malformed frames
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep streaming past frames with malformed JSON instead of failing
+    ///
+    /// Chunk streams drop such frames, and event streams report them as
+    /// [`StreamEventType::ParseError`].
+    #[must_use]
+    pub fn skip_malformed_events(mut self, skip: bool) -> Self {
+        self.skip_malformed_events = skip;
+        self
+    }
 }
 
 /// Events for function calling streams
@@ -76,39 +109,45 @@ pub enum FunctionStreamEvent {
     },
 }
 
-/// Builder for accumulating function call deltas
-#[derive(Debug, Clone)]
-pub(crate) struct FunctionCallBuilder {
-    /// ID of the function call
+/// Tool call state accumulated from streamed deltas for a single index
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartialToolCall {
+    /// ID of the function call, once streamed
     pub call_id: Option<String>,
-    /// Name of the function being called
+    /// Name of the function being called, once streamed
     pub name: Option<String>,
-    /// JSON arguments for the function call
+    /// JSON argument string received so far
     pub arguments: String,
+    /// Whether a `FunctionCallStarted` event has been emitted
+    pub(crate) started: bool,
+    /// Whether a `FunctionCallCompleted` event has been emitted
+    pub(crate) completed: bool,
 }
 
-impl FunctionCallBuilder {
-    /// Create a new function call builder
-    pub fn new() -> Self {
-        Self {
-            call_id: None,
-            name: None,
-            arguments: String::new(),
-        }
+impl PartialToolCall {
+    /// Check whether a completed event has been emitted for this call
+    #[must_use]
+    pub fn is_completed(&self) -> bool {
+        self.completed
     }
 
-    /// Build the final function call if complete
-    pub fn build(self) -> Option<FunctionCall> {
-        match (self.call_id, self.name) {
-            (Some(call_id), Some(name)) => Some(FunctionCall::new(call_id, name, self.arguments)),
-            _ => None,
-        }
+    /// Check whether the accumulated arguments form a complete JSON value
+    #[must_use]
+    pub fn arguments_complete(&self) -> bool {
+        serde_json::from_str::<serde::de::IgnoredAny>(&self.arguments).is_ok()
     }
-}
 
-impl Default for FunctionCallBuilder {
-    fn default() -> Self {
-        Self::new()
+    /// Build the function call if its ID and name are known
+    #[must_use]
+    pub fn to_function_call(&self) -> Option<FunctionCall> {
+        match (&self.call_id, &self.name) {
+            (Some(call_id), Some(name)) => Some(FunctionCall::new(
+                call_id.clone(),
+                name.clone(),
+                self.arguments.clone(),
+            )),
+            _ => None,
+        }
     }
 }
 
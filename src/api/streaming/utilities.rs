@@ -5,8 +5,9 @@ use crate::models::responses::{
     ResponseChoice, ResponseOutput, ResponseResult, StreamChunk, Usage,
 };
 use serde::Serialize;
+use std::collections::HashMap;
 
-use super::types::{ResponseStream, StreamEventType};
+use super::types::{ResponseStream, StreamConfig, StreamEventType};
 
 /// Streaming request helper to consolidate JSON streaming setup patterns
 ///
@@ -56,6 +57,20 @@ pub fn process_stream_event(
         eventsource_stream::Event,
         eventsource_stream::EventStreamError<reqwest::Error>,
     >,
+) -> Option<Result<StreamChunk>> {
+    process_stream_event_with(event_result, StreamConfig::default())
+}
+
+/// Process individual stream events, honouring `config`
+///
+/// With [`StreamConfig::skip_malformed_events`] set, a frame with malformed
+/// JSON is dropped instead of producing an error.
+pub fn process_stream_event_with(
+    event_result: std::result::Result<
+        eventsource_stream::Event,
+        eventsource_stream::EventStreamError<reqwest::Error>,
+    >,
+    config: StreamConfig,
 ) -> Option<Result<StreamChunk>> {
     match event_result {
         Ok(event) => {
@@ -65,6 +80,7 @@ pub fn process_stream_event(
 
             match serde_json::from_str::<StreamChunk>(&event.data) {
                 Ok(chunk) => Some(Ok(chunk)),
+                Err(_) if config.skip_malformed_events => None,
                 Err(e) => Some(Err(OpenAIError::streaming(format!(
                     "Failed to parse chunk: {e}"
                 )))),
@@ -74,6 +90,33 @@ pub fn process_stream_event(
     }
 }
 
+/// Decode one stream frame into events, honouring `config`
+///
+/// Returns `None` at the end of the stream. A frame with malformed JSON is an
+/// error unless [`StreamConfig::skip_malformed_events`] is set, in which case it
+/// becomes a [`StreamEventType::ParseError`] and the stream carries on.
+pub fn decode_stream_event(
+    event_result: std::result::Result<
+        eventsource_stream::Event,
+        eventsource_stream::EventStreamError<reqwest::Error>,
+    >,
+    config: StreamConfig,
+) -> Option<Result<Vec<StreamEventType>>> {
+    match event_result {
+        Ok(event) if event.data == "[DONE]" => None,
+        Ok(event) => match serde_json::from_str::<StreamChunk>(&event.data) {
+            Ok(chunk) => Some(Ok(chunk_to_events(chunk))),
+            Err(_) if config.skip_malformed_events => {
+                Some(Ok(vec![StreamEventType::ParseError { raw: event.data }]))
+            }
+            Err(e) => Some(Err(OpenAIError::streaming(format!(
+                "Failed to parse chunk: {e}"
+            )))),
+        },
+        Err(e) => Some(Err(OpenAIError::streaming(format!("Stream error: {e}")))),
+    }
+}
+
 /// Convert stream chunks to events
 #[must_use]
 pub fn chunk_to_events(chunk: StreamChunk) -> Vec<StreamEventType> {
@@ -101,8 +144,11 @@ pub fn chunk_to_events(chunk: StreamChunk) -> Vec<StreamEventType> {
                             function_calls: None,
                             structured_data: None,
                             schema_validation: None,
+                            refusal: None,
+                            annotations: None,
                         },
                         finish_reason: choice.finish_reason.clone(),
+                        stop_sequence: None,
                     }],
                     usage: Some(Usage {
                         prompt_tokens: 0, // Would need actual values
@@ -111,6 +157,7 @@ pub fn chunk_to_events(chunk: StreamChunk) -> Vec<StreamEventType> {
                         prompt_tokens_details: None,
                         completion_tokens_details: None,
                     }),
+                    extra: HashMap::new(),
                 },
             });
         }
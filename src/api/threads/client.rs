@@ -37,9 +37,7 @@ impl ThreadsApi {
     /// # Ok::<(), openai_rust_sdk::OpenAIError>(())
     /// ```
     pub fn with_base_url<S: Into<String>>(api_key: S, base_url: S) -> Result<Self> {
-        Ok(Self {
-            http_client: HttpClient::new_with_base_url(api_key, base_url)?,
-        })
+        <Self as ApiClientConstructors>::new_with_base_url(api_key, base_url)
     }
 }
 
@@ -5,7 +5,7 @@
 
 use super::{
     client::ThreadsApi,
-    types::{DeletionStatus, Thread, ThreadRequest},
+    types::{DeletionStatus, ModifyThreadRequest, Thread, ThreadRequest},
 };
 use crate::api::base::validate_request;
 use crate::constants::endpoints;
@@ -77,6 +77,9 @@ impl ThreadsApi {
 
     /// Modify a thread's metadata
     ///
+    /// Empty metadata is not sent, so it cannot be cleared here; use
+    /// [`ThreadsApi::modify_thread_partial`] to remove it.
+    ///
     /// # Arguments
     ///
     /// * `thread_id` - The ID of the thread to modify
@@ -116,6 +119,38 @@ impl ThreadsApi {
         self.http_client.post_with_beta(&path, &request).await
     }
 
+    /// Modify only the fields changed in a [`ModifyThreadRequest`]
+    ///
+    /// Unlike [`ThreadsApi::modify_thread`], this can remove all metadata by
+    /// sending `null`. Build the request with [`Thread::to_modify_request`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::api::{threads::ThreadsApi, common::ApiClientConstructors};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = ThreadsApi::new("your-api-key")?;
+    /// let thread = api.retrieve_thread("thread_abc123").await?;
+    /// let request = thread.to_modify_request().metadata_pair("status", "resolved").build();
+    ///
+    /// let thread = api.modify_thread_partial(&thread.id, request).await?;
+    /// println!("Modified thread: {}", thread.id);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// # });
+    /// ```
+    pub async fn modify_thread_partial(
+        &self,
+        thread_id: impl Into<String>,
+        request: ModifyThreadRequest,
+    ) -> Result<Thread> {
+        validate_request(&request)?;
+
+        let thread_id = thread_id.into();
+        let path = endpoints::threads::by_id(&thread_id);
+        self.http_client.post_with_beta(&path, &request).await
+    }
+
     /// Delete a thread
     ///
     /// # Arguments
@@ -6,5 +6,6 @@
 // Re-export all thread-related types from the models module
 pub use crate::models::threads::{
     DeletionStatus, ListMessageFilesResponse, ListMessagesParams, ListMessagesResponse, Message,
-    MessageFile, MessageRequest, MessageRole, SortOrder, Thread, ThreadRequest,
+    MessageFile, MessageRequest, MessageRole, ModifyThreadRequest, SortOrder, Thread,
+    ThreadRequest,
 };
@@ -45,17 +45,20 @@
 //! # });
 //! ```
 
-use crate::api::base::HttpClient;
+use crate::api::base::{Deadline, HttpClient};
 use crate::api::common::ApiClientConstructors;
+use crate::api::resource::{BulkDeleteSummary, DEFAULT_BULK_DELETE_CONCURRENCY, DeletableResource};
 use crate::constants::endpoints;
 use crate::error::{OpenAIError, Result};
+use crate::helpers::backoff::Backoff;
 use crate::models::vector_stores::{
     ListVectorStoreFilesParams, ListVectorStoreFilesResponse, ListVectorStoresParams,
-    ListVectorStoresResponse, QueryParamBuilder, VectorStore, VectorStoreDeleteResponse,
-    VectorStoreFile, VectorStoreFileBatch, VectorStoreFileBatchRequest,
-    VectorStoreFileDeleteResponse, VectorStoreFileRequest, VectorStoreRequest,
+    ListVectorStoresResponse, ModifyVectorStoreRequest, QueryParamBuilder, VectorStore,
+    VectorStoreDeleteResponse, VectorStoreFile, VectorStoreFileBatch, VectorStoreFileBatchRequest,
+    VectorStoreFileDeleteResponse, VectorStoreFileRequest, VectorStoreRequest, VectorStoreStatus,
 };
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// `OpenAI` Vector Stores API client for vector store management operations
 #[derive(Debug, Clone)]
@@ -193,6 +196,9 @@ impl VectorStoresApi {
 
     /// Modifies a vector store
     ///
+    /// Fields left as `None` are not sent, so they cannot be cleared here; use
+    /// [`VectorStoresApi::modify_vector_store_partial`] to remove a value.
+    ///
     /// # Arguments
     ///
     /// * `vector_store_id` - The ID of the vector store to modify
@@ -229,6 +235,37 @@ impl VectorStoresApi {
         self.http_client.post(&path, &request).await
     }
 
+    /// Modifies only the fields set in a [`ModifyVectorStoreRequest`]
+    ///
+    /// Fields left unchanged keep their current values, and cleared fields are
+    /// sent as `null`. Build the request with [`VectorStore::to_modify_request`]
+    /// to send only what changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::api::{vector_stores::VectorStoresApi, common::ApiClientConstructors};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = VectorStoresApi::new("your-api-key")?;
+    ///
+    /// let store = api.retrieve_vector_store("vs-abc123").await?;
+    /// let request = store.to_modify_request().add_metadata("version", "2.0").build();
+    /// let store = api.modify_vector_store_partial(&store.id, request).await?;
+    /// println!("Updated vector store: {}", store.id);
+    /// # Ok::<(), openai_rust_sdk::OpenAIError>(())
+    /// # });
+    /// ```
+    pub async fn modify_vector_store_partial(
+        &self,
+        vector_store_id: impl Into<String>,
+        request: ModifyVectorStoreRequest,
+    ) -> Result<VectorStore> {
+        let vector_store_id = vector_store_id.into();
+        let path = endpoints::vector_stores::by_id(&vector_store_id);
+        self.http_client.post(&path, &request).await
+    }
+
     /// Deletes a vector store
     ///
     /// # Arguments
@@ -709,29 +746,40 @@ impl VectorStoresApi {
         vector_store_id: impl Into<String>,
         max_wait_seconds: Option<u64>,
         poll_interval_seconds: Option<u64>,
+    ) -> Result<VectorStore> {
+        let deadline = Deadline::after(Duration::from_secs(max_wait_seconds.unwrap_or(300)));
+        let poll_interval = Duration::from_secs(poll_interval_seconds.unwrap_or(5));
+        self.wait_for_vector_store_ready_until(vector_store_id, poll_interval, &deadline)
+            .await
+    }
+
+    /// Wait for a vector store to be ready without running past `deadline`
+    ///
+    /// Each status request is cut short when the deadline passes, so the
+    /// total wait never exceeds the deadline's budget. Poll intervals are
+    /// jittered by up to 10% so many waiters do not poll in lockstep.
+    pub async fn wait_for_vector_store_ready_until(
+        &self,
+        vector_store_id: impl Into<String>,
+        poll_interval: Duration,
+        deadline: &Deadline,
     ) -> Result<VectorStore> {
         let vector_store_id = vector_store_id.into();
-        let max_wait = max_wait_seconds.unwrap_or(300);
-        let poll_interval = poll_interval_seconds.unwrap_or(5);
-        let start_time = std::time::Instant::now();
+        let operation = format!("waiting for vector store {vector_store_id} to become ready");
+        let mut intervals = Backoff::polling(poll_interval);
 
         loop {
-            let vector_store = self.retrieve_vector_store(&vector_store_id).await?;
+            let vector_store = deadline
+                .run(&operation, self.retrieve_vector_store(&vector_store_id))
+                .await?;
 
             // Check if processing is complete
             if vector_store.is_ready() || vector_store.has_failed() {
                 return Ok(vector_store);
             }
 
-            // Check timeout
-            if start_time.elapsed().as_secs() >= max_wait {
-                return Err(OpenAIError::Timeout(format!(
-                    "Vector store {vector_store_id} did not become ready within {max_wait} seconds"
-                )));
-            }
-
-            // Wait before next poll
-            tokio::time::sleep(tokio::time::Duration::from_secs(poll_interval)).await;
+            let interval = intervals.next().unwrap_or(poll_interval);
+            deadline.sleep(&operation, interval).await?;
         }
     }
 
@@ -769,16 +817,36 @@ impl VectorStoresApi {
         batch_id: impl Into<String>,
         max_wait_seconds: Option<u64>,
         poll_interval_seconds: Option<u64>,
+    ) -> Result<VectorStoreFileBatch> {
+        let deadline = Deadline::after(Duration::from_secs(max_wait_seconds.unwrap_or(300)));
+        let poll_interval = Duration::from_secs(poll_interval_seconds.unwrap_or(5));
+        self.wait_for_file_batch_complete_until(vector_store_id, batch_id, poll_interval, &deadline)
+            .await
+    }
+
+    /// Wait for a file batch to complete without running past `deadline`
+    ///
+    /// Each status request is cut short when the deadline passes, so the
+    /// total wait never exceeds the deadline's budget. Poll intervals are
+    /// jittered by up to 10% so many waiters do not poll in lockstep.
+    pub async fn wait_for_file_batch_complete_until(
+        &self,
+        vector_store_id: impl Into<String>,
+        batch_id: impl Into<String>,
+        poll_interval: Duration,
+        deadline: &Deadline,
     ) -> Result<VectorStoreFileBatch> {
         let vector_store_id = vector_store_id.into();
         let batch_id = batch_id.into();
-        let max_wait = max_wait_seconds.unwrap_or(300);
-        let poll_interval = poll_interval_seconds.unwrap_or(5);
-        let start_time = std::time::Instant::now();
+        let operation = format!("waiting for file batch {batch_id} to complete");
+        let mut intervals = Backoff::polling(poll_interval);
 
         loop {
-            let batch = self
-                .retrieve_vector_store_file_batch(&vector_store_id, &batch_id)
+            let batch = deadline
+                .run(
+                    &operation,
+                    self.retrieve_vector_store_file_batch(&vector_store_id, &batch_id),
+                )
                 .await?;
 
             // Check if processing is complete
@@ -789,15 +857,8 @@ impl VectorStoresApi {
                 return Ok(batch);
             }
 
-            // Check timeout
-            if start_time.elapsed().as_secs() >= max_wait {
-                return Err(OpenAIError::Timeout(format!(
-                    "File batch {batch_id} did not complete within {max_wait} seconds"
-                )));
-            }
-
-            // Wait before next poll
-            tokio::time::sleep(tokio::time::Duration::from_secs(poll_interval)).await;
+            let interval = intervals.next().unwrap_or(poll_interval);
+            deadline.sleep(&operation, interval).await?;
         }
     }
 
@@ -891,6 +952,40 @@ impl VectorStoresApi {
             Err(e) => Err(e),
         }
     }
+
+    /// Deletes every vector store that has expired
+    ///
+    /// A vector store is considered expired when its status is `expired` or
+    /// its `expires_at` timestamp has passed. All pages are listed and the
+    /// matching stores are deleted with bounded concurrency.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of vector stores deleted and the number that failed
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::api::{vector_stores::VectorStoresApi, common::ApiClientConstructors};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let api = VectorStoresApi::new("your-api-key")?;
+    /// let summary = api.delete_expired().await?;
+    /// println!("Deleted {} expired vector stores ({} failed)", summary.deleted, summary.failed);
+    /// # Ok::<(), openai_rust_sdk::OpenAIError>(())
+    /// # });
+    /// ```
+    pub async fn delete_expired(&self) -> Result<BulkDeleteSummary> {
+        let now = crate::models::vector_stores::common_types::utils::current_timestamp();
+        self.delete_where(
+            |store| {
+                matches!(store.status, VectorStoreStatus::Expired)
+                    || store.expires_at.is_some_and(|expires_at| expires_at <= now)
+            },
+            DEFAULT_BULK_DELETE_CONCURRENCY,
+        )
+        .await
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,8 @@
+use crate::api::base::helpers::url::normalize_base_url;
+use crate::api::base::{ClientConfig, HttpClient};
+use crate::api::embeddings::EmbeddingsApi;
 use crate::api::functions::{FunctionConfig, FunctionResponseResult, FunctionsApi};
+use crate::api::images::ImagesApi;
 use crate::api::responses_v2::{
     DeleteResponseAck, ListResponsesParams as ResponsesListParams, ResponseInputItemList,
     ResponseList, ResponsesApiV2, ResponsesEventStream,
@@ -9,14 +13,27 @@ use crate::api::{
     ResponsesApi,
     common::{ApiClientConstructors, StandardListParams},
 };
-use crate::error::Result;
+use crate::error::{OpenAIError, Result};
+use crate::models::embeddings::{EmbeddingRequest, EmbeddingResponse};
 use crate::models::functions::{FunctionCall, FunctionCallOutput, Tool, ToolChoice};
+use crate::models::images::{ImageGenerationRequest, ImageResponse};
+use crate::models::pricing::{CostEndpoint, CostTracker};
 use crate::models::responses::{
-    Message, MessageRole, ResponseInput, ResponseRequest, ResponseResult,
+    ConversationTrimmer, Message, MessageRole, ResponseChoice, ResponseInput, ResponseRequest,
+    ResponseResult,
 };
 use crate::models::responses_v2::{
     CreateResponseRequest, Instructions, ResponseObject, from_legacy_request, to_legacy_response,
 };
+use std::sync::Arc;
+
+pub mod chat_result;
+pub mod concurrency;
+pub mod fallback;
+
+pub use chat_result::ChatResult;
+pub use concurrency::ConcurrencyConfig;
+pub use fallback::{FallbackOutcome, ModelFallbackChain, SkippedModel};
 
 /// Main `OpenAI` client that provides access to all APIs
 #[derive(Clone)]
@@ -29,6 +46,14 @@ pub struct OpenAIClient {
     streaming_api: StreamingApi,
     /// API client for function calling
     functions_api: FunctionsApi,
+    /// API client for embeddings
+    embeddings_api: EmbeddingsApi,
+    /// API client for image generation
+    images_api: ImagesApi,
+    /// Accumulator for the estimated cost of completed requests
+    cost_tracker: Arc<CostTracker>,
+    /// Whether chat requests that overflow the context window are trimmed and retried
+    auto_trim_on_overflow: bool,
 }
 
 /// State management for function calling conversations
@@ -73,43 +98,125 @@ impl ConversationState {
 impl OpenAIClient {
     /// Create a new `OpenAI` client with an API key
     pub fn new(api_key: impl Into<String>) -> Result<Self> {
-        let api_key = api_key.into();
-        let responses_api = ResponsesApi::new(&api_key)?;
-        let responses_api_v2 = ResponsesApiV2::new(&api_key)?;
-        let streaming_api = StreamingApi::new(&api_key)?;
-        let functions_api = FunctionsApi::new(&api_key)?;
-
-        Ok(Self {
-            responses_api,
-            responses_api_v2,
-            streaming_api,
-            functions_api,
-        })
+        Self::from_config(ClientConfig::new(api_key.into())?)
     }
 
     /// Create a new `OpenAI` client with custom base URL
     pub fn with_base_url(api_key: impl Into<String>, base_url: impl Into<String>) -> Result<Self> {
-        let api_key = api_key.into();
-        let base_url = base_url.into();
-        let responses_api = ResponsesApi::with_base_url(&api_key, &base_url)?;
-        let responses_api_v2 = ResponsesApiV2::new_with_base_url(&api_key, &base_url)?;
-        let streaming_api = StreamingApi::with_base_url(&api_key, &base_url)?;
-        let functions_api = FunctionsApi::with_base_url(&api_key, &base_url)?;
+        Self::from_config(ClientConfig::new_with_base_url(
+            api_key.into(),
+            base_url.into(),
+        )?)
+    }
+
+    /// Create a new `OpenAI` client from a client configuration
+    ///
+    /// Use this to combine a custom base URL with a non-`/v1` path prefix,
+    /// e.g. for gateways serving the API under `/openai/v1`.
+    pub fn from_config(config: ClientConfig) -> Result<Self> {
+        let http_client = HttpClient::from_config(config.clone())?;
+        let auto_trim_on_overflow = config.trims_on_overflow();
 
         Ok(Self {
-            responses_api,
-            responses_api_v2,
-            streaming_api,
-            functions_api,
+            responses_api: ResponsesApi::from_http_client(http_client.clone()),
+            responses_api_v2: ResponsesApiV2::from_http_client(http_client.clone()),
+            streaming_api: StreamingApi::from_http_client(http_client.clone()),
+            functions_api: FunctionsApi::from_config(config)?,
+            embeddings_api: EmbeddingsApi::from_http_client(http_client.clone()),
+            images_api: ImagesApi::from_http_client(http_client),
+            cost_tracker: Arc::new(CostTracker::default()),
+            auto_trim_on_overflow,
         })
     }
 
+    /// Use the given cost tracker to record the estimated cost of requests
+    ///
+    /// Sharing one tracker between several clients produces a combined
+    /// spend total; a tracker built with a custom
+    /// [`PricingTable`](crate::models::pricing::PricingTable) applies
+    /// negotiated rates.
+    #[must_use]
+    pub fn with_cost_tracker(mut self, cost_tracker: Arc<CostTracker>) -> Self {
+        self.cost_tracker = cost_tracker;
+        self
+    }
+
+    /// Get the tracker accumulating the estimated cost of completed requests
+    ///
+    /// Chat, responses, embeddings, and image requests made through this
+    /// client are recorded; streaming requests are not. Audio is not recorded
+    /// automatically because responses do not report a duration; use
+    /// [`CostTracker::record_audio_minutes`] for it.
+    #[must_use]
+    pub fn cost_tracker(&self) -> &Arc<CostTracker> {
+        &self.cost_tracker
+    }
+
+    /// Record the usage of a completed Responses API call
+    fn record_response_cost(
+        &self,
+        endpoint: CostEndpoint,
+        request: &CreateResponseRequest,
+        response: &ResponseObject,
+    ) {
+        if let Some(usage) = &response.usage {
+            let model = response.model.as_deref().unwrap_or(&request.model);
+            let cached_tokens = usage
+                .input_tokens_details
+                .as_ref()
+                .map_or(0, |details| details.cached_tokens);
+            self.cost_tracker.record_tokens(
+                endpoint,
+                model,
+                usage.input_tokens,
+                cached_tokens,
+                usage.output_tokens,
+            );
+        }
+    }
+
+    /// Create a response and record its cost under the given endpoint
+    async fn create_tracked_response(
+        &self,
+        endpoint: CostEndpoint,
+        request: &CreateResponseRequest,
+    ) -> Result<ResponseObject> {
+        let response = self.responses_api_v2.create_response(request).await?;
+        self.record_response_cost(endpoint, request, &response);
+        Ok(response)
+    }
+
+    /// Create embeddings and record their cost
+    pub async fn create_embeddings(&self, request: &EmbeddingRequest) -> Result<EmbeddingResponse> {
+        let response = self.embeddings_api.create_embeddings(request).await?;
+        self.cost_tracker.record_tokens(
+            CostEndpoint::Embeddings,
+            &response.model,
+            response.usage.prompt_tokens,
+            0,
+            0,
+        );
+        Ok(response)
+    }
+
+    /// Generate images and record their cost
+    pub async fn create_image(&self, request: &ImageGenerationRequest) -> Result<ImageResponse> {
+        let response = self.images_api.create_image(request).await?;
+        let count = u32::try_from(response.data.len()).unwrap_or(u32::MAX);
+        self.cost_tracker.record_images(
+            &request.model,
+            request.size.as_ref(),
+            request.quality.as_ref(),
+            count,
+        );
+        Ok(response)
+    }
+
     /// Create a response using the responses API
     pub async fn create_response(&self, request: &ResponseRequest) -> Result<ResponseResult> {
         let modern_request = from_legacy_request(request);
         let response = self
-            .responses_api_v2
-            .create_response(&modern_request)
+            .create_tracked_response(CostEndpoint::Responses, &modern_request)
             .await?;
         Ok(to_legacy_response(&response))
     }
@@ -135,6 +242,17 @@ impl OpenAIClient {
         Ok(response.output_text())
     }
 
+    /// Generate text from a simple prompt, keeping the finish reason and usage
+    pub async fn generate_text_detailed(
+        &self,
+        model: impl Into<String>,
+        prompt: impl Into<String>,
+    ) -> Result<ChatResult> {
+        let request = CreateResponseRequest::new_text(model, prompt);
+        let response = self.create_response_v2(&request).await?;
+        Ok(ChatResult::from(&response))
+    }
+
     /// Generate text with streaming
     pub async fn generate_text_stream(
         &self,
@@ -175,9 +293,8 @@ impl OpenAIClient {
         model: impl Into<String>,
         messages: Vec<Message>,
     ) -> Result<String> {
-        let request = CreateResponseRequest::new_messages(model, messages);
-        let response = self.create_response_v2(&request).await?;
-        Ok(response.output_text())
+        let result = self.send_chat(model.into(), messages).await?;
+        Ok(result.text)
     }
 
     /// Create a streaming chat completion
@@ -199,6 +316,99 @@ impl OpenAIClient {
         self.create_chat_completion(model, messages).await
     }
 
+    /// Build a conversation and get the response with its finish reason and usage
+    pub async fn chat_detailed(
+        &self,
+        model: impl Into<String>,
+        conversation: ChatBuilder,
+    ) -> Result<ChatResult> {
+        self.send_chat(model.into(), conversation.build()).await
+    }
+
+    /// Send a chat request, trimming and retrying it on overflow if enabled
+    async fn send_chat(&self, model: String, messages: Vec<Message>) -> Result<ChatResult> {
+        let request = CreateResponseRequest::new_messages(model.clone(), messages.clone());
+        match self
+            .create_tracked_response(CostEndpoint::Chat, &request)
+            .await
+        {
+            Ok(response) => Ok(ChatResult::from(&response)),
+            Err(error) if self.auto_trim_on_overflow && error.is_context_length_exceeded() => {
+                self.retry_chat_trimmed(model, messages, error).await
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Retry a chat request once with its oldest turns removed
+    ///
+    /// Returns the original error if nothing could be removed.
+    async fn retry_chat_trimmed(
+        &self,
+        model: String,
+        messages: Vec<Message>,
+        error: OpenAIError,
+    ) -> Result<ChatResult> {
+        let estimated: u32 = messages.iter().map(Message::estimate_tokens).sum();
+        let trimmer = ConversationTrimmer::from_overflow_message(&error.to_string(), estimated)
+            .unwrap_or_else(|| ConversationTrimmer::new(estimated / 2));
+        let trimmed = trimmer.trim(messages);
+        if trimmed.removed == 0 {
+            return Err(error);
+        }
+
+        let request = CreateResponseRequest::new_messages(model, trimmed.messages);
+        let response = self
+            .create_tracked_response(CostEndpoint::Chat, &request)
+            .await?;
+        Ok(ChatResult {
+            trimmed_messages: trimmed.removed,
+            ..ChatResult::from(&response)
+        })
+    }
+
+    /// Run many conversations with bounded concurrency
+    ///
+    /// Requests are issued according to `config`, retrying transient failures.
+    /// Results are returned in the order of `conversations`, each with its own
+    /// error if that conversation ultimately failed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use openai_rust_sdk::client::{ChatBuilder, ConcurrencyConfig, OpenAIClient};
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = OpenAIClient::new("your-api-key")?;
+    /// let conversations = ["apples", "pears"]
+    ///     .iter()
+    ///     .map(|fruit| ChatBuilder::new().user(format!("Describe {fruit} in one line")))
+    ///     .collect();
+    ///
+    /// let config = ConcurrencyConfig::new(8).with_requests_per_minute(500);
+    /// for result in client.chat_many("gpt-4o-mini", conversations, config).await {
+    ///     match result {
+    ///         Ok(result) => println!("{}", result.text),
+    ///         Err(error) => eprintln!("failed: {error}"),
+    ///     }
+    /// }
+    /// # Ok::<(), openai_rust_sdk::OpenAIError>(())
+    /// # });
+    /// ```
+    pub async fn chat_many(
+        &self,
+        model: impl Into<String>,
+        conversations: Vec<ChatBuilder>,
+        config: ConcurrencyConfig,
+    ) -> Vec<Result<ChatResult>> {
+        let model = model.into();
+        config
+            .run_all(conversations, |conversation| {
+                self.chat_detailed(model.clone(), conversation.clone())
+            })
+            .await
+    }
+
     /// Build a conversation and get streaming response
     pub async fn chat_stream(
         &self,
@@ -238,8 +448,7 @@ impl OpenAIClient {
 
         let modern_request = from_legacy_request(&legacy_request);
         let response = self
-            .responses_api_v2
-            .create_response(&modern_request)
+            .create_tracked_response(CostEndpoint::Responses, &modern_request)
             .await?;
         Ok(to_legacy_response(&response))
     }
@@ -261,7 +470,41 @@ impl OpenAIClient {
         &self,
         request: &CreateResponseRequest,
     ) -> Result<ResponseObject> {
-        self.responses_api_v2.create_response(request).await
+        self.create_tracked_response(CostEndpoint::Responses, request)
+            .await
+    }
+
+    /// Create a response, moving through the chain's models on rate-limit or capacity errors
+    ///
+    /// The request's `model` is replaced by each model of the chain in turn;
+    /// the outcome reports which model served the response.
+    pub async fn create_response_with_fallback(
+        &self,
+        chain: &ModelFallbackChain,
+        request: &CreateResponseRequest,
+    ) -> Result<FallbackOutcome<ResponseObject>> {
+        chain
+            .run(|model| {
+                let mut request = request.clone();
+                request.model = model;
+                async move { self.create_response_v2(&request).await }
+            })
+            .await
+    }
+
+    /// Generate text from a prompt using a model fallback chain
+    pub async fn generate_text_with_fallback(
+        &self,
+        chain: &ModelFallbackChain,
+        prompt: impl Into<String>,
+    ) -> Result<FallbackOutcome<String>> {
+        let request = CreateResponseRequest::new_text(String::new(), prompt);
+        let outcome = self.create_response_with_fallback(chain, &request).await?;
+        Ok(FallbackOutcome {
+            value: outcome.value.output_text(),
+            model: outcome.model,
+            skipped: outcome.skipped,
+        })
     }
 
     /// Stream a response using the modern Responses API
@@ -559,6 +802,119 @@ impl ChatBuilder {
     }
 }
 
+impl From<ChatBuilder> for Vec<Message> {
+    fn from(builder: ChatBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Built-in heuristics for scoring candidate choices in [`select_best_by`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionHeuristic {
+    /// Prefer the choice with the most characters of text
+    Longest,
+    /// Prefer the choice with the fewest characters of text
+    Shortest,
+    /// Prefer choices whose text is, or contains, a parseable JSON object or array
+    ContainsJson,
+}
+
+impl SelectionHeuristic {
+    /// Score a single choice; higher scores are preferred
+    #[must_use]
+    pub fn score(self, choice: &ResponseChoice) -> f64 {
+        let text = choice.message.content.as_deref().unwrap_or_default();
+        let length = text.chars().count() as f64;
+        match self {
+            Self::Longest => length,
+            Self::Shortest => -length,
+            Self::ContainsJson => json_score(text),
+        }
+    }
+}
+
+/// Score text as 1.0 if it is JSON, 0.5 if it embeds JSON, and 0.0 otherwise
+fn json_score(text: &str) -> f64 {
+    let is_json = |candidate: &str| {
+        serde_json::from_str::<serde_json::Value>(candidate)
+            .is_ok_and(|value| value.is_object() || value.is_array())
+    };
+
+    let trimmed = text.trim();
+    if is_json(trimmed) {
+        return 1.0;
+    }
+
+    let start = trimmed.find(['{', '[']);
+    let end = trimmed.rfind(['}', ']']);
+    match (start, end) {
+        (Some(start), Some(end)) if start < end && is_json(&trimmed[start..=end]) => 0.5,
+        _ => 0.0,
+    }
+}
+
+/// The choice picked by [`select_best`] together with the score of every candidate
+#[derive(Debug, Clone)]
+pub struct BestChoice<'a> {
+    /// The highest-scoring choice
+    pub choice: &'a ResponseChoice,
+    /// Position of the chosen choice in the candidate slice
+    pub position: usize,
+    /// Scores for all candidates, in the same order as the input
+    pub scores: Vec<f64>,
+}
+
+/// Pick the best of several candidate choices using a scoring closure
+///
+/// Every choice is scored with `scorer` and the highest score wins; ties go to
+/// the earliest candidate. Choices scored `NaN` or infinite cannot win. Returns
+/// `None` when `choices` is empty or no score is finite.
+///
+/// ```rust
+/// use openai_rust_sdk::client::select_best;
+/// # use openai_rust_sdk::models::responses::ResponseChoice;
+/// # fn pick(choices: &[ResponseChoice]) {
+/// let best = select_best(choices, |choice| {
+///     choice.message.content.as_deref().map_or(0.0, |text| {
+///         if text.contains("because") { 1.0 } else { 0.0 }
+///     })
+/// });
+/// # }
+/// ```
+pub fn select_best<F>(choices: &[ResponseChoice], scorer: F) -> Option<BestChoice<'_>>
+where
+    F: Fn(&ResponseChoice) -> f64,
+{
+    let scores: Vec<f64> = choices.iter().map(scorer).collect();
+    let position = scores
+        .iter()
+        .enumerate()
+        .filter(|(_, score)| score.is_finite())
+        .fold(
+            None,
+            |best: Option<(usize, f64)>, (index, &score)| match best {
+                Some((_, best_score)) if score.total_cmp(&best_score).is_le() => best,
+                _ => Some((index, score)),
+            },
+        )?
+        .0;
+
+    Some(BestChoice {
+        choice: &choices[position],
+        position,
+        scores,
+    })
+}
+
+/// Pick the best of several candidate choices using a built-in heuristic
+#[must_use]
+pub fn select_best_by(
+    choices: &[ResponseChoice],
+    heuristic: SelectionHeuristic,
+) -> Option<BestChoice<'_>> {
+    select_best(choices, |choice| heuristic.score(choice))
+}
+
 /// Convenience function to create a client from environment variables
 pub fn from_env() -> Result<OpenAIClient> {
     let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| {
@@ -590,13 +946,35 @@ pub fn from_env() -> Result<OpenAIClient> {
 }
 
 /// Convenience function to create a client with custom base URL from environment
+///
+/// The base URL must be an absolute `http` or `https` URL; a trailing slash
+/// is removed.
 pub fn from_env_with_base_url(base_url: impl Into<String>) -> Result<OpenAIClient> {
+    let base_url = normalize_base_url(&base_url.into())?;
     let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| {
         crate::error::OpenAIError::authentication("OPENAI_API_KEY environment variable not set")
     })?;
     OpenAIClient::with_base_url(api_key, base_url)
 }
 
+/// Convenience function to create a client with custom base URL and path prefix from environment
+///
+/// The path prefix replaces the `/v1` segment of every endpoint path, so
+/// `from_env_with_base_url_and_prefix("https://gateway.test", "/openai/v1")`
+/// sends chat requests to `https://gateway.test/openai/v1/chat/completions`.
+pub fn from_env_with_base_url_and_prefix(
+    base_url: impl Into<String>,
+    path_prefix: impl Into<String>,
+) -> Result<OpenAIClient> {
+    let base_url = normalize_base_url(&base_url.into())?;
+    let api_key = std::env::var("OPENAI_API_KEY").map_err(|_| {
+        crate::error::OpenAIError::authentication("OPENAI_API_KEY environment variable not set")
+    })?;
+    OpenAIClient::from_config(
+        ClientConfig::new_with_base_url(api_key, base_url)?.with_path_prefix(path_prefix),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -637,6 +1015,74 @@ mod tests {
         assert!(!builder.is_empty());
     }
 
+    fn text_choice(index: u32, text: &str) -> ResponseChoice {
+        ResponseChoice {
+            index,
+            message: crate::models::responses::ResponseOutput {
+                content: Some(text.to_string()),
+                tool_calls: None,
+                function_calls: None,
+                structured_data: None,
+                schema_validation: None,
+                refusal: None,
+                annotations: None,
+            },
+            finish_reason: Some("stop".to_string()),
+            stop_sequence: None,
+        }
+    }
+
+    #[test]
+    fn test_select_best_with_custom_scorer() {
+        let choices = vec![
+            text_choice(0, "no"),
+            text_choice(1, "yes, because it rains"),
+            text_choice(2, "yes"),
+        ];
+
+        let best = select_best(&choices, |choice| {
+            choice
+                .message
+                .content
+                .as_deref()
+                .map_or(0.0, |text| f64::from(u8::from(text.contains("because"))))
+        })
+        .unwrap();
+
+        assert_eq!(best.position, 1);
+        assert_eq!(best.choice.index, 1);
+        assert_eq!(best.scores, vec![0.0, 1.0, 0.0]);
+        assert!(select_best(&[], |_| 1.0).is_none());
+        assert!(select_best(&choices, |_| f64::NAN).is_none());
+    }
+
+    #[test]
+    fn test_select_best_by_heuristics() {
+        let choices = vec![
+            text_choice(0, "plain answer without structure"),
+            text_choice(1, "Here you go: {\"ok\": true}"),
+            text_choice(2, "[1, 2, 3]"),
+            text_choice(3, "ok"),
+        ];
+
+        let longest = select_best_by(&choices, SelectionHeuristic::Longest).unwrap();
+        assert_eq!(longest.position, 0);
+
+        let shortest = select_best_by(&choices, SelectionHeuristic::Shortest).unwrap();
+        assert_eq!(shortest.position, 3);
+
+        let json = select_best_by(&choices, SelectionHeuristic::ContainsJson).unwrap();
+        assert_eq!(json.position, 2);
+        assert_eq!(json.scores, vec![0.0, 0.5, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_select_best_ties_prefer_first_candidate() {
+        let choices = vec![text_choice(0, "abc"), text_choice(1, "xyz")];
+        let best = select_best_by(&choices, SelectionHeuristic::Longest).unwrap();
+        assert_eq!(best.position, 0);
+    }
+
     #[test]
     fn test_empty_api_key() {
         let result = OpenAIClient::new("");
@@ -667,4 +1113,260 @@ mod tests {
             std::env::remove_var("OPENAI_BASE_URL");
         }
     }
+
+    #[test]
+    fn test_from_env_with_base_url_normalizes_input() {
+        let _guard = ENV_LOCK.lock().expect("lock poisoned");
+
+        // SAFETY: test runs under ENV_LOCK so no concurrent env access.
+        // nosemgrep: rust.lang.security.unsafe-usage.unsafe-usage
+        unsafe {
+            std::env::set_var("OPENAI_API_KEY", "test-key");
+        }
+
+        let client = from_env_with_base_url("https://host/").expect("client creation failed");
+        assert_eq!(client.responses().base_url(), "https://host");
+
+        let err = from_env_with_base_url("api.openai.com")
+            .err()
+            .expect("missing scheme must be rejected");
+        assert!(err.to_string().contains("missing scheme"));
+
+        // SAFETY: test runs under ENV_LOCK so no concurrent env access.
+        // nosemgrep: rust.lang.security.unsafe-usage.unsafe-usage
+        unsafe {
+            std::env::remove_var("OPENAI_API_KEY");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_generate_text_with_fallback_reports_serving_model() {
+        use httpmock::prelude::*;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        let premium = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/responses")
+                    .body_includes(r#""model":"gpt-4o""#);
+                then.status(429).json_body(json!({
+                    "error": {"message": "Rate limit reached", "type": "requests", "code": null}
+                }));
+            })
+            .await;
+        let cheap = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/responses")
+                    .body_includes(r#""model":"gpt-4o-mini""#);
+                then.status(200).json_body(json!({
+                    "id": "resp_1",
+                    "object": "response",
+                    "status": "completed",
+                    "output_text": "fallback answer"
+                }));
+            })
+            .await;
+
+        let client = OpenAIClient::with_base_url("test-key", server.base_url()).unwrap();
+        let chain = ModelFallbackChain::new(["gpt-4o", "gpt-4o-mini"]).unwrap();
+        let outcome = client
+            .generate_text_with_fallback(&chain, "Hello")
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.model, "gpt-4o-mini");
+        assert_eq!(outcome.value, "fallback answer");
+        assert_eq!(outcome.skipped[0].model, "gpt-4o");
+        premium.assert_async().await;
+        cheap.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_chat_many_returns_per_item_results_in_order() {
+        use httpmock::prelude::*;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        let failing = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/responses")
+                    .body_includes("broken");
+                then.status(500).json_body(json!({
+                    "error": {"message": "Server error", "type": "server_error", "code": null}
+                }));
+            })
+            .await;
+        for word in ["first", "third"] {
+            server
+                .mock_async(|when, then| {
+                    when.method(POST).path("/v1/responses").body_includes(word);
+                    then.status(200).json_body(json!({
+                        "id": "resp_1",
+                        "object": "response",
+                        "status": "completed",
+                        "output_text": word
+                    }));
+                })
+                .await;
+        }
+
+        let client = OpenAIClient::with_base_url("test-key", server.base_url()).unwrap();
+        let conversations = ["first", "broken", "third"]
+            .iter()
+            .map(|word| ChatBuilder::new().user(*word))
+            .collect();
+        let config = ConcurrencyConfig::new(2).with_retries(1, std::time::Duration::ZERO);
+        let results = client.chat_many("gpt-4o", conversations, config).await;
+
+        assert_eq!(results[0].as_ref().unwrap().text, "first");
+        assert_eq!(results[1].as_ref().unwrap_err().status_code(), Some(500));
+        assert_eq!(results[2].as_ref().unwrap().text, "third");
+        failing.assert_calls_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn test_chat_detailed_trims_and_retries_on_overflow() {
+        use httpmock::prelude::*;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        let overflow = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/responses")
+                    .body_includes("old turn");
+                then.status(400).json_body(json!({
+                    "error": {
+                        "message": "Your input exceeds the context window of this model. \
+                                    Please adjust your input and try again.",
+                        "type": "invalid_request_error",
+                        "code": "context_length_exceeded"
+                    }
+                }));
+            })
+            .await;
+        let trimmed = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/responses")
+                    .body_excludes("old turn");
+                then.status(200).json_body(json!({
+                    "id": "resp_1",
+                    "object": "response",
+                    "status": "completed",
+                    "output_text": "short answer"
+                }));
+            })
+            .await;
+
+        let conversation = ChatBuilder::new()
+            .developer("Be brief")
+            .user(format!("old turn {}", "x".repeat(800)))
+            .user("Latest question");
+
+        let client = OpenAIClient::with_base_url("test-key", server.base_url()).unwrap();
+        let err = client
+            .chat_detailed("gpt-4o", conversation.clone())
+            .await
+            .unwrap_err();
+        assert!(err.is_context_length_exceeded());
+
+        let config = ClientConfig::new_with_base_url("test-key".to_string(), server.base_url())
+            .unwrap()
+            .auto_trim_on_overflow(true);
+        let client = OpenAIClient::from_config(config).unwrap();
+        let result = client
+            .chat_detailed("gpt-4o", conversation.clone())
+            .await
+            .unwrap();
+        assert_eq!(result.text, "short answer");
+        assert_eq!(result.trimmed_messages, 1);
+        assert!(result.was_trimmed());
+
+        let text = client.chat("gpt-4o", conversation).await.unwrap();
+        assert_eq!(text, "short answer");
+        overflow.assert_calls_async(3).await;
+        trimmed.assert_calls_async(2).await;
+    }
+
+    #[tokio::test]
+    async fn test_cost_tracker_records_embeddings_and_images() {
+        use httpmock::prelude::*;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/embeddings");
+                then.status(200).json_body(json!({
+                    "object": "list",
+                    "data": [{"object": "embedding", "embedding": [0.1, 0.2], "index": 0}],
+                    "model": "text-embedding-3-small",
+                    "usage": {"prompt_tokens": 500_000, "total_tokens": 500_000}
+                }));
+            })
+            .await;
+        server
+            .mock_async(|when, then| {
+                when.method(POST).path("/v1/images/generations");
+                then.status(200).json_body(json!({
+                    "created": 1,
+                    "data": [{"url": "https://example.test/a.png"}, {"url": "https://example.test/b.png"}]
+                }));
+            })
+            .await;
+
+        let tracker = Arc::new(CostTracker::default());
+        let client = OpenAIClient::with_base_url("test-key", server.base_url())
+            .unwrap()
+            .with_cost_tracker(tracker.clone());
+
+        client
+            .create_embeddings(&EmbeddingRequest::new("text-embedding-3-small", "hello"))
+            .await
+            .unwrap();
+        client
+            .create_image(&ImageGenerationRequest::new("dall-e-3", "a cat"))
+            .await
+            .unwrap();
+
+        let by_endpoint = client.cost_tracker().by_endpoint();
+        assert!((by_endpoint[&CostEndpoint::Embeddings] - 0.01).abs() < 1e-9);
+        assert!((by_endpoint[&CostEndpoint::Images] - 0.08).abs() < 1e-9);
+        assert!((tracker.total() - 0.09).abs() < 1e-9);
+        assert_eq!(tracker.by_model()["dall-e-3"].requests, 1);
+    }
+
+    #[tokio::test]
+    async fn test_path_prefix_routes_requests_through_gateway() {
+        use httpmock::prelude::*;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        let embeddings = server
+            .mock_async(|when, then| {
+                when.method(POST).path("/openai/v1/embeddings");
+                then.status(200).json_body(json!({
+                    "object": "list",
+                    "data": [{"object": "embedding", "embedding": [0.5], "index": 0}],
+                    "model": "text-embedding-3-small",
+                    "usage": {"prompt_tokens": 1, "total_tokens": 1}
+                }));
+            })
+            .await;
+
+        let config = ClientConfig::new_with_base_url("test-key".to_string(), server.base_url())
+            .unwrap()
+            .with_path_prefix("/openai/v1");
+        let client = OpenAIClient::from_config(config).unwrap();
+
+        client
+            .create_embeddings(&EmbeddingRequest::new("text-embedding-3-small", "hi"))
+            .await
+            .unwrap();
+        embeddings.assert_async().await;
+    }
 }
@@ -0,0 +1,92 @@
+//! Text results that keep the finish reason and usage of a response
+//!
+//! The plain `generate_text`/`chat` helpers return only the text, which hides
+//! whether the model stopped on its own or was cut off. A [`ChatResult`]
+//! carries that information so callers can detect incomplete output.
+
+use crate::models::responses::FinishReason;
+use crate::models::responses_v2::{ResponseObject, ResponseUsage};
+
+/// Text generated by a convenience call together with how it ended
+#[derive(Debug, Clone, Default)]
+pub struct ChatResult {
+    /// The generated text
+    pub text: String,
+    /// Why generation stopped
+    pub finish_reason: Option<FinishReason>,
+    /// Token usage reported for the request
+    pub usage: Option<ResponseUsage>,
+    /// Number of messages dropped to fit the context window before retrying
+    pub trimmed_messages: usize,
+}
+
+impl ChatResult {
+    /// Check whether the output was cut off by the token limit
+    #[must_use]
+    pub fn was_truncated(&self) -> bool {
+        self.finish_reason
+            .as_ref()
+            .is_some_and(FinishReason::needs_continuation)
+    }
+
+    /// Check whether the output was stopped by the content filter
+    #[must_use]
+    pub fn was_filtered(&self) -> bool {
+        self.finish_reason == Some(FinishReason::ContentFilter)
+    }
+
+    /// Check whether older messages were dropped to fit the context window
+    #[must_use]
+    pub const fn was_trimmed(&self) -> bool {
+        self.trimmed_messages > 0
+    }
+
+    /// Check whether the output is neither truncated nor filtered
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        !self.was_truncated() && !self.was_filtered()
+    }
+}
+
+impl From<&ResponseObject> for ChatResult {
+    fn from(response: &ResponseObject) -> Self {
+        Self {
+            text: response.output_text(),
+            finish_reason: response.finish_reason().map(FinishReason::from),
+            usage: response.usage.clone(),
+            trimmed_messages: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_chat_result_predicates() {
+        let response: ResponseObject = serde_json::from_value(json!({
+            "id": "resp_1",
+            "object": "response",
+            "status": "incomplete",
+            "incomplete_details": {"reason": "content_filter"},
+            "output_text": "Partial",
+            "usage": {"input_tokens": 4, "output_tokens": 2, "total_tokens": 6}
+        }))
+        .unwrap();
+
+        let result = ChatResult::from(&response);
+        assert_eq!(result.text, "Partial");
+        assert!(result.was_filtered());
+        assert!(!result.was_truncated());
+        assert!(!result.is_complete());
+        assert_eq!(result.usage.unwrap().total_tokens, 6);
+
+        let truncated = ChatResult {
+            finish_reason: Some(FinishReason::Length),
+            ..ChatResult::default()
+        };
+        assert!(truncated.was_truncated());
+    }
+}
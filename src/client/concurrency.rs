@@ -0,0 +1,191 @@
+//! Running many requests with bounded concurrency
+//!
+//! [`ConcurrencyConfig`] limits how many requests are in flight at once and,
+//! optionally, how many start per minute. Each request is retried on rate
+//! limits, server errors and timeouts. Results come back in input order and
+//! every input gets its own result, so one failure does not discard the rest.
+
+use crate::error::{OpenAIError, Result};
+use crate::helpers::backoff::Backoff;
+use futures::StreamExt;
+use std::future::Future;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Limits and retry behaviour for running many requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcurrencyConfig {
+    /// Maximum number of requests in flight at once
+    pub max_concurrency: usize,
+    /// Maximum number of requests started per minute, if limited
+    pub requests_per_minute: Option<u32>,
+    /// Number of times a failed request is retried
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled with jitter for each further retry
+    pub retry_delay: Duration,
+}
+
+impl Default for ConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrency: 4,
+            requests_per_minute: None,
+            max_retries: 2,
+            retry_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl ConcurrencyConfig {
+    /// Create a configuration with the given concurrency and default retries
+    #[must_use]
+    pub fn new(max_concurrency: usize) -> Self {
+        Self {
+            max_concurrency,
+            ..Self::default()
+        }
+    }
+
+    /// Limit how many requests start per minute
+    #[must_use]
+    pub const fn with_requests_per_minute(mut self, requests_per_minute: u32) -> Self {
+        self.requests_per_minute = Some(requests_per_minute);
+        self
+    }
+
+    /// Set how many times a failed request is retried and the initial delay
+    #[must_use]
+    pub const fn with_retries(mut self, max_retries: u32, retry_delay: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Check whether a failed request is worth retrying
+    ///
+    /// Rate limits, server errors and request timeouts are transient; other
+    /// errors such as invalid requests would fail the same way again.
+    #[must_use]
+    pub fn should_retry(error: &OpenAIError) -> bool {
+        error.is_rate_limit()
+            || error.is_request_timeout()
+            || error.status_code().is_some_and(|status| status >= 500)
+    }
+
+    /// Run an operation for every input, returning results in input order
+    ///
+    /// The operation is called again for each retry, so it receives the input
+    /// by reference.
+    pub async fn run_all<I, T, F, Fut>(&self, inputs: Vec<I>, operation: F) -> Vec<Result<T>>
+    where
+        F: Fn(&I) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let limiter = self.requests_per_minute.map(RateLimiter::new);
+        let limiter = limiter.as_ref();
+        let operation = &operation;
+
+        futures::stream::iter(inputs)
+            .map(|input| async move {
+                let mut retries = 0;
+                let mut backoff = Backoff::new(self.retry_delay).with_jitter(0.2);
+                loop {
+                    if let Some(limiter) = limiter {
+                        limiter.acquire().await;
+                    }
+                    match operation(&input).await {
+                        Err(error) if retries < self.max_retries && Self::should_retry(&error) => {
+                            tokio::time::sleep(backoff.next().unwrap_or_default()).await;
+                            retries += 1;
+                        }
+                        result => return result,
+                    }
+                }
+            })
+            .buffered(self.max_concurrency.max(1))
+            .collect()
+            .await
+    }
+}
+
+/// Spaces request starts evenly to stay under a per-minute limit
+#[derive(Debug)]
+struct RateLimiter {
+    /// Minimum time between two request starts
+    interval: Duration,
+    /// Earliest time the next request may start
+    next_start: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `requests_per_minute` starts per minute
+    fn new(requests_per_minute: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(60) / requests_per_minute.max(1),
+            next_start: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait until the next request may start
+    async fn acquire(&self) {
+        let start = {
+            let mut next_start = self.next_start.lock().await;
+            let start = (*next_start).max(Instant::now());
+            *next_start = start + self.interval;
+            start
+        };
+        tokio::time::sleep_until(start).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_run_all_preserves_order_and_retries_transient_errors() {
+        let config = ConcurrencyConfig::new(3).with_retries(2, Duration::from_millis(1));
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let attempts = AtomicUsize::new(0);
+
+        let results = config
+            .run_all(vec![30u64, 1, 10, 0, 5], |&input| {
+                let in_flight = &in_flight;
+                let peak = &peak;
+                let attempts = &attempts;
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(input)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    match input {
+                        0 => {
+                            attempts.fetch_add(1, Ordering::SeqCst);
+                            Err(OpenAIError::api_error(503, "overloaded"))
+                        }
+                        5 => Err(OpenAIError::invalid_request("bad input")),
+                        _ => Ok(input * 2),
+                    }
+                }
+            })
+            .await;
+
+        let values: Vec<_> = results.iter().map(|r| r.as_ref().ok().copied()).collect();
+        assert_eq!(values, [Some(60), Some(2), Some(20), None, None]);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_spaces_request_starts() {
+        let config = ConcurrencyConfig::new(10).with_requests_per_minute(1200);
+        let started = Instant::now();
+        let results = config.run_all(vec![(); 4], |()| async { Ok(()) }).await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert!(started.elapsed() >= Duration::from_millis(150));
+    }
+}
@@ -0,0 +1,182 @@
+//! Graceful degradation across an ordered list of models
+//!
+//! A [`ModelFallbackChain`] runs a request against its first model and moves
+//! on to the next one when the API reports a rate limit or an unavailable
+//! model. Any other error is returned immediately, since retrying it against a
+//! different model would not help.
+
+use crate::error::{OpenAIError, Result};
+use std::future::Future;
+
+/// Ordered list of models to try, from most to least preferred
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelFallbackChain {
+    /// Models in the order they are tried
+    models: Vec<String>,
+}
+
+/// A model that was skipped while running a fallback chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedModel {
+    /// The model that failed
+    pub model: String,
+    /// Description of the error that caused the fallback
+    pub error: String,
+}
+
+/// Result of a request served by a fallback chain
+#[derive(Debug, Clone)]
+pub struct FallbackOutcome<T> {
+    /// The model that served the request
+    pub model: String,
+    /// The value returned for that model
+    pub value: T,
+    /// Models tried before `model`, in order
+    pub skipped: Vec<SkippedModel>,
+}
+
+impl<T> FallbackOutcome<T> {
+    /// Check whether the preferred model served the request
+    #[must_use]
+    pub fn used_primary(&self) -> bool {
+        self.skipped.is_empty()
+    }
+}
+
+impl ModelFallbackChain {
+    /// Create a chain from models in order of preference
+    ///
+    /// Duplicate and blank model names are dropped. Returns an error if no
+    /// models remain.
+    pub fn new<I, S>(models: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut unique: Vec<String> = Vec::new();
+        for model in models {
+            let model = model.into().trim().to_string();
+            if !model.is_empty() && !unique.contains(&model) {
+                unique.push(model);
+            }
+        }
+        if unique.is_empty() {
+            return Err(OpenAIError::invalid_request(
+                "Model fallback chain requires at least one model",
+            ));
+        }
+        Ok(Self { models: unique })
+    }
+
+    /// Get the models in the order they are tried
+    #[must_use]
+    pub fn models(&self) -> &[String] {
+        &self.models
+    }
+
+    /// Check whether an error should move the chain on to the next model
+    #[must_use]
+    pub fn should_fall_back(error: &OpenAIError) -> bool {
+        error.is_rate_limit() || error.is_model_unavailable()
+    }
+
+    /// Run an operation against each model in turn until one succeeds
+    ///
+    /// The operation receives the model name to use. Errors that do not
+    /// warrant a fallback, and the error from the last model, are returned
+    /// unchanged.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> Result<FallbackOutcome<T>>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut skipped = Vec::new();
+        let last = self.models.len() - 1;
+
+        for (index, model) in self.models.iter().enumerate() {
+            match operation(model.clone()).await {
+                Ok(value) => {
+                    return Ok(FallbackOutcome {
+                        model: model.clone(),
+                        value,
+                        skipped,
+                    });
+                }
+                Err(error) if index < last && Self::should_fall_back(&error) => {
+                    skipped.push(SkippedModel {
+                        model: model.clone(),
+                        error: error.to_string(),
+                    });
+                }
+                Err(error) => return Err(error),
+            }
+        }
+
+        unreachable!("fallback chain always contains at least one model")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_new_rejects_empty_chain() {
+        assert!(ModelFallbackChain::new(Vec::<String>::new()).is_err());
+        assert!(ModelFallbackChain::new([" ", ""]).is_err());
+
+        let chain = ModelFallbackChain::new(["gpt-4o", "gpt-4o", "gpt-4o-mini"]).unwrap();
+        assert_eq!(chain.models(), ["gpt-4o", "gpt-4o-mini"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_falls_back_on_capacity_errors_only() {
+        let chain = ModelFallbackChain::new(["premium", "overloaded", "cheap"]).unwrap();
+        let calls = Mutex::new(Vec::new());
+
+        let outcome = chain
+            .run(|model| {
+                calls.lock().unwrap().push(model.clone());
+                async move {
+                    match model.as_str() {
+                        "premium" => Err(OpenAIError::api_error(429, "Rate limit reached")),
+                        "overloaded" => Err(OpenAIError::api_error(503, "Service unavailable")),
+                        _ => Ok(format!("served by {model}")),
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.model, "cheap");
+        assert_eq!(outcome.value, "served by cheap");
+        assert!(!outcome.used_primary());
+        assert_eq!(outcome.skipped.len(), 2);
+        assert_eq!(outcome.skipped[0].model, "premium");
+        assert_eq!(*calls.lock().unwrap(), ["premium", "overloaded", "cheap"]);
+
+        let err = chain
+            .run(|_| async { Err::<(), _>(OpenAIError::api_error(401, "Invalid API key")) })
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), Some(401));
+    }
+
+    #[tokio::test]
+    async fn test_run_returns_last_error_when_all_models_fail() {
+        let chain = ModelFallbackChain::new(["a", "b"]).unwrap();
+        let err = chain
+            .run(|model| async move {
+                Err::<(), _>(OpenAIError::api_error(
+                    404,
+                    format!("The model `{model}` does not exist"),
+                ))
+            })
+            .await
+            .unwrap_err();
+
+        assert!(err.is_model_unavailable());
+        assert!(err.to_string().contains("`b`"));
+    }
+}
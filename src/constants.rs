@@ -295,6 +295,18 @@ pub mod endpoints {
             format!("{BASE}/{id}/cancel")
         }
 
+        /// Pause fine-tuning job
+        #[must_use]
+        pub fn pause(id: &str) -> String {
+            format!("{BASE}/{id}/pause")
+        }
+
+        /// Resume paused fine-tuning job
+        #[must_use]
+        pub fn resume(id: &str) -> String {
+            format!("{BASE}/{id}/resume")
+        }
+
         /// Fine-tuning job events endpoint
         #[must_use]
         pub fn events(id: &str) -> String {
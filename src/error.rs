@@ -4,6 +4,7 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum OpenAIError {
     /// HTTP request failed
+    #[cfg(feature = "net")]
     #[error("HTTP request failed: {0}")]
     Request(#[from] reqwest::Error),
 
@@ -20,6 +21,20 @@ pub enum OpenAIError {
         message: String,
     },
 
+    /// API returned an error response carrying an error code
+    ///
+    /// Same as [`OpenAIError::Api`] for responses whose body includes a
+    /// machine-readable `code`; see [`OpenAIError::api_code`].
+    #[error("API returned an error: {status_code} - {message} ({code})")]
+    CodedApi {
+        /// HTTP status code from the API
+        status_code: u16,
+        /// Error message from the API
+        message: String,
+        /// Error code for programmatic handling, such as `context_length_exceeded`
+        code: String,
+    },
+
     /// Authentication failed (invalid API key, etc.)
     #[error("Authentication failed: {0}")]
     Authentication(String),
@@ -36,6 +51,15 @@ pub enum OpenAIError {
     #[error("Timeout occurred: {0}")]
     Timeout(String),
 
+    /// A multi-step operation did not finish within its overall deadline
+    #[error("Deadline of {budget:?} exceeded while {operation}")]
+    DeadlineExceeded {
+        /// What was in progress when the deadline passed
+        operation: String,
+        /// The total time budget of the operation
+        budget: std::time::Duration,
+    },
+
     /// File operation failed
     #[error("File error: {0}")]
     FileError(String),
@@ -48,6 +72,10 @@ pub enum OpenAIError {
     #[error("Parse error: {0}")]
     ParseError(String),
 
+    /// The model declined to produce the requested output
+    #[error("Model refused the request: {0}")]
+    Refusal(String),
+
     /// API returned an error with status and message
     #[error("API error: {status} - {message}")]
     ApiError {
@@ -93,10 +121,20 @@ impl From<String> for OpenAIError {
 impl OpenAIError {
     /// Create an API error from a response
     #[must_use]
+    ///
+    /// Responses with an error `code` become [`OpenAIError::CodedApi`].
     pub fn from_api_response(status_code: u16, error_response: ApiErrorResponse) -> Self {
-        Self::Api {
-            status_code,
-            message: error_response.error.message,
+        let message = error_response.error.message;
+        match error_response.error.code {
+            Some(code) => Self::CodedApi {
+                status_code,
+                message,
+                code,
+            },
+            None => Self::Api {
+                status_code,
+                message,
+            },
         }
     }
 
@@ -137,4 +175,94 @@ impl OpenAIError {
             message: message.into(),
         }
     }
+
+    /// Get the HTTP status code for errors returned by the API
+    #[must_use]
+    pub const fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Api { status_code, .. } | Self::CodedApi { status_code, .. } => {
+                Some(*status_code)
+            }
+            Self::ApiError { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Get the message of errors returned by the API
+    fn api_message(&self) -> Option<&str> {
+        match self {
+            Self::Api { message, .. }
+            | Self::CodedApi { message, .. }
+            | Self::ApiError { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Get the machine-readable code of an API error, such as `context_length_exceeded`
+    #[must_use]
+    pub fn api_code(&self) -> Option<&str> {
+        match self {
+            Self::CodedApi { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// Check whether a multi-step operation ran past its overall deadline
+    #[must_use]
+    pub const fn is_deadline_exceeded(&self) -> bool {
+        matches!(self, Self::DeadlineExceeded { .. })
+    }
+
+    /// Check whether a single request timed out
+    ///
+    /// Unlike [`is_deadline_exceeded`](Self::is_deadline_exceeded), this is
+    /// the per-request timeout of the HTTP client.
+    #[must_use]
+    pub fn is_request_timeout(&self) -> bool {
+        match self {
+            Self::Timeout(_) => true,
+            #[cfg(feature = "net")]
+            Self::Request(error) => error.is_timeout(),
+            _ => false,
+        }
+    }
+
+    /// Check whether the request was too long for the model's context window
+    ///
+    /// The API's `context_length_exceeded` error code is authoritative; the
+    /// message is only consulted for errors that arrive without a code.
+    #[must_use]
+    pub fn is_context_length_exceeded(&self) -> bool {
+        if let Some(code) = self.api_code() {
+            return code == "context_length_exceeded";
+        }
+        self.api_message().is_some_and(|message| {
+            message.contains("context_length_exceeded")
+                || message
+                    .to_ascii_lowercase()
+                    .contains("maximum context length")
+        })
+    }
+
+    /// Check whether the API rejected the request because of rate limiting
+    #[must_use]
+    pub fn is_rate_limit(&self) -> bool {
+        self.status_code() == Some(429)
+    }
+
+    /// Check whether the requested model is overloaded, unavailable or unknown
+    #[must_use]
+    pub fn is_model_unavailable(&self) -> bool {
+        match self.status_code() {
+            Some(503 | 529) => true,
+            Some(400 | 404) => self.api_message().is_some_and(|message| {
+                let message = message.to_ascii_lowercase();
+                message.contains("model")
+                    && ["does not exist", "not found", "not available", "overloaded"]
+                        .iter()
+                        .any(|phrase| message.contains(phrase))
+            }),
+            _ => false,
+        }
+    }
 }
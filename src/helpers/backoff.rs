@@ -0,0 +1,184 @@
+//! Exponential backoff with jitter
+//!
+//! [`Backoff`] is the delay schedule used when retrying requests in
+//! `chat_many` and between polls of batches, vector stores, containers and
+//! fine-tuning jobs. It is public so applications can pace their own loops
+//! the same way.
+
+use std::time::Duration;
+
+/// Iterator of delays that grow exponentially up to a maximum
+///
+/// Each delay is `base * factor^attempt`, capped at `max`, then reduced by a
+/// random amount of up to `jitter` of itself so that many clients retrying at
+/// once spread out. The iterator never ends; combine it with `take` to limit
+/// the number of attempts.
+///
+/// # Example
+///
+/// ```
+/// use openai_rust_sdk::helpers::backoff::Backoff;
+/// use std::time::Duration;
+///
+/// let delays: Vec<Duration> = Backoff::new(Duration::from_millis(100))
+///     .with_max(Duration::from_millis(300))
+///     .take(4)
+///     .collect();
+/// assert_eq!(delays, [100, 200, 300, 300].map(Duration::from_millis));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backoff {
+    /// Delay before the first retry
+    base: Duration,
+    /// Upper bound for any delay
+    max: Duration,
+    /// Multiplier applied for each attempt
+    factor: f64,
+    /// Fraction (0.0-1.0) of each delay that may be removed at random
+    jitter: f64,
+    /// Number of delays yielded so far
+    attempt: u32,
+}
+
+impl Backoff {
+    /// Create a schedule doubling from `base`, capped at 60 seconds, without jitter
+    #[must_use]
+    pub const fn new(base: Duration) -> Self {
+        Self {
+            base,
+            max: Duration::from_secs(60),
+            factor: 2.0,
+            jitter: 0.0,
+            attempt: 0,
+        }
+    }
+
+    /// Create a constant schedule for polling, jittered by up to 10%
+    ///
+    /// The jitter keeps many waiters from polling in lockstep. Intervals
+    /// longer than the default 60 second cap raise the cap to the interval.
+    #[must_use]
+    pub const fn polling(interval: Duration) -> Self {
+        let max = if interval.as_secs() >= 60 {
+            interval
+        } else {
+            Duration::from_secs(60)
+        };
+        Self::new(interval)
+            .with_max(max)
+            .with_factor(1.0)
+            .with_jitter(0.1)
+    }
+
+    /// Set the upper bound for any delay
+    #[must_use]
+    pub const fn with_max(mut self, max: Duration) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the multiplier applied for each attempt; `1.0` keeps delays constant
+    #[must_use]
+    pub const fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Set the fraction of each delay that may be removed at random
+    ///
+    /// Values are clamped to 0.0-1.0; `1.0` gives "full jitter". `NaN`
+    /// disables jitter.
+    #[must_use]
+    pub const fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = if jitter.is_nan() {
+            0.0
+        } else {
+            jitter.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Get the number of delays yielded so far
+    #[must_use]
+    pub const fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Start the schedule over from `base`
+    pub const fn reset(&mut self) {
+        self.attempt = 0;
+    }
+
+    /// Get the delay for an attempt before jitter is applied
+    #[must_use]
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt).unwrap_or(i32::MAX);
+        let seconds = self.base.as_secs_f64() * self.factor.max(1.0).powi(exponent);
+        if seconds.is_finite() && seconds < self.max.as_secs_f64() {
+            Duration::from_secs_f64(seconds)
+        } else {
+            self.max
+        }
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let delay = self.delay_for(self.attempt);
+        self.attempt = self.attempt.saturating_add(1);
+        if self.jitter == 0.0 {
+            return Some(delay);
+        }
+        Some(delay.mul_f64(1.0 - self.jitter * rand::random::<f64>()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delays_grow_and_cap() {
+        let mut backoff = Backoff::new(Duration::from_millis(10))
+            .with_factor(3.0)
+            .with_max(Duration::from_millis(100));
+        let delays: Vec<_> = backoff.by_ref().take(4).collect();
+        assert_eq!(delays, [10, 30, 90, 100].map(Duration::from_millis));
+        assert_eq!(backoff.attempt(), 4);
+        assert_eq!(backoff.delay_for(u32::MAX), Duration::from_millis(100));
+
+        backoff.reset();
+        assert_eq!(backoff.next(), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let backoff = Backoff::new(Duration::from_millis(100))
+            .with_factor(1.0)
+            .with_jitter(0.5);
+        for delay in backoff.take(100) {
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+
+        let mut backoff = Backoff::new(Duration::from_millis(100)).with_jitter(f64::NAN);
+        assert_eq!(backoff.next(), Some(Duration::from_millis(100)));
+        let mut backoff = Backoff::new(Duration::from_millis(100)).with_jitter(f64::INFINITY);
+        assert!(backoff.next().unwrap() <= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn test_polling_keeps_long_intervals() {
+        let interval = Duration::from_secs(300);
+        let backoff = Backoff::polling(interval);
+        assert_eq!(backoff.delay_for(5), interval);
+        for delay in backoff.take(20) {
+            assert!(delay >= interval.mul_f64(0.9) && delay <= interval);
+        }
+        assert_eq!(
+            Backoff::polling(Duration::from_secs(5)).delay_for(3),
+            Duration::from_secs(5)
+        );
+    }
+}
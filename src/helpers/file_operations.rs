@@ -6,6 +6,7 @@
 
 use crate::error::{OpenAIError, Result};
 use std::path::Path;
+#[cfg(feature = "net")]
 use tokio::fs;
 
 /// Internal helper to create consistent file error messages
@@ -23,6 +24,7 @@ fn create_file_error<P: AsRef<Path>>(
 }
 
 /// Macro to generate async read functions with consistent error handling
+#[cfg(feature = "net")]
 macro_rules! impl_async_read {
     ($func_name:ident, $fs_op:ident, $operation:literal, $return_type:ty) => {
         #[doc = concat!("Read a file asynchronously, returning `", stringify!($return_type), "`.")]
@@ -39,6 +41,7 @@ macro_rules! impl_async_read {
 }
 
 /// Macro to generate async write functions with consistent error handling
+#[cfg(feature = "net")]
 macro_rules! impl_async_write {
     ($func_name:ident, $fs_op:ident, $operation:literal, $param_type:ty) => {
         #[doc = concat!("Write data of type `", stringify!($param_type), "` to a file asynchronously.")]
@@ -83,8 +86,11 @@ macro_rules! impl_sync_write {
 }
 
 // Generate async file operation functions using macros to reduce duplication
+#[cfg(feature = "net")]
 impl_async_read!(read_bytes, read, "read", Vec<u8>);
+#[cfg(feature = "net")]
 impl_async_read!(read_string, read_to_string, "read", String);
+#[cfg(feature = "net")]
 impl_async_write!(write_bytes, write, "write", &[u8]);
 
 // Generate sync file operation functions using macros to reduce duplication
@@ -96,6 +102,7 @@ impl_sync_write!(write_bytes_sync, write, "write", &[u8]);
 ///
 /// Maps I/O errors to `OpenAIError::FileError` with a descriptive message
 /// that includes the file path.
+#[cfg(feature = "net")]
 pub async fn write_string<P: AsRef<Path>, S: AsRef<str>>(file_path: P, content: S) -> Result<()> {
     let path = file_path.as_ref();
     let content = content.as_ref();
@@ -4,10 +4,12 @@
 //! used across the codebase to eliminate duplicate patterns and improve
 //! code maintainability.
 
+#[cfg(feature = "net")]
+pub mod backoff;
 pub mod file_operations;
+pub mod sorted_map;
 
 // Re-export commonly used functions for convenience
-pub use file_operations::{
-    read_bytes, read_bytes_sync, read_string, read_string_sync, write_bytes, write_bytes_sync,
-    write_string, write_string_sync,
-};
+#[cfg(feature = "net")]
+pub use file_operations::{read_bytes, read_string, write_bytes, write_string};
+pub use file_operations::{read_bytes_sync, read_string_sync, write_bytes_sync, write_string_sync};
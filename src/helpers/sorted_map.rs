@@ -0,0 +1,103 @@
+//! Serialize maps with their keys in sorted order
+//!
+//! `HashMap` iteration order changes between runs, so request bodies that
+//! contain one would not serialize to the same bytes twice. Fields use these
+//! functions through `#[serde(serialize_with = "...")]` to keep the output
+//! stable, which makes body hashes usable as cache keys.
+
+use crate::models::field_update::FieldUpdate;
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+/// Serialize a map with its keys in ascending order
+pub fn serialize<K, V, H, S>(map: &HashMap<K, V, H>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    H: BuildHasher,
+    S: Serializer,
+{
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// Serialize an optional map with its keys in ascending order
+pub fn serialize_option<K, V, H, S>(
+    map: &Option<HashMap<K, V, H>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    H: BuildHasher,
+    S: Serializer,
+{
+    map.as_ref()
+        .map(|map| map.iter().collect::<BTreeMap<_, _>>())
+        .serialize(serializer)
+}
+
+/// Serialize a map update with its keys in ascending order
+///
+/// Pair it with `skip_serializing_if = "FieldUpdate::is_unchanged"`;
+/// [`FieldUpdate::Clear`] serializes as `null`.
+pub fn serialize_update<K, V, H, S>(
+    update: &FieldUpdate<HashMap<K, V, H>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    H: BuildHasher,
+    S: Serializer,
+{
+    match update {
+        FieldUpdate::Set(map) => serialize(map, serializer),
+        FieldUpdate::Unchanged | FieldUpdate::Clear => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Body {
+        #[serde(serialize_with = "serialize")]
+        metadata: HashMap<String, String>,
+        #[serde(serialize_with = "serialize_option")]
+        headers: Option<HashMap<String, String>>,
+    }
+
+    fn body(keys: &[&str]) -> Body {
+        let map: HashMap<String, String> = keys
+            .iter()
+            .map(|key| ((*key).to_string(), key.to_uppercase()))
+            .collect();
+        Body {
+            metadata: map.clone(),
+            headers: Some(map),
+        }
+    }
+
+    #[test]
+    fn test_keys_are_sorted() {
+        let json = serde_json::to_string(&body(&["zeta", "alpha", "mid"])).unwrap();
+        assert_eq!(
+            json,
+            r#"{"metadata":{"alpha":"ALPHA","mid":"MID","zeta":"ZETA"},"headers":{"alpha":"ALPHA","mid":"MID","zeta":"ZETA"}}"#
+        );
+    }
+
+    #[test]
+    fn test_none_serializes_as_null() {
+        let body = Body {
+            metadata: HashMap::new(),
+            headers: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&body).unwrap(),
+            r#"{"metadata":{},"headers":null}"#
+        );
+    }
+}
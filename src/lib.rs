@@ -122,6 +122,15 @@
 //! - [`testing`]: YARA validation and testing functionality
 //! - [`error`]: Error types and handling
 //!
+//! ## Cargo Features
+//!
+//! - `net` (default): [`client`], [`api`] and the async file helpers, built on
+//!   `reqwest` and `tokio`. Disable default features to use only the
+//!   [`models`], [`schema`], [`prompt_engineering`] and [`builders`] types, for
+//!   example from a `wasm32-unknown-unknown` frontend. `jsonschema` pulls in
+//!   `getrandom`, so wasm builds need `--cfg getrandom_backend="wasm_js"`.
+//! - `yara`: YARA rule validation and scanning via yara-x
+//!
 //! ## Examples
 //!
 //! See the `examples/` directory for complete usage examples:
@@ -142,10 +151,12 @@ pub mod macros;
 pub use macros::{De, Ser};
 
 /// `OpenAI` API functionality
+#[cfg(feature = "net")]
 pub mod api;
 /// Function and schema builders
 pub mod builders;
 /// Client implementations and builders
+#[cfg(feature = "net")]
 pub mod client;
 /// Constants used throughout the SDK
 pub mod constants;
@@ -163,21 +174,29 @@ pub mod schema;
 pub mod testing;
 
 // Re-export main OpenAI API types for convenience
+#[cfg(feature = "net")]
 pub use api::batch::BatchApi;
+#[cfg(feature = "net")]
 pub use api::streaming::StreamingApi;
+#[cfg(feature = "net")]
 pub use api::{
     AdminApi, AssistantsApi, ConversationsApi, CustomToolsApi, EvalsApi, FineTuningApi,
     FunctionsApi, RealtimeAudioApi, ResponsesApi, RunsApi, SkillsApi, ThreadsApi, UploadsApi,
     VectorStoresApi, VideosApi,
 };
 pub use builders::{FunctionBuilder, ObjectSchemaBuilder};
-pub use client::{ChatBuilder, OpenAIClient, from_env, from_env_with_base_url};
+#[cfg(feature = "net")]
+pub use client::{
+    BestChoice, ChatBuilder, ChatResult, FallbackOutcome, ModelFallbackChain, OpenAIClient,
+    SelectionHeuristic, SkippedModel, from_env, from_env_with_base_url,
+    from_env_with_base_url_and_prefix, select_best, select_best_by,
+};
 pub use error::{OpenAIError, Result};
 pub use models::{assistants::*, functions::*, responses::*};
 pub use prompt_engineering::{
     Example, PromptBuilder, PromptPatterns, PromptTemplateBuilder, XmlContentBuilder,
 };
-pub use schema::{EnhancedSchemaBuilder, JsonSchema, SchemaBuilder};
+pub use schema::{EnhancedSchemaBuilder, JsonSchema, SchemaBuilder, StructuredOutput};
 
 // Re-export testing functionality
 pub use testing::batch_generator::BatchJobGenerator;
@@ -186,5 +205,6 @@ pub use testing::batch_generator::BatchJobGenerator;
 #[cfg(feature = "yara")]
 pub use testing::{
     test_cases::YaraTestCases,
+    yara_scanner::{FileScanResult, ScanOptions, YaraScanner},
     yara_validator::{ValidationError, ValidationResult, YaraValidator},
 };
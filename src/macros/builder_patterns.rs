@@ -76,6 +76,13 @@ macro_rules! impl_map_setters {
 /// and returns a `Result<T, String>`. It supports both required fields validation and
 /// optional final validation on the constructed object.
 ///
+/// A `build_validated()` method is generated alongside it. Instead of stopping
+/// at the first problem it reports every missing required field, and with
+/// `validate: true` every message from [`Validate::validate_all`], as a
+/// `Vec<ValidationError>`.
+///
+/// [`Validate::validate_all`]: crate::models::shared_traits::Validate::validate_all
+///
 /// # Basic usage with required fields only:
 /// ```rust,ignore
 /// impl_builder_build! {
@@ -104,22 +111,8 @@ macro_rules! impl_builder_build {
         optional: [$( $opt_field:ident ),* $(,)?],
         validate: true
     }) => {
-        impl $builder {
-            /// Build the request
-            pub fn build(self) -> std::result::Result<$target, String> {
-                $(
-                    let $req_field = self.$req_field.ok_or($req_msg)?;
-                )*
-
-                let request = $target {
-                    $( $req_field, )*
-                    $( $opt_field: self.$opt_field, )*
-                };
-
-                request.validate()?;
-                Ok(request)
-            }
-        }
+        $crate::impl_builder_build!(@impl $builder => $target,
+            [$( $req_field: $req_msg ),*], [$( $opt_field ),*], validate);
     };
 
     // Pattern for builders with required fields and optional fields (no post-validation)
@@ -127,6 +120,24 @@ macro_rules! impl_builder_build {
         required: [$( $req_field:ident: $req_msg:literal ),* $(,)?],
         optional: [$( $opt_field:ident ),* $(,)?]
     }) => {
+        $crate::impl_builder_build!(@impl $builder => $target,
+            [$( $req_field: $req_msg ),*], [$( $opt_field ),*]);
+    };
+
+    // Pattern for builders with only required fields (no optional fields or validation)
+    ($builder:ident => $target:ident {
+        required: [$( $req_field:ident: $req_msg:literal ),* $(,)?]
+    }) => {
+        $crate::impl_builder_build!(@impl $builder => $target,
+            [$( $req_field: $req_msg ),*], []);
+    };
+
+    // Shared implementation; the trailing `validate` token turns on post-construction validation
+    (@impl $builder:ident => $target:ident,
+        [$( $req_field:ident: $req_msg:literal ),*],
+        [$( $opt_field:ident ),*]
+        $(, $validate:ident)?
+    ) => {
         impl $builder {
             /// Build the request
             pub fn build(self) -> std::result::Result<$target, String> {
@@ -134,28 +145,45 @@ macro_rules! impl_builder_build {
                     let $req_field = self.$req_field.ok_or($req_msg)?;
                 )*
 
-                Ok($target {
+                let request = $target {
                     $( $req_field, )*
                     $( $opt_field: self.$opt_field, )*
-                })
+                };
+
+                $(
+                    let _ = stringify!($validate);
+                    request.validate()?;
+                )?
+                Ok(request)
             }
-        }
-    };
 
-    // Pattern for builders with only required fields (no optional fields or validation)
-    ($builder:ident => $target:ident {
-        required: [$( $req_field:ident: $req_msg:literal ),* $(,)?]
-    }) => {
-        impl $builder {
-            /// Build the request
-            pub fn build(self) -> std::result::Result<$target, String> {
+            /// Build the request, reporting every problem instead of only the first
+            pub fn build_validated(
+                self,
+            ) -> std::result::Result<$target, Vec<$crate::models::shared_traits::ValidationError>> {
+                let mut errors = Vec::new();
                 $(
-                    let $req_field = self.$req_field.ok_or($req_msg)?;
+                    if self.$req_field.is_none() {
+                        errors.push($crate::models::shared_traits::ValidationError::with_field(
+                            $req_msg,
+                            stringify!($req_field),
+                        ));
+                    }
                 )*
+                let ($( Some($req_field), )*) = ($( self.$req_field, )*) else {
+                    return Err(errors);
+                };
 
-                Ok($target {
+                let request = $target {
                     $( $req_field, )*
-                })
+                    $( $opt_field: self.$opt_field, )*
+                };
+
+                $(
+                    let _ = stringify!($validate);
+                    $crate::models::shared_traits::validation_errors(&request)?;
+                )?
+                Ok(request)
             }
         }
     };
@@ -4,7 +4,7 @@
 #[macro_export]
 macro_rules! impl_list_query_params {
     ($struct_name:ident, $order_type:ty) => {
-        impl $crate::api::common::ListQueryParams for $struct_name {
+        impl $crate::models::shared_traits::ListQueryParams for $struct_name {
             fn limit(&self) -> Option<u32> {
                 self.limit
             }
@@ -16,7 +16,7 @@ pub mod utilities;
 // Re-export commonly used items
 pub use utilities::{De, Ser};
 
-#[cfg(test)]
+#[cfg(all(test, feature = "net"))]
 mod tests {
     use super::*;
     use crate::api::base::HttpClient;
@@ -369,6 +369,12 @@ mod tests {
             }
         }
 
+        impl crate::models::shared_traits::Validate for TestRequest {
+            fn validate(&self) -> std::result::Result<(), String> {
+                self.validate()
+            }
+        }
+
         #[derive(Debug, Default)]
         struct TestRequestBuilder {
             required_field: Option<String>,
@@ -512,6 +518,31 @@ mod tests {
             );
         }
 
+        #[test]
+        fn test_builder_macro_build_validated_reports_all_errors() {
+            let errors = SimpleRequestBuilder::new().build_validated().unwrap_err();
+            let fields: Vec<_> = errors.iter().filter_map(|e| e.field.as_deref()).collect();
+            assert_eq!(fields, ["field1", "field2"]);
+            assert_eq!(errors[1].message, "field2 is required");
+
+            let errors = TestRequestBuilder::new()
+                .required_field("")
+                .build_validated()
+                .unwrap_err();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].message, "Required field cannot be empty");
+
+            let request = MinimalRequestBuilder::new()
+                .name("test_name")
+                .build_validated()
+                .expect("Build should succeed");
+            assert_eq!(request.name, "test_name");
+            assert_eq!(
+                MinimalRequestBuilder::new().build_validated().unwrap_err()[0].to_string(),
+                "Builder error in field 'name': name is required"
+            );
+        }
+
         #[test]
         fn test_builder_macro_without_validation() {
             // Test successful build
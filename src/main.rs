@@ -3,25 +3,58 @@
 mod testing;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 #[cfg(feature = "yara")]
 use std::fs;
+#[cfg(feature = "yara")]
+use std::io::IsTerminal;
+#[cfg(feature = "yara")]
+use std::path::Path;
 use std::path::PathBuf;
 #[cfg(feature = "yara")]
 use testing::BatchJobGenerator;
 
 #[cfg(feature = "yara")]
-use testing::{YaraTestCases, YaraValidator};
+use openai_rust_sdk::api::batch::{Batch, BatchApi};
+#[cfg(feature = "yara")]
+use openai_rust_sdk::api::common::ApiClientConstructors;
+#[cfg(feature = "yara")]
+use testing::{ScanOptions, YaraScanner, YaraTestCases, YaraValidator};
 
 #[derive(Parser)]
 #[command(name = "openai_rust_sdk")]
 #[command(version)]
 #[command(about = "YARA rule validation testing")]
 struct Cli {
+    /// Output format for command results
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
     #[command(subcommand)]
     command: Commands,
 }
 
+/// Output format for command results
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Human-readable report, colorized when writing to a terminal
+    #[default]
+    Text,
+    /// Pretty-printed JSON
+    Json,
+}
+
+/// Scan outcome that makes the `scan` command exit with code 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum FailOn {
+    /// Exit with 1 when any file matches
+    #[default]
+    Match,
+    /// Exit with 1 when no file matches
+    NoMatch,
+    /// Always exit with 0 after a successful scan
+    Never,
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Validate a YARA rule
@@ -30,9 +63,25 @@ enum Commands {
         file: PathBuf,
         #[arg(short, long)]
         verbose: bool,
+        /// Output format for the validation result (overrides `--output`)
+        #[arg(long, value_enum)]
+        format: Option<OutputFormat>,
+    },
+    /// Validate every .yar/.yara rule file in a directory
+    ValidateDir {
+        /// Directory containing the rule files
+        #[arg(short, long)]
+        dir: PathBuf,
+        /// Descend into subdirectories
+        #[arg(short = 'R', long)]
+        recursive: bool,
     },
     /// Run test suite
-    RunTests,
+    RunTests {
+        /// Also write the results as a JUnit XML report to this path
+        #[arg(long)]
+        junit: Option<PathBuf>,
+    },
     /// Generate batch jobs
     GenerateBatch {
         #[arg(short, long)]
@@ -40,30 +89,73 @@ enum Commands {
         #[arg(short, long, default_value = "comprehensive")]
         suite: String,
     },
+    /// Scan files with YARA rules
+    Scan {
+        /// Rule file to compile (repeat for multiple files)
+        #[arg(short, long, required = true)]
+        rule: Vec<PathBuf>,
+        /// File or directory to scan
+        #[arg(short, long)]
+        target: PathBuf,
+        /// Descend into subdirectories of the target
+        #[arg(short = 'R', long)]
+        recursive: bool,
+        /// Only scan file names matching this glob (e.g. "*.exe")
+        #[arg(short, long)]
+        glob: Option<String>,
+        /// Scan outcome that produces exit code 1
+        #[arg(long, value_enum, default_value_t = FailOn::Match)]
+        fail_on: FailOn,
+    },
+    /// Show the status of a batch job (requires OPENAI_API_KEY)
+    BatchStatus {
+        /// Batch ID to inspect
+        #[arg(long)]
+        id: String,
+        /// Keep polling until the batch reaches a terminal state
+        #[arg(short, long)]
+        wait: bool,
+        /// Seconds between status polls when waiting
+        #[arg(long, default_value_t = 30)]
+        poll_interval: u64,
+    },
+    /// Download the results of a completed batch job (requires OPENAI_API_KEY)
+    BatchResults {
+        /// Batch ID whose results should be downloaded
+        #[arg(long)]
+        id: String,
+        /// Path of the JSONL file to write the results to
+        #[arg(long)]
+        out: PathBuf,
+    },
 }
 
 #[cfg(feature = "yara")]
-fn handle_validate_rule(file: &PathBuf, verbose: bool) -> Result<()> {
+fn handle_validate_rule(file: &PathBuf, verbose: bool, format: OutputFormat) -> Result<()> {
     let rule_content = openai_rust_sdk::helpers::read_string_sync(file)
         .map_err(|e| anyhow::anyhow!("Failed to read file: {e}"))?;
     let validator = YaraValidator::new();
 
     match validator.validate_rule(&rule_content) {
-        Ok(result) => {
-            println!("Valid: {}", result.is_valid);
-            if let Some(name) = &result.rule_name {
-                println!("Rule Name: {name}");
-            }
-            println!("Compilation Time: {}ms", result.metrics.compilation_time_ms);
-
-            if verbose {
-                println!("Features: {:?}", result.features);
+        Ok(result) => match format {
+            OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&result)?);
             }
+            OutputFormat::Text => {
+                println!(
+                    "{}",
+                    result.to_report_string(std::io::stdout().is_terminal())
+                );
 
-            if !result.errors.is_empty() {
-                println!("Errors: {:?}", result.errors);
+                if verbose && !result.pattern_tests.is_empty() {
+                    println!("Pattern Tests:");
+                    for test in &result.pattern_tests {
+                        let outcome = if test.matched { "matched" } else { "no match" };
+                        println!("  - {}: {outcome}", test.test_data);
+                    }
+                }
             }
-        }
+        },
         Err(e) => {
             eprintln!("Failed to validate: {e}");
             std::process::exit(1);
@@ -73,10 +165,57 @@ fn handle_validate_rule(file: &PathBuf, verbose: bool) -> Result<()> {
 }
 
 #[cfg(feature = "yara")]
-fn handle_run_tests() -> Result<()> {
+fn handle_validate_dir(dir: &Path, recursive: bool, output: OutputFormat) -> Result<()> {
+    let report = YaraValidator::new().validate_directory(dir, recursive)?;
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        OutputFormat::Text => {
+            for file in &report.files {
+                if let Some(error) = &file.error {
+                    println!("{}: error: {error}", file.path);
+                } else if file.passed() {
+                    println!("{}: ok", file.path);
+                } else if let Some(result) = &file.result {
+                    let errors: Vec<String> =
+                        result.errors.iter().map(ToString::to_string).collect();
+                    println!("{}: FAILED: {}", file.path, errors.join("; "));
+                }
+            }
+            if !report.slowest_rules.is_empty() {
+                println!("Slowest rules:");
+                for rule in &report.slowest_rules {
+                    println!("  {}µs {}", rule.compilation_time_us, rule.path);
+                }
+            }
+            println!(
+                "Validated {} files: {} passed, {} failed",
+                report.total_files, report.passed_files, report.failed_files
+            );
+        }
+    }
+
+    if !report.all_passed() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "yara")]
+fn handle_run_tests(junit: Option<&Path>, output: OutputFormat) -> Result<()> {
     let test_cases = YaraTestCases::new();
     let results = test_cases.run_all_tests()?;
 
+    if let Some(path) = junit {
+        fs::write(path, results.to_junit_xml())
+            .map_err(|e| anyhow::anyhow!("Failed to write JUnit report {}: {e}", path.display()))?;
+    }
+
+    if output == OutputFormat::Json {
+        println!("{}", serde_json::to_string_pretty(&results)?);
+        return Ok(());
+    }
+
     println!("Test Results:");
     println!("Total Tests: {}", results.total_tests);
     println!("Passed: {}", results.passed_tests);
@@ -86,15 +225,144 @@ fn handle_run_tests() -> Result<()> {
 }
 
 #[cfg(feature = "yara")]
-fn handle_generate_batch(output_dir: &PathBuf, suite: &str) -> Result<()> {
+fn handle_generate_batch(output_dir: &PathBuf, suite: &str, output: OutputFormat) -> Result<()> {
     fs::create_dir_all(output_dir)?;
 
     let generator = BatchJobGenerator::new(None);
     let output_file = output_dir.join(format!("{suite}_batch_jobs.jsonl"));
 
-    generator.generate_test_suite(&output_file, suite)?;
+    let summary = generator.generate_test_suite_with_summary(&output_file, suite)?;
 
-    println!("Generated batch jobs: {}", output_file.display());
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&summary)?),
+        OutputFormat::Text => println!(
+            "Generated {} batch jobs: {}",
+            summary.request_count, summary.output_file
+        ),
+    }
+    Ok(())
+}
+
+#[cfg(feature = "yara")]
+fn handle_scan(
+    rules: &[PathBuf],
+    target: &Path,
+    options: &ScanOptions,
+    fail_on: FailOn,
+    output: OutputFormat,
+) -> Result<()> {
+    let scanner = YaraScanner::from_files(rules)?;
+    let results = scanner.scan_path(target, options)?;
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&results)?),
+        OutputFormat::Text => {
+            for result in &results {
+                if let Some(error) = &result.error {
+                    println!("{}: error: {error}", result.path);
+                } else if result.is_match() {
+                    println!("{}: {}", result.path, result.matched_rules.join(", "));
+                } else {
+                    println!("{}: no matches", result.path);
+                }
+            }
+            let matched = results.iter().filter(|r| r.is_match()).count();
+            println!("Scanned {} files, {matched} matched", results.len());
+        }
+    }
+
+    let any_match = results.iter().any(|r| r.is_match());
+    let failed = match fail_on {
+        FailOn::Match => any_match,
+        FailOn::NoMatch => !any_match,
+        FailOn::Never => false,
+    };
+    if failed {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "yara")]
+fn batch_api_from_env() -> Result<BatchApi> {
+    let api_key = std::env::var("OPENAI_API_KEY")
+        .map_err(|_| anyhow::anyhow!("OPENAI_API_KEY environment variable not set"))?;
+    Ok(BatchApi::new(api_key)?)
+}
+
+#[cfg(feature = "yara")]
+fn print_batch_progress(batch: &Batch) {
+    let counts = &batch.request_counts;
+    println!(
+        "Batch {}: {} ({}/{} completed, {} failed)",
+        batch.id, batch.status, counts.completed, counts.total, counts.failed
+    );
+}
+
+#[cfg(feature = "yara")]
+async fn handle_batch_status(
+    id: &str,
+    wait: bool,
+    poll_interval: u64,
+    output: OutputFormat,
+) -> Result<()> {
+    let api = batch_api_from_env()?;
+    let mut batch = api.get_batch_status(id).await?;
+
+    while wait && !batch.status.is_terminal() {
+        if output == OutputFormat::Text {
+            print_batch_progress(&batch);
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(poll_interval)).await;
+        batch = api.get_batch_status(id).await?;
+    }
+
+    match output {
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&batch)?),
+        OutputFormat::Text => {
+            print_batch_progress(&batch);
+            if let Some(file_id) = &batch.output_file_id {
+                println!("Output File: {file_id}");
+            }
+            if let Some(file_id) = &batch.error_file_id {
+                println!("Error File: {file_id}");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "yara")]
+async fn handle_batch_results(id: &str, out: &Path, output: OutputFormat) -> Result<()> {
+    let api = batch_api_from_env()?;
+    let batch = api.get_batch_status(id).await?;
+
+    if batch.output_file_id.is_none() {
+        anyhow::bail!("Batch {id} has no results yet (status: {})", batch.status);
+    }
+
+    let result_count = api.download_batch_results(id, out).await?;
+    let counts = &batch.request_counts;
+
+    match output {
+        OutputFormat::Json => {
+            let summary = serde_json::json!({
+                "batch_id": batch.id,
+                "status": batch.status,
+                "output_file": out.display().to_string(),
+                "results_written": result_count,
+                "request_counts": counts,
+            });
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+        }
+        OutputFormat::Text => {
+            println!("Downloaded {result_count} results to {}", out.display());
+            println!(
+                "Summary: {}/{} completed, {} failed ({})",
+                counts.completed, counts.total, counts.failed, batch.status
+            );
+        }
+    }
     Ok(())
 }
 
@@ -117,14 +385,44 @@ async fn main() -> Result<()> {
         let cli = Cli::parse();
 
         match cli.command {
-            Commands::ValidateRule { file, verbose } => {
-                handle_validate_rule(&file, verbose)?;
+            Commands::ValidateRule {
+                file,
+                verbose,
+                format,
+            } => {
+                handle_validate_rule(&file, verbose, format.unwrap_or(cli.output))?;
+            }
+            Commands::ValidateDir { dir, recursive } => {
+                handle_validate_dir(&dir, recursive, cli.output)?;
             }
-            Commands::RunTests => {
-                handle_run_tests()?;
+            Commands::RunTests { junit } => {
+                handle_run_tests(junit.as_deref(), cli.output)?;
             }
             Commands::GenerateBatch { output_dir, suite } => {
-                handle_generate_batch(&output_dir, &suite)?;
+                handle_generate_batch(&output_dir, &suite, cli.output)?;
+            }
+            Commands::Scan {
+                rule,
+                target,
+                recursive,
+                glob,
+                fail_on,
+            } => {
+                let mut options = ScanOptions::new().with_recursive(recursive);
+                if let Some(glob) = glob {
+                    options = options.with_glob(glob);
+                }
+                handle_scan(&rule, &target, &options, fail_on, cli.output)?;
+            }
+            Commands::BatchStatus {
+                id,
+                wait,
+                poll_interval,
+            } => {
+                handle_batch_status(&id, wait, poll_interval, cli.output).await?;
+            }
+            Commands::BatchResults { id, out } => {
+                handle_batch_results(&id, &out, cli.output).await?;
             }
         }
     }
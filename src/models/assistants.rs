@@ -35,10 +35,13 @@
 //!     .build();
 //! ```
 
-use crate::api::base::Validate;
+use crate::models::field_update::FieldUpdate;
 use crate::models::functions::FunctionTool;
+use crate::models::responses::ResponseFormat;
+use crate::models::shared_traits::{Validate, collect_errors};
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
 /// Tools that can be used by an assistant
@@ -112,10 +115,15 @@ pub struct Assistant {
     #[serde(default)]
     pub file_ids: Vec<String>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, String>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(Assistant);
+
 crate::impl_default_object_type!(default_object_type, "assistant");
 
 /// Request to create or modify an assistant
@@ -139,8 +147,21 @@ pub struct AssistantRequest {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub file_ids: Vec<String>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "crate::helpers::sorted_map::serialize"
+    )]
     pub metadata: HashMap<String, String>,
+    /// The format the assistant's replies must follow
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+    /// Sampling temperature between 0 and 2
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Nucleus sampling probability mass between 0 and 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
 }
 
 impl AssistantRequest {
@@ -160,6 +181,9 @@ impl AssistantRequest {
             tools: Vec::new(),
             file_ids: Vec::new(),
             metadata: HashMap::new(),
+            response_format: None,
+            temperature: None,
+            top_p: None,
         }
     }
 
@@ -168,6 +192,8 @@ impl AssistantRequest {
         self.validate_text_fields()?;
         self.validate_collections()?;
         self.validate_metadata()?;
+        self.validate_sampling()?;
+        self.validate_response_format()?;
         Ok(())
     }
 
@@ -259,6 +285,40 @@ impl AssistantRequest {
         }
         Ok(())
     }
+
+    /// Validate temperature and top_p ranges
+    fn validate_sampling(&self) -> Result<(), String> {
+        self.validate_temperature()?;
+        self.validate_top_p()?;
+        Ok(())
+    }
+
+    /// Validate temperature range
+    fn validate_temperature(&self) -> Result<(), String> {
+        if let Some(temperature) = self.temperature
+            && !(0.0..=2.0).contains(&temperature)
+        {
+            return Err("Assistant temperature must be between 0 and 2".to_string());
+        }
+        Ok(())
+    }
+
+    /// Validate top_p range
+    fn validate_top_p(&self) -> Result<(), String> {
+        if let Some(top_p) = self.top_p
+            && !(0.0..=1.0).contains(&top_p)
+        {
+            return Err("Assistant top_p must be between 0 and 1".to_string());
+        }
+        Ok(())
+    }
+
+    /// Validate that a strict JSON schema response format is enforceable
+    fn validate_response_format(&self) -> Result<(), String> {
+        self.response_format
+            .as_ref()
+            .map_or(Ok(()), ResponseFormat::check_strict_compatible)
+    }
 }
 
 /// Implementation of Validate trait for AssistantRequest
@@ -266,6 +326,21 @@ impl Validate for AssistantRequest {
     fn validate(&self) -> Result<(), String> {
         self.validate()
     }
+
+    fn validate_all(&self) -> Result<(), Vec<String>> {
+        collect_errors([
+            self.validate_name(),
+            self.validate_description(),
+            self.validate_instructions(),
+            self.validate_tools_count(),
+            self.validate_file_ids_count(),
+            self.validate_metadata_count(),
+            self.validate_metadata_entries(),
+            self.validate_temperature(),
+            self.validate_top_p(),
+            self.validate_response_format(),
+        ])
+    }
 }
 
 /// Builder for creating assistant requests
@@ -285,6 +360,12 @@ pub struct AssistantRequestBuilder {
     file_ids: Vec<String>,
     /// Metadata for the assistant
     metadata: HashMap<String, String>,
+    /// The format the assistant's replies must follow
+    response_format: Option<ResponseFormat>,
+    /// Sampling temperature
+    temperature: Option<f32>,
+    /// Nucleus sampling probability mass
+    top_p: Option<f32>,
 }
 
 impl AssistantRequestBuilder {
@@ -357,17 +438,346 @@ impl AssistantRequestBuilder {
         self.metadata = metadata;
         self
     }
+
+    /// Set the response format
+    #[must_use]
+    pub fn response_format(mut self, response_format: ResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
+    /// Require replies that match a strict JSON schema
+    pub fn json_schema(mut self, name: impl Into<String>, schema: Value) -> Self {
+        self.response_format = Some(ResponseFormat::strict_json_schema(name, schema));
+        self
+    }
+
+    /// Set the sampling temperature
+    #[must_use]
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Set the nucleus sampling probability mass
+    #[must_use]
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
 }
 
 // Generate the build method for AssistantRequestBuilder
 crate::impl_builder_build! {
     AssistantRequestBuilder => AssistantRequest {
         required: [model: "Model is required"],
-        optional: [name, description, instructions, tools, file_ids, metadata],
+        optional: [
+            name,
+            description,
+            instructions,
+            tools,
+            file_ids,
+            metadata,
+            response_format,
+            temperature,
+            top_p
+        ],
         validate: true
     }
 }
 
+/// Request to modify an existing assistant
+///
+/// Unlike [`AssistantRequest`], every field is optional and unchanged fields
+/// are left out of the body, so the service keeps their current values.
+/// Fields the service lets you clear use [`FieldUpdate`]: `Unchanged` leaves
+/// them as they are, `Set` replaces them and `Clear` sends `null`. Build one
+/// with [`Assistant::to_modify_request`] to send only the fields that changed.
+#[derive(Debug, Clone, Default, Ser, De)]
+pub struct ModifyAssistantRequest {
+    /// The model ID to use
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The name of the assistant
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub name: FieldUpdate<String>,
+    /// The description of the assistant
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub description: FieldUpdate<String>,
+    /// Instructions that the assistant uses
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub instructions: FieldUpdate<String>,
+    /// The full list of tools, replacing the current list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<AssistantTool>>,
+    /// The full list of file IDs, replacing the current list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_ids: Option<Vec<String>>,
+    /// The full metadata map, replacing the current metadata
+    #[serde(
+        default,
+        skip_serializing_if = "FieldUpdate::is_unchanged",
+        serialize_with = "crate::helpers::sorted_map::serialize_update"
+    )]
+    pub metadata: FieldUpdate<HashMap<String, String>>,
+    /// The format the assistant's replies must follow
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub response_format: FieldUpdate<ResponseFormat>,
+    /// Sampling temperature
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub temperature: FieldUpdate<f32>,
+    /// Nucleus sampling probability mass
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub top_p: FieldUpdate<f32>,
+}
+
+impl ModifyAssistantRequest {
+    /// Check whether the request leaves every field unchanged
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.model.is_none()
+            && self.name.is_unchanged()
+            && self.description.is_unchanged()
+            && self.instructions.is_unchanged()
+            && self.tools.is_none()
+            && self.file_ids.is_none()
+            && self.metadata.is_unchanged()
+            && self.response_format.is_unchanged()
+            && self.temperature.is_unchanged()
+            && self.top_p.is_unchanged()
+    }
+
+    /// The fields being set, as a create request for validation
+    fn as_assistant_request(&self) -> AssistantRequest {
+        AssistantRequest {
+            model: self.model.clone().unwrap_or_default(),
+            name: self.name.value().cloned(),
+            description: self.description.value().cloned(),
+            instructions: self.instructions.value().cloned(),
+            tools: self.tools.clone().unwrap_or_default(),
+            file_ids: self.file_ids.clone().unwrap_or_default(),
+            metadata: self.metadata.value().cloned().unwrap_or_default(),
+            response_format: self.response_format.value().cloned(),
+            temperature: self.temperature.value().copied(),
+            top_p: self.top_p.value().copied(),
+        }
+    }
+}
+
+impl Validate for ModifyAssistantRequest {
+    fn validate(&self) -> Result<(), String> {
+        self.as_assistant_request().validate()
+    }
+
+    fn validate_all(&self) -> Result<(), Vec<String>> {
+        Validate::validate_all(&self.as_assistant_request())
+    }
+}
+
+impl Assistant {
+    /// Start a modify request seeded with this assistant's current values
+    ///
+    /// Changes made through the builder apply on top of the current values,
+    /// so `metadata_pair` keeps the other metadata entries. Only fields that
+    /// end up different from the current values are sent.
+    #[must_use]
+    pub fn to_modify_request(&self) -> ModifyAssistantRequestBuilder {
+        ModifyAssistantRequestBuilder {
+            current: self.clone(),
+            metadata: None,
+            updated: ModifyAssistantRequest::default(),
+        }
+    }
+}
+
+/// Builder for [`ModifyAssistantRequest`] seeded from an existing assistant
+#[derive(Debug, Clone)]
+pub struct ModifyAssistantRequestBuilder {
+    /// The assistant the builder was seeded from
+    current: Assistant,
+    /// The edited metadata, or `None` while it is untouched
+    metadata: Option<HashMap<String, String>>,
+    /// Values set through the builder
+    updated: ModifyAssistantRequest,
+}
+
+impl ModifyAssistantRequestBuilder {
+    /// Set the model
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.updated.model = Some(model.into());
+        self
+    }
+
+    /// Set the name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.updated.name = FieldUpdate::Set(name.into());
+        self
+    }
+
+    /// Clear the name
+    #[must_use]
+    pub fn clear_name(mut self) -> Self {
+        self.updated.name = FieldUpdate::Clear;
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.updated.description = FieldUpdate::Set(description.into());
+        self
+    }
+
+    /// Clear the description
+    #[must_use]
+    pub fn clear_description(mut self) -> Self {
+        self.updated.description = FieldUpdate::Clear;
+        self
+    }
+
+    /// Set the instructions
+    pub fn instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.updated.instructions = FieldUpdate::Set(instructions.into());
+        self
+    }
+
+    /// Clear the instructions
+    #[must_use]
+    pub fn clear_instructions(mut self) -> Self {
+        self.updated.instructions = FieldUpdate::Clear;
+        self
+    }
+
+    /// Add a tool to the current tools
+    #[must_use]
+    pub fn tool(mut self, tool: AssistantTool) -> Self {
+        self.tools_mut().push(tool);
+        self
+    }
+
+    /// Replace all tools
+    #[must_use]
+    pub fn tools(mut self, tools: Vec<AssistantTool>) -> Self {
+        self.updated.tools = Some(tools);
+        self
+    }
+
+    /// Replace all file IDs
+    #[must_use]
+    pub fn file_ids(mut self, file_ids: Vec<String>) -> Self {
+        self.updated.file_ids = Some(file_ids);
+        self
+    }
+
+    /// Add or overwrite one metadata entry, keeping the others
+    pub fn metadata_pair(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata_mut().insert(key.into(), value.into());
+        self
+    }
+
+    /// Remove one metadata entry, keeping the others
+    pub fn remove_metadata(mut self, key: &str) -> Self {
+        self.metadata_mut().remove(key);
+        self
+    }
+
+    /// Replace all metadata
+    #[must_use]
+    pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Remove all metadata
+    ///
+    /// Entries added afterwards start from an empty map.
+    #[must_use]
+    pub fn clear_metadata(mut self) -> Self {
+        self.metadata = Some(HashMap::new());
+        self
+    }
+
+    /// Set the response format
+    #[must_use]
+    pub fn response_format(mut self, response_format: ResponseFormat) -> Self {
+        self.updated.response_format = FieldUpdate::Set(response_format);
+        self
+    }
+
+    /// Clear the response format, going back to the service default
+    #[must_use]
+    pub fn clear_response_format(mut self) -> Self {
+        self.updated.response_format = FieldUpdate::Clear;
+        self
+    }
+
+    /// Set the sampling temperature
+    #[must_use]
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.updated.temperature = FieldUpdate::Set(temperature);
+        self
+    }
+
+    /// Clear the sampling temperature, going back to the service default
+    #[must_use]
+    pub fn clear_temperature(mut self) -> Self {
+        self.updated.temperature = FieldUpdate::Clear;
+        self
+    }
+
+    /// Set the nucleus sampling probability mass
+    #[must_use]
+    pub fn top_p(mut self, top_p: f32) -> Self {
+        self.updated.top_p = FieldUpdate::Set(top_p);
+        self
+    }
+
+    /// Clear the nucleus sampling probability mass, going back to the service default
+    #[must_use]
+    pub fn clear_top_p(mut self) -> Self {
+        self.updated.top_p = FieldUpdate::Clear;
+        self
+    }
+
+    /// Build a request holding only the fields that differ from the current values
+    ///
+    /// The response format and sampling settings are not part of the
+    /// [`Assistant`] model, so any change to them is always sent.
+    #[must_use]
+    pub fn build(self) -> ModifyAssistantRequest {
+        let Self {
+            current,
+            metadata,
+            updated,
+        } = self;
+        ModifyAssistantRequest {
+            model: updated.model.filter(|model| *model != current.model),
+            name: updated.name.against(current.name.as_ref()),
+            description: updated.description.against(current.description.as_ref()),
+            instructions: updated.instructions.against(current.instructions.as_ref()),
+            tools: updated.tools.filter(|tools| *tools != current.tools),
+            file_ids: updated
+                .file_ids
+                .filter(|file_ids| *file_ids != current.file_ids),
+            metadata: FieldUpdate::from_edited_map(metadata, &current.metadata),
+            response_format: updated.response_format,
+            temperature: updated.temperature,
+            top_p: updated.top_p,
+        }
+    }
+
+    /// The tools list being edited, starting from the current tools
+    fn tools_mut(&mut self) -> &mut Vec<AssistantTool> {
+        let current = &self.current.tools;
+        self.updated.tools.get_or_insert_with(|| current.clone())
+    }
+
+    /// The metadata being edited, starting from the current metadata
+    fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        let current = &self.current.metadata;
+        self.metadata.get_or_insert_with(|| current.clone())
+    }
+}
+
 /// Response from listing assistants
 #[derive(Debug, Clone, Ser, De)]
 pub struct ListAssistantsResponse {
@@ -460,7 +870,7 @@ impl ListAssistantsParams {
     }
 }
 
-impl crate::api::common::ListQueryParams for ListAssistantsParams {
+impl crate::models::shared_traits::ListQueryParams for ListAssistantsParams {
     fn limit(&self) -> Option<u32> {
         self.limit
     }
@@ -506,6 +916,7 @@ pub struct DeletionStatus {
 }
 
 crate::impl_default_object_type!(default_deletion_object, "assistant.deleted");
+crate::impl_delete_response!(DeletionStatus, "assistant.deleted");
 
 #[cfg(test)]
 mod tests {
@@ -558,6 +969,100 @@ mod tests {
         assert!(request.is_ok());
     }
 
+    #[test]
+    fn test_assistant_request_build_validated_collects_errors() {
+        let errors = AssistantRequest::builder()
+            .model("gpt-4")
+            .name("a".repeat(257))
+            .description("d".repeat(513))
+            .build_validated()
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].message.contains("name"));
+        assert!(errors[1].message.contains("description"));
+
+        let errors = AssistantRequest::builder().build_validated().unwrap_err();
+        assert_eq!(errors[0].field.as_deref(), Some("model"));
+    }
+
+    #[test]
+    fn test_assistant_request_structured_output() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {"answer": {"type": "string"}},
+            "required": ["answer"],
+            "additionalProperties": false
+        });
+        let request = AssistantRequest::builder()
+            .model("gpt-4o")
+            .json_schema("answer", schema)
+            .temperature(0.2)
+            .top_p(0.9)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["response_format"]["type"], "json_schema");
+        assert_eq!(json["response_format"]["json_schema"]["name"], "answer");
+        assert_eq!(json["response_format"]["json_schema"]["strict"], true);
+        assert!((json["temperature"].as_f64().unwrap() - 0.2).abs() < 1e-6);
+
+        let plain = serde_json::to_value(AssistantRequest::new("gpt-4o")).unwrap();
+        assert!(plain.get("response_format").is_none());
+        assert!(plain.get("temperature").is_none());
+    }
+
+    #[test]
+    fn test_assistant_request_rejects_non_strict_schema() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {"answer": {"type": "string"}}
+        });
+        let err = AssistantRequest::builder()
+            .model("gpt-4o")
+            .json_schema("answer", schema.clone())
+            .build()
+            .unwrap_err();
+        assert!(err.contains("additionalProperties"));
+
+        let lenient = AssistantRequest::builder()
+            .model("gpt-4o")
+            .response_format(ResponseFormat::json_schema("answer", schema))
+            .build();
+        assert!(lenient.is_ok());
+    }
+
+    #[test]
+    fn test_assistant_request_sampling_ranges() {
+        let errors = AssistantRequest::builder()
+            .model("gpt-4o")
+            .temperature(2.5)
+            .top_p(1.5)
+            .build_validated()
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn test_assistant_request_serialization_is_stable() {
+        let keys: Vec<String> = (0..16).map(|i| format!("key_{i:02}")).collect();
+        let build = |keys: &[String]| {
+            let mut builder = AssistantRequest::builder().model("gpt-4o");
+            for key in keys {
+                builder = builder.metadata_pair(key.clone(), "value");
+            }
+            serde_json::to_string(&builder.build().unwrap()).unwrap()
+        };
+
+        let forward = build(&keys);
+        let reversed: Vec<String> = keys.iter().rev().cloned().collect();
+        for _ in 0..4 {
+            assert_eq!(build(&keys), forward);
+            assert_eq!(build(&reversed), forward);
+        }
+        assert!(forward.find("key_00").unwrap() < forward.find("key_15").unwrap());
+    }
+
     #[test]
     fn test_list_params_limit_clamping() {
         let params = ListAssistantsParams::new().limit(150);
@@ -572,4 +1077,108 @@ mod tests {
         let order = SortOrder::default();
         assert_eq!(order, SortOrder::Desc);
     }
+
+    fn sample_assistant() -> Assistant {
+        serde_json::from_value(serde_json::json!({
+            "id": "asst_123",
+            "object": "assistant",
+            "created_at": 1_700_000_000,
+            "name": "Helper",
+            "description": "Answers questions",
+            "model": "gpt-4o",
+            "instructions": "Be brief.",
+            "tools": [{"type": "code_interpreter"}],
+            "metadata": {"team": "support", "tier": "gold"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_to_modify_request_sends_only_changed_fields() {
+        let request = sample_assistant()
+            .to_modify_request()
+            .name("Helper v2")
+            .model("gpt-4o")
+            .instructions("Be brief.")
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"name": "Helper v2"})
+        );
+    }
+
+    #[test]
+    fn test_to_modify_request_merges_metadata_and_tools() {
+        let request = sample_assistant()
+            .to_modify_request()
+            .metadata_pair("tier", "platinum")
+            .remove_metadata("team")
+            .tool(AssistantTool::retrieval())
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "tools": [{"type": "code_interpreter"}, {"type": "retrieval"}],
+                "metadata": {"tier": "platinum"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_to_modify_request_without_changes_is_empty() {
+        let request = sample_assistant()
+            .to_modify_request()
+            .metadata_pair("team", "support")
+            .build();
+        assert!(request.is_empty());
+        assert_eq!(serde_json::to_string(&request).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_modify_request_field_update_states() {
+        let assistant = sample_assistant();
+
+        // Unchanged: left out entirely
+        let unchanged = assistant.to_modify_request().build();
+        assert!(unchanged.name.is_unchanged());
+        assert_eq!(serde_json::to_string(&unchanged).unwrap(), "{}");
+
+        // Set: sent as the new value
+        let set = assistant.to_modify_request().description("New").build();
+        assert_eq!(
+            serde_json::to_value(&set).unwrap(),
+            serde_json::json!({"description": "New"})
+        );
+
+        // Clear: sent as null
+        let clear = assistant
+            .to_modify_request()
+            .clear_instructions()
+            .clear_temperature()
+            .clear_metadata()
+            .build();
+        assert_eq!(
+            serde_json::to_value(&clear).unwrap(),
+            serde_json::json!({"instructions": null, "temperature": null, "metadata": null})
+        );
+    }
+
+    #[test]
+    fn test_modify_request_clearing_empty_field_is_unchanged() {
+        let mut assistant = sample_assistant();
+        assistant.description = None;
+        let request = assistant.to_modify_request().clear_description().build();
+        assert!(request.is_empty());
+    }
+
+    #[test]
+    fn test_modify_assistant_request_validation() {
+        let request = sample_assistant()
+            .to_modify_request()
+            .temperature(3.0)
+            .build();
+        assert!(Validate::validate(&request).is_err());
+    }
 }
@@ -51,6 +51,30 @@ impl SpeechBuilder {
     pub fn build(self) -> AudioSpeechRequest {
         <Self as crate::models::common_builder::Builder<AudioSpeechRequest>>::build(self)
     }
+
+    /// Build the request, rejecting formats the model cannot produce
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the incompatible model and format.
+    pub fn try_build(self) -> Result<AudioSpeechRequest, String> {
+        let request = self.build();
+        request.validate()?;
+        Ok(request)
+    }
+
+    /// Build a request for streaming playback
+    ///
+    /// Also rejects formats that cannot be played while they arrive.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the incompatible model and format.
+    pub fn try_build_for_streaming(self) -> Result<AudioSpeechRequest, String> {
+        let request = self.build();
+        request.validate_for_streaming()?;
+        Ok(request)
+    }
 }
 
 // Apply common builder traits
@@ -112,6 +112,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_audio_format_content_types() {
+        assert_eq!(AudioFormat::Mp3.content_type(), "audio/mpeg");
+        assert_eq!(AudioFormat::Opus.content_type(), "audio/ogg");
+        assert_eq!(AudioFormat::Pcm.extension(), "pcm");
+        for format in [
+            AudioFormat::Mp3,
+            AudioFormat::Opus,
+            AudioFormat::Aac,
+            AudioFormat::Flac,
+            AudioFormat::Wav,
+            AudioFormat::Pcm,
+        ] {
+            assert_eq!(
+                AudioFormat::from_content_type(format.content_type()),
+                Some(format)
+            );
+        }
+        assert_eq!(
+            AudioFormat::from_content_type("audio/wav; codecs=1"),
+            Some(AudioFormat::Wav)
+        );
+
+        let response = AudioSpeechResponse::new(vec![0], "audio/ogg".to_string());
+        assert_eq!(response.format(), Some(AudioFormat::Opus));
+    }
+
+    #[test]
+    fn test_speech_builder_rejects_incompatible_combinations() {
+        assert!(
+            SpeechBuilder::tts_1("Hi", Voice::Alloy)
+                .format(AudioFormat::Flac)
+                .try_build()
+                .is_ok()
+        );
+
+        let err = SpeechBuilder::new(AudioModels::WHISPER_1, "Hi", Voice::Alloy)
+            .try_build()
+            .unwrap_err();
+        assert!(err.contains("cannot generate speech"));
+
+        let err = SpeechBuilder::tts_1("Hi", Voice::Alloy)
+            .format(AudioFormat::Flac)
+            .try_build_for_streaming()
+            .unwrap_err();
+        assert!(err.contains("flac"));
+        assert!(
+            SpeechBuilder::new(AudioModels::GPT_4O_MINI_TTS, "Hi", Voice::Alloy)
+                .format(AudioFormat::Pcm)
+                .try_build_for_streaming()
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_speed_clamping() {
         let req = SpeechBuilder::tts_1("test", Voice::Alloy)
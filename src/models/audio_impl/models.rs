@@ -2,6 +2,8 @@
 //!
 //! Common audio models for the OpenAI Audio API.
 
+use super::types::AudioFormat;
+
 /// Common audio models
 pub struct AudioModels;
 
@@ -12,6 +14,34 @@ impl AudioModels {
     /// Text-to-speech model (high definition quality)
     pub const TTS_1_HD: &'static str = "tts-1-hd";
 
+    /// Text-to-speech model that accepts voice instructions
+    pub const GPT_4O_MINI_TTS: &'static str = "gpt-4o-mini-tts";
+
     /// Speech-to-text model (Whisper)
     pub const WHISPER_1: &'static str = "whisper-1";
+
+    /// Get the output formats a text-to-speech model supports
+    ///
+    /// Returns `None` for models that are not known text-to-speech models.
+    #[must_use]
+    pub fn speech_formats(model: &str) -> Option<&'static [AudioFormat]> {
+        const ALL: &[AudioFormat] = &[
+            AudioFormat::Mp3,
+            AudioFormat::Opus,
+            AudioFormat::Aac,
+            AudioFormat::Flac,
+            AudioFormat::Wav,
+            AudioFormat::Pcm,
+        ];
+        match model {
+            Self::TTS_1 | Self::TTS_1_HD | Self::GPT_4O_MINI_TTS => Some(ALL),
+            _ => None,
+        }
+    }
+
+    /// Whether a model is a known speech-to-text model
+    #[must_use]
+    pub fn is_transcription_model(model: &str) -> bool {
+        model == Self::WHISPER_1 || model.contains("transcribe")
+    }
 }
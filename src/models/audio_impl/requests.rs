@@ -3,6 +3,7 @@
 //! Request structures for the OpenAI Audio API including text-to-speech,
 //! speech-to-text transcription, and translation endpoints.
 
+use super::models::AudioModels;
 use super::types::{AudioFormat, TimestampGranularity, TranscriptionFormat, Voice};
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
@@ -107,6 +108,56 @@ impl AudioSpeechRequest {
         self.speed = Some(speed.clamp(0.25, 4.0));
         self
     }
+
+    /// Get the requested output format, which defaults to MP3
+    #[must_use]
+    pub fn format(&self) -> AudioFormat {
+        self.response_format.clone().unwrap_or(AudioFormat::Mp3)
+    }
+
+    /// Check that the model can produce the requested format
+    ///
+    /// Models that are not known text-to-speech models are accepted unless they
+    /// are speech-to-text models, so newly released models keep working.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the incompatible combination.
+    pub fn validate(&self) -> Result<(), String> {
+        if AudioModels::is_transcription_model(&self.model) {
+            return Err(format!(
+                "Model '{}' transcribes audio and cannot generate speech",
+                self.model
+            ));
+        }
+        let format = self.format();
+        if let Some(formats) = AudioModels::speech_formats(&self.model)
+            && !formats.contains(&format)
+        {
+            return Err(format!(
+                "Model '{}' does not support the {format} output format",
+                self.model
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check the request as for [`Self::validate`], and that its format streams
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the incompatible combination.
+    pub fn validate_for_streaming(&self) -> Result<(), String> {
+        self.validate()?;
+        let format = self.format();
+        if format.is_streamable() {
+            Ok(())
+        } else {
+            Err(format!(
+                "The {format} output format cannot be played while streaming; use mp3, opus, aac, wav or pcm"
+            ))
+        }
+    }
 }
 
 impl AudioTranscriptionRequest {
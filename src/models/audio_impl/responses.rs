@@ -3,6 +3,7 @@
 //! Response structures for the OpenAI Audio API including speech generation,
 //! transcription, and translation responses.
 
+use super::types::AudioFormat;
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
 
@@ -122,7 +123,14 @@ impl AudioSpeechResponse {
         &self.content_type
     }
 
+    /// Get the audio format matching the content type, if it is recognised
+    #[must_use]
+    pub fn format(&self) -> Option<AudioFormat> {
+        AudioFormat::from_content_type(&self.content_type)
+    }
+
     /// Save the audio to a file
+    #[cfg(feature = "net")]
     pub async fn save_to_file(
         &self,
         path: impl AsRef<std::path::Path>,
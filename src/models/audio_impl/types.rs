@@ -53,6 +53,58 @@ pub enum AudioFormat {
     Pcm,
 }
 
+impl AudioFormat {
+    /// Get the MIME type of audio in this format
+    #[must_use]
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Mp3 => "audio/mpeg",
+            Self::Opus => "audio/ogg",
+            Self::Aac => "audio/aac",
+            Self::Flac => "audio/flac",
+            Self::Wav => "audio/wav",
+            Self::Pcm => "audio/pcm",
+        }
+    }
+
+    /// Get the file extension for audio in this format
+    #[must_use]
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Mp3 => "mp3",
+            Self::Opus => "opus",
+            Self::Aac => "aac",
+            Self::Flac => "flac",
+            Self::Wav => "wav",
+            Self::Pcm => "pcm",
+        }
+    }
+
+    /// Whether audio in this format can be played while it is still arriving
+    ///
+    /// FLAC is excluded because players generally wait for the complete
+    /// stream before decoding it.
+    #[must_use]
+    pub fn is_streamable(&self) -> bool {
+        !matches!(self, Self::Flac)
+    }
+
+    /// Find the format matching a MIME type, ignoring any parameters
+    #[must_use]
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next()?.trim().to_ascii_lowercase();
+        match mime.as_str() {
+            "audio/mpeg" | "audio/mp3" => Some(Self::Mp3),
+            "audio/ogg" | "audio/opus" => Some(Self::Opus),
+            "audio/aac" => Some(Self::Aac),
+            "audio/flac" => Some(Self::Flac),
+            "audio/wav" | "audio/x-wav" | "audio/wave" => Some(Self::Wav),
+            "audio/pcm" | "audio/l16" => Some(Self::Pcm),
+            _ => None,
+        }
+    }
+}
+
 crate::impl_enum_display! {
     AudioFormat {
         Mp3 => "mp3",
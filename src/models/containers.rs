@@ -14,7 +14,10 @@ pub struct ContainerConfig {
     pub name: Option<String>,
 
     /// Container metadata
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, serde_json::Value>>,
 
     /// Python version to use (e.g., "3.9", "3.10", "3.11")
@@ -85,7 +88,7 @@ pub struct Container {
     pub libraries: Vec<String>,
 
     /// Container metadata
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, serde_json::Value>,
 
     /// Files in the container
@@ -291,7 +294,7 @@ pub struct CodeCitation {
     pub lines: Option<Vec<u32>>,
 
     /// Additional metadata
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, serde_json::Value>,
 }
 
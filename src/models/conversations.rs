@@ -31,7 +31,10 @@ pub struct Conversation {
     pub created_at: u64,
 
     /// Optional metadata associated with the conversation.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -39,7 +42,10 @@ pub struct Conversation {
 #[derive(Debug, Clone, Ser, De)]
 pub struct CreateConversationRequest {
     /// Optional metadata to attach to the conversation.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -47,7 +53,10 @@ pub struct CreateConversationRequest {
 #[derive(Debug, Clone, Ser, De)]
 pub struct UpdateConversationRequest {
     /// Optional metadata to update on the conversation.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
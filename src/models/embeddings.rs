@@ -4,6 +4,14 @@
 
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub mod index;
+pub mod store;
+
+pub use index::{SearchResult, VectorIndex};
+pub use store::EmbeddingStore;
 
 /// Request for creating embeddings
 #[derive(Debug, Clone, Ser, De)]
@@ -40,10 +48,11 @@ pub enum EmbeddingInput {
 }
 
 /// Encoding format for embeddings
-#[derive(Debug, Clone, PartialEq, Ser, De)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Ser, De)]
 #[serde(rename_all = "lowercase")]
 pub enum EncodingFormat {
     /// Return embeddings as float arrays
+    #[default]
     Float,
     /// Return embeddings as base64-encoded strings
     Base64,
@@ -63,8 +72,13 @@ pub struct EmbeddingResponse {
 
     /// Token usage statistics
     pub usage: EmbeddingUsage,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(EmbeddingResponse);
+
 /// Individual embedding object
 #[derive(Debug, Clone, Ser, De)]
 pub struct Embedding {
@@ -88,6 +102,38 @@ pub enum EmbeddingVector {
     Base64(String),
 }
 
+impl EmbeddingVector {
+    /// Get the vector as floats, decoding the base64 wire format if needed
+    ///
+    /// Base64 embeddings are packed little-endian `f32` values.
+    pub fn to_floats(&self) -> Result<Vec<f32>, String> {
+        match self {
+            Self::Float(vec) => Ok(vec.clone()),
+            Self::Base64(encoded) => decode_base64_floats(encoded),
+        }
+    }
+}
+
+/// Decode a base64 string of packed little-endian `f32` values
+fn decode_base64_floats(encoded: &str) -> Result<Vec<f32>, String> {
+    use base64::{Engine as _, engine::general_purpose};
+
+    let bytes = general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| format!("Failed to decode base64 embedding: {e}"))?;
+    if bytes.len() % 4 != 0 {
+        return Err(format!(
+            "Base64 embedding has {} bytes, which is not a whole number of f32 values",
+            bytes.len()
+        ));
+    }
+
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
 /// Usage statistics for embeddings
 #[derive(Debug, Clone, Ser, De)]
 pub struct EmbeddingUsage {
@@ -154,25 +200,57 @@ impl EmbeddingRequest {
 }
 
 impl EmbeddingResponse {
-    /// Get all embeddings as float vectors (if format is float)
+    /// Get all embeddings as float vectors, decoding base64 embeddings as needed
+    ///
+    /// There is one entry per item in `data`, in the same order; an embedding
+    /// that fails to decode is returned as an empty vector. Use
+    /// [`Self::decode_embeddings`] or [`Self::decode_embeddings_by_index`] to
+    /// get the decoding error instead.
     #[must_use]
     pub fn get_embeddings(&self) -> Vec<Vec<f32>> {
         self.data
             .iter()
-            .filter_map(|e| match &e.embedding {
-                EmbeddingVector::Float(vec) => Some(vec.clone()),
-                _ => None,
-            })
+            .map(|e| e.embedding.to_floats().unwrap_or_default())
             .collect()
     }
 
-    /// Get the first embedding as a float vector
+    /// Decode all embeddings into float vectors, failing on the first malformed one
+    pub fn decode_embeddings(&self) -> Result<Vec<Vec<f32>>, String> {
+        self.data.iter().map(|e| e.embedding.to_floats()).collect()
+    }
+
+    /// Decode all embeddings into float vectors ordered by their input `index`
+    ///
+    /// The API does not promise to return `data` in input order, so each
+    /// vector is placed at its reported index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an embedding fails to decode, or if the indices
+    /// are not exactly `0..data.len()`.
+    pub fn decode_embeddings_by_index(&self) -> Result<Vec<Vec<f32>>, String> {
+        let mut ordered: Vec<Option<Vec<f32>>> = vec![None; self.data.len()];
+        for embedding in &self.data {
+            let slot = ordered.get_mut(embedding.index).ok_or_else(|| {
+                format!(
+                    "Embedding index {} is out of range for {} embeddings",
+                    embedding.index,
+                    self.data.len()
+                )
+            })?;
+            if slot.is_some() {
+                return Err(format!("Duplicate embedding index {}", embedding.index));
+            }
+            *slot = Some(embedding.embedding.to_floats()?);
+        }
+        // Every slot is filled: there are as many items as slots and no duplicates
+        Ok(ordered.into_iter().flatten().collect())
+    }
+
+    /// Get the first embedding as a float vector, decoding base64 if needed
     #[must_use]
     pub fn get_first_embedding(&self) -> Option<Vec<f32>> {
-        self.data.first().and_then(|e| match &e.embedding {
-            EmbeddingVector::Float(vec) => Some(vec.clone()),
-            _ => None,
-        })
+        self.data.first().and_then(|e| e.embedding.to_floats().ok())
     }
 
     /// Get all embeddings as base64 strings (if format is base64)
@@ -192,7 +270,7 @@ impl EmbeddingResponse {
     pub fn dimension(&self) -> Option<usize> {
         self.data.first().and_then(|e| match &e.embedding {
             EmbeddingVector::Float(vec) => Some(vec.len()),
-            _ => None,
+            EmbeddingVector::Base64(_) => e.embedding.to_floats().ok().map(|vec| vec.len()),
         })
     }
 }
@@ -259,6 +337,13 @@ impl EmbeddingBuilder {
         self
     }
 
+    /// Set the wire encoding format; decoded embeddings are floats either way
+    #[must_use]
+    pub fn encoding_format(mut self, format: EncodingFormat) -> Self {
+        self.request.encoding_format = Some(format);
+        self
+    }
+
     /// Use float encoding format (default)
     #[must_use]
     pub fn float_format(mut self) -> Self {
@@ -310,6 +395,98 @@ mod tests {
         assert_eq!(req.user, Some("user123".to_string()));
     }
 
+    fn base64_of(values: &[f32]) -> String {
+        use base64::{Engine as _, engine::general_purpose};
+        let bytes: Vec<u8> = values.iter().flat_map(|v| v.to_le_bytes()).collect();
+        general_purpose::STANDARD.encode(bytes)
+    }
+
+    #[test]
+    fn test_base64_embeddings_decode_to_floats() {
+        let values = [0.25_f32, -1.5, 3.0];
+        let response: EmbeddingResponse = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [
+                { "object": "embedding", "index": 0, "embedding": base64_of(&values) },
+                { "object": "embedding", "index": 1, "embedding": [1.0, 2.0, 3.0] }
+            ],
+            "model": "text-embedding-3-small",
+            "usage": { "prompt_tokens": 2, "total_tokens": 2 }
+        }))
+        .unwrap();
+
+        assert!(response.data[0].as_base64().is_some());
+        assert_eq!(response.get_first_embedding(), Some(values.to_vec()));
+        assert_eq!(response.dimension(), Some(3));
+        assert_eq!(
+            response.decode_embeddings().unwrap(),
+            vec![values.to_vec(), vec![1.0, 2.0, 3.0]]
+        );
+    }
+
+    #[test]
+    fn test_embeddings_are_ordered_by_index() {
+        let response: EmbeddingResponse = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [
+                { "object": "embedding", "index": 1, "embedding": [2.0] },
+                { "object": "embedding", "index": 0, "embedding": [1.0] }
+            ],
+            "model": "text-embedding-3-small",
+            "usage": { "prompt_tokens": 2, "total_tokens": 2 }
+        }))
+        .unwrap();
+        assert_eq!(
+            response.decode_embeddings_by_index().unwrap(),
+            vec![vec![1.0], vec![2.0]]
+        );
+
+        let mut duplicate = response.clone();
+        duplicate.data[0].index = 0;
+        assert!(duplicate.decode_embeddings_by_index().is_err());
+        let mut out_of_range = response;
+        out_of_range.data[0].index = 2;
+        assert!(out_of_range.decode_embeddings_by_index().is_err());
+    }
+
+    #[test]
+    fn test_malformed_base64_embedding_is_rejected() {
+        let truncated = EmbeddingVector::Base64(base64_of(&[1.0])[..4].to_string());
+        assert!(truncated.to_floats().is_err());
+
+        let response: EmbeddingResponse = serde_json::from_value(serde_json::json!({
+            "object": "list",
+            "data": [
+                { "object": "embedding", "index": 0, "embedding": "not base64!" },
+                { "object": "embedding", "index": 1, "embedding": [1.0, 2.0] }
+            ],
+            "model": "text-embedding-3-small",
+            "usage": { "prompt_tokens": 2, "total_tokens": 2 }
+        }))
+        .unwrap();
+        assert_eq!(response.get_embeddings(), vec![vec![], vec![1.0, 2.0]]);
+        assert!(response.decode_embeddings().is_err());
+        assert!(
+            EmbeddingVector::Base64("not base64!".to_string())
+                .to_floats()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_encoding_format_defaults_to_float() {
+        assert_eq!(EncodingFormat::default(), EncodingFormat::Float);
+        let request = EmbeddingBuilder::new(EmbeddingModels::EMBEDDING_3_SMALL, "Test")
+            .encoding_format(EncodingFormat::Base64)
+            .build();
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["encoding_format"], "base64");
+
+        let default_request = EmbeddingRequest::new(EmbeddingModels::EMBEDDING_3_SMALL, "Test");
+        let json = serde_json::to_value(&default_request).unwrap();
+        assert!(json.get("encoding_format").is_none());
+    }
+
     #[test]
     fn test_batch_embeddings() {
         let inputs = vec![
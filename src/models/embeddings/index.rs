@@ -0,0 +1,270 @@
+//! # Vector Index
+//!
+//! A brute-force, in-memory cosine-similarity index for modest corpora.
+//! Every query scans all vectors, which keeps the index dependency-free and
+//! exact; the length of each vector is computed once, when it is added.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use openai_rust_sdk::models::embeddings::VectorIndex;
+//!
+//! let mut index = VectorIndex::new(2).with_normalize_on_insert(true);
+//! index.add("north", vec![0.0, 1.0])?;
+//! index.add("east", vec![1.0, 0.0])?;
+//!
+//! let results = index.query(&[0.1, 0.9], 1)?;
+//! assert_eq!(results[0].id, "north");
+//! # Ok::<(), openai_rust_sdk::OpenAIError>(())
+//! ```
+
+use super::store::EmbeddingStore;
+use crate::error::{OpenAIError, Result};
+
+/// A ranked match returned by [`VectorIndex::query`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    /// Identifier the vector was added with
+    pub id: String,
+    /// Cosine similarity to the query, in `[-1.0, 1.0]` unless a vector holds NaN or infinity
+    pub score: f32,
+}
+
+/// Exact nearest-neighbour index ranking vectors by cosine similarity
+#[derive(Debug, Clone, Default)]
+pub struct VectorIndex {
+    /// Length of every indexed vector
+    dimension: usize,
+    /// Whether vectors are scaled to unit length when added
+    normalize_on_insert: bool,
+    /// Identifiers in insertion order
+    ids: Vec<String>,
+    /// Indexed vectors, parallel to `ids`
+    vectors: Vec<Vec<f32>>,
+    /// Euclidean length of each stored vector, parallel to `ids`
+    norms: Vec<f32>,
+}
+
+impl VectorIndex {
+    /// Create an empty index for vectors of the given dimension
+    #[must_use]
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension,
+            ..Self::default()
+        }
+    }
+
+    /// Normalize vectors to unit length as they are added
+    ///
+    /// Scores are true cosine similarities either way; this only changes the
+    /// stored vectors. It does not rescale vectors already in the index.
+    #[must_use]
+    pub fn with_normalize_on_insert(mut self, normalize: bool) -> Self {
+        self.normalize_on_insert = normalize;
+        self
+    }
+
+    /// Build an index from every embedding in a store
+    #[must_use]
+    pub fn from_store(store: &EmbeddingStore, normalize_on_insert: bool) -> Self {
+        let mut index = Self::new(store.dimension()).with_normalize_on_insert(normalize_on_insert);
+        for (id, vector) in store.iter() {
+            index.push(id.to_string(), vector.to_vec());
+        }
+        index
+    }
+
+    /// Length of every vector in the index
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Number of indexed vectors
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Check whether the index is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Add a vector under `id`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vector length does not match the index dimension
+    pub fn add(&mut self, id: impl Into<String>, vector: Vec<f32>) -> Result<()> {
+        self.check_dimension(&vector)?;
+        self.push(id.into(), vector);
+        Ok(())
+    }
+
+    /// Return the `top_k` vectors most similar to `query`, best first
+    ///
+    /// Ties keep insertion order. Zero vectors score `0.0` against everything,
+    /// and vectors whose score is not finite (NaN or infinite components) rank
+    /// after every finite score.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query length does not match the index dimension
+    pub fn query(&self, query: &[f32], top_k: usize) -> Result<Vec<SearchResult>> {
+        self.check_dimension(query)?;
+
+        let query_norm = norm(query);
+        let mut scored: Vec<(usize, f32)> = self
+            .vectors
+            .iter()
+            .enumerate()
+            .map(|(position, vector)| {
+                let denominator = query_norm * self.norms[position];
+                let score = if denominator == 0.0 {
+                    0.0
+                } else {
+                    dot(query, vector) / denominator
+                };
+                (position, score)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.1.is_finite()
+                .cmp(&a.1.is_finite())
+                .then_with(|| b.1.total_cmp(&a.1))
+        });
+        scored.truncate(top_k);
+
+        Ok(scored
+            .into_iter()
+            .map(|(position, score)| SearchResult {
+                id: self.ids[position].clone(),
+                score,
+            })
+            .collect())
+    }
+
+    /// Store a vector that already has the right dimension
+    fn push(&mut self, id: String, mut vector: Vec<f32>) {
+        if self.normalize_on_insert {
+            let length = norm(&vector);
+            if length > 0.0 {
+                vector.iter_mut().for_each(|value| *value /= length);
+            }
+        }
+        self.norms.push(norm(&vector));
+        self.ids.push(id);
+        self.vectors.push(vector);
+    }
+
+    /// Reject vectors whose length differs from the index dimension
+    fn check_dimension(&self, vector: &[f32]) -> Result<()> {
+        if vector.len() == self.dimension {
+            Ok(())
+        } else {
+            Err(OpenAIError::InvalidRequest(format!(
+                "Vector has {} dimensions, index expects {}",
+                vector.len(),
+                self.dimension
+            )))
+        }
+    }
+}
+
+/// Dot product of two equal-length vectors
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Euclidean length of a vector
+fn norm(vector: &[f32]) -> f32 {
+    dot(vector, vector).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn synthetic_index(normalize: bool) -> VectorIndex {
+        let mut index = VectorIndex::new(3).with_normalize_on_insert(normalize);
+        index.add("x", vec![10.0, 0.0, 0.0]).unwrap();
+        index.add("xy", vec![1.0, 1.0, 0.0]).unwrap();
+        index.add("y", vec![0.0, 2.0, 0.0]).unwrap();
+        index.add("neg-x", vec![-3.0, 0.0, 0.0]).unwrap();
+        index.add("zero", vec![0.0, 0.0, 0.0]).unwrap();
+        index
+    }
+
+    #[test]
+    fn test_query_ranks_by_cosine_similarity() {
+        for normalize in [false, true] {
+            let index = synthetic_index(normalize);
+            let results = index.query(&[1.0, 0.2, 0.0], 5).unwrap();
+
+            let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+            assert_eq!(ids, vec!["x", "xy", "y", "zero", "neg-x"]);
+            assert!((results[0].score - 0.980_580_7).abs() < 1e-5);
+            assert!((results[4].score + 0.980_580_7).abs() < 1e-5);
+            assert_eq!(results[3].score, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_query_truncates_to_top_k() {
+        let index = synthetic_index(true);
+        let results = index.query(&[0.0, 1.0, 0.0], 2).unwrap();
+        let ids: Vec<&str> = results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["y", "xy"]);
+        assert!(index.query(&[0.0, 1.0, 0.0], 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_enabling_normalization_late_keeps_true_cosine() {
+        let mut index = VectorIndex::new(2);
+        index.add("long-x", vec![10.0, 0.0]).unwrap();
+        let mut index = index.with_normalize_on_insert(true);
+        index.add("diagonal", vec![1.0, 1.0]).unwrap();
+
+        let results = index.query(&[1.0, 0.0], 2).unwrap();
+        assert_eq!(results[0].id, "long-x");
+        assert!((results[0].score - 1.0).abs() < 1e-6);
+        assert!((results[1].score - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_non_finite_scores_rank_last() {
+        let mut index = VectorIndex::new(2);
+        index.add("nan", vec![f32::NAN, 0.0]).unwrap();
+        index.add("opposite", vec![-1.0, 0.0]).unwrap();
+
+        let results = index.query(&[1.0, 0.0], 2).unwrap();
+        assert_eq!(results[0].id, "opposite");
+        assert_eq!(results[1].id, "nan");
+        assert!(results[1].score.is_nan());
+    }
+
+    #[test]
+    fn test_dimension_mismatch_is_rejected() {
+        let mut index = VectorIndex::new(3);
+        assert!(index.add("short", vec![1.0]).is_err());
+        assert!(index.query(&[1.0, 2.0], 1).is_err());
+        assert!(index.is_empty());
+    }
+
+    #[test]
+    fn test_from_store() {
+        let mut store = EmbeddingStore::new(2);
+        store.insert("a", vec![1.0, 0.0]).unwrap();
+        store.insert("b", vec![0.0, 5.0]).unwrap();
+
+        let index = VectorIndex::from_store(&store, true);
+        assert_eq!(index.len(), 2);
+        let best = &index.query(&[0.0, 1.0], 1).unwrap()[0];
+        assert_eq!(best.id, "b");
+        assert!((best.score - 1.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,371 @@
+//! # Embedding Store
+//!
+//! A compact on-disk cache of `(id, vector)` pairs so a corpus does not need
+//! to be re-embedded between runs.
+//!
+//! ## File Format
+//!
+//! All integers are little-endian.
+//!
+//! | Field       | Size                 | Description                                  |
+//! |-------------|----------------------|----------------------------------------------|
+//! | magic       | 4 bytes              | `EMBS`                                       |
+//! | version     | `u32`                | Format version, currently `1`                |
+//! | dimension   | `u32`                | Length of every vector                       |
+//! | count       | `u64`                | Number of records                            |
+//! | checksum    | `u64`                | FNV-1a hash of the record payload            |
+//! | records     | `count` × record     | `u32` id length, UTF-8 id, `dimension` × `f32` |
+//!
+//! Loading verifies the magic, version, checksum and record layout before
+//! returning, so truncated or corrupted files fail fast.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use openai_rust_sdk::models::embeddings::EmbeddingStore;
+//!
+//! let mut store = EmbeddingStore::new(3);
+//! store.insert("doc-1", vec![0.1, 0.2, 0.3])?;
+//! store.save("embeddings.bin")?;
+//!
+//! let cached = EmbeddingStore::load_with_dimension("embeddings.bin", 3)?;
+//! assert_eq!(cached.get("doc-1"), Some(&[0.1, 0.2, 0.3][..]));
+//! # Ok::<(), openai_rust_sdk::OpenAIError>(())
+//! ```
+
+use crate::error::{OpenAIError, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Magic bytes identifying an embedding store file
+const MAGIC: &[u8; 4] = b"EMBS";
+
+/// Current file format version
+const FORMAT_VERSION: u32 = 1;
+
+/// Size of the fixed header in bytes
+const HEADER_LEN: usize = 4 + 4 + 4 + 8 + 8;
+
+/// In-memory collection of embeddings with a fixed dimension
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbeddingStore {
+    /// Length of every stored vector
+    dimension: usize,
+    /// Stored `(id, vector)` pairs in insertion order
+    entries: Vec<(String, Vec<f32>)>,
+    /// Position of each id in `entries`
+    positions: HashMap<String, usize>,
+}
+
+impl EmbeddingStore {
+    /// Create an empty store for vectors of the given dimension
+    #[must_use]
+    pub fn new(dimension: usize) -> Self {
+        Self {
+            dimension,
+            entries: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Length of every vector in the store
+    #[must_use]
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Number of stored embeddings
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the store is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Insert or replace the embedding for `id`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vector length does not match the store dimension
+    pub fn insert(&mut self, id: impl Into<String>, vector: Vec<f32>) -> Result<()> {
+        if vector.len() != self.dimension {
+            return Err(OpenAIError::InvalidRequest(format!(
+                "Embedding has {} dimensions, store expects {}",
+                vector.len(),
+                self.dimension
+            )));
+        }
+
+        let id = id.into();
+        match self.positions.get(&id) {
+            Some(&position) => self.entries[position].1 = vector,
+            None => {
+                self.positions.insert(id.clone(), self.entries.len());
+                self.entries.push((id, vector));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the embedding stored for `id`
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&[f32]> {
+        self.positions
+            .get(id)
+            .map(|&position| self.entries[position].1.as_slice())
+    }
+
+    /// Iterate over stored `(id, vector)` pairs in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[f32])> {
+        self.entries
+            .iter()
+            .map(|(id, vector)| (id.as_str(), vector.as_slice()))
+    }
+
+    /// Serialize the store into its binary file format
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dimension, an id length or the record count
+    /// does not fit the header fields
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let dimension = u32::try_from(self.dimension).map_err(|_| {
+            OpenAIError::InvalidRequest(format!("Dimension {} is too large", self.dimension))
+        })?;
+
+        let mut payload = Vec::with_capacity(self.entries.len() * (8 + self.dimension * 4));
+        for (id, vector) in &self.entries {
+            let id_len = u32::try_from(id.len()).map_err(|_| {
+                OpenAIError::InvalidRequest(format!(
+                    "Embedding id of {} bytes is too long",
+                    id.len()
+                ))
+            })?;
+            payload.extend_from_slice(&id_len.to_le_bytes());
+            payload.extend_from_slice(id.as_bytes());
+            for value in vector {
+                payload.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&dimension.to_le_bytes());
+        bytes.extend_from_slice(&(self.entries.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&checksum(&payload).to_le_bytes());
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Parse a store from its binary file format
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error if the data is truncated, has the wrong magic or
+    /// version, fails the checksum, or contains malformed records
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader::new(bytes);
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(corrupt("missing embedding store header"));
+        }
+        let version = reader.read_u32()?;
+        if version != FORMAT_VERSION {
+            return Err(corrupt(&format!("unsupported format version {version}")));
+        }
+        let dimension = reader.read_u32()? as usize;
+        let count = reader.read_u64()?;
+        let expected_checksum = reader.read_u64()?;
+
+        if checksum(reader.remaining()) != expected_checksum {
+            return Err(corrupt("checksum mismatch"));
+        }
+
+        let mut store = Self::new(dimension);
+        for _ in 0..count {
+            let id_len = reader.read_u32()? as usize;
+            let id = std::str::from_utf8(reader.take(id_len)?)
+                .map_err(|_| corrupt("embedding id is not valid UTF-8"))?
+                .to_string();
+            let vector = reader
+                .take(dimension * 4)?
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect();
+            store.insert(id, vector)?;
+        }
+
+        if !reader.remaining().is_empty() {
+            return Err(corrupt("unexpected trailing data"));
+        }
+        Ok(store)
+    }
+
+    /// Write the store to `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the store cannot be serialized or the file cannot be written
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        crate::helpers::write_bytes_sync(path, &self.to_bytes()?)
+    }
+
+    /// Read a store previously written with [`Self::save`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is corrupted
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_bytes(&crate::helpers::read_bytes_sync(path)?)
+    }
+
+    /// Read a store and check that it holds vectors of the expected dimension
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, is corrupted, or was
+    /// written with a different dimension
+    pub fn load_with_dimension(path: impl AsRef<Path>, dimension: usize) -> Result<Self> {
+        let bytes = crate::helpers::read_bytes_sync(path)?;
+        let stored = bytes
+            .get(8..12)
+            .map(|field| u32::from_le_bytes([field[0], field[1], field[2], field[3]]) as usize);
+        if let Some(stored) = stored
+            && bytes.starts_with(MAGIC)
+            && stored != dimension
+        {
+            return Err(OpenAIError::InvalidRequest(format!(
+                "Embedding store has {stored} dimensions, expected {dimension}"
+            )));
+        }
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Cursor over a byte slice that reports truncation as a parse error
+struct ByteReader<'a> {
+    /// Bytes not yet consumed
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    /// Create a reader over `bytes`
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Consume the next `len` bytes
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(corrupt("file is truncated"));
+        }
+        let (head, tail) = self.bytes.split_at(len);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    /// Consume a little-endian `u32`
+    fn read_u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    /// Consume a little-endian `u64`
+    fn read_u64(&mut self) -> Result<u64> {
+        let mut buffer = [0u8; 8];
+        buffer.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(buffer))
+    }
+
+    /// Bytes not yet consumed
+    fn remaining(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+/// Build a parse error for a corrupted store
+fn corrupt(reason: &str) -> OpenAIError {
+    OpenAIError::ParseError(format!("Corrupted embedding store: {reason}"))
+}
+
+/// 64-bit FNV-1a hash used as the payload checksum
+fn checksum(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn sample_store() -> EmbeddingStore {
+        let mut store = EmbeddingStore::new(3);
+        store.insert("doc-1", vec![0.1, 0.2, 0.3]).unwrap();
+        store.insert("doc-é", vec![-1.0, 0.0, 1.5]).unwrap();
+        store
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("embeddings.bin");
+
+        let store = sample_store();
+        store.save(&path).unwrap();
+        let loaded = EmbeddingStore::load(&path).unwrap();
+
+        assert_eq!(loaded, store);
+        assert_eq!(loaded.get("doc-é"), Some(&[-1.0, 0.0, 1.5][..]));
+        let ids: Vec<&str> = loaded.iter().map(|(id, _)| id).collect();
+        assert_eq!(ids, vec!["doc-1", "doc-é"]);
+    }
+
+    #[test]
+    fn test_insert_rejects_wrong_dimension_and_replaces_ids() {
+        let mut store = sample_store();
+        assert!(store.insert("doc-3", vec![1.0]).is_err());
+
+        store.insert("doc-1", vec![9.0, 9.0, 9.0]).unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get("doc-1"), Some(&[9.0, 9.0, 9.0][..]));
+    }
+
+    #[test]
+    fn test_corruption_is_detected() {
+        let bytes = sample_store().to_bytes().unwrap();
+
+        let mut flipped = bytes.clone();
+        *flipped.last_mut().unwrap() ^= 0xFF;
+        assert!(matches!(
+            EmbeddingStore::from_bytes(&flipped),
+            Err(OpenAIError::ParseError(message)) if message.contains("checksum")
+        ));
+
+        assert!(EmbeddingStore::from_bytes(&bytes[..bytes.len() - 2]).is_err());
+        assert!(EmbeddingStore::from_bytes(&bytes[..10]).is_err());
+        assert!(EmbeddingStore::from_bytes(b"NOPE").is_err());
+    }
+
+    #[test]
+    fn test_load_with_dimension_fails_fast_on_mismatch() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("embeddings.bin");
+        sample_store().save(&path).unwrap();
+
+        assert!(EmbeddingStore::load_with_dimension(&path, 3).is_ok());
+        assert!(matches!(
+            EmbeddingStore::load_with_dimension(&path, 1536),
+            Err(OpenAIError::InvalidRequest(_))
+        ));
+    }
+}
@@ -52,7 +52,10 @@ pub struct Eval {
     pub description: Option<String>,
 
     /// Metadata key-value pairs attached to the eval
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// Configuration for the data source used by the eval
@@ -73,7 +76,10 @@ pub struct CreateEvalRequest {
     pub description: Option<String>,
 
     /// Metadata key-value pairs to attach to the eval
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// Configuration for the data source used by the eval
@@ -95,7 +101,10 @@ pub struct UpdateEvalRequest {
     pub description: Option<String>,
 
     /// Updated metadata key-value pairs
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -163,7 +172,10 @@ pub struct EvalRun {
     pub name: Option<String>,
 
     /// Metadata key-value pairs attached to the eval run
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// Counts of results by status
@@ -198,7 +210,10 @@ pub struct CreateEvalRunRequest {
     pub name: Option<String>,
 
     /// Metadata key-value pairs to attach to the eval run
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// The data source configuration for the eval run
@@ -0,0 +1,166 @@
+//! Three-state values for partial updates
+//!
+//! Modify endpoints treat a missing field as "keep the current value" and an
+//! explicit `null` as "clear it". A plain `Option` cannot tell those apart,
+//! so modify requests use [`FieldUpdate`] for fields the service lets you
+//! clear. Mark such fields with
+//! `#[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+/// A change to one field of an existing object
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum FieldUpdate<T> {
+    /// Leave the field as it is; the field is left out of the request body
+    #[default]
+    Unchanged,
+    /// Set the field to a new value
+    Set(T),
+    /// Clear the field; serialized as `null`
+    Clear,
+}
+
+impl<T> FieldUpdate<T> {
+    /// Check whether the field is left as it is
+    #[must_use]
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+
+    /// Check whether the field is set to a new value
+    #[must_use]
+    pub fn is_set(&self) -> bool {
+        matches!(self, Self::Set(_))
+    }
+
+    /// Check whether the field is cleared
+    #[must_use]
+    pub fn is_clear(&self) -> bool {
+        matches!(self, Self::Clear)
+    }
+
+    /// Get the new value, if the field is being set
+    #[must_use]
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Self::Set(value) => Some(value),
+            Self::Unchanged | Self::Clear => None,
+        }
+    }
+
+    /// Drop the update when it would leave `current` as it is
+    ///
+    /// Setting the current value, or clearing a field that is already empty,
+    /// becomes [`FieldUpdate::Unchanged`].
+    #[must_use]
+    pub fn against(self, current: Option<&T>) -> Self
+    where
+        T: PartialEq,
+    {
+        match (&self, current) {
+            (Self::Set(value), Some(current)) if value == current => Self::Unchanged,
+            (Self::Clear, None) => Self::Unchanged,
+            _ => self,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> FieldUpdate<HashMap<K, V, S>> {
+    /// Turn a map edited by a builder into an update against `current`
+    ///
+    /// `None` means the map was never touched. A map edited down to nothing
+    /// clears the field, and a map equal to `current` is left out.
+    pub(crate) fn from_edited_map(
+        edited: Option<HashMap<K, V, S>>,
+        current: &HashMap<K, V, S>,
+    ) -> Self {
+        let update = match edited {
+            None => Self::Unchanged,
+            Some(map) if map.is_empty() => Self::Clear,
+            Some(map) => Self::Set(map),
+        };
+        update.against((!current.is_empty()).then_some(current))
+    }
+}
+
+impl<T: Serialize> Serialize for FieldUpdate<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Set(value) => value.serialize(serializer),
+            Self::Unchanged | Self::Clear => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for FieldUpdate<T> {
+    /// Read `null` as [`FieldUpdate::Clear`]; a missing field needs
+    /// `#[serde(default)]` to become [`FieldUpdate::Unchanged`]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Option::<T>::deserialize(deserializer)?.map_or(Self::Clear, Self::Set))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Patch {
+        #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+        name: FieldUpdate<String>,
+    }
+
+    #[test]
+    fn test_serialization_of_each_state() {
+        let unchanged = Patch::default();
+        assert_eq!(serde_json::to_string(&unchanged).unwrap(), "{}");
+
+        let set = Patch {
+            name: FieldUpdate::Set("docs".to_string()),
+        };
+        assert_eq!(serde_json::to_string(&set).unwrap(), r#"{"name":"docs"}"#);
+
+        let clear = Patch {
+            name: FieldUpdate::Clear,
+        };
+        assert_eq!(serde_json::to_string(&clear).unwrap(), r#"{"name":null}"#);
+    }
+
+    #[test]
+    fn test_deserialization_of_each_state() {
+        let parse = |json: &str| serde_json::from_str::<Patch>(json).unwrap().name;
+        assert_eq!(parse("{}"), FieldUpdate::Unchanged);
+        assert_eq!(
+            parse(r#"{"name":"docs"}"#),
+            FieldUpdate::Set("docs".to_string())
+        );
+        assert_eq!(parse(r#"{"name":null}"#), FieldUpdate::Clear);
+    }
+
+    #[test]
+    fn test_against_current_value() {
+        let docs = "docs".to_string();
+        assert!(
+            FieldUpdate::Set(docs.clone())
+                .against(Some(&docs))
+                .is_unchanged()
+        );
+        assert!(FieldUpdate::Set(docs.clone()).against(None).is_set());
+        assert!(FieldUpdate::<String>::Clear.against(None).is_unchanged());
+        assert!(FieldUpdate::Clear.against(Some(&docs)).is_clear());
+    }
+
+    #[test]
+    fn test_from_edited_map() {
+        let current = HashMap::from([("team".to_string(), "docs".to_string())]);
+        let empty = HashMap::new();
+
+        assert!(FieldUpdate::from_edited_map(None, &current).is_unchanged());
+        assert!(FieldUpdate::from_edited_map(Some(current.clone()), &current).is_unchanged());
+        assert!(FieldUpdate::from_edited_map(Some(HashMap::new()), &current).is_clear());
+        assert!(FieldUpdate::from_edited_map(Some(HashMap::new()), &empty).is_unchanged());
+        assert!(FieldUpdate::from_edited_map(Some(current.clone()), &empty).is_set());
+    }
+}
@@ -36,9 +36,11 @@
 //! };
 //! ```
 
-use crate::api::base::Validate;
+use crate::models::shared_traits::{Validate, collect_errors};
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
 
@@ -178,8 +180,13 @@ pub struct File {
     /// Additional details about the file's status, if available
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status_details: Option<String>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(File);
+
 crate::impl_default_object_type!(default_file_status, "uploaded");
 
 impl File {
@@ -245,6 +252,7 @@ impl FileUploadRequest {
     }
 
     /// Create a file upload request from a file path
+    #[cfg(feature = "net")]
     pub async fn from_file_path(
         file_path: &std::path::Path,
         purpose: FilePurpose,
@@ -261,14 +269,17 @@ impl FileUploadRequest {
 
     /// Validate the file upload request
     pub fn validate(&self) -> Result<(), String> {
+        self.validate_file_data()?;
+        self.validate_filename()?;
+        self.validate_extension()
+    }
+
+    /// Check that the file is neither empty nor over the size limit
+    fn validate_file_data(&self) -> Result<(), String> {
         if self.file.is_empty() {
             return Err("File cannot be empty".to_string());
         }
 
-        if self.filename.is_empty() {
-            return Err("Filename cannot be empty".to_string());
-        }
-
         // Check file size limits (200MB for most purposes)
         const MAX_FILE_SIZE: usize = 200 * 1024 * 1024; // 200MB
         if self.file.len() > MAX_FILE_SIZE {
@@ -278,30 +289,39 @@ impl FileUploadRequest {
                 MAX_FILE_SIZE
             ));
         }
+        Ok(())
+    }
+
+    /// Check that a filename is given
+    fn validate_filename(&self) -> Result<(), String> {
+        if self.filename.is_empty() {
+            return Err("Filename cannot be empty".to_string());
+        }
+        Ok(())
+    }
 
-        // Validate file extension for certain purposes
+    /// Check the file extension required by the purpose
+    fn validate_extension(&self) -> Result<(), String> {
         match self.purpose {
             FilePurpose::FineTune if !self.filename.ends_with(".jsonl") => {
-                return Err("Fine-tuning files must be in JSONL format".to_string());
+                Err("Fine-tuning files must be in JSONL format".to_string())
             }
             FilePurpose::Batch if !self.filename.ends_with(".jsonl") => {
-                return Err("Batch files must be in JSONL format".to_string());
+                Err("Batch files must be in JSONL format".to_string())
             }
             FilePurpose::Vision => {
                 let valid_extensions = [".png", ".jpg", ".jpeg", ".gif", ".webp"];
-                if !valid_extensions
+                if valid_extensions
                     .iter()
                     .any(|ext| self.filename.to_lowercase().ends_with(ext))
                 {
-                    return Err(
-                        "Vision files must be images (PNG, JPG, JPEG, GIF, WebP)".to_string()
-                    );
+                    Ok(())
+                } else {
+                    Err("Vision files must be images (PNG, JPG, JPEG, GIF, WebP)".to_string())
                 }
             }
-            _ => {} // Other purposes allow various file types
+            _ => Ok(()), // Other purposes allow various file types
         }
-
-        Ok(())
     }
 
     /// Get the MIME type for the file based on its extension
@@ -349,6 +369,14 @@ impl Validate for FileUploadRequest {
     fn validate(&self) -> Result<(), String> {
         self.validate()
     }
+
+    fn validate_all(&self) -> Result<(), Vec<String>> {
+        collect_errors([
+            self.validate_file_data(),
+            self.validate_filename(),
+            self.validate_extension(),
+        ])
+    }
 }
 
 /// Response from the list files API endpoint
@@ -417,6 +445,7 @@ pub struct FileDeleteResponse {
 }
 
 crate::impl_response_constructors!(FileDeleteResponse, id, "file");
+crate::impl_delete_response!(FileDeleteResponse, "file");
 
 /// Parameters for listing files
 #[derive(Debug, Clone, Default)]
@@ -577,6 +606,20 @@ mod tests {
         assert!(wrong_extension_request.validate().is_err());
     }
 
+    #[test]
+    fn test_file_upload_request_validate_all_reports_every_error() {
+        let request = FileUploadRequest::new(Vec::new(), String::new(), FilePurpose::Batch);
+        let errors = Validate::validate_all(&request).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                "File cannot be empty",
+                "Filename cannot be empty",
+                "Batch files must be in JSONL format",
+            ]
+        );
+    }
+
     #[test]
     fn test_file_upload_request_mime_type() {
         let jsonl_request = FileUploadRequest::new(
@@ -605,6 +648,7 @@ mod tests {
             purpose: "user_data".to_string(),
             status: "uploaded".to_string(),
             status_details: None,
+            extra: HashMap::new(),
         };
         assert_eq!(file.size_human_readable(), "1.0 KB");
     }
@@ -635,6 +679,7 @@ mod tests {
                 purpose: "fine-tune".to_string(),
                 status: "uploaded".to_string(),
                 status_details: None,
+                extra: HashMap::new(),
             },
             File {
                 id: "file-2".to_string(),
@@ -645,6 +690,7 @@ mod tests {
                 purpose: "assistants".to_string(),
                 status: "uploaded".to_string(),
                 status_details: None,
+                extra: HashMap::new(),
             },
         ];
 
@@ -61,6 +61,8 @@ pub enum FineTuningJobStatus {
     Queued,
     /// Job is currently running
     Running,
+    /// Job was paused by the user and can be resumed
+    Paused,
     /// Job completed successfully
     Succeeded,
     /// Job failed with an error
@@ -72,7 +74,7 @@ pub enum FineTuningJobStatus {
 // Generate status enum methods using macro
 impl_status_enum!(FineTuningJobStatus, {
     terminal: [Succeeded, Failed, Cancelled],
-    active: [ValidatingFiles, Queued, Running],
+    active: [ValidatingFiles, Queued, Running, Paused],
     failed: [Failed],
     completed: [Succeeded],
 });
@@ -84,10 +86,13 @@ impl FineTuningJobStatus {
         matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
     }
 
-    /// Check if the job is currently active (validating, queued, or running)
+    /// Check if the job is currently active (validating, queued, running, or paused)
     #[must_use]
     pub fn is_active(&self) -> bool {
-        matches!(self, Self::ValidatingFiles | Self::Queued | Self::Running)
+        matches!(
+            self,
+            Self::ValidatingFiles | Self::Queued | Self::Running | Self::Paused
+        )
     }
 }
 
@@ -215,7 +220,10 @@ pub struct FineTuningJob {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<FineTuningError>,
     /// Up to 10 sets of key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -236,7 +244,10 @@ pub struct FineTuningJobRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub suffix: Option<String>,
     /// Up to 10 sets of key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -381,6 +392,49 @@ pub struct FineTuningJobCheckpoint {
     pub fine_tuning_job_id: String,
 }
 
+impl FineTuningJobCheckpoint {
+    /// Get the most representative loss recorded at this checkpoint
+    ///
+    /// Prefers full validation loss, then validation loss, then training
+    /// loss, since later ones are noisier estimates of model quality.
+    #[must_use]
+    pub fn loss(&self) -> Option<f64> {
+        self.metrics
+            .full_valid_loss
+            .or(self.metrics.valid_loss)
+            .or(self.metrics.train_loss)
+    }
+
+    /// Find the checkpoint with the lowest loss
+    ///
+    /// Losses are only compared within one metric: full validation loss if
+    /// every checkpoint has it, otherwise validation loss, otherwise training
+    /// loss. Checkpoints without any recorded loss are ignored. Returns
+    /// `None` when no metric is recorded by every remaining checkpoint.
+    #[must_use]
+    pub fn best(checkpoints: &[Self]) -> Option<&Self> {
+        let candidates: Vec<&Self> = checkpoints
+            .iter()
+            .filter(|checkpoint| checkpoint.loss().is_some())
+            .collect();
+        let metrics: [fn(&CheckpointMetrics) -> Option<f64>; 3] = [
+            |metrics| metrics.full_valid_loss,
+            |metrics| metrics.valid_loss,
+            |metrics| metrics.train_loss,
+        ];
+        let metric = metrics.into_iter().find(|metric| {
+            candidates
+                .iter()
+                .all(|checkpoint| metric(&checkpoint.metrics).is_some())
+        })?;
+        candidates
+            .into_iter()
+            .filter_map(|checkpoint| metric(&checkpoint.metrics).map(|loss| (checkpoint, loss)))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(checkpoint, _)| checkpoint)
+    }
+}
+
 /// Default object type for fine-tuning job checkpoints
 fn default_checkpoint_object_type() -> String {
     "fine_tuning.job.checkpoint".to_string()
@@ -530,4 +584,54 @@ mod tests {
         assert_eq!(params.after, Some("ft-job-123".to_string()));
         assert_eq!(params.limit, Some(50));
     }
+
+    fn checkpoint(id: &str, step: u32, metrics: CheckpointMetrics) -> FineTuningJobCheckpoint {
+        FineTuningJobCheckpoint {
+            id: id.to_string(),
+            object: default_checkpoint_object_type(),
+            created_at: 0,
+            fine_tuned_model_checkpoint: format!("ft:gpt-4o-mini:org::{id}"),
+            step_number: step,
+            metrics,
+            fine_tuning_job_id: "ftjob-1".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_best_checkpoint_prefers_validation_loss() {
+        let metrics = |train_loss, valid_loss| CheckpointMetrics {
+            train_loss,
+            train_mean_token_accuracy: None,
+            valid_loss,
+            valid_mean_token_accuracy: None,
+            full_valid_loss: None,
+            full_valid_mean_token_accuracy: None,
+        };
+        let checkpoints = vec![
+            checkpoint("ckpt-1", 100, metrics(Some(0.9), Some(0.8))),
+            checkpoint("ckpt-2", 200, metrics(Some(0.2), Some(0.6))),
+            checkpoint("ckpt-3", 300, metrics(Some(0.1), Some(0.7))),
+            checkpoint("ckpt-4", 400, metrics(None, None)),
+        ];
+
+        assert_eq!(checkpoints[2].loss(), Some(0.7));
+        let best = FineTuningJobCheckpoint::best(&checkpoints).unwrap();
+        assert_eq!(best.step_number, 200);
+        assert!(FineTuningJobCheckpoint::best(&checkpoints[3..]).is_none());
+
+        // Mixed metrics fall back to the one every checkpoint has
+        let mixed = vec![
+            checkpoint("ckpt-1", 100, metrics(Some(0.3), Some(0.9))),
+            checkpoint("ckpt-2", 200, metrics(Some(0.5), None)),
+        ];
+        assert_eq!(
+            FineTuningJobCheckpoint::best(&mixed).unwrap().step_number,
+            100
+        );
+        let unrelated = vec![
+            checkpoint("ckpt-1", 100, metrics(None, Some(0.9))),
+            checkpoint("ckpt-2", 200, metrics(Some(0.5), None)),
+        ];
+        assert!(FineTuningJobCheckpoint::best(&unrelated).is_none());
+    }
 }
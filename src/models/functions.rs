@@ -1,3 +1,4 @@
+use crate::models::tools::check_tool_names;
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
 use serde_json::Value;
@@ -161,6 +162,36 @@ impl FunctionTool {
         self
     }
 
+    /// Check that the parameter schema can be used in strict mode
+    ///
+    /// Strict mode needs an object at the root, and every object in the schema
+    /// must set `additionalProperties: false` and list all of its properties
+    /// in `required`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first incompatible location in the schema.
+    pub fn check_strict_compatible(&self) -> Result<(), String> {
+        if self.parameters.get("type").and_then(Value::as_str) != Some("object") {
+            return Err(format!(
+                "function '{}': strict mode requires an object parameter schema",
+                self.name
+            ));
+        }
+        strict_schema_violation(&self.parameters, "parameters").map_or(Ok(()), |violation| {
+            Err(format!("function '{}': {violation}", self.name))
+        })
+    }
+
+    /// Return this function with its parameter schema made strict-compatible
+    ///
+    /// See [`sanitize_for_strict`].
+    #[must_use]
+    pub fn sanitized_for_strict(mut self) -> Self {
+        self.parameters = sanitize_for_strict(&self.parameters);
+        self
+    }
+
     /// Create a simple function with no parameters
     pub fn simple(name: impl Into<String>, description: impl Into<String>) -> Self {
         Self::new(
@@ -338,9 +369,223 @@ impl FunctionCallOutput {
     }
 }
 
+/// Maximum number of tools `OpenAI` accepts in a single request
+pub const MAX_TOOLS: usize = 128;
+
+/// Maximum length of a function name
+pub const MAX_FUNCTION_NAME_LENGTH: usize = 64;
+
+/// Maximum length of a function description
+pub const MAX_FUNCTION_DESCRIPTION_LENGTH: usize = 1024;
+
+/// Check a list of tools against `OpenAI`'s request limits
+///
+/// Rejects more than [`MAX_TOOLS`] tools, duplicate names, names that reuse a
+/// built-in tool type, names that do not match `^[a-zA-Z0-9_-]{1,64}$`, and
+/// descriptions longer than [`MAX_FUNCTION_DESCRIPTION_LENGTH`] characters.
+///
+/// # Errors
+///
+/// Returns a message describing the first tool that breaks a limit
+pub fn validate_tools(tools: &[Tool]) -> Result<(), String> {
+    if tools.len() > MAX_TOOLS {
+        return Err(format!(
+            "Too many tools: {} given, at most {MAX_TOOLS} allowed",
+            tools.len()
+        ));
+    }
+
+    check_tool_names(tools.iter().map(Tool::name).enumerate())?;
+    for (index, tool) in tools.iter().enumerate() {
+        let name = tool.name();
+        if !is_valid_function_name(name) {
+            return Err(format!(
+                "tools[{index}] has invalid name '{name}': names must be 1-{MAX_FUNCTION_NAME_LENGTH} \
+                 characters of letters, digits, underscores or dashes"
+            ));
+        }
+        let description_length = tool.description().chars().count();
+        if description_length > MAX_FUNCTION_DESCRIPTION_LENGTH {
+            return Err(format!(
+                "tools[{index}] '{name}' has a description of {description_length} characters, \
+                 at most {MAX_FUNCTION_DESCRIPTION_LENGTH} allowed"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Check a name against `^[a-zA-Z0-9_-]{1,64}$`
+fn is_valid_function_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_FUNCTION_NAME_LENGTH
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Adjust a JSON schema to meet the requirements of strict function calling
+///
+/// Every object in the schema, including nested properties, array items,
+/// `anyOf` branches and definitions, gets `additionalProperties: false` and
+/// lists all of its properties in `required`. Properties that were optional
+/// become nullable instead, so the model can still leave them empty.
+#[must_use]
+pub fn sanitize_for_strict(schema: &Value) -> Value {
+    let mut schema = schema.clone();
+    sanitize_in_place(&mut schema);
+    schema
+}
+
+/// Apply [`sanitize_for_strict`] to a schema in place
+fn sanitize_in_place(schema: &mut Value) {
+    let Value::Object(map) = schema else {
+        return;
+    };
+
+    let is_object =
+        map.get("type").and_then(Value::as_str) == Some("object") || map.contains_key("properties");
+    if is_object {
+        map.insert("additionalProperties".to_string(), Value::Bool(false));
+        let mut required: Vec<String> = map
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|fields| {
+                fields
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+        if let Some(Value::Object(properties)) = map.get_mut("properties") {
+            for (name, property) in properties.iter_mut() {
+                if !required.contains(name) {
+                    make_nullable(property);
+                    required.push(name.clone());
+                }
+                sanitize_in_place(property);
+            }
+        }
+        map.insert("required".to_string(), serde_json::json!(required));
+    }
+
+    if let Some(items) = map.get_mut("items") {
+        sanitize_in_place(items);
+    }
+    for keyword in ["anyOf", "$defs", "definitions"] {
+        match map.get_mut(keyword) {
+            Some(Value::Array(schemas)) => schemas.iter_mut().for_each(sanitize_in_place),
+            Some(Value::Object(schemas)) => schemas.values_mut().for_each(sanitize_in_place),
+            _ => {}
+        }
+    }
+}
+
+/// Allow `null` for a property schema
+///
+/// A declared `type` gains `"null"` and an `enum` gains a `null` value.
+/// Schemas without a type, such as `$ref` or `anyOf`, get a `{"type": "null"}`
+/// branch, wrapping the original schema in `anyOf` if needed.
+fn make_nullable(schema: &mut Value) {
+    let Some(map) = schema.as_object_mut() else {
+        return;
+    };
+    if let Some(Value::Array(values)) = map.get_mut("enum")
+        && !values.contains(&Value::Null)
+    {
+        values.push(Value::Null);
+    }
+    let nullable = match map.get("type") {
+        Some(Value::String(kind)) if kind != "null" => serde_json::json!([kind, "null"]),
+        Some(Value::Array(kinds)) if !kinds.contains(&Value::from("null")) => {
+            let mut kinds = kinds.clone();
+            kinds.push(Value::from("null"));
+            Value::Array(kinds)
+        }
+        Some(_) => return,
+        None if map.contains_key("enum") => return,
+        None => {
+            let null_branch = serde_json::json!({"type": "null"});
+            if let Some(Value::Array(branches)) = map.get_mut("anyOf") {
+                if !branches.contains(&null_branch) {
+                    branches.push(null_branch);
+                }
+            } else {
+                let inner = std::mem::take(schema);
+                *schema = serde_json::json!({"anyOf": [inner, null_branch]});
+            }
+            return;
+        }
+    };
+    map.insert("type".to_string(), nullable);
+}
+
+/// Find the first place in a schema that strict mode would reject
+pub(crate) fn strict_schema_violation(schema: &Value, path: &str) -> Option<String> {
+    let Value::Object(map) = schema else {
+        return None;
+    };
+
+    let is_object =
+        map.get("type").and_then(Value::as_str) == Some("object") || map.contains_key("properties");
+    if is_object {
+        if map.get("additionalProperties") != Some(&Value::Bool(false)) {
+            return Some(format!("{path} must set additionalProperties to false"));
+        }
+        let required: Vec<&str> = map
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|fields| fields.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        if let Some(properties) = map.get("properties").and_then(Value::as_object) {
+            if let Some(missing) = properties
+                .keys()
+                .find(|name| !required.contains(&name.as_str()))
+            {
+                return Some(format!("{path} must list '{missing}' in required"));
+            }
+            for (name, property) in properties {
+                if let Some(violation) =
+                    strict_schema_violation(property, &format!("{path}.{name}"))
+                {
+                    return Some(violation);
+                }
+            }
+        }
+    }
+
+    if let Some(items) = map.get("items")
+        && let Some(violation) = strict_schema_violation(items, &format!("{path}[]"))
+    {
+        return Some(violation);
+    }
+    for keyword in ["anyOf", "$defs", "definitions"] {
+        let nested: Vec<(String, &Value)> = match map.get(keyword) {
+            Some(Value::Array(schemas)) => schemas
+                .iter()
+                .enumerate()
+                .map(|(i, schema)| (format!("{path}.{keyword}[{i}]"), schema))
+                .collect(),
+            Some(Value::Object(schemas)) => schemas
+                .iter()
+                .map(|(name, schema)| (format!("{path}.{keyword}.{name}"), schema))
+                .collect(),
+            _ => Vec::new(),
+        };
+        for (nested_path, nested_schema) in nested {
+            if let Some(violation) = strict_schema_violation(nested_schema, &nested_path) {
+                return Some(violation);
+            }
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeSet;
 
     #[test]
     fn test_function_tool_creation() {
@@ -436,4 +681,178 @@ mod tests {
         assert_eq!(json_output.call_id, "call-456");
         assert!(json_output.output.contains("temperature"));
     }
+
+    #[test]
+    fn test_strict_compatibility_check() {
+        assert!(
+            FunctionTool::simple("noop", "Nothing")
+                .check_strict_compatible()
+                .is_ok()
+        );
+
+        let open = FunctionTool::new(
+            "search",
+            "Search",
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "query": {"type": "string"},
+                    "filters": {
+                        "type": "object",
+                        "properties": {"lang": {"type": "string"}},
+                        "required": ["lang"]
+                    }
+                },
+                "required": ["query", "filters"],
+                "additionalProperties": false
+            }),
+        );
+        let err = open.check_strict_compatible().unwrap_err();
+        assert!(err.contains("parameters.filters must set additionalProperties"));
+
+        let not_object = FunctionTool::new("echo", "Echo", serde_json::json!({"type": "string"}));
+        assert!(not_object.check_strict_compatible().is_err());
+    }
+
+    #[test]
+    fn test_sanitize_for_strict_is_recursive() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "query": {"type": "string"},
+                "limit": {"type": "integer"},
+                "filters": {
+                    "type": "object",
+                    "properties": {"lang": {"type": "string"}}
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {"type": "object", "properties": {"name": {"type": "string"}}}
+                }
+            },
+            "required": ["query"]
+        });
+
+        let sanitized = sanitize_for_strict(&schema);
+        assert_eq!(sanitized["additionalProperties"], false);
+        // The order of the added names depends on whether serde_json
+        // preserves key order, so compare `required` as a set
+        let required = sanitized["required"].as_array().unwrap();
+        let required: BTreeSet<&str> = required.iter().filter_map(Value::as_str).collect();
+        let properties: BTreeSet<&str> = sanitized["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(required, properties);
+        assert_eq!(sanitized["required"].as_array().unwrap().len(), 4);
+        assert_eq!(sanitized["properties"]["query"]["type"], "string");
+        for (name, kind) in [
+            ("limit", "integer"),
+            ("filters", "object"),
+            ("tags", "array"),
+        ] {
+            assert_eq!(
+                sanitized["properties"][name]["type"],
+                serde_json::json!([kind, "null"])
+            );
+        }
+        assert_eq!(
+            sanitized["properties"]["filters"]["additionalProperties"],
+            false
+        );
+        assert_eq!(
+            sanitized["properties"]["tags"]["items"]["required"],
+            serde_json::json!(["name"])
+        );
+
+        let tool = FunctionTool::new("search", "Search", schema).sanitized_for_strict();
+        assert!(tool.check_strict_compatible().is_ok());
+    }
+
+    #[test]
+    fn test_sanitize_for_strict_makes_untyped_optionals_nullable() {
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "unit": {"enum": ["celsius", "fahrenheit"]},
+                "mode": {"type": "string", "enum": ["fast", "exact"]},
+                "location": {"$ref": "#/$defs/location"},
+                "id": {"anyOf": [{"type": "string"}, {"type": "integer"}]}
+            },
+            "required": [],
+            "$defs": {
+                "location": {"type": "object", "properties": {"city": {"type": "string"}}}
+            }
+        });
+
+        let sanitized = sanitize_for_strict(&schema);
+        let properties = &sanitized["properties"];
+        assert_eq!(
+            properties["unit"],
+            serde_json::json!({"enum": ["celsius", "fahrenheit", null]})
+        );
+        assert_eq!(
+            properties["mode"],
+            serde_json::json!({"type": ["string", "null"], "enum": ["fast", "exact", null]})
+        );
+        assert_eq!(
+            properties["location"],
+            serde_json::json!({"anyOf": [{"$ref": "#/$defs/location"}, {"type": "null"}]})
+        );
+        assert_eq!(
+            properties["id"]["anyOf"],
+            serde_json::json!([{"type": "string"}, {"type": "integer"}, {"type": "null"}])
+        );
+        assert_eq!(sanitized["required"].as_array().unwrap().len(), 4);
+
+        let tool = FunctionTool::new("convert", "Convert", schema).sanitized_for_strict();
+        assert!(tool.check_strict_compatible().is_ok());
+    }
+
+    #[test]
+    fn test_validate_tools_limits() {
+        let tool = |name: &str| Tool::function(FunctionTool::simple(name, "Does a thing"));
+        assert!(validate_tools(&[tool("get_weather"), tool("lookup-2")]).is_ok());
+        assert!(validate_tools(&[tool("")]).is_err());
+        assert!(validate_tools(&[tool(&"a".repeat(65))]).is_err());
+
+        let too_many: Vec<Tool> = (0..=MAX_TOOLS).map(|i| tool(&format!("f{i}"))).collect();
+        assert!(
+            validate_tools(&too_many)
+                .unwrap_err()
+                .contains("Too many tools")
+        );
+
+        let long = Tool::function(FunctionTool::simple("long", "x".repeat(1025)));
+        assert!(
+            validate_tools(&[long])
+                .unwrap_err()
+                .contains("1025 characters")
+        );
+    }
+
+    #[test]
+    fn test_validate_tools_rejects_ambiguous_names() {
+        let tool = |name: &str| Tool::function(FunctionTool::simple(name, "Does a thing"));
+
+        let err = validate_tools(&[tool("lookup"), tool("other"), tool("lookup")]).unwrap_err();
+        assert_eq!(err, "tools[0] and tools[2] are both named 'lookup'");
+
+        let err = validate_tools(&[tool("file_search")]).unwrap_err();
+        assert!(err.contains("built-in tool type"));
+    }
+
+    #[test]
+    fn test_response_request_rejects_function_shadowing_built_in_tool() {
+        use crate::models::responses::ResponseRequest;
+        use crate::models::tools::{EnhancedTool, ToolBuilder};
+
+        let request = ResponseRequest::new_text("gpt-4o", "Hi").with_enhanced_tools(vec![
+            EnhancedTool::WebSearchPreview,
+            ToolBuilder::function("web_search", "Search").build(),
+        ]);
+        assert!(request.validate().unwrap_err().contains("tools[1]"));
+    }
 }
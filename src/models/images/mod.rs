@@ -28,7 +28,7 @@ pub use types::*;
 pub use common::ImageRequestCommon;
 
 // Re-export validation for advanced users
-pub use validation::validate_request;
+pub use validation::{max_prompt_length, validate_prompt_length, validate_request};
 
 #[cfg(test)]
 mod tests {
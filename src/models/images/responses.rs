@@ -77,6 +77,7 @@ impl ImageData {
     }
 
     /// Save base64 image data to file
+    #[cfg(feature = "net")]
     pub async fn save_b64_to_file(&self, path: impl AsRef<std::path::Path>) -> Result<(), String> {
         let data = self.decode_b64_json()?;
         tokio::fs::write(path, data)
@@ -21,6 +21,14 @@ pub mod errors {
         "DALL-E 2 does not support 1792x1024 or 1024x1792 sizes";
 }
 
+/// Maximum prompt lengths, in characters
+pub mod limits {
+    /// Maximum prompt length accepted by DALL-E 2
+    pub const DALL_E_2_MAX_PROMPT_CHARS: usize = 1000;
+    /// Maximum prompt length accepted by DALL-E 3
+    pub const DALL_E_3_MAX_PROMPT_CHARS: usize = 4000;
+}
+
 /// Model name constants for validation
 pub mod models {
     /// DALL-E 3 model identifier
@@ -31,6 +39,7 @@ pub mod models {
 
 /// Validate DALL-E 3 specific parameters
 pub fn validate_dall_e_3(request: &ImageGenerationRequest) -> Result<(), String> {
+    validate_prompt_length(&request.model, &request.prompt)?;
     validate_dall_e_3_image_count(request)?;
     validate_dall_e_3_image_size(request)?;
     Ok(())
@@ -38,11 +47,39 @@ pub fn validate_dall_e_3(request: &ImageGenerationRequest) -> Result<(), String>
 
 /// Validate DALL-E 2 specific parameters
 pub fn validate_dall_e_2(request: &ImageGenerationRequest) -> Result<(), String> {
+    validate_prompt_length(&request.model, &request.prompt)?;
     validate_dall_e_2_quality_and_style(request)?;
     validate_dall_e_2_image_size(request)?;
     Ok(())
 }
 
+/// Get the maximum prompt length for a model, if it has a known limit
+#[must_use]
+pub fn max_prompt_length(model: &str) -> Option<usize> {
+    match model {
+        models::DALL_E_2 => Some(limits::DALL_E_2_MAX_PROMPT_CHARS),
+        models::DALL_E_3 => Some(limits::DALL_E_3_MAX_PROMPT_CHARS),
+        _ => None,
+    }
+}
+
+/// Validate the prompt length for a model
+///
+/// Trailing whitespace is not counted, since it is trimmed before the
+/// request is sent.
+pub fn validate_prompt_length(model: &str, prompt: &str) -> Result<(), String> {
+    let Some(limit) = max_prompt_length(model) else {
+        return Ok(());
+    };
+    let length = prompt.trim_end().chars().count();
+    if length > limit {
+        return Err(format!(
+            "Prompt is {length} characters but {model} accepts at most {limit}"
+        ));
+    }
+    Ok(())
+}
+
 /// Validate DALL-E 3 image count parameter
 fn validate_dall_e_3_image_count(request: &ImageGenerationRequest) -> Result<(), String> {
     if let Some(n) = request.n
@@ -174,6 +211,26 @@ mod tests {
         assert!(validate_request(&invalid_dall_e_2).is_err());
     }
 
+    #[test]
+    fn test_prompt_length_limits() {
+        let mut req = create_test_request(models::DALL_E_2);
+        req.prompt = "a".repeat(limits::DALL_E_2_MAX_PROMPT_CHARS) + "   \n";
+        assert!(validate_request(&req).is_ok());
+
+        req.prompt = "a".repeat(limits::DALL_E_2_MAX_PROMPT_CHARS + 1);
+        assert_eq!(
+            validate_request(&req).unwrap_err(),
+            "Prompt is 1001 characters but dall-e-2 accepts at most 1000"
+        );
+
+        req.model = models::DALL_E_3.to_string();
+        assert!(validate_request(&req).is_ok());
+        req.prompt = "é".repeat(limits::DALL_E_3_MAX_PROMPT_CHARS + 1);
+        assert!(validate_request(&req).unwrap_err().contains("at most 4000"));
+
+        assert!(validate_prompt_length("gpt-image-1", &"a".repeat(10_000)).is_ok());
+    }
+
     #[test]
     fn test_error_messages() {
         let mut req = create_test_request(models::DALL_E_3);
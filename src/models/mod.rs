@@ -2,6 +2,8 @@
 pub mod common;
 /// Common builder patterns to reduce duplication
 pub mod common_builder;
+/// Three-state field values for partial updates
+pub mod field_update;
 /// Shared traits and implementations to eliminate model duplication
 pub mod shared_traits;
 
@@ -36,6 +38,8 @@ pub mod models;
 pub mod moderations;
 /// Modular moderations models for content policy classification
 pub mod moderations_modular;
+/// Token pricing tables and cost tracking for usage statistics
+pub mod pricing;
 /// Real-time audio models for WebRTC streaming
 pub mod realtime_audio;
 /// Response models and data structures
@@ -50,6 +54,8 @@ pub mod skills;
 pub mod threads;
 /// Comprehensive tools support (web search, file search, MCP, etc.)
 pub mod tools;
+/// Export of captured conversations as fine-tuning training data
+pub mod training_data;
 /// Uploads models for multipart large file uploads
 pub mod uploads;
 /// Vector stores models for RAG and knowledge management
@@ -60,6 +66,9 @@ pub mod videos;
 // Re-export commonly used types while avoiding conflicts
 // For conflicting types, users should import from specific modules
 
+pub use field_update::FieldUpdate;
+pub use shared_traits::{AsText, ExtraFields};
+
 // Admin API
 pub use admin::{
     AuditLog, AuditLogList, CreateInviteRequest, CreateProjectRequest,
@@ -76,6 +85,7 @@ pub use admin::{
 // Assistants API
 pub use assistants::{
     Assistant, AssistantRequest, AssistantTool, ListAssistantsParams, ListAssistantsResponse,
+    ModifyAssistantRequest, ModifyAssistantRequestBuilder,
 };
 
 // Audio API - explicit exports to avoid conflicts
@@ -116,12 +126,16 @@ pub use files::{
 
 // Fine-tuning API
 pub use fine_tuning::*;
+pub use training_data::{
+    ExampleTokens, TrainingExample, TrainingFileReport, TrainingFunction, TrainingLineError,
+    TrainingMessage, TrainingToolCall, TrainingTurn, to_finetune_jsonl,
+};
 
 // Functions API
 pub use functions::{
     AllowedToolSelection, CustomTool, FunctionCall as FunctionCallType, FunctionCallOutput,
     FunctionTool as FunctionToolType, FunctionToolSelection, Grammar, Tool,
-    ToolChoice as FunctionToolChoice,
+    ToolChoice as FunctionToolChoice, sanitize_for_strict, validate_tools,
 };
 
 // GPT-5 API
@@ -150,6 +164,11 @@ pub use moderations::{
     ModerationResult,
 };
 
+// Pricing
+pub use pricing::{
+    CostEndpoint, CostTracker, ModelCost, ModelPricing, PricingTable, TrainingCostEstimate,
+};
+
 // Real-time Audio API
 pub use realtime_audio::*;
 
@@ -160,23 +179,24 @@ pub use responses::{
     ResponseInput, ResponseOutput, ResponseRequest, SchemaValidationResult, TextContent, Usage,
 };
 pub use responses_v2::{
-    Annotation as ResponsesApiAnnotation,
+    Annotation as ResponsesApiAnnotation, CitationSpan,
     CompletionTokenDetails as ResponsesApiCompletionTokenDetails,
     ContentPart as ResponsesApiContentPart, ConversationObject as ResponsesApiConversationObject,
     ConversationReference as ResponsesApiConversationReference, CreateResponseRequest,
-    Instructions as ResponsesApiInstructions, PromptTokenDetails as ResponsesApiPromptTokenDetails,
-    ResponseError as ResponsesApiError, ResponseInput as ResponsesApiInput,
-    ResponseItem as ResponsesApiItem, ResponseObject, ResponseStatus, ResponseStreamEvent,
+    FileCitation as ResponsesApiFileCitation, Instructions as ResponsesApiInstructions,
+    PromptTokenDetails as ResponsesApiPromptTokenDetails, ResponseError as ResponsesApiError,
+    ResponseInput as ResponsesApiInput, ResponseItem as ResponsesApiItem, ResponseObject,
+    ResponseStatus, ResponseStreamAssembler, ResponseStreamEvent,
     ResponseUsage as ResponsesApiUsage, ServiceTier as ResponsesApiServiceTier,
     StreamOptions as ResponsesApiStreamOptions,
 };
 
 // Runs API
 pub use runs::{
-    CreateThreadAndRunRequest, FunctionCall as RunFunctionCall, ListRunStepsParams,
-    ListRunStepsResponse, ListRunsParams, ListRunsResponse, RequiredAction, Run, RunError,
-    RunRequest, RunRequestBuilder, RunStatus, RunStep, RunStepStatus, StepDetails,
-    SubmitToolOutputsRequest, ToolOutput, Usage as RunUsage,
+    AssistantStreamEvent, CreateThreadAndRunRequest, FunctionCall as RunFunctionCall,
+    ListRunStepsParams, ListRunStepsResponse, ListRunsParams, ListRunsResponse, RequiredAction,
+    Run, RunError, RunRequest, RunRequestBuilder, RunStatus, RunStep, RunStepStatus, StepDetails,
+    StepToolCall, SubmitToolOutputsRequest, ToolOutput, Usage as RunUsage, step_tool_calls,
 };
 
 // Skills API
@@ -190,8 +210,9 @@ pub use skills::{
 pub use threads::{
     Annotation, DeletionStatus, FileCitation, FilePathInfo, ImageFile, ListMessageFilesResponse,
     ListMessagesParams, ListMessagesResponse, ListThreadsResponse, Message, MessageFile,
-    MessageRequest, MessageRequestBuilder, MessageRole as ThreadMessageRole,
-    SortOrder as ThreadSortOrder, Thread, ThreadRequest, ThreadRequestBuilder,
+    MessageRequest, MessageRequestBuilder, MessageRole as ThreadMessageRole, ModifyThreadRequest,
+    ModifyThreadRequestBuilder, SortOrder as ThreadSortOrder, Thread, ThreadRequest,
+    ThreadRequestBuilder,
 };
 
 // Tools API
@@ -199,7 +220,8 @@ pub use tools::{
     CodeInterpreterBuilder, CodeInterpreterConfig, ComputerUseBuilder, ComputerUseConfig,
     EnhancedTool, EnhancedToolChoice, FileSearchBuilder, FileSearchConfig, FunctionBuilder,
     FunctionTool, ImageGenerationConfig, ImageGenerationToolBuilder, McpApproval, McpBuilder,
-    McpTool, SearchFilters, SpecificToolChoice, ToolBuilder, WebSearchBuilder, WebSearchConfig,
+    McpTool, SearchContextSize, SearchFilters, SpecificToolChoice, ToolBuilder, UserLocation,
+    WebSearchBuilder, WebSearchConfig,
 };
 
 // Uploads API
@@ -214,7 +236,8 @@ pub use videos::{
 // Vector Stores API
 pub use vector_stores::{
     ChunkingStrategy, ExpirationPolicy, FileCounts, ListVectorStoreFilesParams,
-    ListVectorStoreFilesResponse, ListVectorStoresParams, ListVectorStoresResponse, VectorStore,
+    ListVectorStoreFilesResponse, ListVectorStoresParams, ListVectorStoresResponse,
+    ModifyVectorStoreRequest, ModifyVectorStoreRequestBuilder, VectorStore,
     VectorStoreDeleteResponse, VectorStoreFile, VectorStoreFileBatch, VectorStoreFileBatchRequest,
     VectorStoreFileBatchStatus, VectorStoreFileDeleteResponse, VectorStoreFileError,
     VectorStoreFileRequest, VectorStoreFileStatus, VectorStoreRequest, VectorStoreRequestBuilder,
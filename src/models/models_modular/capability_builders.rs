@@ -4,8 +4,32 @@
 
 use super::enums::{CompletionType, ModelFamily, ModelTier};
 use super::types::ModelCapabilities;
+use crate::models::gpt5::ReasoningEffort;
 
 impl ModelCapabilities {
+    /// Create capabilities with no optional features for a family and tier
+    ///
+    /// Sampling controls are accepted; every other capability is off and
+    /// limits and costs are unknown.
+    #[must_use]
+    pub fn new(family: ModelFamily, tier: ModelTier) -> Self {
+        Self {
+            max_tokens: None,
+            training_cutoff: None,
+            completion_types: Vec::new(),
+            supports_function_calling: false,
+            supports_vision: false,
+            supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: true,
+            family,
+            tier,
+            input_cost_per_1m_tokens: None,
+            output_cost_per_1m_tokens: None,
+        }
+    }
+
     /// Create capabilities for GPT-4o models
     pub(crate) fn gpt4o_capabilities(family: ModelFamily, tier: ModelTier) -> Self {
         Self {
@@ -15,6 +39,9 @@ impl ModelCapabilities {
             supports_function_calling: true,
             supports_vision: true,
             supports_code_interpreter: true,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: true,
             family,
             tier,
             input_cost_per_1m_tokens: Some(5.0),
@@ -22,6 +49,81 @@ impl ModelCapabilities {
         }
     }
 
+    /// Create capabilities for GPT-5 models
+    ///
+    /// The chat-tuned snapshots are not reasoning models: they take sampling
+    /// controls but no reasoning effort or verbosity.
+    pub(crate) fn gpt5_capabilities(model_id: &str, family: ModelFamily, tier: ModelTier) -> Self {
+        let is_chat = model_id.contains("chat");
+        let (input_cost, output_cost) = if model_id.contains("nano") {
+            (0.05, 0.40)
+        } else if model_id.contains("mini") {
+            (0.25, 2.00)
+        } else {
+            (1.25, 10.00)
+        };
+
+        Self {
+            max_tokens: Some(if is_chat { 128_000 } else { 400_000 }),
+            training_cutoff: Some("2024-09".to_string()),
+            completion_types: vec![CompletionType::Chat, CompletionType::Code],
+            supports_function_calling: !is_chat,
+            supports_vision: true,
+            supports_code_interpreter: true,
+            reasoning_efforts: if is_chat {
+                Vec::new()
+            } else {
+                vec![
+                    ReasoningEffort::Minimal,
+                    ReasoningEffort::Low,
+                    ReasoningEffort::Medium,
+                    ReasoningEffort::High,
+                ]
+            },
+            supports_verbosity: !is_chat,
+            supports_sampling: is_chat,
+            family,
+            tier,
+            input_cost_per_1m_tokens: Some(input_cost),
+            output_cost_per_1m_tokens: Some(output_cost),
+        }
+    }
+
+    /// Create capabilities for O-series reasoning models
+    pub(crate) fn reasoning_capabilities(
+        model_id: &str,
+        family: ModelFamily,
+        tier: ModelTier,
+    ) -> Self {
+        let is_o1_mini = model_id.starts_with("o1-mini");
+        let is_text_only = is_o1_mini || model_id.starts_with("o3-mini");
+
+        Self {
+            max_tokens: Some(if is_o1_mini { 128_000 } else { 200_000 }),
+            training_cutoff: Some("2023-10".to_string()),
+            completion_types: vec![CompletionType::Chat, CompletionType::Code],
+            supports_function_calling: !is_o1_mini,
+            supports_vision: !is_text_only,
+            supports_code_interpreter: true,
+            // o1-mini reasons, but does not accept a `reasoning_effort` setting
+            reasoning_efforts: if is_o1_mini {
+                Vec::new()
+            } else {
+                vec![
+                    ReasoningEffort::Low,
+                    ReasoningEffort::Medium,
+                    ReasoningEffort::High,
+                ]
+            },
+            supports_verbosity: false,
+            supports_sampling: false,
+            family,
+            tier,
+            input_cost_per_1m_tokens: None,
+            output_cost_per_1m_tokens: None,
+        }
+    }
+
     /// Create capabilities for GPT-4 Turbo models
     pub(crate) fn gpt4_turbo_capabilities(
         model_id: &str,
@@ -35,6 +137,9 @@ impl ModelCapabilities {
             supports_function_calling: true,
             supports_vision: model_id.contains("vision"),
             supports_code_interpreter: true,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: true,
             family,
             tier,
             input_cost_per_1m_tokens: Some(10.0),
@@ -55,6 +160,9 @@ impl ModelCapabilities {
             supports_function_calling: true,
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: true,
             family,
             tier,
             input_cost_per_1m_tokens: Some(30.0),
@@ -75,6 +183,9 @@ impl ModelCapabilities {
             supports_function_calling: !model_id.contains("0301"),
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: true,
             family,
             tier,
             input_cost_per_1m_tokens: Some(0.5),
@@ -91,6 +202,9 @@ impl ModelCapabilities {
             supports_function_calling: false,
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: false,
             family,
             tier,
             input_cost_per_1m_tokens: None,
@@ -107,6 +221,9 @@ impl ModelCapabilities {
             supports_function_calling: false,
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: false,
             family,
             tier,
             input_cost_per_1m_tokens: None,
@@ -123,6 +240,9 @@ impl ModelCapabilities {
             supports_function_calling: false,
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: false,
             family,
             tier,
             input_cost_per_1m_tokens: None,
@@ -139,6 +259,9 @@ impl ModelCapabilities {
             supports_function_calling: false,
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: false,
             family,
             tier,
             input_cost_per_1m_tokens: Some(0.02),
@@ -155,6 +278,9 @@ impl ModelCapabilities {
             supports_function_calling: false,
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: false,
             family,
             tier,
             input_cost_per_1m_tokens: None,
@@ -171,6 +297,9 @@ impl ModelCapabilities {
             supports_function_calling: false,
             supports_vision: false,
             supports_code_interpreter: false,
+            reasoning_efforts: Vec::new(),
+            supports_verbosity: false,
+            supports_sampling: true,
             family,
             tier,
             input_cost_per_1m_tokens: None,
@@ -20,6 +20,8 @@ impl ModelCapabilities {
             ModelType::Gpt4Turbo => Self::gpt4_turbo_capabilities(model_id, family, tier),
             ModelType::Gpt4 => Self::gpt4_capabilities(model_id, family, tier),
             ModelType::Gpt35 => Self::gpt35_capabilities(model_id, family, tier),
+            ModelType::Gpt5 => Self::gpt5_capabilities(model_id, family, tier),
+            ModelType::Reasoning => Self::reasoning_capabilities(model_id, family, tier),
             ModelType::Dalle => Self::dalle_capabilities(family, tier),
             ModelType::Whisper => Self::whisper_capabilities(family, tier),
             ModelType::Tts => Self::tts_capabilities(family, tier),
@@ -49,6 +51,10 @@ impl ModelCapabilities {
         let prefix_mappings = [
             ("gpt-4", ModelType::Gpt4),
             ("gpt-3.5-turbo", ModelType::Gpt35),
+            ("gpt-5", ModelType::Gpt5),
+            ("o1", ModelType::Reasoning),
+            ("o3", ModelType::Reasoning),
+            ("o4", ModelType::Reasoning),
             ("dall-e", ModelType::Dalle),
             ("whisper", ModelType::Whisper),
             ("tts", ModelType::Tts),
@@ -102,6 +108,10 @@ impl ModelCapabilities {
             // General patterns
             ("gpt-4", true, ModelFamily::GPT4),
             ("gpt-3.5", true, ModelFamily::GPT35),
+            ("gpt-5", true, ModelFamily::GPT5),
+            ("o1", true, ModelFamily::OSeries),
+            ("o3", true, ModelFamily::OSeries),
+            ("o4", true, ModelFamily::OSeries),
             ("dall-e", true, ModelFamily::DALLE),
             ("whisper", true, ModelFamily::Whisper),
             ("tts", true, ModelFamily::TTS),
@@ -144,6 +154,12 @@ impl ModelCapabilities {
         }
     }
 
+    /// Check whether the model accepts a reasoning effort setting
+    #[must_use]
+    pub fn supports_reasoning(&self) -> bool {
+        !self.reasoning_efforts.is_empty()
+    }
+
     /// Get estimated monthly cost for processing tokens
     #[must_use]
     pub fn estimate_monthly_cost(
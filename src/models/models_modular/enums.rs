@@ -32,6 +32,10 @@ pub enum ModelFamily {
     GPT4o,
     /// GPT-3.5 series models
     GPT35,
+    /// GPT-5 series models
+    GPT5,
+    /// O-series reasoning models (o1, o3, o4)
+    OSeries,
     /// DALL-E image generation models
     #[allow(clippy::upper_case_acronyms)]
     DALLE,
@@ -72,6 +76,10 @@ pub(crate) enum ModelType {
     Gpt4,
     /// GPT-3.5 models
     Gpt35,
+    /// GPT-5 models
+    Gpt5,
+    /// O-series reasoning models
+    Reasoning,
     /// DALL-E image generation models
     Dalle,
     /// Whisper audio models
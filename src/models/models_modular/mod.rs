@@ -34,6 +34,7 @@ pub use types::*;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
 
     #[test]
     fn test_model_family_classification() {
@@ -87,6 +88,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_model_capabilities_new() {
+        let mut capabilities = ModelCapabilities::new(ModelFamily::GPT4o, ModelTier::Standard);
+        capabilities.supports_vision = true;
+        assert!(capabilities.supports_vision);
+        assert!(capabilities.supports_sampling);
+        assert!(capabilities.reasoning_efforts.is_empty());
+        assert_eq!(capabilities.family, ModelFamily::GPT4o);
+    }
+
     #[test]
     fn test_model_capabilities() {
         let caps = ModelCapabilities::from_model_id("gpt-4o");
@@ -94,6 +105,34 @@ mod tests {
         assert!(caps.supports_function_calling);
         assert!(caps.supports_vision);
         assert_eq!(caps.max_tokens, Some(128_000));
+        assert!(caps.supports_sampling);
+        assert!(!caps.supports_reasoning());
+    }
+
+    #[test]
+    fn test_reasoning_model_capabilities() {
+        use crate::models::gpt5::ReasoningEffort;
+
+        let gpt5 = ModelCapabilities::from_model_id("gpt-5-mini-2025-08-07");
+        assert_eq!(gpt5.family, ModelFamily::GPT5);
+        assert!(gpt5.reasoning_efforts.contains(&ReasoningEffort::Minimal));
+        assert!(gpt5.supports_verbosity);
+        assert!(!gpt5.supports_sampling);
+
+        let chat = ModelCapabilities::from_model_id("gpt-5-chat-latest");
+        assert!(!chat.supports_reasoning());
+        assert!(chat.supports_sampling);
+
+        let o3 = ModelCapabilities::from_model_id("o3");
+        assert_eq!(o3.family, ModelFamily::OSeries);
+        assert!(o3.supports_reasoning());
+        assert!(!o3.reasoning_efforts.contains(&ReasoningEffort::Minimal));
+        assert!(!o3.supports_verbosity);
+
+        let o1_mini = ModelCapabilities::from_model_id("o1-mini");
+        assert_eq!(o1_mini.family, ModelFamily::OSeries);
+        assert!(o1_mini.reasoning_efforts.is_empty());
+        assert!(!o1_mini.supports_sampling);
     }
 
     #[test]
@@ -106,6 +145,7 @@ mod tests {
             root: None,
             parent: None,
             permission: None,
+            extra: HashMap::new(),
         };
 
         assert!(model.is_deprecated());
@@ -122,6 +162,7 @@ mod tests {
             root: None,
             parent: None,
             permission: None,
+            extra: HashMap::new(),
         };
 
         assert!(model.supports_completion_type(&CompletionType::Chat));
@@ -171,6 +212,7 @@ mod tests {
                 root: None,
                 parent: None,
                 permission: None,
+                extra: HashMap::new(),
             },
             Model {
                 id: "dall-e-3".to_string(),
@@ -180,6 +222,7 @@ mod tests {
                 root: None,
                 parent: None,
                 permission: None,
+                extra: HashMap::new(),
             },
             Model {
                 id: "text-davinci-003".to_string(), // deprecated
@@ -189,6 +232,7 @@ mod tests {
                 root: None,
                 parent: None,
                 permission: None,
+                extra: HashMap::new(),
             },
         ];
 
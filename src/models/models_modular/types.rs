@@ -4,6 +4,8 @@
 
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
 /// Information about an `OpenAI` model
 #[derive(Debug, Clone, Ser, De)]
@@ -31,8 +33,13 @@ pub struct Model {
     /// List of permissions for this model
     #[serde(skip_serializing_if = "Option::is_none")]
     pub permission: Option<Vec<ModelPermission>>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(Model);
+
 /// Permissions for a model
 #[derive(Debug, Clone, Ser, De)]
 pub struct ModelPermission {
@@ -98,7 +105,11 @@ pub struct ModelDeleteResponse {
 }
 
 /// Detailed information about model capabilities
+///
+/// New capabilities are added over time, so build one with
+/// [`ModelCapabilities::new`] and set the fields you need.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ModelCapabilities {
     /// Maximum context window size in tokens
     pub max_tokens: Option<u32>,
@@ -118,6 +129,15 @@ pub struct ModelCapabilities {
     /// Whether the model supports code interpreter
     pub supports_code_interpreter: bool,
 
+    /// Reasoning effort levels the model accepts; empty for non-reasoning models
+    pub reasoning_efforts: Vec<crate::models::gpt5::ReasoningEffort>,
+
+    /// Whether the model accepts the `verbosity` text setting
+    pub supports_verbosity: bool,
+
+    /// Whether the model accepts sampling controls such as `temperature` and `top_p`
+    pub supports_sampling: bool,
+
     /// Model family (e.g., "gpt-4", "gpt-3.5", "dall-e")
     pub family: super::enums::ModelFamily,
 
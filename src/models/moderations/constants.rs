@@ -41,6 +41,9 @@ impl ModerationModels {
 
     /// Latest moderation model (may change over time)
     pub const LATEST: &'static str = "text-moderation-latest";
+
+    /// Latest multimodal moderation model
+    pub const OMNI_LATEST: &'static str = "omni-moderation-latest";
 }
 
 /// Safety threshold presets for interpreting confidence scores
@@ -1,12 +1,12 @@
 //! Request structures for the moderations API
 
-use super::ModerationInput;
+use super::{ModerationContentPart, ModerationInput};
 use crate::{De, Ser};
 
 /// Request for content moderation
 #[derive(Debug, Clone, Ser, De)]
 pub struct ModerationRequest {
-    /// Input text to moderate (string or array of strings)
+    /// Input to moderate (string, array of strings, or multimodal content parts)
     pub input: ModerationInput,
 
     /// ID of the model to use (e.g., "text-moderation-stable", "text-moderation-latest")
@@ -32,6 +32,26 @@ impl ModerationRequest {
         }
     }
 
+    /// Create a new moderation request from multimodal content parts
+    #[must_use]
+    pub fn new_multimodal(parts: Vec<ModerationContentPart>) -> Self {
+        Self {
+            input: ModerationInput::Parts(parts),
+            model: None,
+        }
+    }
+
+    /// Add an image, given as an `https` URL or a base64 `data:` URL
+    ///
+    /// Text input is converted to content parts, so the request is classified
+    /// as a single multimodal input. Images require an omni moderation model.
+    pub fn with_image(mut self, url: impl Into<String>) -> Self {
+        let mut parts = self.input.into_parts();
+        parts.push(ModerationContentPart::image_url(url));
+        self.input = ModerationInput::Parts(parts);
+        self
+    }
+
     /// Set the model to use for moderation
     pub fn with_model(mut self, model: impl Into<String>) -> Self {
         self.model = Some(model.into());
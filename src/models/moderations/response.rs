@@ -1,7 +1,9 @@
 //! Response structures for the moderations API
 
-use super::{CategoryScores, ModerationCategories, constants::CATEGORY_MAPPINGS};
+use super::{CategoryScores, InputType, ModerationCategories, constants::CATEGORY_MAPPINGS};
 use crate::{De, Ser};
+use serde_json::Value;
+use std::collections::HashMap;
 
 /// Response from moderations API
 #[derive(Debug, Clone, Ser, De)]
@@ -14,8 +16,13 @@ pub struct ModerationResponse {
 
     /// List of moderation results
     pub results: Vec<ModerationResult>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(ModerationResponse);
+
 /// Individual moderation result
 #[derive(Debug, Clone, Ser, De)]
 pub struct ModerationResult {
@@ -27,6 +34,14 @@ pub struct ModerationResult {
 
     /// Category-specific confidence scores
     pub category_scores: CategoryScores,
+
+    /// Input types that each category was applied to (omni moderation models only)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
+    pub category_applied_input_types: Option<HashMap<String, Vec<InputType>>>,
 }
 
 impl ModerationResponse {
@@ -121,4 +136,17 @@ impl ModerationResult {
     pub fn max_confidence_score(&self) -> f64 {
         self.category_scores.max_score()
     }
+
+    /// Get the input types a category was evaluated on, e.g. `triggered_by("violence")`
+    ///
+    /// Returns an empty list when the category is unknown or the model does
+    /// not report applied input types.
+    #[must_use]
+    pub fn triggered_by(&self, category: &str) -> Vec<InputType> {
+        self.category_applied_input_types
+            .as_ref()
+            .and_then(|types| types.get(category))
+            .cloned()
+            .unwrap_or_default()
+    }
 }
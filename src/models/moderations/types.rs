@@ -10,6 +10,77 @@ pub enum ModerationInput {
     String(String),
     /// Array of text strings
     StringArray(Vec<String>),
+    /// Mixed text and image content, accepted by the omni moderation models
+    Parts(Vec<ModerationContentPart>),
+}
+
+/// A single piece of multimodal moderation input
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModerationContentPart {
+    /// Text content
+    Text {
+        /// The text to classify
+        text: String,
+    },
+    /// Image content referenced by URL or data URL
+    ImageUrl {
+        /// The image location
+        image_url: ModerationImageUrl,
+    },
+}
+
+/// Location of an image to moderate
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct ModerationImageUrl {
+    /// An `https` URL or a base64 `data:` URL
+    pub url: String,
+}
+
+impl ModerationContentPart {
+    /// Create a text part
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// Create an image part from a URL
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::ImageUrl {
+            image_url: ModerationImageUrl { url: url.into() },
+        }
+    }
+
+    /// Create an image part from base64-encoded image data
+    pub fn image_base64(mime_type: &str, data: &str) -> Self {
+        Self::image_url(format!("data:{mime_type};base64,{data}"))
+    }
+}
+
+impl ModerationInput {
+    /// Convert the input into content parts, keeping every text entry
+    #[must_use]
+    pub fn into_parts(self) -> Vec<ModerationContentPart> {
+        match self {
+            Self::String(text) => vec![ModerationContentPart::text(text)],
+            Self::StringArray(texts) => {
+                texts.into_iter().map(ModerationContentPart::text).collect()
+            }
+            Self::Parts(parts) => parts,
+        }
+    }
+}
+
+/// Kind of input that triggered a moderation category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ser, De)]
+#[serde(rename_all = "snake_case")]
+pub enum InputType {
+    /// Text input
+    Text,
+    /// Image input
+    Image,
+    /// An input type not known to this SDK version
+    #[serde(other)]
+    Unknown,
 }
 
 /// Type alias for category getter function
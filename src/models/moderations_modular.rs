@@ -107,6 +107,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_multimodal_request_serialization() {
+        let req = ModerationRequest::new("Is this ok?")
+            .with_image("https://example.com/cat.png")
+            .with_model(ModerationModels::OMNI_LATEST);
+
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "input": [
+                    {"type": "text", "text": "Is this ok?"},
+                    {"type": "image_url", "image_url": {"url": "https://example.com/cat.png"}}
+                ],
+                "model": "omni-moderation-latest"
+            })
+        );
+
+        let parsed: ModerationRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.input, req.input);
+    }
+
+    #[test]
+    fn test_image_only_request() {
+        let req = ModerationRequest::new_multimodal(vec![ModerationContentPart::image_base64(
+            "image/png",
+            "aGVsbG8=",
+        )]);
+
+        match req.input {
+            ModerationInput::Parts(parts) => assert_eq!(
+                parts,
+                vec![ModerationContentPart::image_url(
+                    "data:image/png;base64,aGVsbG8="
+                )]
+            ),
+            _ => panic!("Expected content parts"),
+        }
+    }
+
     #[test]
     fn test_category_scores_max() {
         let scores = CategoryScores {
@@ -182,6 +222,7 @@ mod tests {
             flagged: true,
             categories,
             category_scores: scores,
+            category_applied_input_types: None,
         };
 
         assert!(result.has_hate_violations());
@@ -221,6 +262,7 @@ mod tests {
                 flagged,
                 categories: categories_creator(),
                 category_scores: scores.clone(),
+                category_applied_input_types: None,
             };
 
             let violations = result.violated_categories();
@@ -236,4 +278,50 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_category_applied_input_types() {
+        let categories: serde_json::Map<String, serde_json::Value> = CATEGORY_MAPPINGS
+            .iter()
+            .map(|(name, _)| (name.to_string(), serde_json::json!(*name == "violence")))
+            .collect();
+        let scores: serde_json::Map<String, serde_json::Value> = CATEGORY_MAPPINGS
+            .iter()
+            .map(|(name, _)| (name.to_string(), serde_json::json!(0.1)))
+            .collect();
+
+        let result: ModerationResult = serde_json::from_value(serde_json::json!({
+            "flagged": true,
+            "categories": categories,
+            "category_scores": scores,
+            "category_applied_input_types": {
+                "violence": ["text", "image"],
+                "sexual/minors": ["text"],
+                "self-harm": ["audio"]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            result.triggered_by("violence"),
+            vec![InputType::Text, InputType::Image]
+        );
+        assert_eq!(result.triggered_by("sexual/minors"), vec![InputType::Text]);
+        assert_eq!(result.triggered_by("self-harm"), vec![InputType::Unknown]);
+        assert!(result.triggered_by("hate").is_empty());
+    }
+
+    #[test]
+    fn test_applied_input_types_optional() {
+        let result = ModerationResult {
+            flagged: false,
+            categories: create_no_violations_categories(),
+            category_scores: create_test_category_scores(),
+            category_applied_input_types: None,
+        };
+
+        assert!(result.triggered_by("violence").is_empty());
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json.get("category_applied_input_types").is_none());
+    }
 }
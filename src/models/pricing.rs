@@ -0,0 +1,816 @@
+//! # Model Pricing and Cost Tracking
+//!
+//! Token prices for `OpenAI` models and helpers to turn [`Usage`] statistics
+//! into estimated dollar amounts.
+//!
+//! Prices are expressed in USD per one million tokens. The default
+//! [`PricingTable`] contains published rates for common models; custom or
+//! negotiated rates can be added with [`PricingTable::with_model`].
+//!
+//! DALL-E images are priced per image by size and quality. Audio models are
+//! priced per minute, but since responses do not report a duration, audio
+//! spend is only tracked when recorded explicitly with
+//! [`CostTracker::record_audio_minutes`]; text-to-speech is not priced.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use openai_rust_sdk::models::pricing::{CostTracker, PricingTable};
+//! use openai_rust_sdk::models::responses::Usage;
+//!
+//! let usage = Usage {
+//!     prompt_tokens: 1_000,
+//!     completion_tokens: 500,
+//!     total_tokens: 1_500,
+//!     prompt_tokens_details: None,
+//!     completion_tokens_details: None,
+//! };
+//!
+//! let pricing = PricingTable::default();
+//! let cost = usage.estimated_cost("gpt-4o-mini", &pricing);
+//! assert!(cost.is_some());
+//!
+//! let tracker = CostTracker::new(pricing);
+//! tracker.record("gpt-4o-mini", &usage);
+//! tracker.record("gpt-4o-mini", &usage);
+//! println!("Session total: ${:.6}", tracker.total());
+//! ```
+
+use crate::models::images::{ImageQuality, ImageSize};
+use crate::models::responses::Usage;
+use crate::models::training_data::TrainingFileReport;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Number of tokens that per-million rates are expressed in
+const TOKENS_PER_MILLION: f64 = 1_000_000.0;
+
+/// Token rates for a single model, in USD per one million tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelPricing {
+    /// Price of uncached prompt tokens
+    pub input_per_million: f64,
+    /// Discounted price of prompt tokens served from the prompt cache
+    ///
+    /// When `None`, cached tokens are billed at the regular input rate.
+    pub cached_input_per_million: Option<f64>,
+    /// Price of completion tokens, including reasoning tokens
+    pub output_per_million: f64,
+    /// Price of one generated image by size and quality, for image models
+    pub images: Option<ImagePricing>,
+    /// Price of one minute of audio, for transcription models
+    pub per_minute: Option<f64>,
+    /// Price of tokens trained on by a fine-tuning job, per epoch
+    pub training_per_million: Option<f64>,
+}
+
+impl ModelPricing {
+    /// Creates pricing with the given input and output rates and no cache discount
+    #[must_use]
+    pub fn new(input_per_million: f64, output_per_million: f64) -> Self {
+        Self {
+            input_per_million,
+            cached_input_per_million: None,
+            output_per_million,
+            images: None,
+            per_minute: None,
+            training_per_million: None,
+        }
+    }
+
+    /// Creates pricing for an image model billed per generated image
+    #[must_use]
+    pub fn image(images: ImagePricing) -> Self {
+        Self {
+            images: Some(images),
+            ..Self::new(0.0, 0.0)
+        }
+    }
+
+    /// Creates pricing for an audio model billed per minute of audio
+    #[must_use]
+    pub fn audio(per_minute: f64) -> Self {
+        Self {
+            per_minute: Some(per_minute),
+            ..Self::new(0.0, 0.0)
+        }
+    }
+
+    /// Sets the discounted rate for cached prompt tokens
+    #[must_use]
+    pub fn with_cached_input(mut self, cached_input_per_million: f64) -> Self {
+        self.cached_input_per_million = Some(cached_input_per_million);
+        self
+    }
+
+    /// Sets the rate for tokens trained on by fine-tuning jobs
+    #[must_use]
+    pub fn with_training(mut self, training_per_million: f64) -> Self {
+        self.training_per_million = Some(training_per_million);
+        self
+    }
+
+    /// Calculates the cost in USD of the given token counts
+    ///
+    /// `cached_tokens` is the portion of `prompt_tokens` served from the
+    /// prompt cache. Reasoning tokens are billed as output and are already
+    /// included in `completion_tokens`.
+    #[must_use]
+    pub fn cost(&self, prompt_tokens: u32, cached_tokens: u32, completion_tokens: u32) -> f64 {
+        let cached_tokens = cached_tokens.min(prompt_tokens);
+        let uncached_tokens = prompt_tokens - cached_tokens;
+        let cached_rate = self
+            .cached_input_per_million
+            .unwrap_or(self.input_per_million);
+
+        (f64::from(uncached_tokens) * self.input_per_million
+            + f64::from(cached_tokens) * cached_rate
+            + f64::from(completion_tokens) * self.output_per_million)
+            / TOKENS_PER_MILLION
+    }
+}
+
+/// Per-image prices for an image model, in USD
+///
+/// Standard-quality prices are required for every size the model supports.
+/// Models without an HD tier bill HD requests at the standard rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImagePricing {
+    /// Standard-quality 1024x1024 image, the default size
+    pub square: f64,
+    /// Standard-quality 1792x1024 or 1024x1792 image
+    pub wide: Option<f64>,
+    /// Standard-quality 512x512 image
+    pub medium: Option<f64>,
+    /// Standard-quality 256x256 image
+    pub small: Option<f64>,
+    /// HD 1024x1024 image
+    pub hd_square: Option<f64>,
+    /// HD 1792x1024 or 1024x1792 image
+    pub hd_wide: Option<f64>,
+}
+
+impl ImagePricing {
+    /// Creates pricing for a model that only supports standard 1024x1024 images
+    #[must_use]
+    pub fn new(square: f64) -> Self {
+        Self {
+            square,
+            wide: None,
+            medium: None,
+            small: None,
+            hd_square: None,
+            hd_wide: None,
+        }
+    }
+
+    /// Sets the price of standard-quality images at the given size
+    #[must_use]
+    pub fn with_size(mut self, size: &ImageSize, price: f64) -> Self {
+        match size {
+            ImageSize::Size1024x1024 => self.square = price,
+            ImageSize::Size1792x1024 | ImageSize::Size1024x1792 => self.wide = Some(price),
+            ImageSize::Size512x512 => self.medium = Some(price),
+            ImageSize::Size256x256 => self.small = Some(price),
+        }
+        self
+    }
+
+    /// Sets the price of HD images at the given size
+    ///
+    /// HD is only offered at 1024x1024 and the wide sizes; prices for smaller
+    /// sizes are ignored.
+    #[must_use]
+    pub fn with_hd(mut self, size: &ImageSize, price: f64) -> Self {
+        match size {
+            ImageSize::Size1024x1024 => self.hd_square = Some(price),
+            ImageSize::Size1792x1024 | ImageSize::Size1024x1792 => self.hd_wide = Some(price),
+            ImageSize::Size512x512 | ImageSize::Size256x256 => {}
+        }
+        self
+    }
+
+    /// Returns the price of one image, or `None` if the size is not priced
+    ///
+    /// A missing size means 1024x1024 and a missing quality means standard,
+    /// matching the API defaults.
+    #[must_use]
+    pub fn price(&self, size: Option<&ImageSize>, quality: Option<&ImageQuality>) -> Option<f64> {
+        let size = size.unwrap_or(&ImageSize::Size1024x1024);
+        let standard = match size {
+            ImageSize::Size1024x1024 => Some(self.square),
+            ImageSize::Size1792x1024 | ImageSize::Size1024x1792 => self.wide,
+            ImageSize::Size512x512 => self.medium,
+            ImageSize::Size256x256 => self.small,
+        }?;
+        let hd = match size {
+            ImageSize::Size1024x1024 => self.hd_square,
+            ImageSize::Size1792x1024 | ImageSize::Size1024x1792 => self.hd_wide,
+            ImageSize::Size512x512 | ImageSize::Size256x256 => None,
+        };
+
+        match quality {
+            Some(ImageQuality::Hd) => Some(hd.unwrap_or(standard)),
+            Some(ImageQuality::Standard) | None => Some(standard),
+        }
+    }
+}
+
+/// Lookup table mapping model names to their token rates
+///
+/// Lookups match the exact model name first. Dated snapshots such as
+/// `gpt-4o-2024-08-06` then fall back to the entry without the
+/// `-YYYY-MM-DD` suffix; other variants such as `o1-mini` or
+/// `gpt-4o-mini-tts` need their own entries and are never priced as their
+/// base model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PricingTable {
+    /// Rates keyed by model name
+    models: HashMap<String, ModelPricing>,
+}
+
+impl PricingTable {
+    /// Creates an empty pricing table
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            models: HashMap::new(),
+        }
+    }
+
+    /// Adds or replaces the rates for a model
+    #[must_use]
+    pub fn with_model(mut self, model: impl Into<String>, pricing: ModelPricing) -> Self {
+        self.insert(model, pricing);
+        self
+    }
+
+    /// Adds or replaces the rates for a model
+    pub fn insert(&mut self, model: impl Into<String>, pricing: ModelPricing) {
+        self.models.insert(model.into(), pricing);
+    }
+
+    /// Returns the rates for a model, or `None` if the model is unknown
+    #[must_use]
+    pub fn get(&self, model: &str) -> Option<&ModelPricing> {
+        self.models
+            .get(model)
+            .or_else(|| self.models.get(strip_snapshot_date(model)?))
+    }
+}
+
+/// Strips a `-YYYY-MM-DD` snapshot suffix from a model name
+fn strip_snapshot_date(model: &str) -> Option<&str> {
+    let split = model.len().checked_sub(11)?;
+    let (base, suffix) = (model.get(..split)?, model.get(split..)?);
+    let digits: Vec<&str> = suffix.strip_prefix('-')?.split('-').collect();
+    let is_date = matches!(digits.as_slice(), [year, month, day]
+        if year.len() == 4 && month.len() == 2 && day.len() == 2)
+        && digits
+            .iter()
+            .all(|part| part.bytes().all(|b| b.is_ascii_digit()));
+    (is_date && !base.is_empty()).then_some(base)
+}
+
+impl Default for PricingTable {
+    /// Creates a table with published rates for common models
+    fn default() -> Self {
+        Self::empty()
+            .with_model(
+                "gpt-5",
+                ModelPricing::new(1.25, 10.00).with_cached_input(0.125),
+            )
+            .with_model(
+                "gpt-5-mini",
+                ModelPricing::new(0.25, 2.00).with_cached_input(0.025),
+            )
+            .with_model(
+                "gpt-5-nano",
+                ModelPricing::new(0.05, 0.40).with_cached_input(0.005),
+            )
+            .with_model(
+                "gpt-4.1",
+                ModelPricing::new(2.00, 8.00)
+                    .with_cached_input(0.50)
+                    .with_training(25.00),
+            )
+            .with_model(
+                "gpt-4.1-mini",
+                ModelPricing::new(0.40, 1.60)
+                    .with_cached_input(0.10)
+                    .with_training(5.00),
+            )
+            .with_model(
+                "gpt-4.1-nano",
+                ModelPricing::new(0.10, 0.40)
+                    .with_cached_input(0.025)
+                    .with_training(1.50),
+            )
+            .with_model(
+                "gpt-4o",
+                ModelPricing::new(2.50, 10.00)
+                    .with_cached_input(1.25)
+                    .with_training(25.00),
+            )
+            .with_model(
+                "gpt-4o-mini",
+                ModelPricing::new(0.15, 0.60)
+                    .with_cached_input(0.075)
+                    .with_training(3.00),
+            )
+            .with_model("gpt-4-turbo", ModelPricing::new(10.00, 30.00))
+            .with_model(
+                "gpt-3.5-turbo",
+                ModelPricing::new(0.50, 1.50).with_training(8.00),
+            )
+            .with_model(
+                "o1",
+                ModelPricing::new(15.00, 60.00).with_cached_input(7.50),
+            )
+            .with_model("o3", ModelPricing::new(2.00, 8.00).with_cached_input(0.50))
+            .with_model(
+                "o3-mini",
+                ModelPricing::new(1.10, 4.40).with_cached_input(0.55),
+            )
+            .with_model(
+                "o4-mini",
+                ModelPricing::new(1.10, 4.40).with_cached_input(0.275),
+            )
+            .with_model("text-embedding-3-small", ModelPricing::new(0.02, 0.0))
+            .with_model("text-embedding-3-large", ModelPricing::new(0.13, 0.0))
+            .with_model("text-embedding-ada-002", ModelPricing::new(0.10, 0.0))
+            .with_model(
+                "dall-e-3",
+                ModelPricing::image(
+                    ImagePricing::new(0.040)
+                        .with_size(&ImageSize::Size1792x1024, 0.080)
+                        .with_hd(&ImageSize::Size1024x1024, 0.080)
+                        .with_hd(&ImageSize::Size1792x1024, 0.120),
+                ),
+            )
+            .with_model(
+                "dall-e-2",
+                ModelPricing::image(
+                    ImagePricing::new(0.020)
+                        .with_size(&ImageSize::Size512x512, 0.018)
+                        .with_size(&ImageSize::Size256x256, 0.016),
+                ),
+            )
+            .with_model("whisper-1", ModelPricing::audio(0.006))
+            .with_model("gpt-4o-transcribe", ModelPricing::audio(0.006))
+            .with_model("gpt-4o-mini-transcribe", ModelPricing::audio(0.003))
+    }
+}
+
+impl Usage {
+    /// Estimates the cost of this usage in USD for the given model
+    ///
+    /// Cached prompt tokens are billed at the model's cached-input rate and
+    /// reasoning tokens at the output rate. Returns `None` when the pricing
+    /// table has no entry for the model.
+    #[must_use]
+    pub fn estimated_cost(&self, model: &str, pricing: &PricingTable) -> Option<f64> {
+        let cached_tokens = self.cached_tokens().unwrap_or(0);
+
+        pricing
+            .get(model)
+            .map(|rates| rates.cost(self.prompt_tokens, cached_tokens, self.completion_tokens))
+    }
+}
+
+/// Estimated cost of a fine-tuning job
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrainingCostEstimate {
+    /// Estimated tokens in one pass over the training file
+    pub tokens_per_epoch: u64,
+    /// Number of epochs the estimate covers
+    pub n_epochs: u32,
+    /// Estimated tokens trained on across all epochs
+    pub trained_tokens: u64,
+    /// Estimated cost in USD
+    pub cost: f64,
+}
+
+impl TrainingFileReport {
+    /// Estimates the cost in USD of fine-tuning `model` on this file
+    ///
+    /// Multiplies the estimated tokens of the valid examples by `n_epochs`
+    /// and the model's training rate. Returns `None` when the pricing table
+    /// has no training rate for the model.
+    #[must_use]
+    pub fn estimated_cost(
+        &self,
+        model: &str,
+        n_epochs: u32,
+        pricing: &PricingTable,
+    ) -> Option<TrainingCostEstimate> {
+        let rate = pricing.get(model)?.training_per_million?;
+        let trained_tokens = self.total_tokens * u64::from(n_epochs);
+
+        Some(TrainingCostEstimate {
+            tokens_per_epoch: self.total_tokens,
+            n_epochs,
+            trained_tokens,
+            cost: trained_tokens as f64 * rate / TOKENS_PER_MILLION,
+        })
+    }
+}
+
+/// API endpoint category used to break down tracked costs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CostEndpoint {
+    /// Chat-style conversations
+    Chat,
+    /// Responses API requests
+    Responses,
+    /// Embeddings requests
+    Embeddings,
+    /// Image generation requests
+    Images,
+    /// Audio transcription and translation requests
+    Audio,
+}
+
+crate::impl_enum_display! {
+    CostEndpoint {
+        Chat => "chat",
+        Responses => "responses",
+        Embeddings => "embeddings",
+        Images => "images",
+        Audio => "audio",
+    }
+}
+
+/// Accumulated spend for one model
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ModelCost {
+    /// Number of priced requests recorded for the model
+    pub requests: u64,
+    /// Total prompt tokens recorded for the model
+    pub prompt_tokens: u64,
+    /// Total completion tokens recorded for the model
+    pub completion_tokens: u64,
+    /// Total estimated cost in USD
+    pub cost: f64,
+}
+
+/// Running totals shared behind the tracker's lock
+#[derive(Debug, Default)]
+struct CostTotals {
+    /// Spend broken down by model
+    by_model: HashMap<String, ModelCost>,
+    /// Spend in USD broken down by endpoint
+    by_endpoint: HashMap<CostEndpoint, f64>,
+    /// Number of recorded requests whose model had no pricing
+    unpriced_requests: u64,
+}
+
+/// Accumulates estimated costs across many responses
+///
+/// The tracker is safe to share between threads; wrap it in an `Arc` to
+/// record usage from concurrent tasks or attach it to an
+/// [`OpenAIClient`](crate::client::OpenAIClient) with
+/// [`with_cost_tracker`](crate::client::OpenAIClient::with_cost_tracker).
+#[derive(Debug, Default)]
+pub struct CostTracker {
+    /// Rates used to price recorded usage
+    pricing: PricingTable,
+    /// Accumulated totals
+    totals: Mutex<CostTotals>,
+}
+
+impl CostTracker {
+    /// Creates a tracker that prices usage with the given table
+    #[must_use]
+    pub fn new(pricing: PricingTable) -> Self {
+        Self {
+            pricing,
+            totals: Mutex::new(CostTotals::default()),
+        }
+    }
+
+    /// Returns the pricing table used by this tracker
+    #[must_use]
+    pub fn pricing(&self) -> &PricingTable {
+        &self.pricing
+    }
+
+    /// Records the usage of one chat response and returns its estimated cost
+    ///
+    /// Usage for models without pricing is counted in
+    /// [`unpriced_requests`](Self::unpriced_requests) and returns `None`.
+    pub fn record(&self, model: &str, usage: &Usage) -> Option<f64> {
+        self.record_usage(CostEndpoint::Chat, model, usage)
+    }
+
+    /// Records the usage of one response from the given endpoint
+    pub fn record_usage(&self, endpoint: CostEndpoint, model: &str, usage: &Usage) -> Option<f64> {
+        let cached_tokens = usage.cached_tokens().unwrap_or(0);
+        self.record_tokens(
+            endpoint,
+            model,
+            usage.prompt_tokens,
+            cached_tokens,
+            usage.completion_tokens,
+        )
+    }
+
+    /// Records raw token counts from the given endpoint
+    ///
+    /// `cached_tokens` is the portion of `prompt_tokens` served from the
+    /// prompt cache.
+    pub fn record_tokens(
+        &self,
+        endpoint: CostEndpoint,
+        model: &str,
+        prompt_tokens: u32,
+        cached_tokens: u32,
+        completion_tokens: u32,
+    ) -> Option<f64> {
+        let cost = self
+            .pricing
+            .get(model)
+            .map(|rates| rates.cost(prompt_tokens, cached_tokens, completion_tokens));
+        self.add(endpoint, model, cost, prompt_tokens, completion_tokens)
+    }
+
+    /// Records the generation of `count` images with a per-image priced model
+    ///
+    /// The rate depends on the requested size and quality; `None` for either
+    /// means the API default. Sizes the model has no price for are unpriced.
+    pub fn record_images(
+        &self,
+        model: &str,
+        size: Option<&ImageSize>,
+        quality: Option<&ImageQuality>,
+        count: u32,
+    ) -> Option<f64> {
+        let cost = self
+            .pricing
+            .get(model)
+            .and_then(|rates| rates.images)
+            .and_then(|images| images.price(size, quality))
+            .map(|rate| rate * f64::from(count));
+        self.add(CostEndpoint::Images, model, cost, 0, 0)
+    }
+
+    /// Records `minutes` of audio processed by a per-minute priced model
+    ///
+    /// Audio responses do not report a duration, so clients never call this
+    /// automatically; record transcriptions and translations here yourself.
+    pub fn record_audio_minutes(&self, model: &str, minutes: f64) -> Option<f64> {
+        let cost = self
+            .pricing
+            .get(model)
+            .and_then(|rates| rates.per_minute)
+            .map(|rate| rate * minutes);
+        self.add(CostEndpoint::Audio, model, cost, 0, 0)
+    }
+
+    /// Adds one priced (or unpriced) request to the running totals
+    fn add(
+        &self,
+        endpoint: CostEndpoint,
+        model: &str,
+        cost: Option<f64>,
+        prompt_tokens: u32,
+        completion_tokens: u32,
+    ) -> Option<f64> {
+        let mut totals = self.lock();
+
+        match cost {
+            Some(cost) => {
+                let entry = totals.by_model.entry(model.to_string()).or_default();
+                entry.requests += 1;
+                entry.prompt_tokens += u64::from(prompt_tokens);
+                entry.completion_tokens += u64::from(completion_tokens);
+                entry.cost += cost;
+                *totals.by_endpoint.entry(endpoint).or_default() += cost;
+            }
+            None => totals.unpriced_requests += 1,
+        }
+
+        cost
+    }
+
+    /// Returns the total estimated cost in USD across all recorded usage
+    #[must_use]
+    pub fn total(&self) -> f64 {
+        self.lock().by_model.values().map(|model| model.cost).sum()
+    }
+
+    /// Returns the accumulated spend broken down by model
+    #[must_use]
+    pub fn by_model(&self) -> HashMap<String, ModelCost> {
+        self.lock().by_model.clone()
+    }
+
+    /// Returns the accumulated spend in USD broken down by endpoint
+    #[must_use]
+    pub fn by_endpoint(&self) -> HashMap<CostEndpoint, f64> {
+        self.lock().by_endpoint.clone()
+    }
+
+    /// Returns the number of recorded requests whose model had no pricing
+    #[must_use]
+    pub fn unpriced_requests(&self) -> u64 {
+        self.lock().unpriced_requests
+    }
+
+    /// Clears all accumulated totals
+    pub fn reset(&self) {
+        *self.lock() = CostTotals::default();
+    }
+
+    /// Locks the totals, recovering the data if a previous holder panicked
+    fn lock(&self) -> std::sync::MutexGuard<'_, CostTotals> {
+        self.totals
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::responses::PromptTokenDetails;
+
+    fn usage(prompt: u32, cached: u32, completion: u32) -> Usage {
+        Usage {
+            prompt_tokens: prompt,
+            completion_tokens: completion,
+            total_tokens: prompt + completion,
+            prompt_tokens_details: Some(PromptTokenDetails {
+                cached_tokens: Some(cached),
+                audio_tokens: None,
+            }),
+            completion_tokens_details: None,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn test_estimated_cost_applies_cache_discount() {
+        let pricing = PricingTable::empty().with_model(
+            "test-model",
+            ModelPricing::new(2.0, 8.0).with_cached_input(0.5),
+        );
+
+        let cost = usage(1_000_000, 400_000, 500_000)
+            .estimated_cost("test-model", &pricing)
+            .unwrap();
+
+        // 600k uncached * $2 + 400k cached * $0.5 + 500k output * $8
+        assert_close(cost, 1.2 + 0.2 + 4.0);
+    }
+
+    #[test]
+    fn test_estimated_cost_unknown_model() {
+        let pricing = PricingTable::empty();
+        assert!(
+            usage(10, 0, 10)
+                .estimated_cost("gpt-4o", &pricing)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_pricing_table_resolves_dated_snapshots() {
+        let pricing = PricingTable::default();
+
+        let mini = pricing.get("gpt-4o-mini-2024-07-18").unwrap();
+        assert_close(mini.input_per_million, 0.15);
+
+        let full = pricing.get("gpt-4o-2024-08-06").unwrap();
+        assert_close(full.input_per_million, 2.50);
+
+        assert!(pricing.get("unknown-model").is_none());
+    }
+
+    #[test]
+    fn test_pricing_table_does_not_price_variants_as_base_model() {
+        let pricing = PricingTable::default();
+
+        for model in [
+            "o1-mini",
+            "o1-pro",
+            "o3-pro",
+            "gpt-5-pro",
+            "gpt-5-codex",
+            "gpt-4o-mini-tts",
+            "gpt-4o-audio-preview",
+            "gpt-4o-2024",
+        ] {
+            assert!(pricing.get(model).is_none(), "{model} should be unpriced");
+        }
+    }
+
+    #[test]
+    fn test_cost_tracker_accumulates() {
+        let pricing = PricingTable::empty()
+            .with_model("model-a", ModelPricing::new(1.0, 2.0))
+            .with_model("model-b", ModelPricing::new(3.0, 4.0));
+        let tracker = CostTracker::new(pricing);
+
+        tracker.record("model-a", &usage(1_000_000, 0, 0));
+        tracker.record("model-a", &usage(0, 0, 1_000_000));
+        tracker.record("model-b", &usage(1_000_000, 0, 0));
+        assert!(tracker.record("model-c", &usage(10, 0, 10)).is_none());
+
+        assert_close(tracker.total(), 1.0 + 2.0 + 3.0);
+        let by_model = tracker.by_model();
+        assert_eq!(by_model["model-a"].requests, 2);
+        assert_close(by_model["model-b"].cost, 3.0);
+        assert_eq!(tracker.unpriced_requests(), 1);
+
+        tracker.reset();
+        assert_close(tracker.total(), 0.0);
+    }
+
+    #[test]
+    fn test_cost_tracker_breaks_down_by_endpoint() {
+        let tracker = CostTracker::new(PricingTable::default());
+
+        assert!(tracker.record_images("dall-e-3", None, None, 2).is_some());
+        assert!(tracker.record_audio_minutes("whisper-1", 10.0).is_some());
+        tracker.record_tokens(
+            CostEndpoint::Embeddings,
+            "text-embedding-3-small",
+            1_000_000,
+            0,
+            0,
+        );
+        assert!(tracker.record_images("gpt-4o", None, None, 1).is_none());
+
+        let by_endpoint = tracker.by_endpoint();
+        assert_close(by_endpoint[&CostEndpoint::Images], 0.08);
+        assert_close(by_endpoint[&CostEndpoint::Audio], 0.06);
+        assert_close(by_endpoint[&CostEndpoint::Embeddings], 0.02);
+        assert_close(tracker.total(), 0.16);
+        assert_eq!(tracker.unpriced_requests(), 1);
+        assert_eq!(CostEndpoint::Embeddings.to_string(), "embeddings");
+    }
+
+    #[test]
+    fn test_image_pricing_by_size_and_quality() {
+        let pricing = PricingTable::default();
+        let dalle3 = pricing.get("dall-e-3").unwrap().images.unwrap();
+        let wide = ImageSize::Size1024x1792;
+
+        assert_close(dalle3.price(None, None).unwrap(), 0.040);
+        assert_close(dalle3.price(Some(&wide), None).unwrap(), 0.080);
+        assert_close(dalle3.price(None, Some(&ImageQuality::Hd)).unwrap(), 0.080);
+        assert_close(
+            dalle3.price(Some(&wide), Some(&ImageQuality::Hd)).unwrap(),
+            0.120,
+        );
+        assert!(dalle3.price(Some(&ImageSize::Size256x256), None).is_none());
+
+        let dalle2 = pricing.get("dall-e-2").unwrap().images.unwrap();
+        let small = ImageSize::Size256x256;
+        assert_close(dalle2.price(Some(&small), None).unwrap(), 0.016);
+        assert_close(
+            dalle2.price(Some(&small), Some(&ImageQuality::Hd)).unwrap(),
+            0.016,
+        );
+
+        let tracker = CostTracker::new(pricing);
+        let cost = tracker.record_images("dall-e-3", Some(&wide), Some(&ImageQuality::Hd), 2);
+        assert_close(cost.unwrap(), 0.240);
+        assert!(
+            tracker
+                .record_images("dall-e-3", Some(&small), None, 1)
+                .is_none()
+        );
+        assert_eq!(tracker.unpriced_requests(), 1);
+    }
+
+    #[test]
+    fn test_training_cost_estimate() {
+        let report = TrainingFileReport {
+            total_tokens: 200_000,
+            ..TrainingFileReport::default()
+        };
+        let pricing = PricingTable::default();
+
+        let estimate = report
+            .estimated_cost("gpt-4o-mini-2024-07-18", 3, &pricing)
+            .unwrap();
+        assert_eq!(estimate.tokens_per_epoch, 200_000);
+        assert_eq!(estimate.trained_tokens, 600_000);
+        assert!((estimate.cost - 1.80).abs() < 1e-9);
+
+        assert!(report.estimated_cost("o3", 3, &pricing).is_none());
+        assert!(
+            report
+                .estimated_cost("unknown-model", 3, &pricing)
+                .is_none()
+        );
+    }
+}
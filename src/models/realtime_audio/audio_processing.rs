@@ -3,8 +3,21 @@
 //! Contains data structures for WebRTC connections, voice activity detection,
 //! audio buffer management, and real-time audio processing utilities.
 
+use base64::{Engine as _, engine::general_purpose};
 use chrono::{DateTime, Utc};
 
+/// Sample rate of the PCM16 audio the realtime API accepts, in Hz
+pub const REALTIME_PCM16_SAMPLE_RATE: u32 = 24_000;
+
+/// Magnitude of a full-scale PCM16 sample
+const FULL_SCALE: f64 = 32768.0;
+
+/// Most raw audio bytes sent in one `input_audio_buffer.append` event
+///
+/// The API accepts up to 15 MiB per event; base64 grows the payload by a
+/// third, so raw chunks are capped at three quarters of that.
+pub const MAX_AUDIO_APPEND_BYTES: usize = 15 * 1024 * 1024 / 4 * 3;
+
 /// WebRTC connection state
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WebRtcConnectionState {
@@ -168,21 +181,66 @@ impl AudioBuffer {
     }
 
     /// Get the duration of the audio buffer in seconds
+    ///
+    /// Same as [`duration_secs`](Self::duration_secs).
     #[must_use]
     pub fn duration_seconds(&self) -> f64 {
-        self.samples.len() as f64 / (f64::from(self.sample_rate) * f64::from(self.channels))
+        self.duration_secs()
     }
 
     /// Get the duration of the audio buffer in milliseconds
     #[must_use]
     pub fn duration_ms(&self) -> u32 {
-        (self.duration_seconds() * 1000.0) as u32
+        (self.duration_secs() * 1000.0) as u32
     }
 
     /// Get the number of frames in the buffer
     #[must_use]
     pub fn frame_count(&self) -> usize {
-        self.samples.len() / self.channels as usize
+        self.samples.len() / usize::from(self.channels.max(1))
+    }
+
+    /// Get the playback duration in seconds
+    ///
+    /// Counts frames rather than samples, so interleaved multi-channel audio
+    /// is not over-counted. Returns 0.0 for an empty buffer or a buffer with
+    /// a zero sample rate or channel count.
+    #[must_use]
+    pub fn duration_secs(&self) -> f64 {
+        if self.sample_rate == 0 || self.channels == 0 {
+            return 0.0;
+        }
+        self.frame_count() as f64 / f64::from(self.sample_rate)
+    }
+
+    /// Get the RMS level across all channels, from 0.0 (silence) to 1.0 (full scale)
+    ///
+    /// Returns 0.0 for an empty buffer.
+    #[must_use]
+    pub fn rms(&self) -> f32 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let sum_squares: f64 = self
+            .samples
+            .iter()
+            .map(|&s| (f64::from(s) / FULL_SCALE).powi(2))
+            .sum();
+        (sum_squares / self.samples.len() as f64).sqrt().min(1.0) as f32
+    }
+
+    /// Get the peak level across all channels, from 0.0 (silence) to 1.0 (full scale)
+    ///
+    /// Returns 0.0 for an empty buffer.
+    #[must_use]
+    pub fn peak(&self) -> f32 {
+        let peak = self
+            .samples
+            .iter()
+            .map(|s| s.unsigned_abs())
+            .max()
+            .unwrap_or(0);
+        (f64::from(peak) / FULL_SCALE).min(1.0) as f32
     }
 
     /// Convert to mono if stereo
@@ -201,21 +259,20 @@ impl AudioBuffer {
         }
     }
 
-    /// Get RMS (Root Mean Square) energy level
+    /// Get RMS (Root Mean Square) energy level in sample units
+    ///
+    /// This is [`rms`](Self::rms) scaled back to the 16-bit sample range.
     #[must_use]
     pub fn rms_energy(&self) -> f32 {
-        if self.samples.is_empty() {
-            return 0.0;
-        }
-
-        let sum_squares: f64 = self.samples.iter().map(|&s| f64::from(s).powi(2)).sum();
-        (sum_squares / self.samples.len() as f64).sqrt() as f32
+        self.rms() * FULL_SCALE as f32
     }
 
-    /// Get peak amplitude
+    /// Get peak amplitude in sample units
+    ///
+    /// This is [`peak`](Self::peak) scaled back to the 16-bit sample range.
     #[must_use]
     pub fn peak_amplitude(&self) -> i16 {
-        self.samples.iter().map(|&s| s.abs()).max().unwrap_or(0)
+        (f64::from(self.peak()) * FULL_SCALE).min(f64::from(i16::MAX)) as i16
     }
 
     /// Check if buffer is empty
@@ -246,6 +303,25 @@ impl AudioBuffer {
         self.samples.truncate(len);
     }
 
+    /// Encode the buffer as base64 little-endian mono PCM16 chunks
+    ///
+    /// Each chunk holds at most `max_bytes` raw bytes and always ends on a
+    /// sample boundary, so the chunks can be sent as consecutive
+    /// `input_audio_buffer.append` events. An empty buffer yields no chunks.
+    #[must_use]
+    pub fn to_base64_chunks(&self, max_bytes: usize) -> Vec<String> {
+        let bytes: Vec<u8> = self
+            .to_mono()
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        let chunk_len = (max_bytes / 2).max(1) * 2;
+        bytes
+            .chunks(chunk_len)
+            .map(|chunk| general_purpose::STANDARD.encode(chunk))
+            .collect()
+    }
+
     /// Split the buffer at the specified index
     pub fn split_off(&mut self, at: usize) -> AudioBuffer {
         let remaining_samples = self.samples.split_off(at);
@@ -304,6 +380,68 @@ mod tests {
         assert!(energy > 0.0);
     }
 
+    #[test]
+    fn test_audio_buffer_levels() {
+        let stereo = AudioBuffer::new(vec![16384, -16384, 16384, -16384], 2, 2);
+        assert_eq!(stereo.duration_secs(), 1.0);
+        assert_eq!(stereo.duration_ms(), 1000);
+        assert!((stereo.rms() - 0.5).abs() < 1e-6);
+        assert!((stereo.peak() - 0.5).abs() < 1e-6);
+        assert_eq!(stereo.rms_energy(), 16384.0);
+        assert_eq!(stereo.peak_amplitude(), 16384);
+
+        let clipped = AudioBuffer::new(vec![i16::MIN, 0], 24000, 1);
+        assert_eq!(clipped.peak(), 1.0);
+        assert_eq!(clipped.peak_amplitude(), i16::MAX);
+
+        let empty = AudioBuffer::new(Vec::new(), 24000, 2);
+        assert_eq!(empty.duration_secs(), 0.0);
+        assert_eq!(empty.rms(), 0.0);
+        assert_eq!(empty.peak(), 0.0);
+
+        let malformed = AudioBuffer::new(vec![1, 2], 0, 0);
+        assert_eq!(malformed.duration_secs(), 0.0);
+        assert_eq!(malformed.duration_seconds(), 0.0);
+        assert_eq!(malformed.duration_ms(), 0);
+        assert_eq!(malformed.frame_count(), 2);
+    }
+
+    #[test]
+    fn test_audio_buffer_base64_chunks() {
+        let buffer = AudioBuffer::new(vec![1, -2, 3], REALTIME_PCM16_SAMPLE_RATE, 1);
+
+        let whole = buffer.to_base64_chunks(MAX_AUDIO_APPEND_BYTES);
+        assert_eq!(whole.len(), 1);
+        assert_eq!(
+            general_purpose::STANDARD.decode(&whole[0]).unwrap(),
+            vec![1, 0, 0xfe, 0xff, 3, 0]
+        );
+
+        // An odd limit still splits on sample boundaries
+        let chunks = buffer.to_base64_chunks(3);
+        let decoded: Vec<Vec<u8>> = chunks
+            .iter()
+            .map(|chunk| general_purpose::STANDARD.decode(chunk).unwrap())
+            .collect();
+        assert_eq!(decoded, vec![vec![1, 0], vec![0xfe, 0xff], vec![3, 0]]);
+
+        assert!(
+            AudioBuffer::new(Vec::new(), REALTIME_PCM16_SAMPLE_RATE, 1)
+                .to_base64_chunks(MAX_AUDIO_APPEND_BYTES)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_audio_buffer_base64_chunks_downmixes_stereo() {
+        let buffer = AudioBuffer::new(vec![100, 300], REALTIME_PCM16_SAMPLE_RATE, 2);
+        let chunks = buffer.to_base64_chunks(MAX_AUDIO_APPEND_BYTES);
+        assert_eq!(
+            general_purpose::STANDARD.decode(&chunks[0]).unwrap(),
+            200i16.to_le_bytes().to_vec()
+        );
+    }
+
     #[test]
     fn test_webrtc_stats_default() {
         let stats = WebRtcStats::default();
@@ -7,7 +7,13 @@ use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
 
 /// Conversation item
+///
+/// Function call and function call output items carry their data as a single
+/// [`ContentPart::FunctionCall`] or [`ContentPart::FunctionCallOutput`]. On the
+/// wire those fields sit at the top level of the item, without `role` or
+/// `content`, as the Realtime API expects.
 #[derive(Debug, Clone, Ser, De)]
+#[serde(into = "WireConversationItem", try_from = "WireConversationItem")]
 pub struct ConversationItem {
     /// Unique item identifier
     pub id: Option<String>,
@@ -164,6 +170,9 @@ impl ConversationItem {
     }
 
     /// Create a new function call output
+    ///
+    /// Send it with `conversation.item.create` to return a tool result for
+    /// the `call_id` from `response.function_call_arguments.done`.
     #[must_use]
     pub fn new_function_call_output(call_id: String, output: String) -> Self {
         Self {
@@ -204,6 +213,144 @@ impl ConversationItem {
     }
 }
 
+/// Item type names as the Realtime API spells them
+#[derive(Ser, De)]
+#[serde(rename_all = "snake_case")]
+enum WireItemType {
+    /// `message`
+    Message,
+    /// `function_call`
+    FunctionCall,
+    /// `function_call_output`
+    FunctionCallOutput,
+}
+
+/// Wire representation of [`ConversationItem`]
+#[derive(Ser, De)]
+struct WireConversationItem {
+    /// Unique item identifier
+    id: Option<String>,
+    /// Object type
+    object: String,
+    /// Item type
+    #[serde(rename = "type")]
+    item_type: WireItemType,
+    /// Item status
+    status: ConversationItemStatus,
+    /// Item role, only sent for message items
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    role: Option<ConversationRole>,
+    /// Item content, only sent for message items
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    content: Vec<ContentPart>,
+    /// Call ID of a function call or function call output item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    call_id: Option<String>,
+    /// Function name of a function call item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    /// Function arguments (JSON string) of a function call item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    arguments: Option<String>,
+    /// Function result of a function call output item
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    output: Option<String>,
+}
+
+impl From<ConversationItem> for WireConversationItem {
+    fn from(item: ConversationItem) -> Self {
+        let mut wire = Self {
+            id: item.id,
+            object: item.object,
+            item_type: WireItemType::Message,
+            status: item.status,
+            role: None,
+            content: Vec::new(),
+            call_id: None,
+            name: None,
+            arguments: None,
+            output: None,
+        };
+
+        match item.item_type {
+            ConversationItemType::Message => {
+                wire.role = Some(item.role);
+                wire.content = item.content;
+            }
+            ConversationItemType::FunctionCall => {
+                wire.item_type = WireItemType::FunctionCall;
+                for part in item.content {
+                    if let ContentPart::FunctionCall {
+                        call_id,
+                        name,
+                        arguments,
+                    } = part
+                    {
+                        wire.call_id = Some(call_id);
+                        wire.name = Some(name);
+                        wire.arguments = Some(arguments);
+                    }
+                }
+            }
+            ConversationItemType::FunctionCallOutput => {
+                wire.item_type = WireItemType::FunctionCallOutput;
+                for part in item.content {
+                    if let ContentPart::FunctionCallOutput { call_id, output } = part {
+                        wire.call_id = Some(call_id);
+                        wire.output = Some(output);
+                    }
+                }
+            }
+        }
+
+        wire
+    }
+}
+
+impl TryFrom<WireConversationItem> for ConversationItem {
+    type Error = String;
+
+    fn try_from(wire: WireConversationItem) -> Result<Self, Self::Error> {
+        let (item_type, role, content) = match wire.item_type {
+            WireItemType::Message => (
+                ConversationItemType::Message,
+                wire.role.ok_or("message item is missing `role`")?,
+                wire.content,
+            ),
+            WireItemType::FunctionCall => (
+                ConversationItemType::FunctionCall,
+                ConversationRole::Assistant,
+                vec![ContentPart::FunctionCall {
+                    call_id: wire
+                        .call_id
+                        .ok_or("function_call item is missing `call_id`")?,
+                    name: wire.name.unwrap_or_default(),
+                    arguments: wire.arguments.unwrap_or_default(),
+                }],
+            ),
+            WireItemType::FunctionCallOutput => (
+                ConversationItemType::FunctionCallOutput,
+                ConversationRole::User,
+                vec![ContentPart::FunctionCallOutput {
+                    call_id: wire
+                        .call_id
+                        .ok_or("function_call_output item is missing `call_id`")?,
+                    output: wire.output.unwrap_or_default(),
+                }],
+            ),
+        };
+
+        Ok(Self {
+            id: wire.id,
+            object: wire.object,
+            item_type,
+            status: wire.status,
+            role,
+            content,
+        })
+    }
+}
+
 impl ContentPart {
     /// Get the content type as a string
     #[must_use]
@@ -308,6 +455,63 @@ mod tests {
         assert_eq!(item.item_type, ConversationItemType::FunctionCallOutput);
     }
 
+    #[test]
+    fn test_function_call_output_item_serialization() {
+        let item = ConversationItem::new_function_call_output(
+            "call-123".to_string(),
+            "Sunny, 75°F".to_string(),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&item).unwrap(),
+            serde_json::json!({
+                "id": null,
+                "object": "realtime.item",
+                "type": "function_call_output",
+                "status": "completed",
+                "call_id": "call-123",
+                "output": "Sunny, 75°F"
+            })
+        );
+    }
+
+    #[test]
+    fn test_function_call_item_deserialization() {
+        let item: ConversationItem = serde_json::from_value(serde_json::json!({
+            "id": "item_1",
+            "object": "realtime.item",
+            "type": "function_call",
+            "status": "completed",
+            "name": "get_weather",
+            "call_id": "call_1",
+            "arguments": "{\"location\":\"Paris\"}"
+        }))
+        .unwrap();
+
+        assert_eq!(item.item_type, ConversationItemType::FunctionCall);
+        assert_eq!(item.role, ConversationRole::Assistant);
+        assert!(item.has_function_call());
+        assert!(matches!(
+            &item.content[..],
+            [ContentPart::FunctionCall { call_id, name, .. }]
+                if call_id == "call_1" && name == "get_weather"
+        ));
+    }
+
+    #[test]
+    fn test_message_item_round_trip() {
+        let item = ConversationItem::new_text_message(ConversationRole::User, "Hi".to_string());
+        let json = serde_json::to_value(&item).unwrap();
+
+        assert_eq!(json["type"], "message");
+        assert_eq!(json["role"], "user");
+        assert!(json.get("call_id").is_none());
+
+        let back: ConversationItem = serde_json::from_value(json).unwrap();
+        assert_eq!(back.role, ConversationRole::User);
+        assert_eq!(back.get_text_content(), Some("Hi"));
+    }
+
     #[test]
     fn test_content_part_type_detection() {
         let text_part = ContentPart::Text {
@@ -420,7 +420,8 @@ pub enum RealtimeEvent {
         item_id: String,
         /// Output item index
         output_index: u32,
-        /// Content part index
+        /// Content part index, not sent for function call events
+        #[serde(default)]
         content_index: u32,
         /// Call ID
         call_id: String,
@@ -439,10 +440,14 @@ pub enum RealtimeEvent {
         item_id: String,
         /// Output item index
         output_index: u32,
-        /// Content part index
+        /// Content part index, not sent for function call events
+        #[serde(default)]
         content_index: u32,
         /// Call ID
         call_id: String,
+        /// Name of the called function
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
         /// Complete arguments
         arguments: String,
     },
@@ -509,6 +514,29 @@ impl RealtimeEvent {
         )
     }
 
+    /// Check if the server detected the user starting to speak
+    ///
+    /// With server turn detection this marks a barge-in: cancel any
+    /// assistant response that is still playing.
+    #[must_use]
+    pub fn is_speech_started(&self) -> bool {
+        matches!(self, Self::InputAudioBufferSpeechStarted { .. })
+    }
+
+    /// Get the call ID and complete arguments of a finished function call
+    ///
+    /// Returns `None` for every event except
+    /// `response.function_call_arguments.done`.
+    #[must_use]
+    pub fn function_call_arguments(&self) -> Option<(&str, &str)> {
+        match self {
+            Self::ResponseFunctionCallArgumentsDone {
+                call_id, arguments, ..
+            } => Some((call_id, arguments)),
+            _ => None,
+        }
+    }
+
     /// Check if this is a server-to-client event
     #[must_use]
     pub fn is_server_event(&self) -> bool {
@@ -586,4 +614,74 @@ mod tests {
         assert_eq!(event.event_id(), "response-123");
         assert!(event.is_client_event());
     }
+
+    #[test]
+    fn test_function_call_arguments_events_deserialize() {
+        let delta: RealtimeEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.function_call_arguments.delta",
+            "event_id": "event_1",
+            "response_id": "resp_1",
+            "item_id": "item_1",
+            "output_index": 0,
+            "call_id": "call_1",
+            "delta": "{\"loc"
+        }))
+        .unwrap();
+        assert!(delta.is_server_event());
+        assert!(delta.function_call_arguments().is_none());
+
+        let done: RealtimeEvent = serde_json::from_value(serde_json::json!({
+            "type": "response.function_call_arguments.done",
+            "event_id": "event_2",
+            "response_id": "resp_1",
+            "item_id": "item_1",
+            "output_index": 0,
+            "call_id": "call_1",
+            "name": "get_weather",
+            "arguments": "{\"location\":\"Paris\"}"
+        }))
+        .unwrap();
+        assert_eq!(
+            done.function_call_arguments(),
+            Some(("call_1", r#"{"location":"Paris"}"#))
+        );
+    }
+
+    #[test]
+    fn test_speech_started_event() {
+        let event: RealtimeEvent = serde_json::from_value(serde_json::json!({
+            "type": "input_audio_buffer.speech_started",
+            "event_id": "event_4",
+            "audio_start_ms": 1000,
+            "item_id": "item_2"
+        }))
+        .unwrap();
+        assert!(event.is_speech_started());
+        assert!(
+            !RealtimeEvent::ResponseCancel {
+                event_id: "event_5".to_string()
+            }
+            .is_speech_started()
+        );
+    }
+
+    #[test]
+    fn test_function_call_output_item_create_event() {
+        use crate::models::realtime_audio::conversation_types::ConversationItem;
+
+        let event = RealtimeEvent::ConversationItemCreate {
+            event_id: "event_3".to_string(),
+            previous_item_id: None,
+            item: ConversationItem::new_function_call_output(
+                "call_1".to_string(),
+                r#"{"temperature":21}"#.to_string(),
+            ),
+        };
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "conversation.item.create");
+        assert_eq!(json["item"]["type"], "function_call_output");
+        assert_eq!(json["item"]["call_id"], "call_1");
+        assert_eq!(json["item"]["output"], r#"{"temperature":21}"#);
+    }
 }
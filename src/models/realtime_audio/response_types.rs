@@ -62,7 +62,9 @@ pub struct RealtimeSessionInfo {
     /// Input audio transcription configuration
     pub input_audio_transcription: Option<InputAudioTranscriptionConfig>,
 
-    /// Turn detection configuration
+    /// Turn detection configuration, [`TurnDetectionConfig::disabled`] when the
+    /// server reports `null`
+    #[serde(with = "super::session_config::turn_detection_serde")]
     pub turn_detection: TurnDetectionConfig,
 
     /// Tools available in the session
@@ -450,6 +452,31 @@ mod tests {
         assert_eq!(info.output_count(), 0);
     }
 
+    #[test]
+    fn test_session_info_reads_null_turn_detection_as_disabled() {
+        let info: RealtimeSessionInfo = serde_json::from_value(serde_json::json!({
+            "id": "sess_1",
+            "object": "realtime.session",
+            "model": "gpt-4o-realtime-preview",
+            "modalities": ["text"],
+            "instructions": "",
+            "voice": "alloy",
+            "input_audio_format": "pcm16",
+            "output_audio_format": "pcm16",
+            "input_audio_transcription": null,
+            "turn_detection": null,
+            "tools": [],
+            "tool_choice": "auto",
+            "temperature": 0.8,
+            "max_response_output_tokens": null
+        }))
+        .unwrap();
+        assert!(info.turn_detection.is_disabled());
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert!(json["turn_detection"].is_null());
+    }
+
     #[test]
     fn test_error_type_classification() {
         let auth_error = RealtimeError::new(
@@ -3,6 +3,7 @@
 //! Contains data structures for configuring real-time audio sessions,
 //! including audio formats, voice options, detection settings, and tools.
 
+use crate::models::functions::FunctionTool;
 use crate::{De, Ser};
 use chrono::{DateTime, Utc};
 use serde::{self, Deserialize, Serialize};
@@ -50,7 +51,17 @@ pub struct RealtimeSessionConfig {
     pub voice_activity_detection: Option<VoiceActivityDetectionConfig>,
 
     /// Turn detection settings
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// `None` leaves the field out, so a partial `session.update` keeps the
+    /// current setting. [`TurnDetectionConfig::disabled`] serializes as
+    /// `null`, which disables server-side turn detection so the client
+    /// decides when a turn ends (push-to-talk).
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "turn_detection_serde::serialize_option",
+        deserialize_with = "turn_detection_serde::deserialize_option"
+    )]
     pub turn_detection: Option<TurnDetectionConfig>,
 
     /// Tools available to the assistant
@@ -81,6 +92,19 @@ pub enum RealtimeAudioFormat {
     G711Alaw,
 }
 
+impl RealtimeAudioFormat {
+    /// Bytes of encoded audio per second of playback
+    ///
+    /// PCM16 is 16-bit mono at 24 kHz; the G.711 formats are 8-bit at 8 kHz.
+    #[must_use]
+    pub const fn bytes_per_second(&self) -> u32 {
+        match self {
+            Self::Pcm16 => super::audio_processing::REALTIME_PCM16_SAMPLE_RATE * 2,
+            Self::G711Ulaw | Self::G711Alaw => 8_000,
+        }
+    }
+}
+
 /// Voice options for real-time audio responses
 #[derive(Debug, Clone, Ser, De, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -110,6 +134,28 @@ pub struct VoiceActivityDetectionConfig {
 
     /// Silence duration to detect end of speech
     pub silence_duration_ms: u32,
+
+    /// Energy level below which ongoing speech ends
+    ///
+    /// Set it below `threshold` for hysteresis, so energy hovering around the
+    /// start threshold does not flip between speech and silence. Defaults
+    /// to `threshold`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub speech_end_threshold: Option<f32>,
+
+    /// Smoothing factor applied while energy rises, between 0.0 and 1.0
+    ///
+    /// Smaller values react more slowly; 1.0 (the default) disables
+    /// smoothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attack: Option<f32>,
+
+    /// Smoothing factor applied while energy falls, between 0.0 and 1.0
+    ///
+    /// Smaller values let the level decay slowly so short pauses do not end
+    /// speech; 1.0 (the default) disables smoothing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<f32>,
 }
 
 /// Turn detection configuration
@@ -132,6 +178,167 @@ pub struct TurnDetectionConfig {
     pub silence_duration_ms: Option<u32>,
 }
 
+impl TurnDetectionConfig {
+    /// Turn detection switched off, sent to the API as `null`
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self {
+            detection_type: TurnDetectionType::None,
+            threshold: None,
+            prefix_padding_ms: None,
+            silence_duration_ms: None,
+        }
+    }
+
+    /// Check whether this config switches turn detection off
+    #[must_use]
+    pub fn is_disabled(&self) -> bool {
+        matches!(self.detection_type, TurnDetectionType::None)
+    }
+}
+
+/// Serde helpers mapping a disabled [`TurnDetectionConfig`] to and from `null`
+pub(crate) mod turn_detection_serde {
+    use super::TurnDetectionConfig;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialize a config, writing `null` when turn detection is disabled
+    pub(crate) fn serialize<S: Serializer>(
+        config: &TurnDetectionConfig,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if config.is_disabled() {
+            serializer.serialize_none()
+        } else {
+            config.serialize(serializer)
+        }
+    }
+
+    /// Deserialize a config, reading `null` as turn detection disabled
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<TurnDetectionConfig, D::Error> {
+        Ok(Option::<TurnDetectionConfig>::deserialize(deserializer)?
+            .unwrap_or_else(TurnDetectionConfig::disabled))
+    }
+
+    /// Serialize an optional config; callers skip `None` with `skip_serializing_if`
+    pub(crate) fn serialize_option<S: Serializer>(
+        config: &Option<TurnDetectionConfig>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match config {
+            Some(config) => serialize(config, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize a present field, reading `null` as turn detection disabled
+    ///
+    /// A missing field falls back to `None` through `#[serde(default)]`.
+    pub(crate) fn deserialize_option<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<TurnDetectionConfig>, D::Error> {
+        deserialize(deserializer).map(Some)
+    }
+}
+
+/// Builder for the `turn_detection` settings of a realtime session
+///
+/// Start from [`TurnDetectionBuilder::server_vad`] to let the server detect
+/// turns from voice activity, or [`TurnDetectionBuilder::none`] to disable
+/// turn detection for push-to-talk. Numeric settings are range-checked in
+/// [`TurnDetectionBuilder::build`].
+#[derive(Debug, Clone)]
+pub struct TurnDetectionBuilder {
+    /// The config being built, `None` when turn detection is disabled
+    config: Option<TurnDetectionConfig>,
+}
+
+impl TurnDetectionBuilder {
+    /// Longest accepted prefix padding or silence duration, in milliseconds
+    pub const MAX_DURATION_MS: u32 = 10_000;
+
+    /// Server-side voice activity detection with the API defaults
+    #[must_use]
+    pub fn server_vad() -> Self {
+        Self {
+            config: Some(TurnDetectionConfig {
+                detection_type: TurnDetectionType::ServerVad,
+                threshold: None,
+                prefix_padding_ms: None,
+                silence_duration_ms: None,
+            }),
+        }
+    }
+
+    /// Disable turn detection so the client commits audio itself
+    #[must_use]
+    pub fn none() -> Self {
+        Self { config: None }
+    }
+
+    /// Set the activation threshold, between 0.0 and 1.0
+    #[must_use]
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.threshold = Some(threshold);
+        }
+        self
+    }
+
+    /// Set how much audio before detected speech is included, in milliseconds
+    #[must_use]
+    pub fn prefix_padding_ms(mut self, ms: u32) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.prefix_padding_ms = Some(ms);
+        }
+        self
+    }
+
+    /// Set how long a silence ends the turn, in milliseconds
+    #[must_use]
+    pub fn silence_duration_ms(mut self, ms: u32) -> Self {
+        if let Some(config) = self.config.as_mut() {
+            config.silence_duration_ms = Some(ms);
+        }
+        self
+    }
+
+    /// Validate the settings and build the `turn_detection` value
+    ///
+    /// Returns `Ok(None)` for [`TurnDetectionBuilder::none`].
+    pub fn build(self) -> Result<Option<TurnDetectionConfig>, String> {
+        let Some(config) = self.config else {
+            return Ok(None);
+        };
+        if let Some(threshold) = config.threshold
+            && !(0.0..=1.0).contains(&threshold)
+        {
+            return Err(format!(
+                "threshold must be between 0.0 and 1.0, got {threshold}"
+            ));
+        }
+        if let Some(ms) = config.prefix_padding_ms
+            && ms > Self::MAX_DURATION_MS
+        {
+            return Err(format!(
+                "prefix_padding_ms must be at most {}, got {ms}",
+                Self::MAX_DURATION_MS
+            ));
+        }
+        if let Some(ms) = config.silence_duration_ms
+            && (ms == 0 || ms > Self::MAX_DURATION_MS)
+        {
+            return Err(format!(
+                "silence_duration_ms must be between 1 and {}, got {ms}",
+                Self::MAX_DURATION_MS
+            ));
+        }
+        Ok(Some(config))
+    }
+}
+
 /// Types of turn detection
 #[derive(Debug, Clone, Ser, De)]
 #[serde(rename_all = "snake_case")]
@@ -169,6 +376,28 @@ pub struct RealtimeTool {
     pub parameters: serde_json::Value,
 }
 
+impl RealtimeTool {
+    /// Create a function tool
+    pub fn function(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        parameters: serde_json::Value,
+    ) -> Self {
+        Self {
+            tool_type: "function".to_string(),
+            name: name.into(),
+            description: description.into(),
+            parameters,
+        }
+    }
+}
+
+impl From<FunctionTool> for RealtimeTool {
+    fn from(tool: FunctionTool) -> Self {
+        Self::function(tool.name, tool.description, tool.parameters)
+    }
+}
+
 /// Response from session creation
 #[derive(Debug, Clone, Ser, De)]
 pub struct RealtimeSessionResponse {
@@ -289,12 +518,35 @@ impl Default for RealtimeSessionConfig {
     }
 }
 
+impl RealtimeSessionConfig {
+    /// Replace the tools the model may call during the session
+    #[must_use]
+    pub fn with_tools<I, T>(mut self, tools: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<RealtimeTool>,
+    {
+        self.tools = Some(tools.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Replace the turn detection settings, `None` disables turn detection
+    #[must_use]
+    pub fn with_turn_detection(mut self, turn_detection: Option<TurnDetectionConfig>) -> Self {
+        self.turn_detection = Some(turn_detection.unwrap_or_else(TurnDetectionConfig::disabled));
+        self
+    }
+}
+
 impl Default for VoiceActivityDetectionConfig {
     fn default() -> Self {
         Self {
             threshold: 0.5,
             prefix_padding_ms: 300,
             silence_duration_ms: 200,
+            speech_end_threshold: None,
+            attack: None,
+            release: None,
         }
     }
 }
@@ -360,4 +612,101 @@ mod tests {
         assert!(config.turn_detection.is_some());
         assert_eq!(config.tool_choice, Some("auto".to_string()));
     }
+
+    #[test]
+    fn test_turn_detection_builder_server_vad() {
+        let config = TurnDetectionBuilder::server_vad()
+            .threshold(0.6)
+            .prefix_padding_ms(250)
+            .silence_duration_ms(500)
+            .build()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "type": "server_vad",
+                "threshold": 0.6f32,
+                "prefix_padding_ms": 250,
+                "silence_duration_ms": 500
+            })
+        );
+    }
+
+    #[test]
+    fn test_turn_detection_builder_rejects_out_of_range() {
+        assert!(
+            TurnDetectionBuilder::server_vad()
+                .threshold(1.5)
+                .build()
+                .is_err()
+        );
+        assert!(
+            TurnDetectionBuilder::server_vad()
+                .threshold(f32::NAN)
+                .build()
+                .is_err()
+        );
+        assert!(
+            TurnDetectionBuilder::server_vad()
+                .prefix_padding_ms(10_001)
+                .build()
+                .is_err()
+        );
+        assert!(
+            TurnDetectionBuilder::server_vad()
+                .silence_duration_ms(0)
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_turn_detection_none_serializes_as_null() {
+        let turn_detection = TurnDetectionBuilder::none().threshold(2.0).build().unwrap();
+        assert!(turn_detection.is_none());
+
+        let config = RealtimeSessionConfig::default().with_turn_detection(turn_detection);
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(json["turn_detection"].is_null());
+        assert!(json.as_object().unwrap().contains_key("turn_detection"));
+
+        let parsed: RealtimeSessionConfig = serde_json::from_value(json).unwrap();
+        assert!(parsed.turn_detection.unwrap().is_disabled());
+    }
+
+    #[test]
+    fn test_unset_turn_detection_is_omitted() {
+        let config = RealtimeSessionConfig {
+            turn_detection: None,
+            ..RealtimeSessionConfig::default()
+        };
+        let json = serde_json::to_value(&config).unwrap();
+        assert!(!json.as_object().unwrap().contains_key("turn_detection"));
+
+        let parsed: RealtimeSessionConfig = serde_json::from_value(json).unwrap();
+        assert!(parsed.turn_detection.is_none());
+    }
+
+    #[test]
+    fn test_session_config_with_function_tools() {
+        let tool = FunctionTool::new(
+            "get_weather",
+            "Get the weather for a location",
+            serde_json::json!({
+                "type": "object",
+                "properties": {"location": {"type": "string"}},
+                "required": ["location"]
+            }),
+        );
+
+        let config = RealtimeSessionConfig::default().with_tools([tool]);
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["tools"][0]["type"], "function");
+        assert_eq!(json["tools"][0]["name"], "get_weather");
+        assert_eq!(
+            json["tools"][0]["parameters"]["required"],
+            serde_json::json!(["location"])
+        );
+    }
 }
@@ -1,7 +1,11 @@
+/// Conversion between request shapes of the Responses and Chat Completions APIs
+pub mod chat_conversion;
 /// Image utilities and format handling
 pub mod image_utils;
 /// Message types for conversations and multimodal content
 pub mod message_types;
+/// Checking request parameters against model capabilities
+pub mod parameter_check;
 /// Request types for API requests
 pub mod request_types;
 /// Response types for API responses
@@ -10,14 +14,19 @@ pub mod response_types;
 pub mod schema_types;
 /// Streaming types for real-time responses
 pub mod streaming_types;
+/// Trimming conversations to fit a context window
+pub mod trimming;
 /// Usage, prompts, and utility types
 pub mod usage_types;
 
 // Re-export all public types to maintain backward compatibility
+pub use chat_conversion::*;
 pub use image_utils::*;
 pub use message_types::*;
+pub use parameter_check::*;
 pub use request_types::*;
 pub use response_types::*;
 pub use schema_types::*;
 pub use streaming_types::*;
+pub use trimming::*;
 pub use usage_types::*;
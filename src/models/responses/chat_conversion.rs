@@ -0,0 +1,262 @@
+//! Conversion between [`ResponseRequest`] and Chat Completions request bodies
+//!
+//! Helps codebases that still build Chat Completions requests migrate to the
+//! Responses API one call site at a time. Parameters are carried over where
+//! both APIs have an equivalent; anything without one is reported as an error
+//! instead of being dropped silently.
+
+use crate::error::{OpenAIError, Result};
+use crate::models::functions::{Tool, ToolChoice};
+use crate::models::responses_v2::StreamOptions;
+use crate::{De, Ser};
+use serde::{self, Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{
+    message_types::Message,
+    request_types::{ResponseInput, ResponseRequest},
+    schema_types::ResponseFormat,
+};
+
+/// Parameters of a Chat Completions request other than its messages
+#[derive(Debug, Clone, Default, Ser, De)]
+pub struct ChatCompletionOptions {
+    /// Model to use
+    pub model: String,
+    /// Sampling temperature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Maximum number of tokens to generate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    /// Top-p sampling parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// Frequency penalty parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    /// Presence penalty parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    /// Number of choices to generate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    /// Stop sequences
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+    /// Whether to stream the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+    /// Options for streamed responses
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<StreamOptions>,
+    /// Function tools available to the model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<Tool>>,
+    /// Tool choice configuration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
+    /// Whether to allow parallel tool calls
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_tool_calls: Option<bool>,
+    /// Response format specification
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<ResponseFormat>,
+    /// Prompt cache key for optimizing cache routing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_cache_key: Option<String>,
+    /// Per-token logit bias; has no Responses API equivalent
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
+    pub logit_bias: Option<HashMap<String, i32>>,
+    /// Whether to return log probabilities; has no Responses API equivalent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<bool>,
+}
+
+impl ChatCompletionOptions {
+    /// Create options for the given model
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// A Chat Completions request body
+#[derive(Debug, Clone, Ser, De)]
+pub struct ChatCompletionRequest {
+    /// Conversation messages
+    pub messages: Vec<Message>,
+    /// Model and generation parameters
+    #[serde(flatten)]
+    pub options: ChatCompletionOptions,
+}
+
+impl ResponseRequest {
+    /// Build a request from Chat Completions messages and parameters
+    ///
+    /// Accepts anything convertible into messages, such as a `ChatBuilder`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no messages or if `logit_bias` or
+    /// `logprobs` is set, since the Responses API has no equivalent.
+    pub fn from_chat_completion(
+        messages: impl Into<Vec<Message>>,
+        options: ChatCompletionOptions,
+    ) -> Result<Self> {
+        let messages = messages.into();
+        if messages.is_empty() {
+            return Err(OpenAIError::invalid_request(
+                "Chat completion request has no messages",
+            ));
+        }
+        if options.logit_bias.is_some() {
+            return Err(not_mappable("logit_bias", "the Responses API"));
+        }
+        if options.logprobs.is_some() {
+            return Err(not_mappable("logprobs", "the Responses API"));
+        }
+
+        let mut request = Self::new_messages(options.model, messages);
+        request.temperature = options.temperature;
+        request.max_tokens = options.max_tokens;
+        request.top_p = options.top_p;
+        request.frequency_penalty = options.frequency_penalty;
+        request.presence_penalty = options.presence_penalty;
+        request.n = options.n;
+        request.stop = options.stop;
+        request.stream = options.stream;
+        request.stream_options = options.stream_options;
+        request.tools = options.tools;
+        request.tool_choice = options.tool_choice;
+        request.parallel_tool_calls = options.parallel_tool_calls;
+        request.response_format = options.response_format;
+        request.prompt_cache_key = options.prompt_cache_key;
+        Ok(request)
+    }
+
+    /// Convert this request into a Chat Completions request body
+    ///
+    /// Instructions become a leading system message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request uses a Responses API feature with no
+    /// Chat Completions equivalent: `previous_response_id`, `reasoning`,
+    /// `text`, `prompt`, or hosted tools and their tool choice.
+    pub fn to_chat_completion_request(&self) -> Result<ChatCompletionRequest> {
+        let unsupported = [
+            ("previous_response_id", self.previous_response_id.is_some()),
+            ("reasoning", self.reasoning.is_some()),
+            ("text", self.text.is_some()),
+            ("prompt", self.prompt.is_some()),
+            ("enhanced_tools", self.enhanced_tools.is_some()),
+            ("enhanced_tool_choice", self.enhanced_tool_choice.is_some()),
+        ];
+        if let Some((field, _)) = unsupported.iter().find(|(_, is_set)| *is_set) {
+            return Err(not_mappable(field, "Chat Completions"));
+        }
+
+        let mut messages = Vec::new();
+        if let Some(instructions) = &self.instructions {
+            messages.push(Message::system(instructions.clone()));
+        }
+        match &self.input {
+            ResponseInput::Text(text) => messages.push(Message::user(text.clone())),
+            ResponseInput::Messages(input) => messages.extend(input.iter().cloned()),
+        }
+
+        Ok(ChatCompletionRequest {
+            messages,
+            options: ChatCompletionOptions {
+                model: self.model.clone(),
+                temperature: self.temperature,
+                max_tokens: self.max_tokens,
+                top_p: self.top_p,
+                frequency_penalty: self.frequency_penalty,
+                presence_penalty: self.presence_penalty,
+                n: self.n,
+                stop: self.stop.clone(),
+                stream: self.stream,
+                stream_options: self.stream_options.clone(),
+                tools: self.tools.clone(),
+                tool_choice: self.tool_choice.clone(),
+                parallel_tool_calls: self.parallel_tool_calls,
+                response_format: self.response_format.clone(),
+                prompt_cache_key: self.prompt_cache_key.clone(),
+                logit_bias: None,
+                logprobs: None,
+            },
+        })
+    }
+}
+
+/// Build the error for a field that cannot be carried over
+fn not_mappable(field: &str, target: &str) -> OpenAIError {
+    OpenAIError::invalid_request(format!("`{field}` has no equivalent in {target}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::functions::FunctionTool;
+    use crate::models::responses::MessageRole;
+
+    #[test]
+    fn test_round_trip_between_request_shapes() {
+        let options = ChatCompletionOptions {
+            temperature: Some(0.2),
+            stop: Some(vec!["END".to_string()]),
+            prompt_cache_key: Some("user-42".to_string()),
+            tools: Some(vec![Tool::function(FunctionTool::simple("now", "Time"))]),
+            ..ChatCompletionOptions::new("gpt-4o")
+        };
+        let messages = vec![Message::developer("Be terse"), Message::user("Hi")];
+
+        let request = ResponseRequest::from_chat_completion(messages, options).unwrap();
+        assert_eq!(request.model, "gpt-4o");
+        assert_eq!(request.temperature, Some(0.2));
+        assert_eq!(request.stop.as_deref(), Some(&["END".to_string()][..]));
+
+        let chat = request.to_chat_completion_request().unwrap();
+        assert_eq!(chat.messages.len(), 2);
+        let body = serde_json::to_value(&chat).unwrap();
+        assert_eq!(body["model"], "gpt-4o");
+        assert_eq!(body["messages"][0]["role"], "developer");
+        assert_eq!(body["tools"][0]["function"]["name"], "now");
+        assert_eq!(body["prompt_cache_key"], "user-42");
+        assert!(body.get("logprobs").is_none());
+    }
+
+    #[test]
+    fn test_instructions_become_system_message() {
+        let chat = ResponseRequest::new_text("gpt-4o", "Hello")
+            .with_instructions("Answer in French")
+            .to_chat_completion_request()
+            .unwrap();
+        assert_eq!(chat.messages.len(), 2);
+        assert_eq!(chat.messages[0].role, MessageRole::System);
+    }
+
+    #[test]
+    fn test_non_mappable_features_are_rejected() {
+        let options = ChatCompletionOptions {
+            logprobs: Some(true),
+            ..ChatCompletionOptions::new("gpt-4o")
+        };
+        let err =
+            ResponseRequest::from_chat_completion(vec![Message::user("Hi")], options).unwrap_err();
+        assert!(err.to_string().contains("logprobs"));
+
+        let err = ResponseRequest::new_text("gpt-4o", "Hi")
+            .with_web_search()
+            .to_chat_completion_request()
+            .unwrap_err();
+        assert!(err.to_string().contains("enhanced_tools"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::models::shared_traits::AsText;
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
 
@@ -96,6 +97,21 @@ pub struct Message {
     pub content: MessageContentInput,
 }
 
+impl AsText for Message {
+    fn text(&self) -> String {
+        match &self.content {
+            MessageContentInput::Text(text) => text.clone(),
+            MessageContentInput::Array(contents) => contents
+                .iter()
+                .filter_map(|c| match c {
+                    MessageContent::Text { text } => Some(text.as_str()),
+                    MessageContent::Image { .. } => None,
+                })
+                .collect(),
+        }
+    }
+}
+
 impl MessageContent {
     /// Create text content
     pub fn text(text: impl Into<String>) -> Self {
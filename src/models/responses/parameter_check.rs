@@ -0,0 +1,230 @@
+//! Checking request parameters against what a model accepts
+//!
+//! Many parameters only apply to some models: reasoning effort and verbosity
+//! to reasoning models, sampling controls to non-reasoning ones, tools to
+//! models with function calling. [`validate_against_model`] compares a
+//! [`ResponseRequest`] with the model's [`ModelCapabilities`] so these
+//! mismatches show up as clear local diagnostics instead of server
+//! rejections.
+
+use super::request_types::ResponseRequest;
+use crate::error::{OpenAIError, Result};
+use crate::models::models::ModelCapabilities;
+
+/// How incompatible parameters are handled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParameterCheckMode {
+    /// Report incompatible parameters as warnings and keep the request
+    #[default]
+    Warn,
+    /// Reject the request at the first incompatible parameter
+    Error,
+}
+
+/// A parameter the model does not accept
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParameterWarning {
+    /// Name of the parameter, such as `temperature` or `reasoning.effort`
+    pub parameter: &'static str,
+    /// Why the model does not accept it
+    pub message: String,
+}
+
+impl std::fmt::Display for ParameterWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.parameter, self.message)
+    }
+}
+
+/// Check a request's parameters against a model's capabilities
+///
+/// In [`ParameterCheckMode::Warn`] every incompatible parameter is returned
+/// as a warning. In [`ParameterCheckMode::Error`] the first one is returned
+/// as [`OpenAIError::InvalidRequest`].
+///
+/// # Errors
+///
+/// Returns an error in [`ParameterCheckMode::Error`] if any parameter is
+/// incompatible with the model.
+///
+/// # Example
+///
+/// ```rust
+/// use openai_rust_sdk::models::models::ModelCapabilities;
+/// use openai_rust_sdk::models::responses::{
+///     ParameterCheckMode, ResponseRequest, validate_against_model,
+/// };
+///
+/// let request = ResponseRequest::new_text("o3", "Hello").with_temperature(0.2);
+/// let capabilities = ModelCapabilities::from_model_id(&request.model);
+///
+/// let warnings =
+///     validate_against_model(&request, &capabilities, ParameterCheckMode::Warn).unwrap();
+/// assert_eq!(warnings[0].parameter, "temperature");
+/// assert!(validate_against_model(&request, &capabilities, ParameterCheckMode::Error).is_err());
+/// ```
+pub fn validate_against_model(
+    request: &ResponseRequest,
+    capabilities: &ModelCapabilities,
+    mode: ParameterCheckMode,
+) -> Result<Vec<ParameterWarning>> {
+    let warnings = incompatible_parameters(request, capabilities);
+    match (mode, warnings.first()) {
+        (ParameterCheckMode::Error, Some(warning)) => Err(OpenAIError::InvalidRequest(format!(
+            "{} does not accept {warning}",
+            request.model
+        ))),
+        _ => Ok(warnings),
+    }
+}
+
+/// Collect every parameter of the request the model does not accept
+fn incompatible_parameters(
+    request: &ResponseRequest,
+    capabilities: &ModelCapabilities,
+) -> Vec<ParameterWarning> {
+    let mut warnings = Vec::new();
+    let mut flag = |parameter: &'static str, message: String| {
+        warnings.push(ParameterWarning { parameter, message });
+    };
+
+    if let Some(effort) = request.reasoning.as_ref().and_then(|r| r.effort) {
+        if !capabilities.supports_reasoning() {
+            flag(
+                "reasoning.effort",
+                "reasoning effort is not supported by this model".to_string(),
+            );
+        } else if !capabilities.reasoning_efforts.contains(&effort) {
+            flag(
+                "reasoning.effort",
+                format!("effort {effort:?} is not supported by this model"),
+            );
+        }
+    }
+
+    if request.text.as_ref().is_some_and(|t| t.verbosity.is_some())
+        && !capabilities.supports_verbosity
+    {
+        flag(
+            "text.verbosity",
+            "verbosity is not supported by this model".to_string(),
+        );
+    }
+
+    if !capabilities.supports_sampling {
+        let sampling = [
+            ("temperature", request.temperature),
+            ("top_p", request.top_p),
+        ];
+        for (parameter, value) in sampling {
+            if value.is_some_and(|value| value != 1.0) {
+                flag(
+                    parameter,
+                    "reasoning models only support the default value of 1".to_string(),
+                );
+            }
+        }
+        let penalties = [
+            ("frequency_penalty", request.frequency_penalty),
+            ("presence_penalty", request.presence_penalty),
+        ];
+        for (parameter, value) in penalties {
+            if value.is_some() {
+                flag(
+                    parameter,
+                    "penalties are not supported by reasoning models".to_string(),
+                );
+            }
+        }
+    }
+
+    if request
+        .tools
+        .as_ref()
+        .is_some_and(|tools| !tools.is_empty())
+        && !capabilities.supports_function_calling
+    {
+        flag(
+            "tools",
+            "function calling is not supported by this model".to_string(),
+        );
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::functions::{FunctionTool, Tool};
+    use crate::models::gpt5::{ReasoningConfig, TextConfig};
+
+    fn check(request: &ResponseRequest) -> Vec<ParameterWarning> {
+        let capabilities = ModelCapabilities::from_model_id(&request.model);
+        validate_against_model(request, &capabilities, ParameterCheckMode::Warn).unwrap()
+    }
+
+    fn parameters(warnings: &[ParameterWarning]) -> Vec<&'static str> {
+        warnings.iter().map(|w| w.parameter).collect()
+    }
+
+    #[test]
+    fn test_compatible_requests_have_no_warnings() {
+        let chat = ResponseRequest::new_text("gpt-4o", "Hi")
+            .with_temperature(0.3)
+            .with_max_tokens(1_000);
+        assert!(check(&chat).is_empty());
+
+        let mut reasoning = ResponseRequest::new_text("gpt-5", "Hi");
+        reasoning.reasoning = Some(ReasoningConfig::minimal());
+        reasoning.text = Some(TextConfig::low());
+        assert!(check(&reasoning).is_empty());
+    }
+
+    #[test]
+    fn test_reasoning_parameters_on_non_reasoning_model() {
+        let mut request = ResponseRequest::new_text("gpt-4o", "Hi");
+        request.reasoning = Some(ReasoningConfig::high());
+        request.text = Some(TextConfig::low());
+        assert_eq!(
+            parameters(&check(&request)),
+            vec!["reasoning.effort", "text.verbosity"]
+        );
+    }
+
+    #[test]
+    fn test_sampling_parameters_on_reasoning_model() {
+        let mut request = ResponseRequest::new_text("o3", "Hi").with_temperature(0.2);
+        request.top_p = Some(1.0);
+        request.presence_penalty = Some(0.5);
+        request.reasoning = Some(ReasoningConfig::minimal());
+        assert_eq!(
+            parameters(&check(&request)),
+            vec!["reasoning.effort", "temperature", "presence_penalty"]
+        );
+    }
+
+    #[test]
+    fn test_tools_and_output_cap() {
+        let request = ResponseRequest::new_text("o1-mini", "Hi")
+            .with_tools(vec![Tool::function(FunctionTool::simple("now", "Time"))]);
+        assert_eq!(parameters(&check(&request)), vec!["tools"]);
+
+        // `max_tokens` caps the output and is not compared with the context window
+        let request = ResponseRequest::new_text("gpt-4.1", "Hi").with_max_tokens(20_000);
+        assert!(check(&request).is_empty());
+    }
+
+    #[test]
+    fn test_error_mode_rejects_first_incompatibility() {
+        let request = ResponseRequest::new_text("gpt-5", "Hi").with_temperature(0.2);
+        let capabilities = ModelCapabilities::from_model_id(&request.model);
+        let err =
+            validate_against_model(&request, &capabilities, ParameterCheckMode::Error).unwrap_err();
+        assert!(matches!(err, OpenAIError::InvalidRequest(_)));
+        assert!(
+            err.to_string()
+                .contains("gpt-5 does not accept temperature")
+        );
+    }
+}
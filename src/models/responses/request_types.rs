@@ -1,4 +1,7 @@
 use crate::models::functions::{Tool, ToolChoice};
+use crate::models::responses_v2::StreamOptions;
+use crate::models::shared_traits::{Validate, collect_errors};
+use crate::models::tools::validate_enhanced_tools;
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
 use std::collections::HashMap;
@@ -9,6 +12,9 @@ use super::{
     usage_types::{PromptTemplate, PromptVariable},
 };
 
+/// Most stop sequences the API accepts in one request
+pub const MAX_STOP_SEQUENCES: usize = 4;
+
 /// Input for response request - can be string or messages array
 #[derive(Debug, Clone, Ser, De)]
 #[serde(untagged)]
@@ -68,9 +74,18 @@ pub struct ResponseRequest {
     /// Maximum number of tokens to generate
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_tokens: Option<u32>,
+    /// Number of choices to generate for each input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    /// Sequences where the model stops generating; the API accepts up to four
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
     /// Whether to stream the response
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stream: Option<bool>,
+    /// Options for streamed responses, such as including usage in the final chunk
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream_options: Option<StreamOptions>,
     /// Prompt template for reusable prompts
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prompt: Option<PromptTemplate>,
@@ -118,7 +133,10 @@ impl ResponseRequest {
             text: None,
             temperature: None,
             max_tokens: None,
+            n: None,
+            stop: None,
             stream: None,
+            stream_options: None,
             prompt: None,
             top_p: None,
             frequency_penalty: None,
@@ -144,7 +162,10 @@ impl ResponseRequest {
             text: None,
             temperature: None,
             max_tokens: None,
+            n: None,
+            stop: None,
             stream: None,
+            stream_options: None,
             prompt: None,
             top_p: None,
             frequency_penalty: None,
@@ -179,6 +200,29 @@ impl ResponseRequest {
         self
     }
 
+    /// Set the number of choices to generate
+    ///
+    /// Values above 1 cannot be combined with streaming.
+    #[must_use]
+    pub fn with_n(mut self, n: u32) -> Self {
+        self.n = Some(n);
+        self
+    }
+
+    /// Set the sequences where the model stops generating
+    ///
+    /// At most [`MAX_STOP_SEQUENCES`] sequences are accepted. The stop
+    /// sequence is never part of the returned text.
+    #[must_use]
+    pub fn with_stop<I, S>(mut self, sequences: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stop = Some(sequences.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Enable streaming for the request
     #[must_use]
     pub fn with_streaming(mut self, stream: bool) -> Self {
@@ -186,6 +230,22 @@ impl ResponseRequest {
         self
     }
 
+    /// Set the options applied when the response is streamed
+    #[must_use]
+    pub fn with_stream_options(mut self, options: StreamOptions) -> Self {
+        self.stream_options = Some(options);
+        self
+    }
+
+    /// Request token usage in the final chunk of a streamed response
+    #[must_use]
+    pub fn with_stream_usage(mut self, include_usage: bool) -> Self {
+        self.stream_options
+            .get_or_insert_with(StreamOptions::default)
+            .include_usage = Some(include_usage);
+        self
+    }
+
     /// Set prompt template for reusable prompts
     #[must_use]
     pub fn with_prompt(mut self, template: PromptTemplate) -> Self {
@@ -417,4 +477,61 @@ impl ResponseRequest {
         });
         self
     }
+
+    /// Validate parameter combinations before the request is sent
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if `n` is zero, greater than one on a
+    /// streaming request, if more than [`MAX_STOP_SEQUENCES`] stop sequences
+    /// are set, or if the hosted tools contain ambiguous function names.
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_tools()?;
+        self.validate_stop()?;
+        self.validate_n()
+    }
+
+    /// Check that hosted tool function names are unambiguous
+    fn validate_tools(&self) -> Result<(), String> {
+        self.enhanced_tools
+            .as_deref()
+            .map_or(Ok(()), validate_enhanced_tools)
+    }
+
+    /// Check the number of stop sequences
+    fn validate_stop(&self) -> Result<(), String> {
+        match &self.stop {
+            Some(stop) if stop.len() > MAX_STOP_SEQUENCES => Err(format!(
+                "stop accepts at most {MAX_STOP_SEQUENCES} sequences, got {}",
+                stop.len()
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the number of choices against streaming
+    fn validate_n(&self) -> Result<(), String> {
+        match self.n {
+            Some(0) => Err("n must be at least 1".to_string()),
+            Some(n) if n > 1 && self.stream == Some(true) => Err(format!(
+                "n = {n} is not supported for streaming requests; request a single choice"
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Implementation of Validate trait for ResponseRequest
+impl Validate for ResponseRequest {
+    fn validate(&self) -> Result<(), String> {
+        self.validate()
+    }
+
+    fn validate_all(&self) -> Result<(), Vec<String>> {
+        collect_errors([
+            self.validate_tools(),
+            self.validate_stop(),
+            self.validate_n(),
+        ])
+    }
 }
@@ -1,6 +1,9 @@
 use crate::models::functions::FunctionCall;
+use crate::models::shared_traits::AsText;
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
 use super::{
     schema_types::SchemaValidationResult,
@@ -25,6 +28,217 @@ pub struct ResponseOutput {
     /// Schema validation result for structured outputs
     #[serde(skip_serializing_if = "Option::is_none")]
     pub schema_validation: Option<SchemaValidationResult>,
+    /// Refusal message returned instead of content when the model declines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal: Option<String>,
+    /// Annotations attached to the content, such as web search citations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<Vec<OutputAnnotation>>,
+}
+
+impl ResponseOutput {
+    /// Get the refusal message if the model declined to answer
+    #[must_use]
+    pub fn refusal(&self) -> Option<&str> {
+        self.refusal.as_deref()
+    }
+
+    /// Get the web pages cited in the content, de-duplicated by URL
+    ///
+    /// Sources are returned in first-seen order; only the first citation of
+    /// each URL is kept.
+    #[must_use]
+    pub fn web_sources(&self) -> Vec<&UrlCitation> {
+        let mut sources: Vec<&UrlCitation> = Vec::new();
+        for annotation in self.annotations.iter().flatten() {
+            if let OutputAnnotation::UrlCitation { url_citation } = annotation
+                && !sources.iter().any(|source| source.url == url_citation.url)
+            {
+                sources.push(url_citation);
+            }
+        }
+        sources
+    }
+
+    /// Get the files cited in the content, de-duplicated by file id
+    ///
+    /// Citations are returned in first-seen order, with the cited spans of
+    /// every annotation referencing the same file aggregated.
+    #[must_use]
+    pub fn citations(&self) -> Vec<FileCitation> {
+        let mut citations: Vec<FileCitation> = Vec::new();
+        for annotation in self.annotations.iter().flatten() {
+            let OutputAnnotation::FileCitation { file_citation } = annotation else {
+                continue;
+            };
+            match citations
+                .iter_mut()
+                .find(|citation| citation.file_id == file_citation.file_id)
+            {
+                Some(existing) => {
+                    if existing.filename.is_none() {
+                        existing.filename.clone_from(&file_citation.filename);
+                    }
+                    for span in &file_citation.spans {
+                        if !existing.spans.contains(span) {
+                            existing.spans.push(span.clone());
+                        }
+                    }
+                }
+                None => citations.push(file_citation.clone()),
+            }
+        }
+        citations
+    }
+
+    /// Get the identifiers of the files cited in the content, in first-seen order
+    #[must_use]
+    pub fn cited_file_ids(&self) -> Vec<String> {
+        self.citations()
+            .into_iter()
+            .map(|citation| citation.file_id)
+            .collect()
+    }
+}
+
+/// Annotation attached to generated text
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OutputAnnotation {
+    /// A web page cited by the model, typically from the web search tool
+    UrlCitation {
+        /// The cited page and the range of text citing it
+        url_citation: UrlCitation,
+    },
+    /// An uploaded file cited by the model, typically from the file search tool
+    FileCitation {
+        /// The cited file and the ranges of text citing it
+        file_citation: FileCitation,
+    },
+    /// An annotation kind this SDK does not model yet
+    #[serde(other)]
+    Unknown,
+}
+
+/// A web page cited in generated text
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De, Default)]
+pub struct UrlCitation {
+    /// The URL of the cited page
+    pub url: String,
+    /// The title of the cited page
+    #[serde(default)]
+    pub title: String,
+    /// Start of the citing text range, in characters
+    pub start_index: u32,
+    /// End of the citing text range, in characters
+    pub end_index: u32,
+}
+
+/// A file cited by the model, with the places in the output that cite it
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De, Default)]
+pub struct FileCitation {
+    /// Identifier of the cited file
+    pub file_id: String,
+    /// Name of the cited file when reported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// Output text ranges that cite the file
+    #[serde(default)]
+    pub spans: Vec<CitationSpan>,
+}
+
+/// A range of output text that carries a citation
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De, Default)]
+pub struct CitationSpan {
+    /// Start of the range, in characters
+    pub start_index: u32,
+    /// End of the range, in characters
+    pub end_index: u32,
+    /// The cited text, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+}
+
+/// Why the model stopped generating a choice
+///
+/// Deserialized from the `finish_reason` string; values this SDK does not know
+/// yet are kept in [`FinishReason::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Ser, De)]
+#[serde(from = "String", into = "String")]
+pub enum FinishReason {
+    /// The model reached a natural stop point or a stop sequence
+    Stop,
+    /// The output hit the token limit
+    Length,
+    /// The model called one or more tools
+    ToolCalls,
+    /// The output was withheld by the content filter
+    ContentFilter,
+    /// The model called a function (legacy function calling)
+    FunctionCall,
+    /// A reason not covered by the variants above
+    Other(String),
+}
+
+impl FinishReason {
+    /// Get the wire representation of the reason
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Stop => "stop",
+            Self::Length => "length",
+            Self::ToolCalls => "tool_calls",
+            Self::ContentFilter => "content_filter",
+            Self::FunctionCall => "function_call",
+            Self::Other(reason) => reason,
+        }
+    }
+
+    /// Check whether generation ended on its own rather than being cut off
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Self::Stop | Self::ToolCalls | Self::FunctionCall)
+    }
+
+    /// Check whether the output was truncated and can be continued
+    #[must_use]
+    pub fn needs_continuation(&self) -> bool {
+        matches!(self, Self::Length)
+    }
+}
+
+impl From<&str> for FinishReason {
+    fn from(reason: &str) -> Self {
+        match reason {
+            "stop" => Self::Stop,
+            "length" => Self::Length,
+            "tool_calls" => Self::ToolCalls,
+            "content_filter" => Self::ContentFilter,
+            "function_call" => Self::FunctionCall,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<String> for FinishReason {
+    fn from(reason: String) -> Self {
+        Self::from(reason.as_str())
+    }
+}
+
+impl From<FinishReason> for String {
+    fn from(reason: FinishReason) -> Self {
+        match reason {
+            FinishReason::Other(reason) => reason,
+            known => known.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for FinishReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 /// Individual choice in the response
@@ -36,6 +250,107 @@ pub struct ResponseChoice {
     pub message: ResponseOutput,
     /// Reason why the generation finished
     pub finish_reason: Option<String>,
+    /// The stop sequence that ended the output, when known
+    ///
+    /// The OpenAI API does not report which sequence matched, so this is
+    /// usually `None`. It is filled from a `stop_reason` string reported by
+    /// some compatible servers, or by [`ResponseChoice::apply_stop_sequences`]
+    /// when a sequence is found in the text.
+    #[serde(
+        default,
+        alias = "stop_reason",
+        deserialize_with = "deserialize_stop_sequence",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub stop_sequence: Option<String>,
+}
+
+/// Accept a stop reason only when it is a string; some servers report token ids instead
+fn deserialize_stop_sequence<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(value.and_then(|value| value.as_str().map(str::to_string)))
+}
+
+impl AsText for ResponseOutput {
+    fn text(&self) -> String {
+        self.content.clone().unwrap_or_default()
+    }
+}
+
+impl AsText for ResponseChoice {
+    fn text(&self) -> String {
+        self.message.text()
+    }
+}
+
+impl ResponseChoice {
+    /// Get the finish reason as a typed value
+    #[must_use]
+    pub fn finish_reason_kind(&self) -> Option<FinishReason> {
+        self.finish_reason.as_deref().map(FinishReason::from)
+    }
+
+    /// Get the stop sequence that ended the output, on a best-effort basis
+    ///
+    /// The OpenAI API never reports the matched sequence, so expect `None`
+    /// there; see [`ResponseChoice::stop_sequence`] for when it is known.
+    #[must_use]
+    pub fn stopped_by(&self) -> Option<String> {
+        self.stop_sequence.clone()
+    }
+
+    /// Trim the content at the first of the given stop sequences
+    ///
+    /// The API normally omits the stop sequence, but gateways and other
+    /// compatible servers may not. The earliest match is cut from the text
+    /// along with everything after it and recorded as the sequence that
+    /// stopped the output, unless the server already reported one.
+    pub fn apply_stop_sequences(&mut self, stop: &[String]) {
+        let Some(content) = self.message.content.as_mut() else {
+            return;
+        };
+
+        let earliest = stop
+            .iter()
+            .filter(|sequence| !sequence.is_empty())
+            .filter_map(|sequence| content.find(sequence.as_str()).map(|at| (at, sequence)))
+            .min_by_key(|(at, _)| *at);
+
+        if let Some((at, sequence)) = earliest {
+            content.truncate(at);
+            if self.stop_sequence.is_none() {
+                self.stop_sequence = Some(sequence.clone());
+            }
+        }
+    }
+}
+
+/// Text stitched together from a response and its continuations
+#[derive(Debug, Clone)]
+pub struct ContinuedResponse {
+    /// The concatenated output of every request
+    pub text: String,
+    /// Token usage summed across every request
+    pub usage: Option<Usage>,
+    /// Number of follow-up requests issued after the first one
+    pub continuations: u32,
+    /// Finish reason of the last request
+    pub finish_reason: Option<FinishReason>,
+}
+
+impl ContinuedResponse {
+    /// Check whether the output was still truncated when the continuation cap was reached
+    #[must_use]
+    pub fn is_truncated(&self) -> bool {
+        self.finish_reason
+            .as_ref()
+            .is_some_and(FinishReason::needs_continuation)
+    }
 }
 
 /// Full response from the API
@@ -59,9 +374,38 @@ pub struct ResponseResult {
     /// Token usage statistics
     #[serde(skip_serializing_if = "Option::is_none")]
     pub usage: Option<Usage>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(ResponseResult);
+
 impl ResponseResult {
+    /// Get all choices returned for the request
+    ///
+    /// Requests made with `n > 1` return one choice per sampled candidate.
+    #[must_use]
+    pub fn choices(&self) -> &[ResponseChoice] {
+        &self.choices
+    }
+
+    /// Get the text content of the first choice, if any
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        self.choices
+            .first()
+            .and_then(|choice| choice.message.content.as_deref())
+    }
+
+    /// Get the refusal message from the first choice, if the model declined
+    #[must_use]
+    pub fn refusal(&self) -> Option<&str> {
+        self.choices
+            .first()
+            .and_then(|choice| choice.message.refusal())
+    }
+
     /// Get the text output from the first choice
     #[must_use]
     pub fn output_text(&self) -> String {
@@ -127,7 +471,8 @@ impl ResponseResult {
         self.usage
             .as_ref()
             .and_then(|u| u.prompt_tokens_details.as_ref())
-            .map_or(0, |d| d.cached_tokens)
+            .and_then(|d| d.cached_tokens)
+            .unwrap_or(0)
     }
 
     /// Calculate the cache hit rate as a percentage
@@ -167,14 +512,166 @@ mod tests {
                 completion_tokens: 0,
                 total_tokens: prompt_tokens,
                 prompt_tokens_details: Some(PromptTokenDetails {
-                    cached_tokens,
+                    cached_tokens: Some(cached_tokens),
                     audio_tokens: None,
                 }),
                 completion_tokens_details: None,
             }),
+            extra: HashMap::new(),
+        }
+    }
+
+    fn choice(index: u32, content: &str) -> ResponseChoice {
+        ResponseChoice {
+            index,
+            message: ResponseOutput {
+                content: Some(content.to_string()),
+                tool_calls: None,
+                function_calls: None,
+                structured_data: None,
+                schema_validation: None,
+                refusal: None,
+                annotations: None,
+            },
+            finish_reason: Some("stop".to_string()),
+            stop_sequence: None,
         }
     }
 
+    #[test]
+    fn choices_exposes_every_candidate() {
+        let mut resp = response_with_usage(10, 0);
+        assert!(resp.choices().is_empty());
+        assert_eq!(resp.text(), None);
+
+        resp.choices = vec![choice(0, "first"), choice(1, "second")];
+        assert_eq!(resp.choices().len(), 2);
+        assert_eq!(resp.choices()[1].message.content.as_deref(), Some("second"));
+        assert_eq!(resp.text(), Some("first"));
+    }
+
+    #[test]
+    fn usage_details_parse_with_missing_fields() {
+        let usage: Usage = serde_json::from_value(serde_json::json!({
+            "prompt_tokens": 120,
+            "completion_tokens": 80,
+            "total_tokens": 200,
+            "prompt_tokens_details": { "cached_tokens": 64 },
+            "completion_tokens_details": {
+                "reasoning_tokens": 48,
+                "accepted_prediction_tokens": 5,
+                "rejected_prediction_tokens": 2
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(usage.cached_tokens(), Some(64));
+        assert_eq!(usage.prompt_audio_tokens(), None);
+        assert_eq!(usage.reasoning_tokens(), Some(48));
+        assert_eq!(usage.accepted_prediction_tokens(), Some(5));
+        assert_eq!(usage.rejected_prediction_tokens(), Some(2));
+        assert_eq!(usage.completion_audio_tokens(), None);
+
+        let serialized = serde_json::to_value(&usage).unwrap();
+        assert!(
+            serialized["prompt_tokens_details"]
+                .get("audio_tokens")
+                .is_none()
+        );
+
+        let flat: Usage = serde_json::from_value(serde_json::json!({
+            "prompt_tokens": 10,
+            "completion_tokens": 5,
+            "total_tokens": 15,
+            "completion_tokens_details": {}
+        }))
+        .unwrap();
+        assert_eq!(flat.cached_tokens(), None);
+        assert_eq!(flat.reasoning_tokens(), None);
+    }
+
+    #[test]
+    fn finish_reason_is_typed() {
+        let resp: ResponseResult = serde_json::from_value(serde_json::json!({
+            "choices": [{
+                "index": 0,
+                "message": { "content": "partial" },
+                "finish_reason": "length"
+            }]
+        }))
+        .unwrap();
+
+        let reason = resp.choices[0].finish_reason_kind().unwrap();
+        assert_eq!(reason, FinishReason::Length);
+        assert!(reason.needs_continuation());
+        assert!(!reason.is_complete());
+        assert!(FinishReason::ToolCalls.is_complete());
+
+        let parsed: Vec<FinishReason> =
+            serde_json::from_str(r#"["stop", "content_filter", "end_turn"]"#).unwrap();
+        assert_eq!(
+            parsed,
+            [
+                FinishReason::Stop,
+                FinishReason::ContentFilter,
+                FinishReason::Other("end_turn".to_string()),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&parsed).unwrap(),
+            r#"["stop","content_filter","end_turn"]"#
+        );
+    }
+
+    #[test]
+    fn stop_sequences_are_trimmed_and_recorded() {
+        let stop = vec!["END".to_string(), "---".to_string()];
+
+        let mut trimmed = choice(0, "section one\n---\nsection two END");
+        trimmed.apply_stop_sequences(&stop);
+        assert_eq!(trimmed.message.content.as_deref(), Some("section one\n"));
+        assert_eq!(trimmed.stopped_by().as_deref(), Some("---"));
+
+        let mut untouched = choice(0, "no delimiters");
+        untouched.apply_stop_sequences(&stop);
+        assert_eq!(untouched.message.content.as_deref(), Some("no delimiters"));
+        assert!(untouched.stopped_by().is_none());
+
+        let reported: ResponseChoice = serde_json::from_value(serde_json::json!({
+            "index": 0,
+            "message": { "content": "done" },
+            "finish_reason": "stop",
+            "stop_reason": "END"
+        }))
+        .unwrap();
+        assert_eq!(reported.stopped_by().as_deref(), Some("END"));
+
+        let token_id: ResponseChoice = serde_json::from_value(serde_json::json!({
+            "index": 0,
+            "message": { "content": "done" },
+            "finish_reason": "stop",
+            "stop_reason": 50256
+        }))
+        .unwrap();
+        assert!(token_id.stopped_by().is_none());
+    }
+
+    #[test]
+    fn refusal_is_parsed_from_message() {
+        let resp: ResponseResult = serde_json::from_value(serde_json::json!({
+            "choices": [{
+                "index": 0,
+                "message": { "content": null, "refusal": "I can't help with that." },
+                "finish_reason": "stop"
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(resp.refusal(), Some("I can't help with that."));
+        assert_eq!(resp.text(), None);
+        assert_eq!(response_with_usage(1, 0).refusal(), None);
+    }
+
     #[test]
     fn cache_hit_rate_with_cached_tokens() {
         let resp = response_with_usage(100, 50);
@@ -191,6 +688,7 @@ mod tests {
             model: String::new(),
             choices: Vec::new(),
             usage: None,
+            extra: HashMap::new(),
         };
         assert_eq!(resp.cache_hit_rate(), 0.0);
     }
@@ -200,4 +698,75 @@ mod tests {
         let resp = response_with_usage(0, 0);
         assert_eq!(resp.cache_hit_rate(), 0.0);
     }
+
+    #[test]
+    fn web_sources_are_parsed_and_deduplicated() {
+        let output: ResponseOutput = serde_json::from_value(serde_json::json!({
+            "content": "Rust 1.0 shipped in 2015.",
+            "annotations": [
+                {"type": "url_citation", "url_citation": {
+                    "url": "https://blog.rust-lang.org", "title": "Rust Blog",
+                    "start_index": 0, "end_index": 8
+                }},
+                {"type": "file_citation", "file_citation": {"file_id": "file-1"}},
+                {"type": "url_citation", "url_citation": {
+                    "url": "https://blog.rust-lang.org", "title": "Rust Blog",
+                    "start_index": 18, "end_index": 24
+                }},
+                {"type": "file_path", "file_path": {"file_id": "file-2"}}
+            ]
+        }))
+        .unwrap();
+
+        let sources = output.web_sources();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].title, "Rust Blog");
+        assert_eq!(sources[0].end_index, 8);
+        assert_eq!(output.cited_file_ids(), vec!["file-1"]);
+        assert_eq!(
+            output.annotations.as_ref().unwrap()[3],
+            OutputAnnotation::Unknown
+        );
+    }
+
+    #[test]
+    fn test_as_text_for_choices_and_messages() {
+        use crate::models::responses::{MessageContent, message_types::Message};
+
+        assert_eq!(choice(0, "Hello").text(), "Hello");
+        let mut empty = choice(1, "");
+        empty.message.content = None;
+        assert_eq!(empty.text(), "");
+
+        let message = Message::user_with_content(vec![
+            MessageContent::text("Look at "),
+            MessageContent::image_url("https://example.com/cat.png"),
+            MessageContent::text("this"),
+        ]);
+        assert_eq!(message.text(), "Look at this");
+    }
+
+    #[test]
+    fn test_unknown_fields_are_preserved() {
+        use crate::models::shared_traits::ExtraFields;
+
+        let json = serde_json::json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1,
+            "model": "gpt-4o",
+            "choices": [],
+            "service_tier": "default",
+            "system_fingerprint": "fp_123"
+        });
+        let result: ResponseResult = serde_json::from_value(json).unwrap();
+        assert_eq!(result.extra().len(), 2);
+        assert_eq!(
+            result.extra_field("service_tier"),
+            Some(&serde_json::json!("default"))
+        );
+
+        let round_trip = serde_json::to_value(&result).unwrap();
+        assert_eq!(round_trip["system_fingerprint"], "fp_123");
+    }
 }
@@ -141,6 +141,23 @@ impl ResponseFormat {
             _ => None,
         }
     }
+
+    /// Check that a strict JSON schema format can be enforced by the API
+    ///
+    /// Formats that are not strict JSON schemas always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first incompatible location in the schema.
+    pub fn check_strict_compatible(&self) -> Result<(), String> {
+        match self {
+            ResponseFormat::JsonSchema {
+                json_schema,
+                strict,
+            } if *strict || json_schema.strict => json_schema.check_strict_compatible(),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl JsonSchemaSpec {
@@ -191,6 +208,28 @@ impl JsonSchemaSpec {
         self
     }
 
+    /// Check that the schema can be used in strict mode
+    ///
+    /// Strict mode needs an object at the root, and every object in the schema
+    /// must set `additionalProperties: false` and list all of its properties
+    /// in `required`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first incompatible location in the schema.
+    pub fn check_strict_compatible(&self) -> Result<(), String> {
+        if self.schema.get("type").and_then(serde_json::Value::as_str) != Some("object") {
+            return Err(format!(
+                "schema '{}': strict mode requires an object at the root",
+                self.name
+            ));
+        }
+        crate::models::functions::strict_schema_violation(&self.schema, "schema")
+            .map_or(Ok(()), |violation| {
+                Err(format!("schema '{}': {violation}", self.name))
+            })
+    }
+
     /// Validate data against this schema
     #[must_use]
     pub fn validate(&self, data: &serde_json::Value) -> SchemaValidationResult {
@@ -4,7 +4,8 @@ use serde::{self, Deserialize, Serialize};
 
 use super::{
     message_types::MessageRole,
-    response_types::{ResponseOutput, ResponseResult},
+    response_types::{FinishReason, ResponseOutput, ResponseResult},
+    usage_types::Usage,
 };
 
 /// Different types of streaming events
@@ -92,6 +93,9 @@ pub struct StreamChunk {
     pub model: String,
     /// List of streaming choices
     pub choices: Vec<StreamChoice>,
+    /// Token usage, sent in the final chunk when `stream_options.include_usage` is set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Usage>,
 }
 
 /// Choice in streaming chunk
@@ -105,6 +109,14 @@ pub struct StreamChoice {
     pub finish_reason: Option<String>,
 }
 
+impl StreamChoice {
+    /// Get the finish reason as a typed value, once the choice has finished
+    #[must_use]
+    pub fn finish_reason_kind(&self) -> Option<FinishReason> {
+        self.finish_reason.as_deref().map(FinishReason::from)
+    }
+}
+
 /// Delta content in streaming chunk
 #[derive(Debug, Clone, Ser, De)]
 pub struct StreamDelta {
@@ -0,0 +1,163 @@
+//! Trimming conversations to fit a model's context window
+//!
+//! [`ConversationTrimmer`] drops the oldest turns of a conversation until its
+//! estimated size fits a token budget. Developer and system messages and the
+//! latest message are always kept, since the request makes no sense without
+//! them. Sizes use [`Message::estimate_tokens`], which is approximate, so
+//! leave some headroom in the budget.
+
+use super::message_types::{Message, MessageRole};
+
+/// Drops the oldest conversation turns until the conversation fits a budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversationTrimmer {
+    /// Estimated token budget for the trimmed conversation
+    max_tokens: u32,
+}
+
+/// Result of trimming a conversation
+#[derive(Debug, Clone)]
+pub struct TrimmedConversation {
+    /// The messages that were kept, in their original order
+    pub messages: Vec<Message>,
+    /// Number of messages that were removed
+    pub removed: usize,
+    /// Estimated token count of the kept messages
+    pub estimated_tokens: u32,
+}
+
+impl TrimmedConversation {
+    /// Check whether the kept messages fit the trimmer's budget
+    #[must_use]
+    pub const fn fits(&self, max_tokens: u32) -> bool {
+        self.estimated_tokens <= max_tokens
+    }
+}
+
+impl ConversationTrimmer {
+    /// Create a trimmer for the given estimated token budget
+    #[must_use]
+    pub const fn new(max_tokens: u32) -> Self {
+        Self { max_tokens }
+    }
+
+    /// Create a trimmer from a `context_length_exceeded` error message
+    ///
+    /// The API reports the model's limit and the size of the rejected
+    /// request; `estimated_tokens` is this crate's estimate for the same
+    /// request. The budget is the estimate scaled down by the ratio of the
+    /// two, with 10% headroom. Returns `None` if the message does not
+    /// contain both numbers.
+    #[must_use]
+    pub fn from_overflow_message(message: &str, estimated_tokens: u32) -> Option<Self> {
+        let (limit, requested) = parse_context_overflow(message)?;
+        if requested == 0 {
+            return None;
+        }
+        let scaled = f64::from(estimated_tokens) * f64::from(limit) / f64::from(requested) * 0.9;
+        Some(Self::new(scaled as u32))
+    }
+
+    /// Get the estimated token budget
+    #[must_use]
+    pub const fn max_tokens(&self) -> u32 {
+        self.max_tokens
+    }
+
+    /// Remove the oldest removable messages until the conversation fits
+    ///
+    /// If the messages that are always kept exceed the budget on their own,
+    /// every removable message is dropped and the result does not fit.
+    #[must_use]
+    pub fn trim(&self, messages: Vec<Message>) -> TrimmedConversation {
+        let mut estimated_tokens: u32 = messages.iter().map(Message::estimate_tokens).sum();
+        let last = messages.len().saturating_sub(1);
+        let mut keep = vec![true; messages.len()];
+        let mut removed = 0;
+
+        for (index, message) in messages.iter().enumerate() {
+            if estimated_tokens <= self.max_tokens {
+                break;
+            }
+            let pinned = index == last
+                || matches!(message.role, MessageRole::Developer | MessageRole::System);
+            if !pinned {
+                keep[index] = false;
+                removed += 1;
+                estimated_tokens -= message.estimate_tokens();
+            }
+        }
+
+        let messages = messages
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(message, keep)| keep.then_some(message))
+            .collect();
+        TrimmedConversation {
+            messages,
+            removed,
+            estimated_tokens,
+        }
+    }
+}
+
+/// Extract the context limit and requested size from an overflow error message
+///
+/// Understands messages such as "This model's maximum context length is 8192
+/// tokens. However, your messages resulted in 9000 tokens."
+#[must_use]
+pub fn parse_context_overflow(message: &str) -> Option<(u32, u32)> {
+    let lower = message.to_ascii_lowercase();
+    let after_limit = &lower[lower.find("maximum context length")?..];
+    let limit = first_number(after_limit)?;
+    let after_however = &after_limit[after_limit.find("resulted in")?..];
+    let requested = first_number(after_however)?;
+    Some((limit, requested))
+}
+
+/// Parse the first run of ASCII digits in `text`
+fn first_number(text: &str) -> Option<u32> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let digits: String = text[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_keeps_instructions_and_latest_turn() {
+        let messages = vec![
+            Message::developer("Be brief"),
+            Message::user("a".repeat(400)),
+            Message::assistant("b".repeat(400)),
+            Message::user("What now?"),
+        ];
+
+        let trimmed = ConversationTrimmer::new(150).trim(messages);
+        assert_eq!(trimmed.removed, 1);
+        assert_eq!(trimmed.messages.len(), 3);
+        assert_eq!(trimmed.messages[0].role, MessageRole::Developer);
+        assert_eq!(trimmed.messages[1].role, MessageRole::Assistant);
+        assert!(trimmed.fits(150));
+
+        let trimmed = ConversationTrimmer::new(1).trim(vec![Message::user("x".repeat(40))]);
+        assert_eq!(trimmed.removed, 0);
+        assert!(!trimmed.fits(1));
+    }
+
+    #[test]
+    fn test_parse_context_overflow() {
+        let message = "This model's maximum context length is 8192 tokens. However, your \
+                       messages resulted in 10240 tokens. Please reduce the length.";
+        assert_eq!(parse_context_overflow(message), Some((8192, 10240)));
+        assert_eq!(parse_context_overflow("Rate limit reached"), None);
+
+        let trimmer = ConversationTrimmer::from_overflow_message(message, 1000).unwrap();
+        assert_eq!(trimmer.max_tokens(), 720);
+    }
+}
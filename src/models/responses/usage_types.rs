@@ -23,33 +23,97 @@ pub struct Usage {
 }
 
 /// Detailed prompt token information including caching
-#[derive(Debug, Clone, Ser, De)]
+///
+/// Counts the API omits deserialize as `None`.
+#[derive(Debug, Clone, Default, Ser, De)]
 pub struct PromptTokenDetails {
     /// Number of cached tokens used from prompt cache
-    #[serde(default)]
-    pub cached_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_tokens: Option<u32>,
     /// Audio tokens if applicable
     #[serde(skip_serializing_if = "Option::is_none")]
     pub audio_tokens: Option<u32>,
 }
 
 /// Detailed completion token information
-#[derive(Debug, Clone, Ser, De)]
+///
+/// Counts the API omits deserialize as `None`.
+#[derive(Debug, Clone, Default, Ser, De)]
 pub struct CompletionTokenDetails {
     /// Reasoning tokens used (for models with reasoning capabilities)
-    #[serde(default)]
-    pub reasoning_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_tokens: Option<u32>,
     /// Accepted prediction tokens
-    #[serde(default)]
-    pub accepted_prediction_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_prediction_tokens: Option<u32>,
     /// Rejected prediction tokens
-    #[serde(default)]
-    pub rejected_prediction_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected_prediction_tokens: Option<u32>,
     /// Audio tokens if applicable
     #[serde(skip_serializing_if = "Option::is_none")]
     pub audio_tokens: Option<u32>,
 }
 
+impl Usage {
+    /// Prompt tokens served from the prompt cache, if reported
+    #[must_use]
+    pub fn cached_tokens(&self) -> Option<u32> {
+        self.prompt_tokens_details
+            .as_ref()
+            .and_then(|details| details.cached_tokens)
+    }
+
+    /// Audio tokens in the prompt, if reported
+    #[must_use]
+    pub fn prompt_audio_tokens(&self) -> Option<u32> {
+        self.prompt_tokens_details
+            .as_ref()
+            .and_then(|details| details.audio_tokens)
+    }
+
+    /// Reasoning tokens spent before the visible completion, if reported
+    #[must_use]
+    pub fn reasoning_tokens(&self) -> Option<u32> {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.reasoning_tokens)
+    }
+
+    /// Audio tokens in the completion, if reported
+    #[must_use]
+    pub fn completion_audio_tokens(&self) -> Option<u32> {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.audio_tokens)
+    }
+
+    /// Predicted-output tokens that appeared in the completion, if reported
+    #[must_use]
+    pub fn accepted_prediction_tokens(&self) -> Option<u32> {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.accepted_prediction_tokens)
+    }
+
+    /// Add the token counts of another request to this one
+    ///
+    /// Only the top-level counts are summed; the detail breakdowns are kept
+    /// from `self`.
+    pub fn accumulate(&mut self, other: &Usage) {
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+    }
+
+    /// Predicted-output tokens that were discarded but still billed, if reported
+    #[must_use]
+    pub fn rejected_prediction_tokens(&self) -> Option<u32> {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|details| details.rejected_prediction_tokens)
+    }
+}
+
 /// Prompt template with variables for reusable prompts
 #[derive(Debug, Clone, Ser, De)]
 pub struct PromptTemplate {
@@ -59,7 +123,10 @@ pub struct PromptTemplate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version: Option<String>,
     /// Variables to substitute in the template
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub variables: Option<HashMap<String, PromptVariable>>,
 }
 
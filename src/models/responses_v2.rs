@@ -5,8 +5,11 @@ use crate::models::gpt5::{ReasoningConfig, TextConfig};
 use crate::models::responses::message_types::{
     ImageDetail, Message, MessageContent, MessageContentInput, MessageRole,
 };
+pub use crate::models::responses::response_types::{CitationSpan, FileCitation};
+use crate::models::responses::response_types::{OutputAnnotation, UrlCitation};
 use crate::models::responses::schema_types::{JsonSchemaSpec, ResponseFormat};
 use crate::models::responses::usage_types::{PromptTemplate, PromptVariable};
+use crate::models::shared_traits::AsText;
 use crate::models::tools::{EnhancedTool, EnhancedToolChoice};
 use crate::schema::SchemaBuilder;
 use crate::{De, Ser};
@@ -142,7 +145,10 @@ pub struct ResponseObject {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_text: Option<String>,
     /// Optional metadata supplied when the response was created
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, Value>>,
     /// Any additional fields returned by the service that we do not explicitly model
     #[serde(flatten)]
@@ -183,6 +189,38 @@ impl ResponseObject {
     pub fn is_failed(&self) -> bool {
         matches!(self.status, ResponseStatus::Failed)
     }
+
+    /// Chat-style finish reason derived from the response status
+    ///
+    /// Incomplete responses report `length` when the output token limit was
+    /// hit and pass other reasons such as `content_filter` through. Completed
+    /// responses report `tool_calls` when they end in a tool or function call
+    /// and `stop` otherwise.
+    #[must_use]
+    pub fn finish_reason(&self) -> Option<String> {
+        match self.status {
+            ResponseStatus::Incomplete => {
+                let reason = self
+                    .extra
+                    .get("incomplete_details")
+                    .and_then(|details| details.get("reason"))
+                    .and_then(Value::as_str);
+                let reason = match reason {
+                    Some("max_output_tokens") | None => "length",
+                    Some(other) => other,
+                };
+                Some(reason.to_string())
+            }
+            ResponseStatus::Completed => {
+                let has_calls = self
+                    .output
+                    .iter()
+                    .any(|item| item.item_type == "tool_call" || item.item_type == "function_call");
+                Some(if has_calls { "tool_calls" } else { "stop" }.to_string())
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Generic output or input item that is part of a response payload
@@ -208,6 +246,14 @@ pub struct ResponseItem {
     pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(ResponseObject);
+
+impl AsText for ResponseObject {
+    fn text(&self) -> String {
+        self.output_text()
+    }
+}
+
 impl ResponseItem {
     /// Extract contiguous text fragments from the item
     pub fn text_fragments(&self) -> Vec<String> {
@@ -240,6 +286,15 @@ impl ContentPart {
     fn text_fragment(&self) -> Option<String> {
         self.text.clone()
     }
+
+    /// Get the refusal message if this is a `refusal` content part
+    #[must_use]
+    pub fn refusal(&self) -> Option<&str> {
+        if self.part_type != "refusal" {
+            return None;
+        }
+        self.extra.get("refusal").and_then(Value::as_str)
+    }
 }
 
 /// Annotation metadata attached to text content
@@ -253,6 +308,127 @@ pub struct Annotation {
     pub extra: HashMap<String, Value>,
 }
 
+impl Annotation {
+    /// Read an unsigned integer attribute
+    fn index_field(&self, key: &str) -> Option<u32> {
+        self.extra
+            .get(key)
+            .and_then(Value::as_u64)
+            .and_then(|v| u32::try_from(v).ok())
+    }
+
+    /// Read a string attribute
+    fn str_field(&self, key: &str) -> Option<&str> {
+        self.extra.get(key).and_then(Value::as_str)
+    }
+
+    /// Whether this annotation cites an uploaded file
+    #[must_use]
+    pub fn is_file_citation(&self) -> bool {
+        self.annotation_type == "file_citation"
+    }
+
+    /// Start of the annotated text range, in characters
+    #[must_use]
+    pub fn start_index(&self) -> Option<u32> {
+        self.index_field("start_index")
+    }
+
+    /// End of the annotated text range, in characters
+    #[must_use]
+    pub fn end_index(&self) -> Option<u32> {
+        self.index_field("end_index")
+    }
+
+    /// The annotated text, when known
+    #[must_use]
+    pub fn text(&self) -> Option<&str> {
+        self.str_field("text")
+    }
+
+    /// Interpret this annotation as a web page citation
+    ///
+    /// Accepts the flat Responses API form as well as the Chat Completions
+    /// form nesting the attributes under `url_citation`.
+    #[must_use]
+    pub fn url_citation(&self) -> Option<UrlCitation> {
+        if self.annotation_type != "url_citation" {
+            return None;
+        }
+        if let Some(nested) = self.extra.get("url_citation") {
+            return serde_json::from_value(nested.clone()).ok();
+        }
+        Some(UrlCitation {
+            url: self.str_field("url")?.to_string(),
+            title: self.str_field("title").unwrap_or_default().to_string(),
+            start_index: self.start_index().unwrap_or_default(),
+            end_index: self.end_index().unwrap_or_default(),
+        })
+    }
+
+    /// Interpret this annotation as a file citation
+    ///
+    /// Both the ranged form (`start_index`/`end_index`) and the positional
+    /// form (`index`) are accepted; the quote is taken from the annotated text
+    /// or from an explicit `quote` attribute.
+    #[must_use]
+    pub fn file_citation(&self) -> Option<FileCitation> {
+        if !self.is_file_citation() {
+            return None;
+        }
+        let nested = self.extra.get("file_citation");
+        let file_id = self.str_field("file_id").or_else(|| {
+            nested
+                .and_then(|v| v.get("file_id"))
+                .and_then(Value::as_str)
+        })?;
+        let quote = self
+            .text()
+            .or_else(|| self.str_field("quote"))
+            .or_else(|| nested.and_then(|v| v.get("quote")).and_then(Value::as_str))
+            .map(str::to_string);
+
+        let span = match (
+            self.start_index(),
+            self.end_index(),
+            self.index_field("index"),
+        ) {
+            (Some(start_index), Some(end_index), _) => Some(CitationSpan {
+                start_index,
+                end_index,
+                quote,
+            }),
+            (_, _, Some(index)) => Some(CitationSpan {
+                start_index: index,
+                end_index: index,
+                quote,
+            }),
+            _ => None,
+        };
+
+        Some(FileCitation {
+            file_id: file_id.to_string(),
+            filename: self.str_field("filename").map(str::to_string),
+            spans: span.into_iter().collect(),
+        })
+    }
+
+    /// Convert this annotation into the typed annotation enum
+    ///
+    /// Kinds other than URL and file citations become
+    /// [`OutputAnnotation::Unknown`].
+    #[must_use]
+    pub fn to_output_annotation(&self) -> OutputAnnotation {
+        if let Some(url_citation) = self.url_citation() {
+            OutputAnnotation::UrlCitation { url_citation }
+        } else if let Some(file_citation) = self.file_citation() {
+            OutputAnnotation::FileCitation { file_citation }
+        } else {
+            OutputAnnotation::Unknown
+        }
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Request Builder
 // -----------------------------------------------------------------------------
@@ -387,7 +563,10 @@ pub struct ConversationObject {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     /// Optional metadata map
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
     /// Additional conversation fields
     #[serde(flatten)]
@@ -416,7 +595,10 @@ pub struct CreateResponseRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub include: Option<Vec<String>>,
     /// Optional metadata map (string key/value pairs)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
     /// Optional safety identifier for policy tracking
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1165,6 +1347,7 @@ pub fn from_legacy_request(
         presence_penalty: request.presence_penalty,
         max_output_tokens: request.max_tokens,
         stream: request.stream,
+        stream_options: request.stream_options.clone(),
         prompt: request.prompt.clone(),
         prompt_cache_key: request.prompt_cache_key.clone(),
         tools: request.tools.clone(),
@@ -1184,6 +1367,20 @@ pub fn from_legacy_request(
     modern
 }
 
+/// Collect the citations of a response as legacy output annotations
+fn legacy_annotations(response: &ResponseObject) -> Option<Vec<OutputAnnotation>> {
+    let annotations: Vec<_> = response
+        .output
+        .iter()
+        .flat_map(|item| &item.content)
+        .filter_map(|part| part.annotations.as_ref())
+        .flatten()
+        .map(Annotation::to_output_annotation)
+        .filter(|annotation| *annotation != OutputAnnotation::Unknown)
+        .collect();
+    (!annotations.is_empty()).then_some(annotations)
+}
+
 /// Convert a modern `ResponseObject` into the legacy `ResponseResult` structure
 pub fn to_legacy_response(response: &ResponseObject) -> crate::models::responses::ResponseResult {
     use crate::models::functions::FunctionCall;
@@ -1197,7 +1394,7 @@ pub fn to_legacy_response(response: &ResponseObject) -> crate::models::responses
     let finish_reason = if !tool_calls.is_empty() || !function_calls.is_empty() {
         Some("tool_calls".to_string())
     } else {
-        None
+        response.finish_reason()
     };
 
     let legacy_tool_calls: Option<Vec<LegacyToolCall>> =
@@ -1211,12 +1408,20 @@ pub fn to_legacy_response(response: &ResponseObject) -> crate::models::responses
         function_calls: legacy_function_calls,
         structured_data: None,
         schema_validation: None,
+        refusal: response
+            .output
+            .iter()
+            .flat_map(|item| item.content.iter())
+            .find_map(ContentPart::refusal)
+            .map(str::to_string),
+        annotations: legacy_annotations(response),
     };
 
     let choice = LegacyResponseChoice {
         index: 0,
         message: legacy_output,
         finish_reason,
+        stop_sequence: None,
     };
 
     let usage = response.usage.as_ref().map(|usage| LegacyUsage {
@@ -1225,15 +1430,15 @@ pub fn to_legacy_response(response: &ResponseObject) -> crate::models::responses
         total_tokens: usage.total_tokens,
         prompt_tokens_details: usage.input_tokens_details.as_ref().map(|details| {
             crate::models::responses::usage_types::PromptTokenDetails {
-                cached_tokens: details.cached_tokens,
+                cached_tokens: Some(details.cached_tokens),
                 audio_tokens: details.audio_tokens,
             }
         }),
         completion_tokens_details: usage.output_tokens_details.as_ref().map(|details| {
             crate::models::responses::usage_types::CompletionTokenDetails {
-                reasoning_tokens: details.reasoning_tokens,
-                accepted_prediction_tokens: details.accepted_prediction_tokens,
-                rejected_prediction_tokens: details.rejected_prediction_tokens,
+                reasoning_tokens: Some(details.reasoning_tokens),
+                accepted_prediction_tokens: Some(details.accepted_prediction_tokens),
+                rejected_prediction_tokens: Some(details.rejected_prediction_tokens),
                 audio_tokens: details.audio_tokens,
             }
         }),
@@ -1246,6 +1451,7 @@ pub fn to_legacy_response(response: &ResponseObject) -> crate::models::responses
         model: response.model.clone().unwrap_or_default(),
         choices: vec![choice],
         usage,
+        extra: response.extra.clone(),
     }
 }
 
@@ -1614,14 +1820,55 @@ mod tests {
         assert_eq!(usage.prompt_tokens, 12);
         assert_eq!(usage.completion_tokens, 4);
         assert_eq!(usage.total_tokens, 16);
-        assert_eq!(
-            usage
-                .prompt_tokens_details
-                .as_ref()
-                .map(|d| d.cached_tokens)
-                .unwrap_or_default(),
-            2
+        assert_eq!(usage.cached_tokens(), Some(2));
+        assert_eq!(usage.reasoning_tokens(), Some(1));
+    }
+
+    #[test]
+    fn converts_refusal_content_to_legacy_refusal() {
+        let response: ResponseObject = serde_json::from_value(json!({
+            "id": "resp_refused",
+            "object": "response",
+            "created_at": 1,
+            "status": "completed",
+            "output": [{
+                "type": "message",
+                "role": "assistant",
+                "content": [{ "type": "refusal", "refusal": "I can't help with that." }]
+            }]
+        }))
+        .unwrap();
+
+        let legacy = to_legacy_response(&response);
+        assert_eq!(legacy.refusal(), Some("I can't help with that."));
+        assert!(legacy.text().is_none());
+    }
+
+    #[test]
+    fn incomplete_response_reports_finish_reason() {
+        let mut response: ResponseObject = serde_json::from_value(json!({
+            "id": "resp_cut",
+            "object": "response",
+            "status": "incomplete",
+            "incomplete_details": { "reason": "max_output_tokens" },
+            "output_text": "partial"
+        }))
+        .unwrap();
+
+        assert_eq!(response.finish_reason().as_deref(), Some("length"));
+        let legacy = to_legacy_response(&response);
+        assert_eq!(legacy.choices[0].finish_reason.as_deref(), Some("length"));
+
+        response.extra.insert(
+            "incomplete_details".into(),
+            json!({ "reason": "content_filter" }),
         );
+        assert_eq!(response.finish_reason().as_deref(), Some("content_filter"));
+
+        response.status = ResponseStatus::Completed;
+        assert_eq!(response.finish_reason().as_deref(), Some("stop"));
+        response.status = ResponseStatus::InProgress;
+        assert!(response.finish_reason().is_none());
     }
 
     #[test]
@@ -1989,6 +2236,178 @@ mod tests {
         assert_eq!(map.get("name"), Some(&json!("test_fn")));
     }
 
+    #[test]
+    fn legacy_output_citations_are_deduplicated_by_file() {
+        let response: ResponseObject = serde_json::from_value(json!({
+            "id": "resp_1",
+            "object": "response",
+            "output": [
+                {
+                    "type": "message",
+                    "content": [{
+                        "type": "output_text",
+                        "text": "Alpha and beta.",
+                        "annotations": [
+                            {"type": "file_citation", "file_id": "file-a", "index": 5},
+                            {"type": "url_citation", "url": "https://example.com", "start_index": 0, "end_index": 5},
+                            {"type": "file_citation", "file_id": "file-b", "filename": "b.txt", "index": 14}
+                        ]
+                    }]
+                },
+                {
+                    "type": "message",
+                    "content": [{
+                        "type": "output_text",
+                        "text": "Alpha again.",
+                        "annotations": [
+                            {"type": "file_citation", "file_id": "file-a", "filename": "a.txt", "start_index": 0, "end_index": 5, "text": "Alpha"},
+                            {"type": "file_citation", "file_id": "file-a", "index": 5}
+                        ]
+                    }]
+                }
+            ]
+        }))
+        .unwrap();
+
+        let legacy = to_legacy_response(&response);
+        let output = &legacy.choices[0].message;
+        assert_eq!(output.cited_file_ids(), vec!["file-a", "file-b"]);
+
+        let sources = output.web_sources();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].url, "https://example.com");
+        assert_eq!(sources[0].end_index, 5);
+
+        let citations = output.citations();
+        let file_a = &citations[0];
+        assert_eq!(file_a.filename.as_deref(), Some("a.txt"));
+        assert_eq!(file_a.spans.len(), 2);
+        assert_eq!(file_a.spans[1].quote.as_deref(), Some("Alpha"));
+        assert_eq!(citations[1].spans[0].start_index, 14);
+    }
+
+    /// Build a text delta event for the first content part of output 0
+    fn text_delta(delta: &str) -> ResponseStreamEvent {
+        part_delta(0, delta)
+    }
+
+    /// Build a text delta event for a content part of output 0
+    fn part_delta(content_index: u32, delta: &str) -> ResponseStreamEvent {
+        ResponseStreamEvent::OutputTextDelta {
+            event_id: None,
+            response_id: "resp_1".to_string(),
+            output_index: 0,
+            content_index,
+            delta: delta.to_string(),
+        }
+    }
+
+    #[test]
+    fn assembler_resolves_annotation_spanning_later_deltas() {
+        let annotation: ResponseStreamEvent = serde_json::from_value(json!({
+            "type": "response.output_text.annotation.added",
+            "output_index": 0,
+            "content_index": 0,
+            "annotation_index": 0,
+            "annotation": {
+                "type": "file_citation",
+                "file_id": "file-1",
+                "filename": "report.pdf",
+                "start_index": 8,
+                "end_index": 18
+            }
+        }))
+        .unwrap();
+
+        let mut assembler = ResponseStreamAssembler::new();
+        assembler.push(&text_delta("See the "));
+        assembler.push(&annotation);
+        assembler.push(&text_delta("annual"));
+        assert_eq!(assembler.pending_annotations(), 1);
+        assembler.push(&text_delta(" report for details."));
+        assert_eq!(assembler.pending_annotations(), 0);
+        assert_eq!(assembler.annotations(0, 0)[0].text(), Some("annual rep"));
+
+        let items = assembler.finish();
+        assert_eq!(items.len(), 1);
+        let part = &items[0].content[0];
+        assert_eq!(
+            part.text.as_deref(),
+            Some("See the annual report for details.")
+        );
+
+        let citation = part.annotations.as_ref().unwrap()[0]
+            .file_citation()
+            .unwrap();
+        assert_eq!(citation.file_id, "file-1");
+        assert_eq!(citation.filename.as_deref(), Some("report.pdf"));
+        assert_eq!(
+            citation.spans,
+            vec![CitationSpan {
+                start_index: 8,
+                end_index: 18,
+                quote: Some("annual rep".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn assembler_keeps_announced_item_and_positional_citations() {
+        let mut assembler = ResponseStreamAssembler::new();
+        assembler.push(&ResponseStreamEvent::OutputItemAdded {
+            event_id: None,
+            response_id: "resp_1".to_string(),
+            output_index: 0,
+            item: ResponseItem {
+                item_type: "message".to_string(),
+                id: Some("msg_1".to_string()),
+                ..ResponseItem::default()
+            },
+        });
+        assembler.push(&text_delta("Done."));
+        assembler.push(&ResponseStreamEvent::OutputTextAnnotationAdded {
+            event_id: None,
+            response_id: String::new(),
+            output_index: 0,
+            content_index: 0,
+            annotation_index: None,
+            annotation: Annotation {
+                annotation_type: "file_citation".to_string(),
+                extra: HashMap::from([
+                    ("file_id".to_string(), json!("file-2")),
+                    ("index".to_string(), json!(5)),
+                ]),
+            },
+        });
+
+        let items = assembler.finish();
+        assert_eq!(items[0].id.as_deref(), Some("msg_1"));
+        let annotations = items[0].content[0].annotations.as_ref().unwrap();
+        let citation = annotations[0].file_citation().unwrap();
+        assert_eq!(citation.spans[0].start_index, 5);
+        assert_eq!(citation.spans[0].quote, None);
+    }
+
+    #[test]
+    fn assembler_keeps_content_parts_apart() {
+        let mut assembler = ResponseStreamAssembler::new();
+        assembler.push(&part_delta(0, "First"));
+        assembler.push(&part_delta(1, "Second"));
+        assembler.push(&part_delta(0, " part."));
+
+        assert_eq!(assembler.text(0, 0), Some("First part."));
+        assert_eq!(assembler.text(0, 1), Some("Second"));
+        assert_eq!(assembler.text(0, 2), None);
+
+        let items = assembler.finish();
+        let texts: Vec<_> = items[0]
+            .content
+            .iter()
+            .map(|part| part.text.as_deref())
+            .collect();
+        assert_eq!(texts, vec![Some("First part."), Some("Second")]);
+    }
+
     #[test]
     fn ensure_function_metadata_skips_strict_when_none() {
         let mut map = Map::new();
@@ -2038,6 +2457,8 @@ pub enum ResponseStreamEvent {
         event_id: Option<String>,
         response_id: String,
         output_index: u32,
+        #[serde(default)]
+        content_index: u32,
         delta: String,
     },
     /// Output text completion event
@@ -2046,8 +2467,23 @@ pub enum ResponseStreamEvent {
         event_id: Option<String>,
         response_id: String,
         output_index: u32,
+        #[serde(default)]
+        content_index: u32,
         text: String,
     },
+    /// Annotation attached to output text
+    #[serde(rename = "response.output_text.annotation.added")]
+    OutputTextAnnotationAdded {
+        event_id: Option<String>,
+        #[serde(default)]
+        response_id: String,
+        output_index: u32,
+        #[serde(default)]
+        content_index: u32,
+        #[serde(default)]
+        annotation_index: Option<u32>,
+        annotation: Annotation,
+    },
     /// Conversation item created/added event
     #[serde(rename = "conversation.item.created")]
     ConversationItemCreated {
@@ -2078,3 +2514,203 @@ pub enum ResponseStreamEvent {
     #[serde(other)]
     Unknown,
 }
+
+/// Assembles streamed output text and annotations into complete output items
+///
+/// Feed every [`ResponseStreamEvent`] to [`push`](Self::push). Text is kept
+/// per content part, so an item with several text parts keeps them apart.
+/// Annotations may arrive before all the text they cover has been streamed;
+/// their `text` is filled in as soon as the referenced range is available.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseStreamAssembler {
+    /// Output state keyed by output index
+    outputs: BTreeMap<u32, AssembledOutput>,
+}
+
+/// Item and content parts received so far for one output item
+#[derive(Debug, Clone, Default)]
+struct AssembledOutput {
+    /// Item announced by `response.output_item.added`
+    item: Option<ResponseItem>,
+    /// Text parts keyed by content index
+    parts: BTreeMap<u32, AssembledPart>,
+}
+
+/// Text and annotations received so far for one content part
+#[derive(Debug, Clone, Default)]
+struct AssembledPart {
+    /// Output text received so far
+    text: String,
+    /// Annotations in annotation index order
+    annotations: Vec<Annotation>,
+}
+
+impl AssembledPart {
+    /// Store an annotation at its reported position
+    fn add_annotation(&mut self, annotation_index: Option<u32>, annotation: Annotation) {
+        match annotation_index.map(|i| i as usize) {
+            Some(index) if index < self.annotations.len() => self.annotations[index] = annotation,
+            _ => self.annotations.push(annotation),
+        }
+    }
+
+    /// Fill in the text of annotations whose range has been fully received
+    fn resolve_annotations(&mut self) {
+        let received = self.text.chars().count();
+        for annotation in &mut self.annotations {
+            if annotation.text().is_some() {
+                continue;
+            }
+            let (Some(start), Some(end)) = (annotation.start_index(), annotation.end_index())
+            else {
+                continue;
+            };
+            let (start, end) = (start as usize, end as usize);
+            if start <= end && end <= received {
+                let quoted: String = self.text.chars().skip(start).take(end - start).collect();
+                annotation
+                    .extra
+                    .insert("text".to_string(), Value::String(quoted));
+            }
+        }
+    }
+
+    /// Number of ranged annotations still waiting for their text
+    fn pending_count(&self) -> usize {
+        self.annotations
+            .iter()
+            .filter(|a| a.text().is_none() && a.end_index().is_some())
+            .count()
+    }
+}
+
+impl AssembledOutput {
+    /// Get the part at a content index, creating it when first seen
+    fn part(&mut self, content_index: u32) -> &mut AssembledPart {
+        self.parts.entry(content_index).or_default()
+    }
+
+    /// Build the final output item
+    ///
+    /// Each part fills the announced content part at its index, or is
+    /// appended as a new `output_text` part when none was announced.
+    fn into_item(self) -> ResponseItem {
+        let mut item = self.item.unwrap_or_else(|| ResponseItem {
+            item_type: "message".to_string(),
+            role: Some(MessageRole::Assistant),
+            ..ResponseItem::default()
+        });
+        for (content_index, part) in self.parts {
+            let annotations = Some(part.annotations);
+            match item.content.get_mut(content_index as usize) {
+                Some(existing) => {
+                    existing.text = Some(part.text);
+                    existing.annotations = annotations;
+                }
+                None => item.content.push(ContentPart {
+                    part_type: "output_text".to_string(),
+                    text: Some(part.text),
+                    annotations,
+                    extra: HashMap::new(),
+                }),
+            }
+        }
+        item
+    }
+}
+
+impl ResponseStreamAssembler {
+    /// Create an empty assembler
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a stream event
+    pub fn push(&mut self, event: &ResponseStreamEvent) {
+        match event {
+            ResponseStreamEvent::OutputItemAdded {
+                output_index, item, ..
+            } => {
+                self.outputs.entry(*output_index).or_default().item = Some(item.clone());
+            }
+            ResponseStreamEvent::OutputTextDelta {
+                output_index,
+                content_index,
+                delta,
+                ..
+            } => {
+                let part = self.part(*output_index, *content_index);
+                part.text.push_str(delta);
+                part.resolve_annotations();
+            }
+            ResponseStreamEvent::OutputTextDone {
+                output_index,
+                content_index,
+                text,
+                ..
+            } => {
+                let part = self.part(*output_index, *content_index);
+                part.text.clone_from(text);
+                part.resolve_annotations();
+            }
+            ResponseStreamEvent::OutputTextAnnotationAdded {
+                output_index,
+                content_index,
+                annotation_index,
+                annotation,
+                ..
+            } => {
+                let part = self.part(*output_index, *content_index);
+                part.add_annotation(*annotation_index, annotation.clone());
+                part.resolve_annotations();
+            }
+            _ => {}
+        }
+    }
+
+    /// Get the part at an output and content index, creating it when first seen
+    fn part(&mut self, output_index: u32, content_index: u32) -> &mut AssembledPart {
+        self.outputs
+            .entry(output_index)
+            .or_default()
+            .part(content_index)
+    }
+
+    /// Text received so far for a content part of an output item
+    #[must_use]
+    pub fn text(&self, output_index: u32, content_index: u32) -> Option<&str> {
+        self.outputs
+            .get(&output_index)
+            .and_then(|output| output.parts.get(&content_index))
+            .map(|part| part.text.as_str())
+    }
+
+    /// Annotations received so far for a content part of an output item
+    #[must_use]
+    pub fn annotations(&self, output_index: u32, content_index: u32) -> &[Annotation] {
+        self.outputs
+            .get(&output_index)
+            .and_then(|output| output.parts.get(&content_index))
+            .map_or(&[], |part| part.annotations.as_slice())
+    }
+
+    /// Number of annotations whose text range has not been fully streamed yet
+    #[must_use]
+    pub fn pending_annotations(&self) -> usize {
+        self.outputs
+            .values()
+            .flat_map(|output| output.parts.values())
+            .map(AssembledPart::pending_count)
+            .sum()
+    }
+
+    /// Build the assembled output items in output index order
+    #[must_use]
+    pub fn finish(self) -> Vec<ResponseItem> {
+        self.outputs
+            .into_values()
+            .map(AssembledOutput::into_item)
+            .collect()
+    }
+}
@@ -56,6 +56,7 @@
 use crate::models::assistants::AssistantTool;
 use crate::{De, Ser};
 use serde::{self, Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
 /// Common trait for list parameter types
@@ -143,12 +144,17 @@ pub struct Run {
     #[serde(default)]
     pub file_ids: Vec<String>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, String>,
     /// Usage statistics for the completion request
     pub usage: Option<Usage>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(Run);
+
 /// Request to create a new run
 #[derive(Debug, Clone, PartialEq, Ser, De)]
 pub struct RunRequest {
@@ -167,7 +173,10 @@ pub struct RunRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_ids: Option<Vec<String>>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -192,7 +201,10 @@ pub struct CreateThreadAndRunRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_ids: Option<Vec<String>>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -203,7 +215,10 @@ pub struct ThreadCreateRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub messages: Option<Vec<ThreadMessage>>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -218,7 +233,10 @@ pub struct ThreadMessage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub file_ids: Option<Vec<String>>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -341,9 +359,49 @@ pub struct RunStep {
     /// The Unix timestamp (in seconds) for when the run step completed
     pub completed_at: Option<i64>,
     /// Set of 16 key-value pairs that can be attached to an object
+    #[serde(serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, String>,
     /// Usage statistics for this step
     pub usage: Option<Usage>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+crate::impl_extra_fields!(RunStep);
+
+impl RunStep {
+    /// Get the code interpreter calls made in this step
+    ///
+    /// Returns an empty vector for message creation steps and steps that only
+    /// called other tools.
+    #[must_use]
+    pub fn code_interpreter_calls(&self) -> Vec<&CodeInterpreterCall> {
+        match &self.step_details {
+            StepDetails::ToolCalls { tool_calls } => tool_calls
+                .iter()
+                .filter_map(StepToolCall::as_code_interpreter)
+                .collect(),
+            StepDetails::MessageCreation { .. } => Vec::new(),
+        }
+    }
+
+    /// Get every tool call made in this step
+    ///
+    /// Returns an empty slice for message creation steps.
+    #[must_use]
+    pub fn tool_calls(&self) -> &[StepToolCall] {
+        match &self.step_details {
+            StepDetails::ToolCalls { tool_calls } => tool_calls,
+            StepDetails::MessageCreation { .. } => &[],
+        }
+    }
+}
+
+/// Collect the tool calls made across a run's steps, in step order
+#[must_use]
+pub fn step_tool_calls(steps: &[RunStep]) -> Vec<&StepToolCall> {
+    steps.iter().flat_map(RunStep::tool_calls).collect()
 }
 
 /// The status of a run step
@@ -412,6 +470,29 @@ pub enum StepToolCall {
     },
 }
 
+impl StepToolCall {
+    /// Get the ID of the tool call
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::CodeInterpreter { id, .. }
+            | Self::Retrieval { id, .. }
+            | Self::Function { id, .. } => id,
+        }
+    }
+
+    /// Get the code interpreter call, if this is one
+    #[must_use]
+    pub fn as_code_interpreter(&self) -> Option<&CodeInterpreterCall> {
+        match self {
+            Self::CodeInterpreter {
+                code_interpreter, ..
+            } => Some(code_interpreter),
+            _ => None,
+        }
+    }
+}
+
 /// Code interpreter tool call details
 #[derive(Debug, Clone, PartialEq, Ser, De)]
 pub struct CodeInterpreterCall {
@@ -437,6 +518,46 @@ pub enum CodeInterpreterOutput {
     },
 }
 
+impl CodeInterpreterCall {
+    /// Get the text of every logs output, in order
+    #[must_use]
+    pub fn logs(&self) -> Vec<&str> {
+        self.outputs
+            .iter()
+            .filter_map(CodeInterpreterOutput::logs)
+            .collect()
+    }
+
+    /// Get the file IDs of every generated image, in order
+    #[must_use]
+    pub fn image_file_ids(&self) -> Vec<&str> {
+        self.outputs
+            .iter()
+            .filter_map(CodeInterpreterOutput::image_file_id)
+            .collect()
+    }
+}
+
+impl CodeInterpreterOutput {
+    /// Get the log text, if this is a logs output
+    #[must_use]
+    pub fn logs(&self) -> Option<&str> {
+        match self {
+            Self::Logs { logs } => Some(logs),
+            Self::Image { .. } => None,
+        }
+    }
+
+    /// Get the image file ID, if this is an image output
+    #[must_use]
+    pub fn image_file_id(&self) -> Option<&str> {
+        match self {
+            Self::Image { image } => Some(&image.file_id),
+            Self::Logs { .. } => None,
+        }
+    }
+}
+
 /// Image output from Code Interpreter
 #[derive(Debug, Clone, PartialEq, Ser, De)]
 pub struct CodeInterpreterImage {
@@ -444,6 +565,278 @@ pub struct CodeInterpreterImage {
     pub file_id: String,
 }
 
+/// An event from a streamed run
+///
+/// Built from the SSE event name and its JSON payload by
+/// [`AssistantStreamEvent::parse`]. Run step deltas for code interpreter calls
+/// are split into input and output events so code and logs can be rendered as
+/// they arrive; fragments of other tool calls and other step deltas are kept
+/// as raw JSON.
+///
+/// Events serialize with a `type` tag so they can be logged and read back.
+#[derive(Debug, Clone, PartialEq, Ser, De)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AssistantStreamEvent {
+    /// A `thread.run.*` event carrying the updated run
+    Run {
+        /// The SSE event name, for example `thread.run.completed`
+        event: String,
+        /// The run as of this event
+        run: Box<Run>,
+    },
+    /// A `thread.run.step.*` event (other than deltas) carrying the updated step
+    RunStep {
+        /// The SSE event name, for example `thread.run.step.completed`
+        event: String,
+        /// The run step as of this event
+        step: Box<RunStep>,
+    },
+    /// Text appended to a message being generated
+    MessageDelta {
+        /// The ID of the message
+        message_id: String,
+        /// The appended text
+        text: String,
+    },
+    /// Code appended to the input of a code interpreter call
+    CodeInterpreterInputDelta {
+        /// The ID of the run step containing the call
+        step_id: String,
+        /// The index of the call within the step
+        call_index: usize,
+        /// The ID of the call, sent with its first delta
+        call_id: Option<String>,
+        /// The appended code
+        input: String,
+    },
+    /// Output produced by a code interpreter call
+    CodeInterpreterOutputDelta {
+        /// The ID of the run step containing the call
+        step_id: String,
+        /// The index of the call within the step
+        call_index: usize,
+        /// The ID of the call, sent with its first delta
+        call_id: Option<String>,
+        /// The logs or generated image
+        output: CodeInterpreterOutput,
+    },
+    /// Fragment of a tool call other than code interpreter, such as a function call
+    ToolCallDelta {
+        /// The ID of the run step containing the call
+        step_id: String,
+        /// The index of the call within the step
+        call_index: usize,
+        /// The ID of the call, sent with its first delta
+        call_id: Option<String>,
+        /// The raw tool call fragment, including its `type`
+        delta: serde_json::Value,
+    },
+    /// A run step delta that carries no tool calls
+    RunStepDelta {
+        /// The ID of the run step
+        step_id: String,
+        /// The raw changed fields of the step
+        delta: serde_json::Value,
+    },
+    /// An `error` event sent by the server
+    Error {
+        /// The error message
+        message: String,
+    },
+    /// Any other event, with its raw payload
+    Other {
+        /// The SSE event name
+        event: String,
+        /// The event payload
+        data: serde_json::Value,
+    },
+}
+
+impl AssistantStreamEvent {
+    /// Parse one SSE event into stream events
+    ///
+    /// Returns no events for the terminal `done` event. A single step delta can
+    /// produce several events when it carries both code and outputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the payload is not valid JSON for the event type.
+    pub fn parse(event: &str, data: &str) -> crate::error::Result<Vec<Self>> {
+        if event == "done" || data == "[DONE]" {
+            return Ok(Vec::new());
+        }
+
+        let events = match event {
+            "thread.run.step.delta" => {
+                let payload: RunStepDeltaPayload = serde_json::from_str(data)?;
+                payload.into_events()?
+            }
+            "thread.message.delta" => {
+                let payload: MessageDeltaPayload = serde_json::from_str(data)?;
+                let text: String = payload
+                    .delta
+                    .content
+                    .into_iter()
+                    .filter_map(|part| part.text.and_then(|text| text.value))
+                    .collect();
+                if text.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![Self::MessageDelta {
+                        message_id: payload.id,
+                        text,
+                    }]
+                }
+            }
+            "error" => {
+                let value: serde_json::Value = serde_json::from_str(data)?;
+                let message = value
+                    .get("message")
+                    .or_else(|| value.pointer("/error/message"))
+                    .and_then(serde_json::Value::as_str)
+                    .map_or_else(|| value.to_string(), str::to_string);
+                vec![Self::Error { message }]
+            }
+            _ if event.starts_with("thread.run.step.") => vec![Self::RunStep {
+                event: event.to_string(),
+                step: Box::new(serde_json::from_str(data)?),
+            }],
+            _ if event.starts_with("thread.run.") => vec![Self::Run {
+                event: event.to_string(),
+                run: Box::new(serde_json::from_str(data)?),
+            }],
+            _ => vec![Self::Other {
+                event: event.to_string(),
+                data: serde_json::from_str(data)?,
+            }],
+        };
+
+        Ok(events)
+    }
+
+    /// Get the file ID of a generated image, if this event carries one
+    #[must_use]
+    pub fn image_file_id(&self) -> Option<&str> {
+        match self {
+            Self::CodeInterpreterOutputDelta { output, .. } => output.image_file_id(),
+            _ => None,
+        }
+    }
+}
+
+/// Payload of a `thread.run.step.delta` event
+#[derive(Deserialize)]
+struct RunStepDeltaPayload {
+    /// The ID of the run step
+    id: String,
+    /// The changed fields of the step
+    delta: serde_json::Value,
+}
+
+/// Fragment of a tool call in a run step delta
+#[derive(Deserialize)]
+struct ToolCallDelta {
+    /// The index of the call within the step
+    index: usize,
+    /// The ID of the call, sent with its first delta
+    #[serde(default)]
+    id: Option<String>,
+    /// Code interpreter fragment, for code interpreter calls
+    #[serde(default)]
+    code_interpreter: Option<CodeInterpreterDelta>,
+}
+
+/// Fragment of a code interpreter call
+#[derive(Deserialize)]
+struct CodeInterpreterDelta {
+    /// Appended code
+    #[serde(default)]
+    input: Option<String>,
+    /// Outputs completed in this fragment
+    #[serde(default)]
+    outputs: Vec<CodeInterpreterOutput>,
+}
+
+impl RunStepDeltaPayload {
+    /// Split the delta into one or more events per tool call
+    fn into_events(self) -> serde_json::Result<Vec<AssistantStreamEvent>> {
+        let Some(tool_calls) = self
+            .delta
+            .pointer("/step_details/tool_calls")
+            .and_then(serde_json::Value::as_array)
+        else {
+            return Ok(vec![AssistantStreamEvent::RunStepDelta {
+                step_id: self.id,
+                delta: self.delta,
+            }]);
+        };
+
+        let mut events = Vec::new();
+        for raw_call in tool_calls {
+            let call: ToolCallDelta = serde_json::from_value(raw_call.clone())?;
+            let Some(code_interpreter) = call.code_interpreter else {
+                events.push(AssistantStreamEvent::ToolCallDelta {
+                    step_id: self.id.clone(),
+                    call_index: call.index,
+                    call_id: call.id,
+                    delta: raw_call.clone(),
+                });
+                continue;
+            };
+            if let Some(input) = code_interpreter.input.filter(|input| !input.is_empty()) {
+                events.push(AssistantStreamEvent::CodeInterpreterInputDelta {
+                    step_id: self.id.clone(),
+                    call_index: call.index,
+                    call_id: call.id.clone(),
+                    input,
+                });
+            }
+            for output in code_interpreter.outputs {
+                events.push(AssistantStreamEvent::CodeInterpreterOutputDelta {
+                    step_id: self.id.clone(),
+                    call_index: call.index,
+                    call_id: call.id.clone(),
+                    output,
+                });
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Payload of a `thread.message.delta` event
+#[derive(Deserialize)]
+struct MessageDeltaPayload {
+    /// The ID of the message
+    id: String,
+    /// The changed fields of the message
+    delta: MessageDeltaBody,
+}
+
+/// Changed fields of a message
+#[derive(Deserialize)]
+struct MessageDeltaBody {
+    /// Content fragments
+    #[serde(default)]
+    content: Vec<MessageContentDelta>,
+}
+
+/// Fragment of message content
+#[derive(Deserialize)]
+struct MessageContentDelta {
+    /// Text fragment, for text content
+    #[serde(default)]
+    text: Option<TextDelta>,
+}
+
+/// Fragment of message text
+#[derive(Deserialize)]
+struct TextDelta {
+    /// Appended text
+    #[serde(default)]
+    value: Option<String>,
+}
+
 // Generate list response and parameter structures using macros
 crate::impl_list_response!(ListRunsResponse, Run, "runs");
 crate::impl_list_params!(ListRunsParams, "runs");
@@ -465,7 +858,10 @@ pub struct Usage {
 #[derive(Debug, Clone, PartialEq, Ser, De)]
 pub struct ModifyRunRequest {
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
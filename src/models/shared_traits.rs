@@ -7,6 +7,60 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+/// Trait for request types that can be validated
+pub trait Validate {
+    /// Validates the request and returns an error message if invalid
+    fn validate(&self) -> std::result::Result<(), String>;
+
+    /// Validates the request and returns every error message instead of the first
+    ///
+    /// The default implementation reports the single error from [`Validate::validate`].
+    fn validate_all(&self) -> std::result::Result<(), Vec<String>> {
+        self.validate().map_err(|error| vec![error])
+    }
+}
+
+/// Gather the messages of failed checks for [`Validate::validate_all`]
+///
+/// # Errors
+///
+/// Returns the message of every check that failed, in order.
+pub fn collect_errors(
+    checks: impl IntoIterator<Item = std::result::Result<(), String>>,
+) -> std::result::Result<(), Vec<String>> {
+    let errors: Vec<String> = checks.into_iter().filter_map(Result::err).collect();
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Run [`Validate::validate_all`] and wrap each message in a [`ValidationError`]
+///
+/// # Errors
+///
+/// Returns every validation message of `request`.
+pub fn validation_errors<T: Validate>(
+    request: &T,
+) -> std::result::Result<(), Vec<ValidationError>> {
+    request
+        .validate_all()
+        .map_err(|messages| messages.into_iter().map(ValidationError::new).collect())
+}
+
+/// Common trait for list query parameters
+pub trait ListQueryParams {
+    /// Get the limit parameter
+    fn limit(&self) -> Option<u32>;
+    /// Get the order parameter as string
+    fn order_str(&self) -> Option<&str>;
+    /// Get the after cursor parameter
+    fn after(&self) -> Option<&String>;
+    /// Get the before cursor parameter
+    fn before(&self) -> Option<&String>;
+}
+
 /// Common trait for status enums across different APIs
 pub trait StatusEnum: Clone + PartialEq + Serialize + for<'de> Deserialize<'de> + Display {
     /// Check if the status represents a terminal state
@@ -347,6 +401,44 @@ macro_rules! impl_bytes_usage {
     };
 }
 
+/// Uniform access to the text carried by any message or response type
+///
+/// Chat choices, Responses API output and assistant thread messages store text
+/// differently; this trait gives each of them the same `text()` accessor.
+pub trait AsText {
+    /// Concatenate all textual content parts, ignoring images and other non-text parts
+    fn text(&self) -> String;
+}
+
+/// Access to response fields that the crate does not model yet
+///
+/// `OpenAI` often adds fields to responses before they get a dedicated struct
+/// field here. Types implementing this trait keep such fields instead of
+/// dropping them during deserialization.
+pub trait ExtraFields {
+    /// Fields returned by the API that have no dedicated struct field
+    fn extra(&self) -> &HashMap<String, serde_json::Value>;
+
+    /// Look up a single unmodeled field by name
+    fn extra_field(&self, key: &str) -> Option<&serde_json::Value> {
+        self.extra().get(key)
+    }
+}
+
+/// Generic macro to implement ExtraFields for structs with an `extra` map
+#[macro_export]
+macro_rules! impl_extra_fields {
+    ($($type:ty),+ $(,)?) => {
+        $(
+            impl $crate::models::shared_traits::ExtraFields for $type {
+                fn extra(&self) -> &std::collections::HashMap<String, serde_json::Value> {
+                    &self.extra
+                }
+            }
+        )+
+    };
+}
+
 /// Trait for delete responses
 pub trait DeleteResponse {
     /// Check if the deletion was successful
@@ -436,6 +528,9 @@ impl std::fmt::Display for BuilderError {
 
 impl std::error::Error for BuilderError {}
 
+/// A single problem reported by a builder's `build_validated()`
+pub type ValidationError = BuilderError;
+
 impl BuilderError {
     /// Create a new builder error with a message
     pub fn new<S: Into<String>>(message: S) -> Self {
@@ -40,7 +40,10 @@ pub struct Skill {
     pub description: Option<String>,
 
     /// Optional metadata associated with the skill.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// The ID of the latest version of this skill.
@@ -63,7 +66,10 @@ pub struct CreateSkillRequest {
     pub description: Option<String>,
 
     /// Optional metadata to attach to the skill.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// Optional content for the initial version of the skill.
@@ -83,7 +89,10 @@ pub struct UpdateSkillRequest {
     pub description: Option<String>,
 
     /// Optional updated metadata for the skill.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// Optional version ID to set as the active version.
@@ -155,7 +164,10 @@ pub struct SkillVersion {
     pub description: Option<String>,
 
     /// Optional metadata associated with this version.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -167,7 +179,10 @@ pub struct CreateSkillVersionRequest {
     pub description: Option<String>,
 
     /// Optional metadata to attach to the version.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 
     /// The content payload for this version.
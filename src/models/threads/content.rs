@@ -17,6 +17,11 @@ pub enum MessageContent {
         /// The image file details
         image_file: ImageFile,
     },
+    /// The assistant declined to produce the requested output
+    Refusal {
+        /// The refusal message
+        refusal: String,
+    },
 }
 
 impl MessageContent {
@@ -48,6 +53,15 @@ impl MessageContent {
             },
         }
     }
+
+    /// Get the refusal message if this part is a refusal
+    #[must_use]
+    pub fn refusal(&self) -> Option<&str> {
+        match self {
+            Self::Refusal { refusal } => Some(refusal),
+            _ => None,
+        }
+    }
 }
 
 /// Text content with annotations
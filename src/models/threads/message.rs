@@ -1,8 +1,9 @@
 //! Message-related models and builders
 
-use crate::api::base::Validate;
+use crate::models::shared_traits::{AsText, Validate, collect_errors};
 use crate::{De, Ser};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
 use super::builders::MetadataBuilder;
@@ -36,8 +37,33 @@ pub struct Message {
     #[serde(default)]
     pub file_ids: Vec<String>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, String>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+crate::impl_extra_fields!(Message);
+
+impl AsText for Message {
+    fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|content| match content {
+                MessageContent::Text { text } => Some(text.value.as_str()),
+                MessageContent::ImageFile { .. } | MessageContent::Refusal { .. } => None,
+            })
+            .collect()
+    }
+}
+
+impl Message {
+    /// Get the refusal message if the assistant declined to answer
+    #[must_use]
+    pub fn refusal(&self) -> Option<&str> {
+        self.content.iter().find_map(MessageContent::refusal)
+    }
 }
 
 /// Request to create or modify a message
@@ -51,7 +77,11 @@ pub struct MessageRequest {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub file_ids: Vec<String>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "crate::helpers::sorted_map::serialize"
+    )]
     pub metadata: HashMap<String, String>,
 }
 
@@ -91,6 +121,14 @@ impl Validate for MessageRequest {
     fn validate(&self) -> Result<(), String> {
         self.validate()
     }
+
+    fn validate_all(&self) -> Result<(), Vec<String>> {
+        collect_errors([
+            validate_content_length(&self.content),
+            validate_file_ids_count(&self.file_ids),
+            validate_metadata(&self.metadata).map_err(|e| format!("Message {}", e)),
+        ])
+    }
 }
 
 /// Builder for creating message requests
@@ -346,4 +384,21 @@ mod tests {
         assert!(message.file_ids.is_empty());
         assert!(message.metadata.is_empty());
     }
+
+    #[test]
+    fn test_message_text_skips_images() {
+        let message: Message = serde_json::from_value(serde_json::json!({
+            "id": "msg_1",
+            "created_at": 0,
+            "thread_id": "thread_1",
+            "role": "assistant",
+            "content": [
+                {"type": "text", "text": {"value": "Here is the chart.", "annotations": []}},
+                {"type": "image_file", "image_file": {"file_id": "file_1"}},
+                {"type": "text", "text": {"value": " Done."}}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(message.text(), "Here is the chart. Done.");
+    }
 }
@@ -57,7 +57,10 @@ pub use message::{
     ListMessageFilesResponse, ListMessagesParams, ListMessagesResponse, Message, MessageRequest,
     MessageRequestBuilder,
 };
-pub use thread::{ListThreadsResponse, Thread, ThreadRequest, ThreadRequestBuilder};
+pub use thread::{
+    ListThreadsResponse, ModifyThreadRequest, ModifyThreadRequestBuilder, Thread, ThreadRequest,
+    ThreadRequestBuilder,
+};
 pub use types::{DeletionStatus, MessageFile, MessageRole, SortOrder};
 
 // Re-export builder traits
@@ -1,8 +1,10 @@
 //! Thread-related models and builders
 
-use crate::api::base::Validate;
+use crate::models::field_update::FieldUpdate;
+use crate::models::shared_traits::Validate;
 use crate::{De, Ser};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
 use super::builders::MetadataBuilder;
@@ -21,10 +23,15 @@ pub struct Thread {
     /// The Unix timestamp (in seconds) when the thread was created
     pub created_at: i64,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, String>,
+    /// Any additional fields returned by the service that we do not explicitly model
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+crate::impl_extra_fields!(Thread);
+
 /// Request to create or modify a thread
 #[derive(Debug, Clone, Ser, De)]
 pub struct ThreadRequest {
@@ -32,7 +39,11 @@ pub struct ThreadRequest {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub messages: Vec<MessageRequest>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "crate::helpers::sorted_map::serialize"
+    )]
     pub metadata: HashMap<String, String>,
 }
 
@@ -70,6 +81,23 @@ impl Validate for ThreadRequest {
     fn validate(&self) -> Result<(), String> {
         self.validate()
     }
+
+    fn validate_all(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if let Err(error) = validate_metadata(&self.metadata) {
+            errors.push(format!("Thread {error}"));
+        }
+        for message in &self.messages {
+            if let Err(message_errors) = Validate::validate_all(message) {
+                errors.extend(message_errors);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 impl Default for ThreadRequest {
@@ -137,6 +165,108 @@ impl ThreadRequestBuilder {
     }
 }
 
+/// Request to modify an existing thread
+///
+/// An unchanged field is left out of the body so the service keeps it;
+/// [`FieldUpdate::Clear`] sends `null`. Build one with
+/// [`Thread::to_modify_request`] to edit the current metadata.
+#[derive(Debug, Clone, Default, Ser, De)]
+pub struct ModifyThreadRequest {
+    /// The full metadata map, replacing the current metadata
+    #[serde(
+        default,
+        skip_serializing_if = "FieldUpdate::is_unchanged",
+        serialize_with = "crate::helpers::sorted_map::serialize_update"
+    )]
+    pub metadata: FieldUpdate<HashMap<String, String>>,
+}
+
+impl ModifyThreadRequest {
+    /// Check whether the request leaves every field unchanged
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.metadata.is_unchanged()
+    }
+}
+
+impl Validate for ModifyThreadRequest {
+    fn validate(&self) -> Result<(), String> {
+        match self.metadata.value() {
+            Some(metadata) => validate_metadata(metadata).map_err(|e| format!("Thread {}", e)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Thread {
+    /// Start a modify request seeded with this thread's current metadata
+    ///
+    /// Only a metadata map that ends up different from the current one is
+    /// sent.
+    #[must_use]
+    pub fn to_modify_request(&self) -> ModifyThreadRequestBuilder {
+        ModifyThreadRequestBuilder {
+            current: self.metadata.clone(),
+            metadata: None,
+        }
+    }
+}
+
+/// Builder for [`ModifyThreadRequest`] seeded from an existing thread
+#[derive(Debug, Clone)]
+pub struct ModifyThreadRequestBuilder {
+    /// The metadata of the thread the builder was seeded from
+    current: HashMap<String, String>,
+    /// The edited metadata, or `None` while it is untouched
+    metadata: Option<HashMap<String, String>>,
+}
+
+impl ModifyThreadRequestBuilder {
+    /// Add or overwrite one metadata entry, keeping the others
+    pub fn metadata_pair(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata_mut().insert(key.into(), value.into());
+        self
+    }
+
+    /// Remove one metadata entry, keeping the others
+    pub fn remove_metadata(mut self, key: &str) -> Self {
+        self.metadata_mut().remove(key);
+        self
+    }
+
+    /// Replace all metadata
+    #[must_use]
+    pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Remove all metadata
+    ///
+    /// Entries added afterwards start from an empty map.
+    #[must_use]
+    pub fn clear_metadata(mut self) -> Self {
+        self.metadata = Some(HashMap::new());
+        self
+    }
+
+    /// Build a request holding only a metadata change that differs from the current metadata
+    ///
+    /// Metadata edited down to nothing is sent as a clear.
+    #[must_use]
+    pub fn build(self) -> ModifyThreadRequest {
+        ModifyThreadRequest {
+            metadata: FieldUpdate::from_edited_map(self.metadata, &self.current),
+        }
+    }
+
+    /// The metadata being edited, starting from the current metadata
+    fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        let current = &self.current;
+        self.metadata.get_or_insert_with(|| current.clone())
+    }
+}
+
 // Generate list response for threads
 crate::impl_list_response!(ListThreadsResponse, Thread, "Response from listing threads");
 
@@ -185,4 +315,83 @@ mod tests {
         assert_eq!(request.messages.len(), 1);
         assert_eq!(request.messages[0].content, "Hello");
     }
+
+    fn sample_thread() -> Thread {
+        serde_json::from_value(serde_json::json!({
+            "id": "thread_123",
+            "object": "thread",
+            "created_at": 1_700_000_000,
+            "metadata": {"topic": "billing", "priority": "high"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_modify_thread_request_field_update_states() {
+        let thread = sample_thread();
+
+        let unchanged = thread.to_modify_request().build();
+        assert!(unchanged.is_empty());
+        assert_eq!(serde_json::to_string(&unchanged).unwrap(), "{}");
+
+        let set = thread
+            .to_modify_request()
+            .remove_metadata("priority")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&set).unwrap(),
+            serde_json::json!({"metadata": {"topic": "billing"}})
+        );
+
+        let clear = thread.to_modify_request().clear_metadata().build();
+        assert_eq!(
+            serde_json::to_value(&clear).unwrap(),
+            serde_json::json!({"metadata": null})
+        );
+
+        let emptied = thread
+            .to_modify_request()
+            .remove_metadata("topic")
+            .remove_metadata("priority")
+            .build();
+        assert!(emptied.metadata.is_clear());
+
+        let replaced = thread
+            .to_modify_request()
+            .clear_metadata()
+            .metadata_pair("topic", "refunds")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&replaced).unwrap(),
+            serde_json::json!({"metadata": {"topic": "refunds"}})
+        );
+    }
+
+    #[test]
+    fn test_modify_thread_request_validation() {
+        let metadata = (0..17)
+            .map(|i| (format!("key{i}"), "value".to_string()))
+            .collect();
+        let request = sample_thread()
+            .to_modify_request()
+            .metadata(metadata)
+            .build();
+        assert!(Validate::validate(&request).is_err());
+    }
+
+    #[test]
+    fn test_thread_request_validate_all_includes_message_errors() {
+        let mut message = MessageRequest::new(MessageRole::User, "x".repeat(32_769));
+        message.file_ids = (0..11).map(|i| format!("file-{i}")).collect();
+        let mut builder = ThreadRequest::builder().message(message);
+        for i in 0..17 {
+            builder = builder.metadata_pair(format!("key{i}"), "value");
+        }
+
+        let errors = Validate::validate_all(&builder.build()).unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].starts_with("Thread "));
+        assert!(errors[1].contains("32,768"));
+        assert!(errors[2].contains("10 file IDs"));
+    }
 }
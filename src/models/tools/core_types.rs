@@ -34,3 +34,81 @@ pub enum EnhancedTool {
     /// Computer use tool for agentic workflows
     ComputerUse(ComputerUseConfig),
 }
+
+/// Type names of the built-in tools, which function tools must not reuse
+pub const BUILT_IN_TOOL_TYPES: &[&str] = &[
+    "web_search_preview",
+    "web_search",
+    "file_search",
+    "mcp",
+    "image_generation",
+    "code_interpreter",
+    "computer_use",
+];
+
+impl EnhancedTool {
+    /// Get the `type` this tool serializes with
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        match self {
+            Self::WebSearchPreview => "web_search_preview",
+            Self::WebSearch(_) => "web_search",
+            Self::FileSearch(_) => "file_search",
+            Self::Function(_) => "function",
+            Self::Mcp(_) => "mcp",
+            Self::ImageGeneration(_) => "image_generation",
+            Self::CodeInterpreter(_) => "code_interpreter",
+            Self::ComputerUse(_) => "computer_use",
+        }
+    }
+
+    /// Get the function name if this is a function tool
+    #[must_use]
+    pub fn function_name(&self) -> Option<&str> {
+        match self {
+            Self::Function(function) => Some(&function.name),
+            _ => None,
+        }
+    }
+}
+
+/// Check that function tool names are unique and do not shadow built-in tools
+///
+/// # Errors
+///
+/// Returns a message naming both indices of a duplicated function name, or the
+/// index of a function named after a built-in tool type.
+pub fn check_tool_names<'a, I>(names: I) -> Result<(), String>
+where
+    I: IntoIterator<Item = (usize, &'a str)>,
+{
+    let mut seen: Vec<(usize, &str)> = Vec::new();
+    for (index, name) in names {
+        if BUILT_IN_TOOL_TYPES.contains(&name) {
+            return Err(format!(
+                "tools[{index}] is a function named '{name}', which collides with the built-in tool type"
+            ));
+        }
+        if let Some((first, _)) = seen.iter().find(|(_, seen_name)| *seen_name == name) {
+            return Err(format!(
+                "tools[{first}] and tools[{index}] are both named '{name}'"
+            ));
+        }
+        seen.push((index, name));
+    }
+    Ok(())
+}
+
+/// Check a list of Responses API tools for ambiguous function names
+///
+/// # Errors
+///
+/// See [`check_tool_names`].
+pub fn validate_enhanced_tools(tools: &[EnhancedTool]) -> Result<(), String> {
+    check_tool_names(
+        tools
+            .iter()
+            .enumerate()
+            .filter_map(|(index, tool)| tool.function_name().map(|name| (index, name))),
+    )
+}
@@ -17,7 +17,10 @@ pub struct McpTool {
     pub require_approval: McpApproval,
 
     /// Custom headers for MCP server authentication
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub headers: Option<HashMap<String, String>>,
 
     /// Timeout for MCP server calls (in milliseconds)
@@ -16,11 +16,183 @@ pub struct WebSearchConfig {
     /// Time range for search results
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_range: Option<String>,
+
+    /// How much search context is retrieved for the model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search_context_size: Option<SearchContextSize>,
+
+    /// Approximate location of the user, for geographically relevant results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_location: Option<UserLocation>,
+}
+
+impl WebSearchConfig {
+    /// Validate the configuration
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the first invalid field.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(location) = &self.user_location {
+            location.validate()?;
+        }
+        if let Some(domains) = self
+            .filters
+            .as_ref()
+            .and_then(|filters| filters.allowed_domains.as_ref())
+            && domains.iter().any(|domain| domain.trim().is_empty())
+        {
+            return Err("allowed_domains must not contain empty domains".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Amount of search context retrieved for the model
+///
+/// Larger sizes give better answers at a higher cost and latency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ser, De)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchContextSize {
+    /// Least context, fastest and cheapest
+    Low,
+    /// Balanced context (the service default)
+    Medium,
+    /// Most context, most thorough
+    High,
+}
+
+/// Approximate user location used to localize web search results
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct UserLocation {
+    /// Location type, always `approximate`
+    #[serde(rename = "type")]
+    pub location_type: String,
+
+    /// City name, e.g. `San Francisco`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+
+    /// Region or state name, e.g. `California`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Two-letter ISO 3166-1 country code, e.g. `US`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+
+    /// IANA time zone, e.g. `America/Los_Angeles`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+}
+
+impl Default for UserLocation {
+    fn default() -> Self {
+        Self {
+            location_type: "approximate".to_string(),
+            city: None,
+            region: None,
+            country: None,
+            timezone: None,
+        }
+    }
+}
+
+impl UserLocation {
+    /// Create an empty approximate location
+    #[must_use]
+    pub fn approximate() -> Self {
+        Self::default()
+    }
+
+    /// Set the city
+    #[must_use]
+    pub fn with_city(mut self, city: impl Into<String>) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    /// Set the region or state
+    #[must_use]
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Set the two-letter ISO country code
+    #[must_use]
+    pub fn with_country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the IANA time zone
+    #[must_use]
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Validate that the location is a coherent approximate location
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the type is not `approximate`, no field is set, a
+    /// field is blank, the country is not a two-letter code or the time zone
+    /// is not an IANA name.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.location_type != "approximate" {
+            return Err(format!(
+                "user_location type must be 'approximate', got '{}'",
+                self.location_type
+            ));
+        }
+
+        let fields = [
+            ("city", &self.city),
+            ("region", &self.region),
+            ("country", &self.country),
+            ("timezone", &self.timezone),
+        ];
+        if fields.iter().all(|(_, value)| value.is_none()) {
+            return Err(
+                "user_location must set at least one of city, region, country or timezone"
+                    .to_string(),
+            );
+        }
+        if let Some((name, _)) = fields
+            .iter()
+            .find(|(_, value)| value.as_deref().is_some_and(|v| v.trim().is_empty()))
+        {
+            return Err(format!("user_location {name} must not be blank"));
+        }
+
+        if let Some(country) = &self.country
+            && (country.len() != 2 || !country.chars().all(|c| c.is_ascii_uppercase()))
+        {
+            return Err(format!(
+                "user_location country must be a two-letter ISO code such as 'US', got '{country}'"
+            ));
+        }
+        if let Some(timezone) = &self.timezone
+            && timezone != "UTC"
+            && (!timezone.contains('/') || timezone.contains(char::is_whitespace))
+        {
+            return Err(format!(
+                "user_location timezone must be an IANA name such as 'America/New_York', got '{timezone}'"
+            ));
+        }
+        Ok(())
+    }
 }
 
 /// Search filters for web search
-#[derive(Debug, Clone, Ser, De)]
+#[derive(Debug, Clone, Default, Ser, De)]
 pub struct SearchFilters {
+    /// Domains the search is restricted to, as supported by the `web_search` tool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_domains: Option<Vec<String>>,
+
     /// Domains to include in search
     #[serde(skip_serializing_if = "Option::is_none")]
     pub include_domains: Option<Vec<String>>,
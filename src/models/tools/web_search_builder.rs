@@ -1,6 +1,6 @@
 //! Builder for web search tool configurations
 
-use super::{EnhancedTool, SearchFilters, WebSearchConfig};
+use super::{EnhancedTool, SearchContextSize, UserLocation, WebSearchConfig};
 
 /// Builder for web search configuration
 pub struct WebSearchBuilder {
@@ -23,6 +23,8 @@ impl WebSearchBuilder {
                 max_results: None,
                 filters: None,
                 time_range: None,
+                search_context_size: None,
+                user_location: None,
             },
         }
     }
@@ -37,12 +39,7 @@ impl WebSearchBuilder {
     /// Include only results from specified domains
     #[must_use]
     pub fn include_domains(mut self, domains: Vec<String>) -> Self {
-        let filters = self.config.filters.get_or_insert(SearchFilters {
-            include_domains: None,
-            exclude_domains: None,
-            language: None,
-            region: None,
-        });
+        let filters = self.config.filters.get_or_insert_with(Default::default);
         filters.include_domains = Some(domains);
         self
     }
@@ -50,16 +47,40 @@ impl WebSearchBuilder {
     /// Exclude results from specified domains
     #[must_use]
     pub fn exclude_domains(mut self, domains: Vec<String>) -> Self {
-        let filters = self.config.filters.get_or_insert(SearchFilters {
-            include_domains: None,
-            exclude_domains: None,
-            language: None,
-            region: None,
-        });
+        let filters = self.config.filters.get_or_insert_with(Default::default);
         filters.exclude_domains = Some(domains);
         self
     }
 
+    /// Restrict results to the given domains
+    ///
+    /// This is the domain filter supported by the `web_search` tool; subdomains
+    /// of each listed domain are included.
+    #[must_use]
+    pub fn allowed_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let filters = self.config.filters.get_or_insert_with(Default::default);
+        filters.allowed_domains = Some(domains.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set how much search context is retrieved for the model
+    #[must_use]
+    pub fn search_context_size(mut self, size: SearchContextSize) -> Self {
+        self.config.search_context_size = Some(size);
+        self
+    }
+
+    /// Set the approximate user location for localized results
+    #[must_use]
+    pub fn user_location(mut self, location: UserLocation) -> Self {
+        self.config.user_location = Some(location);
+        self
+    }
+
     /// Set the time range for search results (e.g., "past_week", "past_month")
     pub fn time_range(mut self, range: impl Into<String>) -> Self {
         self.config.time_range = Some(range.into());
@@ -71,4 +92,51 @@ impl WebSearchBuilder {
     pub fn build(self) -> EnhancedTool {
         EnhancedTool::WebSearch(self.config)
     }
+
+    /// Validate the configuration and build the web search tool
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the user location or domain filters are invalid.
+    pub fn try_build(self) -> Result<EnhancedTool, String> {
+        self.config.validate()?;
+        Ok(self.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::tools::ToolBuilder;
+
+    #[test]
+    fn test_web_search_context_size_and_location() {
+        let tool = ToolBuilder::web_search_advanced()
+            .search_context_size(SearchContextSize::High)
+            .allowed_domains(["docs.rs", "rust-lang.org"])
+            .user_location(
+                UserLocation::approximate()
+                    .with_city("Austin")
+                    .with_country("US")
+                    .with_timezone("America/Chicago"),
+            )
+            .try_build()
+            .unwrap();
+
+        let json = serde_json::to_value(&tool).unwrap();
+        assert_eq!(json["type"], "web_search");
+        assert_eq!(json["search_context_size"], "high");
+        assert_eq!(json["filters"]["allowed_domains"][1], "rust-lang.org");
+        assert_eq!(json["user_location"]["type"], "approximate");
+        assert_eq!(json["user_location"]["city"], "Austin");
+        assert!(json["user_location"].get("region").is_none());
+
+        let err = WebSearchBuilder::new()
+            .user_location(UserLocation::approximate().with_country("usa"))
+            .try_build()
+            .unwrap_err();
+        assert!(err.contains("two-letter"));
+
+        assert!(UserLocation::approximate().validate().is_err());
+    }
 }
@@ -0,0 +1,694 @@
+//! Export captured conversations as fine-tuning training data
+//!
+//! The fine-tuning API reads one JSON object per line, each holding the
+//! `messages` of one example conversation. [`to_finetune_jsonl`] turns
+//! recorded turns into that format: developer messages become `system`
+//! messages, and function calls and their outputs become assistant
+//! `tool_calls` and `tool` messages.
+//!
+//! ```rust
+//! use openai_rust_sdk::models::functions::{FunctionCall, FunctionCallOutput};
+//! use openai_rust_sdk::models::responses::Message;
+//! use openai_rust_sdk::models::training_data::{TrainingTurn, to_finetune_jsonl};
+//!
+//! let conversation: Vec<TrainingTurn> = vec![
+//!     Message::user("What time is it in Paris?").into(),
+//!     FunctionCall {
+//!         call_id: "call_1".to_string(),
+//!         name: "get_time".to_string(),
+//!         arguments: r#"{"city":"Paris"}"#.to_string(),
+//!     }
+//!     .into(),
+//!     FunctionCallOutput {
+//!         call_id: "call_1".to_string(),
+//!         output: "14:05".to_string(),
+//!     }
+//!     .into(),
+//!     Message::assistant("It is 14:05 in Paris.").into(),
+//! ];
+//!
+//! let jsonl = to_finetune_jsonl(&[conversation]).unwrap();
+//! assert_eq!(jsonl.lines().count(), 1);
+//! ```
+//!
+//! [`TrainingFileReport`] checks an existing training file the same way
+//! before it is uploaded.
+
+use crate::error::{OpenAIError, Result};
+use crate::models::functions::{FunctionCall, FunctionCallOutput};
+use crate::models::responses::{Message, MessageContent, MessageContentInput, MessageRole};
+use crate::{De, Ser};
+use serde::{self, Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Most line errors kept in a [`TrainingFileReport`]
+pub const MAX_REPORTED_ERRORS: usize = 20;
+
+/// Roles accepted in training messages
+const TRAINING_ROLES: [&str; 4] = ["system", "user", "assistant", "tool"];
+
+/// Chat Completions content part types accepted in training messages
+const CONTENT_PART_TYPES: [&str; 2] = ["text", "image_url"];
+
+/// One recorded step of a conversation
+#[derive(Debug, Clone)]
+pub enum TrainingTurn {
+    /// A message from the user, the assistant, or a system/developer prompt
+    Message(Message),
+    /// A function call made by the assistant
+    FunctionCall(FunctionCall),
+    /// The result of a function call
+    FunctionCallOutput(FunctionCallOutput),
+}
+
+impl From<Message> for TrainingTurn {
+    fn from(message: Message) -> Self {
+        Self::Message(message)
+    }
+}
+
+impl From<FunctionCall> for TrainingTurn {
+    fn from(call: FunctionCall) -> Self {
+        Self::FunctionCall(call)
+    }
+}
+
+impl From<FunctionCallOutput> for TrainingTurn {
+    fn from(output: FunctionCallOutput) -> Self {
+        Self::FunctionCallOutput(output)
+    }
+}
+
+/// One training example: a line of a fine-tuning JSONL file
+#[derive(Debug, Clone, PartialEq, Ser, De)]
+pub struct TrainingExample {
+    /// The conversation in Chat Completions message format
+    pub messages: Vec<TrainingMessage>,
+}
+
+/// A message in a training example
+#[derive(Debug, Clone, PartialEq, Ser, De)]
+pub struct TrainingMessage {
+    /// `system`, `user`, `assistant`, or `tool`
+    pub role: String,
+    /// Text, or an array of content parts for multimodal messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<serde_json::Value>,
+    /// Function calls made by an assistant message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<TrainingToolCall>>,
+    /// The call a `tool` message answers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+}
+
+/// A function call in an assistant training message
+#[derive(Debug, Clone, PartialEq, Ser, De)]
+pub struct TrainingToolCall {
+    /// The call ID that the matching `tool` message refers to
+    pub id: String,
+    /// Always `function`
+    #[serde(rename = "type")]
+    pub call_type: String,
+    /// The function name and arguments
+    pub function: TrainingFunction,
+}
+
+/// The function named by a [`TrainingToolCall`]
+#[derive(Debug, Clone, PartialEq, Ser, De)]
+pub struct TrainingFunction {
+    /// Name of the function
+    pub name: String,
+    /// JSON string containing the function arguments
+    pub arguments: String,
+}
+
+impl TrainingExample {
+    /// Build a training example from recorded turns
+    ///
+    /// Consecutive function calls are grouped into one assistant message and
+    /// attached to a directly preceding assistant message when there is one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the conversation does not conform to the
+    /// fine-tuning format; see [`TrainingExample::validate`].
+    pub fn from_turns(turns: &[TrainingTurn]) -> Result<Self> {
+        let mut messages: Vec<TrainingMessage> = Vec::new();
+        for turn in turns {
+            match turn {
+                TrainingTurn::Message(message) => messages.push(TrainingMessage {
+                    role: role_name(&message.role).to_string(),
+                    content: Some(content_value(&message.content)),
+                    tool_calls: None,
+                    tool_call_id: None,
+                }),
+                TrainingTurn::FunctionCall(call) => {
+                    let tool_call = TrainingToolCall {
+                        id: call.call_id.clone(),
+                        call_type: "function".to_string(),
+                        function: TrainingFunction {
+                            name: call.name.clone(),
+                            arguments: call.arguments.clone(),
+                        },
+                    };
+                    match messages.last_mut() {
+                        Some(last) if last.role == "assistant" => {
+                            last.tool_calls.get_or_insert_with(Vec::new).push(tool_call);
+                        }
+                        _ => messages.push(TrainingMessage {
+                            role: "assistant".to_string(),
+                            content: None,
+                            tool_calls: Some(vec![tool_call]),
+                            tool_call_id: None,
+                        }),
+                    }
+                }
+                TrainingTurn::FunctionCallOutput(output) => messages.push(TrainingMessage {
+                    role: "tool".to_string(),
+                    content: Some(serde_json::Value::String(output.output.clone())),
+                    tool_calls: None,
+                    tool_call_id: Some(output.call_id.clone()),
+                }),
+            }
+        }
+
+        let example = Self { messages };
+        example.validate()?;
+        Ok(example)
+    }
+
+    /// Check that the example is accepted by the fine-tuning API
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a message has an unknown role or a content part
+    /// that is not a Chat Completions part (such as the Responses API's
+    /// `input_text`), the example has no user message or no assistant
+    /// message, or a tool message does not answer an earlier call.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(message) = self
+            .messages
+            .iter()
+            .find(|m| !TRAINING_ROLES.contains(&m.role.as_str()))
+        {
+            return Err(OpenAIError::invalid_request(format!(
+                "Training example has a message with unknown role `{}`",
+                message.role
+            )));
+        }
+        if let Some(part_type) = self
+            .messages
+            .iter()
+            .filter_map(|m| m.content.as_ref()?.as_array())
+            .flatten()
+            .map(|part| part["type"].as_str().unwrap_or_default())
+            .find(|part_type| !CONTENT_PART_TYPES.contains(part_type))
+        {
+            return Err(OpenAIError::invalid_request(format!(
+                "Training example has a content part of type `{part_type}`; \
+                 expected Chat Completions parts `text` or `image_url`"
+            )));
+        }
+        if !self.messages.iter().any(|m| m.role == "user") {
+            return Err(OpenAIError::invalid_request(
+                "Training example has no user message",
+            ));
+        }
+        if !self.messages.iter().any(|m| m.role == "assistant") {
+            return Err(OpenAIError::invalid_request(
+                "Training example has no assistant message",
+            ));
+        }
+
+        let mut call_ids = HashSet::new();
+        for message in &self.messages {
+            for call in message.tool_calls.iter().flatten() {
+                call_ids.insert(call.id.as_str());
+            }
+            if let Some(call_id) = &message.tool_call_id
+                && !call_ids.contains(call_id.as_str())
+            {
+                return Err(OpenAIError::invalid_request(format!(
+                    "Tool message answers unknown call `{call_id}`"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the estimated token count of the example
+    ///
+    /// Uses the same rough estimate as [`Message::estimate_tokens`], so leave
+    /// some headroom when comparing it with a context limit.
+    #[must_use]
+    pub fn estimate_tokens(&self) -> u32 {
+        self.messages
+            .iter()
+            .map(TrainingMessage::estimate_tokens)
+            .sum()
+    }
+}
+
+impl TrainingMessage {
+    /// Get the estimated token count of the message content and tool calls
+    #[must_use]
+    pub fn estimate_tokens(&self) -> u32 {
+        let content = match &self.content {
+            Some(serde_json::Value::String(text)) => text_tokens(text),
+            Some(parts @ serde_json::Value::Array(_)) => {
+                serde_json::from_value::<Vec<MessageContent>>(parts.clone())
+                    .map(|parts| Message::user_with_content(parts).estimate_tokens())
+                    .unwrap_or_else(|_| text_tokens(&parts.to_string()))
+            }
+            Some(other) => text_tokens(&other.to_string()),
+            None => 0,
+        };
+        let tool_calls: u32 = self
+            .tool_calls
+            .iter()
+            .flatten()
+            .map(|call| text_tokens(&call.function.name) + text_tokens(&call.function.arguments))
+            .sum();
+        content + tool_calls
+    }
+}
+
+/// A problem found on a single line of a training file
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct TrainingLineError {
+    /// 1-based line number in the file
+    pub line: usize,
+    /// Description of the problem
+    pub message: String,
+}
+
+/// Estimated size of one training example
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ser, De)]
+pub struct ExampleTokens {
+    /// 1-based line number in the file
+    pub line: usize,
+    /// Estimated token count of the example
+    pub estimated_tokens: u32,
+}
+
+/// Maximum tokens per training example for fine-tunable base models
+///
+/// Fine-tuning limits differ from the models' inference context windows.
+/// Entries are matched as prefixes in order, so more specific names come
+/// first.
+const FINE_TUNING_CONTEXT_LIMITS: [(&str, u32); 8] = [
+    ("gpt-4o-mini", 65_536),
+    ("gpt-4o", 65_536),
+    ("gpt-4.1", 65_536),
+    ("gpt-4-0613", 8_192),
+    ("gpt-3.5-turbo-0613", 4_096),
+    ("gpt-3.5-turbo", 16_385),
+    ("davinci-002", 16_384),
+    ("babbage-002", 16_384),
+];
+
+/// Get the maximum tokens per training example when fine-tuning `model`
+///
+/// Accepts base model names, dated snapshots and `ft:` model ids built on
+/// them. Returns `None` for models without a known fine-tuning limit.
+#[must_use]
+pub fn fine_tuning_context_limit(model: &str) -> Option<u32> {
+    let base = model
+        .strip_prefix("ft:")
+        .map_or(model, |rest| rest.split(':').next().unwrap_or(rest));
+    FINE_TUNING_CONTEXT_LIMITS
+        .iter()
+        .find(|(name, _)| {
+            base.strip_prefix(name)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+        })
+        .map(|&(_, limit)| limit)
+}
+
+/// Result of validating a fine-tuning training file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Ser, De)]
+pub struct TrainingFileReport {
+    /// Number of non-empty lines checked
+    pub total_examples: usize,
+    /// Number of lines that are well-formed examples
+    pub valid_examples: usize,
+    /// Number of problems found, including those not kept in `errors`
+    pub error_count: usize,
+    /// The first [`MAX_REPORTED_ERRORS`] problems, in line order
+    pub errors: Vec<TrainingLineError>,
+    /// Estimated size of each valid example, in line order
+    pub example_tokens: Vec<ExampleTokens>,
+    /// Estimated token count of all valid examples
+    pub total_tokens: u64,
+    /// Context limit the examples were checked against, if known
+    pub context_limit: Option<u32>,
+    /// Line numbers of examples estimated to exceed the context limit
+    pub oversized_examples: Vec<usize>,
+}
+
+impl TrainingFileReport {
+    /// Validate the contents of a training JSONL file
+    ///
+    /// Every line must be a `{"messages": [...]}` object that passes
+    /// [`TrainingExample::validate`]. Blank lines are reported as errors,
+    /// except for a trailing newline at the end of the file. Examples whose
+    /// estimated size exceeds `context_limit` are listed in
+    /// `oversized_examples`; the service would truncate them.
+    #[must_use]
+    pub fn from_jsonl(content: &str, context_limit: Option<u32>) -> Self {
+        let mut report = Self {
+            context_limit,
+            ..Self::default()
+        };
+
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() {
+                report.push_error(line_number, "Line is empty");
+                continue;
+            }
+            report.total_examples += 1;
+
+            let example: TrainingExample = match serde_json::from_str(line) {
+                Ok(example) => example,
+                Err(e) => {
+                    report.push_error(line_number, format!("Invalid training example: {e}"));
+                    continue;
+                }
+            };
+            if let Err(e) = example.validate() {
+                report.push_error(line_number, e.to_string());
+                continue;
+            }
+
+            report.valid_examples += 1;
+            let estimated_tokens = example.estimate_tokens();
+            report.total_tokens += u64::from(estimated_tokens);
+            report.example_tokens.push(ExampleTokens {
+                line: line_number,
+                estimated_tokens,
+            });
+            if context_limit.is_some_and(|limit| estimated_tokens > limit) {
+                report.oversized_examples.push(line_number);
+            }
+        }
+        report
+    }
+
+    /// Check whether every line is a well-formed example within the context limit
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.error_count == 0 && self.oversized_examples.is_empty()
+    }
+
+    /// Record a problem on a line, keeping only the first few
+    fn push_error(&mut self, line: usize, message: impl Into<String>) {
+        self.error_count += 1;
+        if self.errors.len() < MAX_REPORTED_ERRORS {
+            self.errors.push(TrainingLineError {
+                line,
+                message: message.into(),
+            });
+        }
+    }
+}
+
+/// Convert conversations into fine-tuning JSONL, one example per line
+///
+/// # Errors
+///
+/// Returns an error naming the first conversation that does not conform to
+/// the fine-tuning format.
+pub fn to_finetune_jsonl<T: AsRef<[TrainingTurn]>>(conversations: &[T]) -> Result<String> {
+    let mut jsonl = String::new();
+    for (index, conversation) in conversations.iter().enumerate() {
+        let example = TrainingExample::from_turns(conversation.as_ref())
+            .map_err(|e| OpenAIError::invalid_request(format!("Conversation {index}: {e}")))?;
+        jsonl.push_str(&serde_json::to_string(&example)?);
+        jsonl.push('\n');
+    }
+    Ok(jsonl)
+}
+
+/// Rough token estimate for text: 1 token per 4 characters
+fn text_tokens(text: &str) -> u32 {
+    u32::try_from(text.len().div_ceil(4)).unwrap_or(u32::MAX)
+}
+
+/// Training-format role for a message role
+fn role_name(role: &MessageRole) -> &'static str {
+    match role {
+        MessageRole::Developer | MessageRole::System => "system",
+        MessageRole::User => "user",
+        MessageRole::Assistant => "assistant",
+    }
+}
+
+/// Training-format content for message content
+fn content_value(content: &MessageContentInput) -> serde_json::Value {
+    match content {
+        MessageContentInput::Text(text) => serde_json::Value::String(text.clone()),
+        MessageContentInput::Array(parts) => parts.iter().map(content_part).collect(),
+    }
+}
+
+/// Chat Completions content part for a message content item
+fn content_part(part: &MessageContent) -> serde_json::Value {
+    match part {
+        MessageContent::Text { text } => serde_json::json!({"type": "text", "text": text}),
+        MessageContent::Image { image_url } => {
+            serde_json::json!({"type": "image_url", "image_url": image_url})
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fine_tuning_context_limit() {
+        assert_eq!(
+            fine_tuning_context_limit("gpt-4.1-2025-04-14"),
+            Some(65_536)
+        );
+        assert_eq!(fine_tuning_context_limit("gpt-4.1-mini"), Some(65_536));
+        assert_eq!(
+            fine_tuning_context_limit("gpt-4o-mini-2024-07-18"),
+            Some(65_536)
+        );
+        assert_eq!(
+            fine_tuning_context_limit("ft:gpt-3.5-turbo-0125:acme::abc123"),
+            Some(16_385)
+        );
+        assert_eq!(fine_tuning_context_limit("gpt-3.5-turbo-0613"), Some(4_096));
+        assert_eq!(fine_tuning_context_limit("gpt-4-turbo"), None);
+        assert_eq!(fine_tuning_context_limit("my-custom-model"), None);
+    }
+
+    fn call(id: &str, name: &str) -> TrainingTurn {
+        FunctionCall {
+            call_id: id.to_string(),
+            name: name.to_string(),
+            arguments: "{}".to_string(),
+        }
+        .into()
+    }
+
+    fn output(id: &str, output: &str) -> TrainingTurn {
+        FunctionCallOutput {
+            call_id: id.to_string(),
+            output: output.to_string(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_roles_and_tool_calls_are_mapped() {
+        let conversation = vec![
+            Message::developer("Be terse").into(),
+            Message::user("Weather and time in Paris?").into(),
+            call("call_1", "get_weather"),
+            call("call_2", "get_time"),
+            output("call_1", "Sunny"),
+            output("call_2", "14:05"),
+            Message::assistant("Sunny, 14:05.").into(),
+        ];
+
+        let jsonl = to_finetune_jsonl(&[conversation]).unwrap();
+        assert!(jsonl.ends_with('\n'));
+        let line: serde_json::Value = serde_json::from_str(jsonl.trim_end()).unwrap();
+        let messages = line["messages"].as_array().unwrap();
+
+        assert_eq!(messages.len(), 6);
+        assert_eq!(messages[0]["role"], "system");
+        assert_eq!(messages[2]["role"], "assistant");
+        assert!(messages[2].get("content").is_none());
+        assert_eq!(messages[2]["tool_calls"].as_array().unwrap().len(), 2);
+        assert_eq!(messages[2]["tool_calls"][1]["type"], "function");
+        assert_eq!(messages[2]["tool_calls"][1]["function"]["name"], "get_time");
+        assert_eq!(messages[4]["role"], "tool");
+        assert_eq!(messages[4]["tool_call_id"], "call_2");
+        assert_eq!(messages[4]["content"], "14:05");
+    }
+
+    #[test]
+    fn test_calls_attach_to_preceding_assistant_message() {
+        let example = TrainingExample::from_turns(&[
+            Message::user("Look it up").into(),
+            Message::assistant("Checking.").into(),
+            call("call_1", "lookup"),
+            output("call_1", "found"),
+            Message::assistant("Found it.").into(),
+        ])
+        .unwrap();
+
+        assert_eq!(example.messages.len(), 4);
+        assert_eq!(
+            example.messages[1].content,
+            Some(serde_json::json!("Checking."))
+        );
+        assert_eq!(example.messages[1].tool_calls.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_each_line_is_one_conversation() {
+        let conversations = vec![
+            vec![
+                Message::user("Hi").into(),
+                Message::assistant("Hello").into(),
+            ],
+            vec![
+                Message::user("Bye").into(),
+                Message::assistant("Goodbye").into(),
+            ],
+        ];
+        let jsonl = to_finetune_jsonl(&conversations).unwrap();
+        let examples: Vec<TrainingExample> = jsonl
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(
+            examples[1].messages[0].content,
+            Some(serde_json::json!("Bye"))
+        );
+    }
+
+    #[test]
+    fn test_non_conforming_conversations_are_rejected() {
+        let no_assistant = vec![Message::user("Hi").into()];
+        let err = to_finetune_jsonl(&[
+            vec![
+                Message::user("Hi").into(),
+                Message::assistant("Hello").into(),
+            ],
+            no_assistant,
+        ])
+        .unwrap_err();
+        assert!(err.to_string().contains("Conversation 1"));
+        assert!(err.to_string().contains("no assistant message"));
+
+        let no_user = [
+            Message::system("Rules").into(),
+            Message::assistant("Ok").into(),
+        ];
+        assert!(TrainingExample::from_turns(&no_user).is_err());
+
+        let orphan_output = [
+            Message::user("Hi").into(),
+            output("call_9", "late"),
+            Message::assistant("Hello").into(),
+        ];
+        let err = TrainingExample::from_turns(&orphan_output).unwrap_err();
+        assert!(err.to_string().contains("call_9"));
+    }
+
+    #[test]
+    fn test_multimodal_content_uses_chat_completions_parts() {
+        let example = TrainingExample::from_turns(&[
+            Message::user_with_content(vec![
+                MessageContent::text("What is shown?"),
+                MessageContent::image_url("https://example.com/cat.png"),
+            ])
+            .into(),
+            Message::assistant("A cat.").into(),
+        ])
+        .unwrap();
+        assert_eq!(
+            example.messages[0].content,
+            Some(serde_json::json!([
+                {"type": "text", "text": "What is shown?"},
+                {"type": "image_url", "image_url": {"url": "https://example.com/cat.png"}}
+            ]))
+        );
+
+        let responses_style = r#"{"messages":[{"role":"user","content":[{"type":"input_text","text":"Hi"}]},{"role":"assistant","content":"Hello"}]}"#;
+        let report = TrainingFileReport::from_jsonl(responses_style, None);
+        assert_eq!(report.error_count, 1);
+        assert!(report.errors[0].message.contains("`input_text`"));
+    }
+
+    #[test]
+    fn test_training_file_report_counts_and_estimates() {
+        let jsonl = to_finetune_jsonl(&[
+            vec![
+                Message::user("Hi").into(),
+                Message::assistant("Hello").into(),
+            ],
+            vec![
+                Message::user("Summarize").into(),
+                Message::assistant("x".repeat(400)).into(),
+            ],
+        ])
+        .unwrap();
+
+        let report = TrainingFileReport::from_jsonl(&jsonl, Some(50));
+        assert_eq!(report.total_examples, 2);
+        assert_eq!(report.valid_examples, 2);
+        assert_eq!(report.error_count, 0);
+        assert_eq!(report.example_tokens[0].estimated_tokens, 3);
+        assert_eq!(report.example_tokens[1].line, 2);
+        assert_eq!(report.total_tokens, 3 + 103);
+        assert_eq!(report.oversized_examples, vec![2]);
+        assert!(!report.is_valid());
+        assert!(TrainingFileReport::from_jsonl(&jsonl, None).is_valid());
+    }
+
+    #[test]
+    fn test_training_file_report_errors_with_line_numbers() {
+        let valid = r#"{"messages":[{"role":"user","content":"Hi"},{"role":"assistant","content":"Hello"}]}"#;
+        let content = [
+            valid,
+            "not json",
+            r#"{"messages":[{"role":"user","content":"Hi"}]}"#,
+            "",
+            r#"{"messages":[{"role":"narrator","content":"Hi"},{"role":"assistant","content":"Hello"}]}"#,
+        ]
+        .join("\n");
+
+        let report = TrainingFileReport::from_jsonl(&content, None);
+        assert_eq!(report.total_examples, 4);
+        assert_eq!(report.valid_examples, 1);
+        let lines: Vec<usize> = report.errors.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![2, 3, 4, 5]);
+        assert!(
+            report.errors[0]
+                .message
+                .starts_with("Invalid training example")
+        );
+        assert!(report.errors[1].message.contains("no assistant message"));
+        assert!(report.errors[3].message.contains("narrator"));
+    }
+
+    #[test]
+    fn test_training_file_report_keeps_first_errors() {
+        let content = vec!["not json"; MAX_REPORTED_ERRORS + 5].join("\n");
+        let report = TrainingFileReport::from_jsonl(&content, None);
+        assert_eq!(report.error_count, MAX_REPORTED_ERRORS + 5);
+        assert_eq!(report.errors.len(), MAX_REPORTED_ERRORS);
+        assert_eq!(report.errors.last().unwrap().line, MAX_REPORTED_ERRORS);
+    }
+}
@@ -66,12 +66,10 @@ pub mod utils {
             return String::new();
         }
 
-        let query_parts: Vec<String> = params
-            .iter()
-            .map(|(key, value)| format!("{key}={value}"))
-            .collect();
-
-        format!("?{}", query_parts.join("&"))
+        let query = ::url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(params)
+            .finish();
+        format!("?{query}")
     }
 
     /// Validate limit parameter (must be between 1 and 100)
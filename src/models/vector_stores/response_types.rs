@@ -136,6 +136,7 @@ pub struct VectorStoreDeleteResponse {
 }
 
 crate::impl_response_constructors!(VectorStoreDeleteResponse, id, "vector_store.deleted");
+crate::impl_delete_response!(VectorStoreDeleteResponse, "vector_store.deleted");
 
 #[cfg(test)]
 mod tests {
@@ -3,6 +3,7 @@
 //! This module contains the main VectorStore type and its associated
 //! request types and builder patterns.
 
+use crate::models::field_update::FieldUpdate;
 use crate::models::vector_stores::common_types::{
     ChunkingStrategy, ExpirationPolicy, FileCounts, FileIdBuilder, MetadataBuilder, StatusChecker,
 };
@@ -38,7 +39,7 @@ pub struct VectorStore {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_active_at: Option<u64>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(default)]
+    #[serde(default, serialize_with = "crate::helpers::sorted_map::serialize")]
     pub metadata: HashMap<String, String>,
 }
 
@@ -74,6 +75,146 @@ impl VectorStore {
     }
 }
 
+/// Request to modify an existing vector store
+///
+/// Unchanged fields are left out of the body, so the service keeps their
+/// current values; [`FieldUpdate::Clear`] sends `null` to remove the name or
+/// the expiration policy. Build one with [`VectorStore::to_modify_request`] to
+/// send only the fields that changed.
+#[derive(Debug, Clone, Default, Ser, De)]
+pub struct ModifyVectorStoreRequest {
+    /// The name of the vector store
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub name: FieldUpdate<String>,
+    /// The expiration policy for the vector store
+    #[serde(default, skip_serializing_if = "FieldUpdate::is_unchanged")]
+    pub expires_after: FieldUpdate<ExpirationPolicy>,
+    /// The full metadata map, replacing the current metadata
+    #[serde(
+        default,
+        skip_serializing_if = "FieldUpdate::is_unchanged",
+        serialize_with = "crate::helpers::sorted_map::serialize_update"
+    )]
+    pub metadata: FieldUpdate<HashMap<String, String>>,
+}
+
+impl ModifyVectorStoreRequest {
+    /// Check whether the request leaves every field unchanged
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.name.is_unchanged()
+            && self.expires_after.is_unchanged()
+            && self.metadata.is_unchanged()
+    }
+}
+
+impl VectorStore {
+    /// Start a modify request seeded with this vector store's current values
+    ///
+    /// Changes made through the builder apply on top of the current values,
+    /// so `add_metadata` keeps the other metadata entries. Only fields that
+    /// end up different from the current values are sent.
+    #[must_use]
+    pub fn to_modify_request(&self) -> ModifyVectorStoreRequestBuilder {
+        ModifyVectorStoreRequestBuilder {
+            current: self.clone(),
+            metadata: None,
+            updated: ModifyVectorStoreRequest::default(),
+        }
+    }
+}
+
+/// Builder for [`ModifyVectorStoreRequest`] seeded from an existing vector store
+#[derive(Debug, Clone)]
+pub struct ModifyVectorStoreRequestBuilder {
+    /// The vector store the builder was seeded from
+    current: VectorStore,
+    /// The edited metadata, or `None` while it is untouched
+    metadata: Option<HashMap<String, String>>,
+    /// Values set through the builder
+    updated: ModifyVectorStoreRequest,
+}
+
+impl ModifyVectorStoreRequestBuilder {
+    /// Set the name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.updated.name = FieldUpdate::Set(name.into());
+        self
+    }
+
+    /// Clear the name
+    #[must_use]
+    pub fn clear_name(mut self) -> Self {
+        self.updated.name = FieldUpdate::Clear;
+        self
+    }
+
+    /// Set the expiration policy
+    #[must_use]
+    pub fn expires_after(mut self, expires_after: ExpirationPolicy) -> Self {
+        self.updated.expires_after = FieldUpdate::Set(expires_after);
+        self
+    }
+
+    /// Remove the expiration policy so the vector store never expires
+    #[must_use]
+    pub fn clear_expires_after(mut self) -> Self {
+        self.updated.expires_after = FieldUpdate::Clear;
+        self
+    }
+
+    /// Add or overwrite one metadata entry, keeping the others
+    pub fn add_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata_mut().insert(key.into(), value.into());
+        self
+    }
+
+    /// Remove one metadata entry, keeping the others
+    pub fn remove_metadata(mut self, key: &str) -> Self {
+        self.metadata_mut().remove(key);
+        self
+    }
+
+    /// Replace all metadata
+    #[must_use]
+    pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Remove all metadata
+    ///
+    /// Entries added afterwards start from an empty map.
+    #[must_use]
+    pub fn clear_metadata(mut self) -> Self {
+        self.metadata = Some(HashMap::new());
+        self
+    }
+
+    /// Build a request holding only the fields that differ from the current values
+    #[must_use]
+    pub fn build(self) -> ModifyVectorStoreRequest {
+        let Self {
+            current,
+            metadata,
+            updated,
+        } = self;
+        ModifyVectorStoreRequest {
+            name: updated.name.against(current.name.as_ref()),
+            expires_after: updated
+                .expires_after
+                .against(current.expires_after.as_ref()),
+            metadata: FieldUpdate::from_edited_map(metadata, &current.metadata),
+        }
+    }
+
+    /// The metadata being edited, starting from the current metadata
+    fn metadata_mut(&mut self) -> &mut HashMap<String, String> {
+        let current = &self.current.metadata;
+        self.metadata.get_or_insert_with(|| current.clone())
+    }
+}
+
 /// Request to create or modify a vector store
 #[derive(Debug, Clone, Ser, De, Default)]
 pub struct VectorStoreRequest {
@@ -90,7 +231,10 @@ pub struct VectorStoreRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chunking_strategy: Option<ChunkingStrategy>,
     /// Set of 16 key-value pairs that can be attached to an object
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "crate::helpers::sorted_map::serialize_option"
+    )]
     pub metadata: Option<HashMap<String, String>>,
 }
 
@@ -371,4 +515,71 @@ mod tests {
         assert_eq!(deserialized.name, store.name);
         assert_eq!(deserialized.status, store.status);
     }
+
+    fn sample_store() -> VectorStore {
+        serde_json::from_value(serde_json::json!({
+            "id": "vs_123",
+            "object": "vector_store",
+            "created_at": 1_700_000_000,
+            "name": "Docs",
+            "usage_bytes": 0,
+            "file_counts": {
+                "in_progress": 0,
+                "completed": 1,
+                "failed": 0,
+                "cancelled": 0,
+                "total": 1
+            },
+            "status": "completed",
+            "metadata": {"env": "prod", "owner": "search"}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_vector_store_to_modify_request() {
+        let request = sample_store()
+            .to_modify_request()
+            .name("Docs")
+            .add_metadata("env", "staging")
+            .build();
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"metadata": {"env": "staging", "owner": "search"}})
+        );
+
+        let unchanged = sample_store().to_modify_request().name("Docs").build();
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_vector_store_modify_request_clears_fields() {
+        let mut store = sample_store();
+        store.expires_after = Some(ExpirationPolicy::new_days(7));
+
+        let request = store
+            .to_modify_request()
+            .clear_name()
+            .clear_expires_after()
+            .clear_metadata()
+            .build();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({"name": null, "expires_after": null, "metadata": null})
+        );
+
+        // Removing every metadata entry clears the metadata
+        let request = store
+            .to_modify_request()
+            .remove_metadata("env")
+            .remove_metadata("owner")
+            .build();
+        assert!(request.metadata.is_clear());
+
+        // Clearing an expiration policy that is not set sends nothing
+        store.expires_after = None;
+        let request = store.to_modify_request().clear_expires_after().build();
+        assert!(request.is_empty());
+    }
 }
@@ -368,6 +368,108 @@ impl SchemaBuilder {
         }
     }
 
+    /// Merge another builder into this one
+    ///
+    /// Properties and definitions are unioned, and required fields are
+    /// concatenated with duplicates removed. When both builders set the same
+    /// property, definition or scalar attribute (format, bounds, description,
+    /// enum values, items and so on), the value from `other` wins.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if both builders set different schema types.
+    pub fn merge(mut self, other: SchemaBuilder) -> Result<Self> {
+        let SchemaBuilder {
+            schema_type,
+            properties,
+            items,
+            required,
+            enum_values,
+            any_of,
+            pattern,
+            format,
+            minimum,
+            maximum,
+            min_length,
+            max_length,
+            min_items,
+            max_items,
+            additional_properties,
+            description,
+            title,
+            default,
+            definitions,
+        } = other;
+
+        if let (Some(ours), Some(theirs)) = (&self.schema_type, &schema_type)
+            && ours != theirs
+        {
+            return Err(OpenAIError::invalid_request(format!(
+                "Cannot merge a '{ours}' schema with a '{theirs}' schema"
+            )));
+        }
+
+        self.properties = merge_maps(self.properties, properties);
+        self.definitions = merge_maps(self.definitions, definitions);
+        if let Some(fields) = required {
+            let merged = self.required.get_or_insert_with(Vec::new);
+            for field in fields {
+                if !merged.contains(&field) {
+                    merged.push(field);
+                }
+            }
+        }
+
+        self.schema_type = schema_type.or(self.schema_type);
+        self.items = items.or(self.items);
+        self.enum_values = enum_values.or(self.enum_values);
+        self.any_of = any_of.or(self.any_of);
+        self.pattern = pattern.or(self.pattern);
+        self.format = format.or(self.format);
+        self.minimum = minimum.or(self.minimum);
+        self.maximum = maximum.or(self.maximum);
+        self.min_length = min_length.or(self.min_length);
+        self.max_length = max_length.or(self.max_length);
+        self.min_items = min_items.or(self.min_items);
+        self.max_items = max_items.or(self.max_items);
+        self.additional_properties = additional_properties.or(self.additional_properties);
+        self.description = description.or(self.description);
+        self.title = title.or(self.title);
+        self.default = default.or(self.default);
+
+        Ok(self)
+    }
+
+    /// Create an `anyOf` schema for an internally tagged union
+    ///
+    /// Each variant becomes an object whose `tag_field` is a required string
+    /// pinned to its tag value with `const`, merged with the variant's own
+    /// properties. This matches serde's `#[serde(tag = "...")]` enums.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variant is not an object schema.
+    pub fn tagged_union(tag_field: &str, variants: &[(&str, SchemaBuilder)]) -> Result<Self> {
+        let variants = variants
+            .iter()
+            .map(|(tag_value, variant)| {
+                let tag_schema = json!({ "type": "string", "const": tag_value });
+                let schema = Self::object()
+                    .property_value(tag_field, tag_schema.clone())
+                    .required(&[tag_field])
+                    .merge(variant.clone())
+                    .map_err(|e| {
+                        OpenAIError::invalid_request(format!(
+                            "Invalid variant '{tag_value}' in tagged union: {e}"
+                        ))
+                    })?;
+                Ok(schema.property_value(tag_field, tag_schema).build().schema)
+            })
+            .collect::<Result<Vec<Value>>>()?;
+
+        Ok(Self::new().any_of_values(&variants))
+    }
+
     /// Build the final JSON schema
     #[must_use]
     pub fn build(self) -> JsonSchema {
@@ -487,6 +589,33 @@ impl SchemaBuilder {
     }
 }
 
+/// Union two optional maps, letting entries from `later` replace earlier ones
+fn merge_maps(
+    earlier: Option<IndexMap<String, Value>>,
+    later: Option<IndexMap<String, Value>>,
+) -> Option<IndexMap<String, Value>> {
+    match (earlier, later) {
+        (Some(mut earlier), Some(later)) => {
+            earlier.extend(later);
+            Some(earlier)
+        }
+        (earlier, later) => later.or(earlier),
+    }
+}
+
+/// Types that describe their own JSON schema for structured outputs
+///
+/// Implementing this lets
+/// [`ResponsesApi::create_typed_with_schema`](crate::api::responses::ResponsesApi::create_typed_with_schema)
+/// attach the schema to a request automatically.
+pub trait StructuredOutput: serde::de::DeserializeOwned {
+    /// Name sent alongside the schema in the response format
+    fn schema_name() -> &'static str;
+
+    /// Schema the model output must conform to
+    fn schema() -> SchemaBuilder;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -507,6 +636,103 @@ mod tests {
         assert!(schema.validate(&valid_data).is_ok());
     }
 
+    #[test]
+    fn test_merge_object_fragments() {
+        let address = SchemaBuilder::object()
+            .property("street", SchemaBuilder::string())
+            .property("city", SchemaBuilder::string())
+            .required(&["street", "city"])
+            .definition("Country", SchemaBuilder::string());
+        let contact = SchemaBuilder::object()
+            .property("email", SchemaBuilder::string().format("email"))
+            .property("city", SchemaBuilder::string().min_length(2))
+            .required(&["email", "city"])
+            .description("Contact details")
+            .additional_properties(false);
+
+        let merged = address.merge(contact).unwrap().build().to_value();
+        let properties = merged["properties"].as_object().unwrap();
+        assert_eq!(properties.len(), 3);
+        assert_eq!(merged["properties"]["city"]["minLength"], 2);
+        assert_eq!(merged["required"], json!(["street", "city", "email"]));
+        assert_eq!(merged["definitions"]["Country"]["type"], "string");
+        assert_eq!(merged["description"], "Contact details");
+        assert_eq!(merged["additionalProperties"], false);
+        assert_eq!(merged["type"], "object");
+    }
+
+    #[test]
+    fn test_merge_later_scalar_wins_and_type_mismatch_errors() {
+        let merged = SchemaBuilder::string()
+            .max_length(10)
+            .description("first")
+            .merge(SchemaBuilder::new().max_length(5).description("second"))
+            .unwrap()
+            .build()
+            .to_value();
+        assert_eq!(merged["type"], "string");
+        assert_eq!(merged["maxLength"], 5);
+        assert_eq!(merged["description"], "second");
+
+        let err = SchemaBuilder::object()
+            .merge(SchemaBuilder::array())
+            .unwrap_err();
+        assert!(err.to_string().contains("'object'"));
+    }
+
+    #[test]
+    fn test_tagged_union() {
+        let schema = SchemaBuilder::tagged_union(
+            "kind",
+            &[
+                (
+                    "circle",
+                    SchemaBuilder::object()
+                        .property("radius", SchemaBuilder::number())
+                        .required(&["radius"]),
+                ),
+                (
+                    "square",
+                    SchemaBuilder::object()
+                        .property("kind", SchemaBuilder::string())
+                        .property("side", SchemaBuilder::number())
+                        .required(&["side"]),
+                ),
+            ],
+        )
+        .unwrap()
+        .build();
+
+        let value = schema.to_value();
+        assert_eq!(value["anyOf"].as_array().unwrap().len(), 2);
+        assert_eq!(value["anyOf"][0]["required"], json!(["kind", "radius"]));
+        assert_eq!(value["anyOf"][1]["properties"]["kind"]["const"], "square");
+
+        assert!(
+            schema
+                .validate(&json!({"kind": "circle", "radius": 1.5}))
+                .is_ok()
+        );
+        assert!(
+            schema
+                .validate(&json!({"kind": "square", "side": 2}))
+                .is_ok()
+        );
+        assert!(
+            schema
+                .validate(&json!({"kind": "circle", "side": 2}))
+                .is_err()
+        );
+        assert!(schema.validate(&json!({"radius": 1.5})).is_err());
+    }
+
+    #[test]
+    fn test_tagged_union_rejects_non_object_variant() {
+        let err =
+            SchemaBuilder::tagged_union("kind", &[("text", SchemaBuilder::string())]).unwrap_err();
+        assert!(err.to_string().contains("'text'"));
+    }
+
     #[test]
     fn test_array_schema() {
         let schema = SchemaBuilder::array()
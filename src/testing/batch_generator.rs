@@ -31,6 +31,7 @@ use std::io::{BufWriter, Write};
 use std::path::Path;
 
 use crate::testing::prompts::PromptsRegistry;
+use crate::testing::suite_spec::BatchSuiteSpec;
 
 /// A single batch job request for the `OpenAI` Batch API
 #[derive(Debug, Clone, Ser, De)]
@@ -67,6 +68,37 @@ pub struct ChatMessage {
     pub content: String,
 }
 
+/// Summary of a generated batch job file
+#[derive(Debug, Clone, Ser, De)]
+pub struct BatchGenerationSummary {
+    /// Name of the test suite that was generated
+    pub suite_name: String,
+    /// Path of the written JSONL file
+    pub output_file: String,
+    /// Model used for every request in the file
+    pub model: String,
+    /// Number of requests written to the file
+    pub request_count: usize,
+    /// Size of the written file in bytes
+    #[serde(default)]
+    pub bytes_written: u64,
+}
+
+/// Progress report passed to the callback of
+/// [`BatchJobGenerator::generate_test_suite_with_progress`]
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct BatchGenerationProgress<'a> {
+    /// Number of requests written so far, including the current one
+    pub completed: usize,
+    /// Total number of requests in the suite
+    pub total: usize,
+    /// Custom ID of the request that was just written
+    pub custom_id: &'a str,
+    /// Bytes written to the file so far
+    pub bytes_written: u64,
+}
+
 /// Generator for `OpenAI` Batch API jobs focused on YARA rule creation
 ///
 /// The generator creates properly formatted batch job files that can be
@@ -144,9 +176,145 @@ impl BatchJobGenerator {
     /// ```
     #[allow(dead_code)]
     pub fn generate_test_suite(&self, output_path: &Path, suite_name: &str) -> Result<()> {
+        self.generate_test_suite_with_summary(output_path, suite_name)
+            .map(|_| ())
+    }
+
+    /// Generates a batch job file and returns a summary of what was written
+    ///
+    /// Behaves like [`generate_test_suite`](Self::generate_test_suite) but
+    /// reports the suite, output path, model, request count and file size so
+    /// callers can surface them (for example as JSON in CI pipelines).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::BatchJobGenerator;
+    ///
+    /// let output = std::env::temp_dir().join("basic_batch.jsonl");
+    /// let generator = BatchJobGenerator::new(None);
+    /// let summary = generator.generate_test_suite_with_summary(&output, "basic")?;
+    /// assert_eq!(summary.request_count, 3);
+    /// # std::fs::remove_file(&output)?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[allow(dead_code)]
+    pub fn generate_test_suite_with_summary(
+        &self,
+        output_path: &Path,
+        suite_name: &str,
+    ) -> Result<BatchGenerationSummary> {
+        self.generate_test_suite_with_progress(output_path, suite_name, |_| {})
+    }
+
+    /// Generates a batch job file, reporting progress after each written request
+    ///
+    /// The callback receives the number of requests written so far, the suite
+    /// total, the custom ID of the request just written and the bytes written,
+    /// which is enough to drive a progress bar for large suites.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::BatchJobGenerator;
+    ///
+    /// let output = std::env::temp_dir().join("progress_batch.jsonl");
+    /// let generator = BatchJobGenerator::new(None);
+    /// let summary = generator.generate_test_suite_with_progress(
+    ///     &output,
+    ///     "basic",
+    ///     |progress| println!("{}/{} {}", progress.completed, progress.total, progress.custom_id),
+    /// )?;
+    /// assert!(summary.bytes_written > 0);
+    /// # std::fs::remove_file(&output)?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    pub fn generate_test_suite_with_progress<F>(
+        &self,
+        output_path: &Path,
+        suite_name: &str,
+        progress: F,
+    ) -> Result<BatchGenerationSummary>
+    where
+        F: FnMut(&BatchGenerationProgress<'_>),
+    {
         let prompts = Self::get_test_suite_prompts(suite_name)?;
         let requests = self.create_batch_requests(suite_name, &prompts);
-        Self::write_requests_to_file(output_path, requests)
+        let request_count = requests.len();
+        let bytes_written = Self::write_requests_to_file(output_path, &requests, progress)?;
+
+        Ok(BatchGenerationSummary {
+            suite_name: suite_name.to_string(),
+            output_file: output_path.display().to_string(),
+            model: self.model.clone(),
+            request_count,
+            bytes_written,
+        })
+    }
+
+    /// Generates a batch job file from a user-defined suite specification
+    ///
+    /// Reads a JSON [`BatchSuiteSpec`] so custom suites can be generated
+    /// without editing the built-in prompt registry. Each test becomes one
+    /// request with the custom ID `{suite}_{test id}`; settings left out of
+    /// the spec fall back to this generator's model and system prompt.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the spec cannot be read or is invalid, with the
+    /// offending test and line, or if the output file cannot be written.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::BatchJobGenerator;
+    ///
+    /// let spec = std::env::temp_dir().join("custom_suite.json");
+    /// let output = std::env::temp_dir().join("custom_batch.jsonl");
+    /// std::fs::write(
+    ///     &spec,
+    ///     r#"{"name": "custom", "tests": [{"id": "upx", "prompt": "Detect UPX packed files"}]}"#,
+    /// )?;
+    /// let generator = BatchJobGenerator::new(None);
+    /// let summary = generator.generate_from_spec(&spec, &output)?;
+    /// assert_eq!(summary.request_count, 1);
+    /// # std::fs::remove_file(&spec)?;
+    /// # std::fs::remove_file(&output)?;
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[allow(dead_code)]
+    pub fn generate_from_spec(
+        &self,
+        spec_path: &Path,
+        output_path: &Path,
+    ) -> Result<BatchGenerationSummary> {
+        let spec = BatchSuiteSpec::load(spec_path)?;
+        let model = spec.model.clone().unwrap_or_else(|| self.model.clone());
+        let system_prompt = spec.system_prompt.as_deref().unwrap_or(&self.system_prompt);
+
+        let requests: Vec<BatchJobRequest> = spec
+            .tests
+            .iter()
+            .map(|test| {
+                Self::build_request(
+                    spec.custom_id(test),
+                    test.model.clone().unwrap_or_else(|| model.clone()),
+                    system_prompt,
+                    &test.prompt,
+                    Some(spec.max_tokens.unwrap_or(1000)),
+                    Some(spec.temperature.unwrap_or(0.3)),
+                )
+            })
+            .collect();
+        let bytes_written = Self::write_requests_to_file(output_path, &requests, |_| {})?;
+
+        Ok(BatchGenerationSummary {
+            suite_name: spec.name,
+            output_file: output_path.display().to_string(),
+            model,
+            request_count: requests.len(),
+            bytes_written,
+        })
     }
 
     /// Gets the prompts for a specific test suite using the prompts registry
@@ -171,41 +339,75 @@ impl BatchJobGenerator {
         suite_name: &str,
         index: usize,
         prompt: &str,
+    ) -> BatchJobRequest {
+        Self::build_request(
+            format!("{suite_name}_{index:03}"),
+            self.model.clone(),
+            &self.system_prompt,
+            prompt,
+            Some(1000),
+            Some(0.3),
+        )
+    }
+
+    /// Builds a chat completion batch request with a system and user message
+    fn build_request(
+        custom_id: String,
+        model: String,
+        system_prompt: &str,
+        prompt: &str,
+        max_tokens: Option<u32>,
+        temperature: Option<f64>,
     ) -> BatchJobRequest {
         BatchJobRequest {
-            custom_id: format!("{suite_name}_{index:03}"),
+            custom_id,
             method: "POST".to_string(),
             url: "/v1/chat/completions".to_string(),
             body: BatchJobBody {
-                model: self.model.clone(),
+                model,
                 messages: vec![
                     ChatMessage {
                         role: "system".to_string(),
-                        content: self.system_prompt.clone(),
+                        content: system_prompt.to_string(),
                     },
                     ChatMessage {
                         role: "user".to_string(),
                         content: prompt.to_string(),
                     },
                 ],
-                max_tokens: Some(1000),
-                temperature: Some(0.3),
+                max_tokens,
+                temperature,
             },
         }
     }
 
-    /// Writes batch job requests to a JSONL file
-    fn write_requests_to_file(output_path: &Path, requests: Vec<BatchJobRequest>) -> Result<()> {
+    /// Writes batch job requests to a JSONL file and returns the bytes written
+    fn write_requests_to_file<F>(
+        output_path: &Path,
+        requests: &[BatchJobRequest],
+        mut progress: F,
+    ) -> Result<u64>
+    where
+        F: FnMut(&BatchGenerationProgress<'_>),
+    {
         let file = File::create(output_path)?;
         let mut writer = BufWriter::new(file);
+        let mut bytes_written = 0u64;
 
-        for request in requests {
-            let json_line = serde_json::to_string(&request)?;
+        for (index, request) in requests.iter().enumerate() {
+            let json_line = serde_json::to_string(request)?;
             writeln!(writer, "{json_line}")?;
+            bytes_written += json_line.len() as u64 + 1;
+            progress(&BatchGenerationProgress {
+                completed: index + 1,
+                total: requests.len(),
+                custom_id: &request.custom_id,
+                bytes_written,
+            });
         }
 
         writer.flush()?;
-        Ok(())
+        Ok(bytes_written)
     }
 }
 
@@ -323,6 +525,49 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_generate_test_suite_with_summary() {
+        let generator = BatchJobGenerator::new(Some("gpt-4o".to_string()));
+        let temp_file = NamedTempFile::new().unwrap();
+        let path = temp_file.path();
+
+        let summary = generator
+            .generate_test_suite_with_summary(path, "malware")
+            .unwrap();
+
+        assert_eq!(summary.suite_name, "malware");
+        assert_eq!(summary.model, "gpt-4o");
+        assert_eq!(summary.output_file, path.display().to_string());
+        let content = std::fs::read_to_string(path).unwrap();
+        assert_eq!(summary.request_count, content.lines().count());
+        assert_eq!(summary.bytes_written, content.len() as u64);
+    }
+
+    #[test]
+    fn test_generate_test_suite_with_progress() {
+        let (generator, temp_file) = setup_test();
+        let mut reports = Vec::new();
+
+        let summary = generator
+            .generate_test_suite_with_progress(temp_file.path(), "basic", |progress| {
+                reports.push((
+                    progress.completed,
+                    progress.total,
+                    progress.custom_id.to_string(),
+                    progress.bytes_written,
+                ));
+            })
+            .unwrap();
+
+        assert_eq!(reports.len(), summary.request_count);
+        assert_eq!(reports[0].0, 1);
+        assert_eq!(reports[0].1, summary.request_count);
+        assert_eq!(reports[0].2, "basic_001");
+        assert_eq!(reports.last().unwrap().3, summary.bytes_written);
+        let file_len = std::fs::metadata(temp_file.path()).unwrap().len();
+        assert_eq!(summary.bytes_written, file_len);
+    }
+
     #[test]
     fn test_batch_job_request_structure() {
         let content = generate_and_read("basic");
@@ -468,4 +713,54 @@ mod tests {
         let result = generator.generate_test_suite(invalid_path, "basic");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_generate_from_spec() {
+        let (generator, output) = setup_test();
+        let mut spec = NamedTempFile::new().unwrap();
+        write!(
+            spec,
+            r#"{{
+  "name": "packers",
+  "system_prompt": "Only YARA.",
+  "temperature": 0.0,
+  "tests": [
+    {{"id": "upx", "prompt": "Detect UPX", "expected": {{"rule_name": "upx"}}}},
+    {{"id": "mpress", "prompt": "Detect MPRESS", "model": "gpt-4o-mini"}}
+  ]
+}}"#
+        )
+        .unwrap();
+
+        let summary = generator
+            .generate_from_spec(spec.path(), output.path())
+            .unwrap();
+        assert_eq!(summary.suite_name, "packers");
+        assert_eq!(summary.model, "gpt-4");
+        assert_eq!(summary.request_count, 2);
+
+        let content = std::fs::read_to_string(output.path()).unwrap();
+        let requests: Vec<BatchJobRequest> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(requests[0].custom_id, "packers_upx");
+        assert_eq!(requests[0].body.model, "gpt-4");
+        assert_eq!(requests[0].body.messages[0].content, "Only YARA.");
+        assert_eq!(requests[0].body.temperature, Some(0.0));
+        assert_eq!(requests[1].body.model, "gpt-4o-mini");
+        assert!(!content.contains("rule_name"));
+    }
+
+    #[test]
+    fn test_generate_from_invalid_spec() {
+        let (generator, output) = setup_test();
+        let mut spec = NamedTempFile::new().unwrap();
+        write!(spec, r#"{{"name": "empty", "tests": []}}"#).unwrap();
+
+        let err = generator
+            .generate_from_spec(spec.path(), output.path())
+            .unwrap_err();
+        assert!(format!("{err:#}").contains("Suite 'empty' has no tests"));
+    }
 }
@@ -0,0 +1,362 @@
+//! # YARA Directory Validation
+//!
+//! Validates every `.yar` and `.yara` file in a directory, compiling files on
+//! a bounded pool of worker threads, and aggregates the outcome into a
+//! [`DirectoryValidationReport`] suitable for gating CI on a rule repository.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use openai_rust_sdk::testing::YaraValidator;
+//!
+//! let dir = std::env::temp_dir().join(format!("yara-rules-{}", std::process::id()));
+//! std::fs::create_dir_all(&dir)?;
+//! std::fs::write(dir.join("ok.yar"), "rule ok { condition: true }")?;
+//! std::fs::write(dir.join("broken.yara"), "rule broken {")?;
+//!
+//! let report = YaraValidator::new().validate_directory(&dir, false)?;
+//! assert_eq!((report.passed_files, report.failed_files), (1, 1));
+//! assert!(!report.all_passed());
+//! # std::fs::remove_dir_all(&dir)?;
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use crate::testing::yara_scanner::{ScanOptions, collect_files};
+use crate::testing::yara_validator::{ValidationResult, YaraValidator};
+use anyhow::Result;
+use serde::{Deserialize as De, Serialize as Ser};
+use std::any::Any;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// File extensions treated as YARA rule files
+const RULE_EXTENSIONS: [&str; 2] = ["yar", "yara"];
+
+/// Maximum number of files compiled at the same time
+const MAX_WORKERS: usize = 8;
+
+/// Number of slowest-compiling rules listed in the report
+const SLOWEST_RULES: usize = 5;
+
+/// Validation outcome of a single rule file
+#[derive(Debug, Clone, Ser, De)]
+pub struct FileValidationResult {
+    /// Path of the rule file
+    pub path: String,
+    /// Validation result, absent if the file could not be read or validated
+    pub result: Option<ValidationResult>,
+    /// Error message if the file could not be read or validation panicked
+    pub error: Option<String>,
+}
+
+impl FileValidationResult {
+    /// Returns true if the file was read and its rules compiled
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.result.as_ref().is_some_and(|result| result.is_valid)
+    }
+}
+
+/// A rule file and how long it took to compile
+#[derive(Debug, Clone, Ser, De)]
+pub struct SlowRule {
+    /// Path of the rule file
+    pub path: String,
+    /// Name of the first rule in the file
+    pub rule_name: Option<String>,
+    /// Time taken to compile the file in microseconds
+    pub compilation_time_us: u64,
+}
+
+/// Aggregated results of validating a directory of rule files
+#[derive(Debug, Clone, Ser, De)]
+pub struct DirectoryValidationReport {
+    /// Number of rule files found
+    pub total_files: usize,
+    /// Number of files whose rules compiled
+    pub passed_files: usize,
+    /// Number of files that failed to compile or could not be read
+    pub failed_files: usize,
+    /// Per-file results in sorted path order
+    pub files: Vec<FileValidationResult>,
+    /// The slowest-compiling files, slowest first
+    pub slowest_rules: Vec<SlowRule>,
+}
+
+impl DirectoryValidationReport {
+    /// Returns true if every rule file passed
+    #[must_use]
+    pub fn all_passed(&self) -> bool {
+        self.failed_files == 0
+    }
+
+    /// Builds the report from per-file results
+    fn from_files(files: Vec<FileValidationResult>) -> Self {
+        let total_files = files.len();
+        let passed_files = files.iter().filter(|file| file.passed()).count();
+
+        let mut slowest_rules: Vec<SlowRule> = files
+            .iter()
+            .filter_map(|file| {
+                let result = file.result.as_ref().filter(|result| result.is_valid)?;
+                Some(SlowRule {
+                    path: file.path.clone(),
+                    rule_name: result.rule_name.clone(),
+                    compilation_time_us: result.metrics.compilation_time_us,
+                })
+            })
+            .collect();
+        slowest_rules.sort_by_key(|rule| std::cmp::Reverse(rule.compilation_time_us));
+        slowest_rules.truncate(SLOWEST_RULES);
+
+        Self {
+            total_files,
+            passed_files,
+            failed_files: total_files - passed_files,
+            files,
+            slowest_rules,
+        }
+    }
+}
+
+impl YaraValidator {
+    /// Validates every `.yar` and `.yara` file in a directory
+    ///
+    /// Files are found in sorted order, descending into subdirectories only
+    /// if `recursive` is set, and compiled on up to eight worker threads.
+    /// A file that cannot be read, or whose validation panics, is reported
+    /// as failed rather than aborting the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or one of its subdirectories cannot
+    /// be read, or if validation itself fails.
+    pub fn validate_directory(
+        &self,
+        dir: &Path,
+        recursive: bool,
+    ) -> Result<DirectoryValidationReport> {
+        let mut paths = Vec::new();
+        collect_files(
+            dir,
+            &ScanOptions::new().with_recursive(recursive),
+            &mut paths,
+        )?;
+        paths.retain(|path| is_rule_file(path));
+
+        let next = AtomicUsize::new(0);
+        let workers = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .clamp(1, MAX_WORKERS)
+            .min(paths.len());
+
+        let (outcomes, worker_panic) = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..workers)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut outcomes = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(path) = paths.get(index) else {
+                                break outcomes;
+                            };
+                            outcomes.push((index, self.validate_file(path)));
+                        }
+                    })
+                })
+                .collect();
+
+            let mut outcomes = Vec::new();
+            let mut worker_panic = None;
+            for handle in handles {
+                match handle.join() {
+                    Ok(worker_outcomes) => outcomes.extend(worker_outcomes),
+                    Err(payload) => worker_panic = Some(panic_message(payload.as_ref())),
+                }
+            }
+            (outcomes, worker_panic)
+        });
+
+        let files = order_outcomes(&paths, outcomes, worker_panic.as_deref())?;
+        Ok(DirectoryValidationReport::from_files(files))
+    }
+
+    /// Reads and validates a single rule file
+    fn validate_file(&self, path: &Path) -> Result<FileValidationResult> {
+        let (result, error) = match std::fs::read_to_string(path) {
+            Ok(source) => match catch_panic(|| self.validate_rule(&source)) {
+                Ok(result) => (Some(result?), None),
+                Err(message) => (None, Some(format!("Validation panicked: {message}"))),
+            },
+            Err(e) => (None, Some(format!("Failed to read rule file: {e}"))),
+        };
+        Ok(FileValidationResult {
+            path: path.display().to_string(),
+            result,
+            error,
+        })
+    }
+}
+
+/// Orders worker outcomes by file index
+///
+/// Files claimed by a worker that panicked have no outcome; they are reported
+/// as failed with the worker's panic message instead of aborting the run.
+fn order_outcomes(
+    paths: &[PathBuf],
+    outcomes: Vec<(usize, Result<FileValidationResult>)>,
+    worker_panic: Option<&str>,
+) -> Result<Vec<FileValidationResult>> {
+    let mut slots: Vec<Option<Result<FileValidationResult>>> = paths.iter().map(|_| None).collect();
+    for (index, outcome) in outcomes {
+        slots[index] = Some(outcome);
+    }
+    slots
+        .into_iter()
+        .zip(paths)
+        .map(|(slot, path)| {
+            slot.unwrap_or_else(|| {
+                Ok(FileValidationResult {
+                    path: path.display().to_string(),
+                    result: None,
+                    error: Some(format!(
+                        "Validation worker panicked: {}",
+                        worker_panic.unwrap_or("unknown panic")
+                    )),
+                })
+            })
+        })
+        .collect()
+}
+
+/// Runs `f`, turning a panic into its message
+fn catch_panic<T>(f: impl FnOnce() -> T) -> std::result::Result<T, String> {
+    catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+/// Extracts the message from a panic payload
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Checks whether a path has a YARA rule file extension
+fn is_rule_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            RULE_EXTENSIONS
+                .iter()
+                .any(|rule_ext| ext.eq_ignore_ascii_case(rule_ext))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_validate_directory_counts_and_order() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("b.yar"), "rule b { condition: true }").unwrap();
+        std::fs::write(dir.path().join("a.YARA"), "rule a { condition: nope( }").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "not a rule").unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(
+            dir.path().join("nested").join("c.yara"),
+            "rule c { strings: $a = \"x\" condition: $a }",
+        )
+        .unwrap();
+
+        let validator = YaraValidator::new();
+
+        let flat = validator.validate_directory(dir.path(), false).unwrap();
+        assert_eq!(flat.total_files, 2);
+        assert_eq!(flat.passed_files, 1);
+        assert_eq!(flat.failed_files, 1);
+        assert!(flat.files[0].path.ends_with("a.YARA"));
+        assert!(!flat.files[0].passed());
+        assert!(flat.files[1].passed());
+        assert_eq!(flat.slowest_rules.len(), 1);
+        assert_eq!(flat.slowest_rules[0].rule_name.as_deref(), Some("b"));
+
+        let recursive = validator.validate_directory(dir.path(), true).unwrap();
+        assert_eq!(recursive.total_files, 3);
+        assert_eq!(recursive.passed_files, 2);
+        assert!(!recursive.all_passed());
+        assert!(recursive.files[2].path.ends_with("c.yara"));
+    }
+
+    #[test]
+    fn test_catch_panic_reports_message() {
+        assert_eq!(catch_panic(|| 7), Ok(7));
+        assert_eq!(
+            catch_panic(|| -> u32 { panic!("bad rule {}", 1) }),
+            Err("bad rule 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_non_ascii_condition_fails_without_panicking() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("quotes.yar"),
+            "rule quotes { condition: “x” }",
+        )
+        .unwrap();
+
+        let report = YaraValidator::new()
+            .validate_directory(dir.path(), false)
+            .unwrap();
+        assert_eq!(report.failed_files, 1);
+        assert!(report.slowest_rules.is_empty());
+    }
+
+    #[test]
+    fn test_validate_empty_and_missing_directory() {
+        let dir = TempDir::new().unwrap();
+        let validator = YaraValidator::new();
+
+        let report = validator.validate_directory(dir.path(), true).unwrap();
+        assert_eq!(report.total_files, 0);
+        assert!(report.all_passed());
+
+        assert!(
+            validator
+                .validate_directory(&dir.path().join("missing"), false)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_worker_panic_marks_unfinished_files_failed() {
+        let paths: Vec<_> = ["a.yar", "b.yar", "c.yar"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        let finished = FileValidationResult {
+            path: "b.yar".to_string(),
+            result: None,
+            error: Some("Failed to read rule file".to_string()),
+        };
+
+        let files = order_outcomes(&paths, vec![(1, Ok(finished))], Some("boom")).unwrap();
+
+        assert_eq!(files.len(), 3);
+        assert_eq!(files[1].error.as_deref(), Some("Failed to read rule file"));
+        for file in [&files[0], &files[2]] {
+            assert!(!file.passed());
+            assert_eq!(
+                file.error.as_deref(),
+                Some("Validation worker panicked: boom")
+            );
+        }
+        assert_eq!(files[2].path, "c.yar");
+    }
+}
@@ -8,28 +8,61 @@
 //! - Core YARA rule validation using the yara-x engine
 //! - Pre-defined test cases and test suite management
 //! - `OpenAI` Batch API job generation for YARA testing
+//! - Matching compiled rules against files and directories
+//! - Caching validation results and compiled rules across validators
+//! - Validating every rule file in a directory as a CI gate
+//! - Diffing validation results to review the impact of rule edits
 //!
 //! ## Sub-modules
 //!
 //! - [`batch_generator`](crate::testing::batch_generator): `OpenAI` Batch API job generation
+//! - [`suite_spec`](crate::testing::suite_spec): User-defined batch test suites loaded from JSON
 
 pub mod batch_generator;
 pub mod prompts;
+pub mod suite_spec;
+
+#[cfg(feature = "yara")]
+pub mod directory_validation;
 
 #[cfg(feature = "yara")]
 pub mod test_cases;
 
+#[cfg(feature = "yara")]
+pub mod validation_cache;
+
+// The CLI binary compiles this module but never diffs results
+#[cfg(feature = "yara")]
+#[allow(dead_code)]
+pub mod validation_diff;
+
 #[cfg(feature = "yara")]
 mod validator_helpers;
 
+#[cfg(feature = "yara")]
+mod yara_formatter;
+
+#[cfg(feature = "yara")]
+pub mod yara_scanner;
+
 #[cfg(feature = "yara")]
 pub mod yara_validator;
 
 #[allow(unused_imports)]
 pub use batch_generator::BatchJobGenerator;
+#[allow(unused_imports)]
+pub use suite_spec::{BatchSuiteSpec, SuiteTestSpec};
 
 #[cfg(feature = "yara")]
 pub use test_cases::YaraTestCases;
 
+#[cfg(feature = "yara")]
+#[allow(unused_imports)]
+pub use yara_scanner::{FileScanResult, ScanOptions, YaraScanner};
+
+#[cfg(feature = "yara")]
+#[allow(unused_imports)]
+pub use validation_cache::{CacheStats, ValidationCache};
+
 #[cfg(feature = "yara")]
 pub use yara_validator::YaraValidator;
@@ -0,0 +1,256 @@
+//! # Batch Suite Specifications
+//!
+//! User-defined test suites for [`BatchJobGenerator`](super::BatchJobGenerator),
+//! loaded from a JSON file instead of the built-in prompt registry.
+//!
+//! ## Format
+//!
+//! ```json
+//! {
+//!   "name": "packers",
+//!   "model": "gpt-4o",
+//!   "system_prompt": "You write YARA rules.",
+//!   "max_tokens": 800,
+//!   "temperature": 0.2,
+//!   "tests": [
+//!     {
+//!       "id": "upx",
+//!       "prompt": "Generate a YARA rule to detect UPX packed executables.",
+//!       "expected": {"rule_name": "upx_packed"}
+//!     },
+//!     {"id": "mpress", "prompt": "Detect MPRESS packed executables.", "model": "gpt-4o-mini"}
+//!   ]
+//! }
+//! ```
+//!
+//! Only `name`, `tests`, and each test's `id` and `prompt` are required. The
+//! `expected` metadata is not sent to the API; look it up by custom ID when
+//! scoring the batch results.
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize as De, Serialize as Ser};
+use serde_json::Value;
+use std::path::Path;
+
+/// A user-defined batch test suite
+#[derive(Debug, Clone, Ser, De)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct BatchSuiteSpec {
+    /// Suite name, used as the custom ID prefix
+    pub name: String,
+    /// Model for every test that does not set its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// System prompt replacing the generator's default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+    /// Maximum tokens per response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    /// Sampling temperature (0.0-2.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    /// The tests in this suite
+    pub tests: Vec<SuiteTestSpec>,
+}
+
+/// A single test within a [`BatchSuiteSpec`]
+#[derive(Debug, Clone, Ser, De)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct SuiteTestSpec {
+    /// Identifier unique within the suite
+    pub id: String,
+    /// User prompt sent to the model
+    pub prompt: String,
+    /// Model overriding the suite model for this test
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Free-form description of the expected output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<Value>,
+}
+
+#[allow(dead_code)]
+impl BatchSuiteSpec {
+    /// Read and validate a suite specification from a JSON file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or the specification is invalid
+    pub fn load(path: &Path) -> Result<Self> {
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read suite spec {}", path.display()))?;
+        Self::from_json_str(&source)
+            .with_context(|| format!("Invalid suite spec {}", path.display()))
+    }
+
+    /// Parse and validate a suite specification
+    ///
+    /// Syntax and schema errors, such as a missing `prompt` or a misspelled
+    /// field, report the line and column. Validation errors report the test
+    /// index and, when it can be found, the line of the test's `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first problem found
+    pub fn from_json_str(source: &str) -> Result<Self> {
+        let spec: Self = serde_json::from_str(source).map_err(|e| anyhow!("{e}"))?;
+        spec.validate_with_lines(Some(source))?;
+        Ok(spec)
+    }
+
+    /// Check the specification for values the batch API would reject
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for an empty name or test list, an empty, duplicate or
+    /// non-alphanumeric test ID, an empty prompt or model, zero `max_tokens`,
+    /// or a temperature outside 0.0-2.0.
+    pub fn validate(&self) -> Result<()> {
+        self.validate_with_lines(None)
+    }
+
+    /// Custom ID used for a test's batch request
+    #[must_use]
+    pub fn custom_id(&self, test: &SuiteTestSpec) -> String {
+        format!("{}_{}", self.name, test.id)
+    }
+
+    /// Find a test by the custom ID of its batch request
+    #[must_use]
+    pub fn test_for_custom_id(&self, custom_id: &str) -> Option<&SuiteTestSpec> {
+        self.tests
+            .iter()
+            .find(|test| self.custom_id(test) == custom_id)
+    }
+
+    /// Validate, pointing test errors at a line of `source` when available
+    fn validate_with_lines(&self, source: Option<&str>) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(anyhow!("Suite name must not be empty"));
+        }
+        if self.tests.is_empty() {
+            return Err(anyhow!("Suite '{}' has no tests", self.name));
+        }
+        if self.max_tokens == Some(0) {
+            return Err(anyhow!("max_tokens must be greater than 0"));
+        }
+        if let Some(temperature) = self.temperature
+            && !(0.0..=2.0).contains(&temperature)
+        {
+            return Err(anyhow!(
+                "temperature must be between 0.0 and 2.0, got {temperature}"
+            ));
+        }
+        if self.model.as_deref().is_some_and(|m| m.trim().is_empty()) {
+            return Err(anyhow!("Suite model must not be empty"));
+        }
+
+        for (index, test) in self.tests.iter().enumerate() {
+            if let Err(message) = self.check_test(index, test) {
+                let line = source.and_then(|source| line_of_id(source, &test.id));
+                return Err(match line {
+                    Some(line) => anyhow!("tests[{index}] (line {line}): {message}"),
+                    None => anyhow!("tests[{index}]: {message}"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Check a single test, returning a message without location
+    fn check_test(&self, index: usize, test: &SuiteTestSpec) -> std::result::Result<(), String> {
+        if test.id.is_empty() {
+            return Err("id must not be empty".to_string());
+        }
+        if !test
+            .id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(format!(
+                "id '{}' may only contain letters, digits, underscores and dashes",
+                test.id
+            ));
+        }
+        if let Some(first) = self.tests[..index].iter().position(|t| t.id == test.id) {
+            return Err(format!("id '{}' duplicates tests[{first}]", test.id));
+        }
+        if test.prompt.trim().is_empty() {
+            return Err(format!("prompt for '{}' is empty", test.id));
+        }
+        if test.model.as_deref().is_some_and(|m| m.trim().is_empty()) {
+            return Err(format!("model for '{}' is empty", test.id));
+        }
+        Ok(())
+    }
+}
+
+/// Find the 1-based line on which a test's `id` value last appears
+///
+/// The last match is used so a duplicate ID points at the repeated entry.
+fn line_of_id(source: &str, id: &str) -> Option<usize> {
+    if id.is_empty() {
+        return None;
+    }
+    let needle = serde_json::to_string(id).ok()?;
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains("\"id\"") && line.contains(&needle))
+        .map(|(number, _)| number + 1)
+        .last()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"{
+  "name": "packers",
+  "model": "gpt-4o",
+  "tests": [
+    {"id": "upx", "prompt": "Detect UPX", "expected": {"rule_name": "upx_packed"}},
+    {"id": "mpress", "prompt": "Detect MPRESS", "model": "gpt-4o-mini"}
+  ]
+}"#;
+
+    #[test]
+    fn test_parse_valid_spec() {
+        let spec = BatchSuiteSpec::from_json_str(SPEC).unwrap();
+        assert_eq!(spec.tests.len(), 2);
+        assert_eq!(spec.custom_id(&spec.tests[1]), "packers_mpress");
+        let test = spec.test_for_custom_id("packers_upx").unwrap();
+        assert_eq!(test.expected.as_ref().unwrap()["rule_name"], "upx_packed");
+    }
+
+    #[test]
+    fn test_schema_errors_report_line() {
+        let source = SPEC.replace(
+            "\"prompt\": \"Detect MPRESS\"",
+            "\"promt\": \"Detect MPRESS\"",
+        );
+        let err = BatchSuiteSpec::from_json_str(&source)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unknown field `promt`"), "{err}");
+        assert!(err.contains("line 6"), "{err}");
+    }
+
+    #[test]
+    fn test_validation_errors_report_test_and_line() {
+        let source = SPEC.replace("\"mpress\"", "\"upx\"");
+        let err = BatchSuiteSpec::from_json_str(&source)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "tests[1] (line 6): id 'upx' duplicates tests[0]");
+
+        let source = SPEC.replace("\"Detect UPX\"", "\"  \"");
+        let err = BatchSuiteSpec::from_json_str(&source)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "tests[0] (line 5): prompt for 'upx' is empty");
+    }
+}
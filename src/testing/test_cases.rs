@@ -9,6 +9,7 @@
 //! - **Test Suite Execution**: Automated execution of all test cases
 //! - **Result Aggregation**: Comprehensive reporting of test results
 //! - **Success Rate Analysis**: Statistical analysis of validation performance
+//! - **Feature Assertions**: Require rules to exhibit specific features
 //!
 //! ## Example
 //!
@@ -23,7 +24,7 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
-use crate::testing::yara_validator::{ValidationResult, YaraValidator};
+use crate::testing::yara_validator::{RuleFeatures, ValidationResult, YaraValidator};
 use anyhow::Result;
 use serde::{Deserialize as De, Serialize as Ser};
 
@@ -55,6 +56,308 @@ pub struct TestCaseResult {
     pub validation_result: ValidationResult,
     /// Error message if the test failed
     pub error_message: Option<String>,
+    /// Expected features the rule did not exhibit
+    #[serde(default)]
+    pub feature_mismatches: Vec<FeatureMismatch>,
+}
+
+/// A YARA test case: a rule and the outcome it must produce
+#[derive(Debug, Clone, Ser, De)]
+pub struct YaraTestCase {
+    /// Unique identifier for the test case
+    pub id: String,
+    /// Human-readable name for the test case
+    pub name: String,
+    /// YARA rule source to validate
+    pub rule: String,
+    /// Whether the rule is expected to compile
+    pub expected_valid: bool,
+    /// Features the rule is expected to exhibit
+    #[serde(default)]
+    pub expected_features: Option<ExpectedFeatures>,
+}
+
+impl YaraTestCase {
+    /// Creates a test case without feature expectations
+    #[must_use]
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        rule: impl Into<String>,
+        expected_valid: bool,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            rule: rule.into(),
+            expected_valid,
+            expected_features: None,
+        }
+    }
+
+    /// Requires the rule to exhibit the given features
+    #[must_use]
+    pub fn with_expected_features(mut self, expected: ExpectedFeatures) -> Self {
+        self.expected_features = Some(expected);
+        self
+    }
+}
+
+/// Features a rule must exhibit for its test case to pass
+///
+/// Every field left as `None` (or empty, for `imported_modules`) is not
+/// checked, so an expectation only names the features that matter.
+///
+/// # Example
+///
+/// ```
+/// use openai_rust_sdk::testing::test_cases::{ExpectedFeatures, YaraTestCase};
+/// use openai_rust_sdk::testing::YaraTestCases;
+///
+/// let case = YaraTestCase::new(
+///     "regex_001",
+///     "Regex rule",
+///     r#"rule r { strings: $a = "plain" condition: $a }"#,
+///     true,
+/// )
+/// .with_expected_features(ExpectedFeatures {
+///     has_regex_patterns: Some(true),
+///     ..ExpectedFeatures::default()
+/// });
+///
+/// let results = YaraTestCases::new().with_test_case(case).run_all_tests()?;
+/// let result = results.test_results.iter().find(|r| r.test_id == "regex_001").unwrap();
+/// assert!(!result.passed);
+/// assert_eq!(
+///     result.feature_mismatches[0].to_string(),
+///     "has_regex_patterns: expected true, got false"
+/// );
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Ser, De)]
+pub struct ExpectedFeatures {
+    /// Whether the rule must (or must not) have a strings section
+    pub has_strings: Option<bool>,
+    /// Whether the rule must (or must not) contain hex patterns
+    pub has_hex_patterns: Option<bool>,
+    /// Whether the rule must (or must not) contain regular expressions
+    pub has_regex_patterns: Option<bool>,
+    /// Whether the rule must (or must not) have metadata
+    pub has_metadata: Option<bool>,
+    /// Whether the rule must (or must not) use iterators
+    pub uses_iterators: Option<bool>,
+    /// Whether the rule must (or must not) import the `pe` module
+    pub uses_pe_module: Option<bool>,
+    /// Whether the rule must (or must not) import the `elf` module
+    pub uses_elf_module: Option<bool>,
+    /// Whether the rule must (or must not) import the `dotnet` module
+    pub uses_dotnet_module: Option<bool>,
+    /// Whether the condition must (or must not) refer to `filesize`
+    pub uses_filesize: Option<bool>,
+    /// Whether the condition must (or must not) compute an entropy
+    pub uses_entropy: Option<bool>,
+    /// Exact number of string patterns
+    pub string_count: Option<usize>,
+    /// Modules the rule must import
+    #[serde(default)]
+    pub imported_modules: Vec<String>,
+}
+
+impl ExpectedFeatures {
+    /// Compares the expectations with the features detected in a rule
+    #[must_use]
+    pub fn mismatches(&self, actual: &RuleFeatures) -> Vec<FeatureMismatch> {
+        let mut mismatches = Vec::new();
+        let mut check = |feature: &str, expected: Option<String>, actual: String| {
+            if let Some(expected) = expected
+                && expected != actual
+            {
+                mismatches.push(FeatureMismatch {
+                    feature: feature.to_string(),
+                    expected,
+                    actual,
+                });
+            }
+        };
+
+        let flags = [
+            ("has_strings", self.has_strings, actual.has_strings),
+            (
+                "has_hex_patterns",
+                self.has_hex_patterns,
+                actual.has_hex_patterns,
+            ),
+            (
+                "has_regex_patterns",
+                self.has_regex_patterns,
+                actual.has_regex_patterns,
+            ),
+            ("has_metadata", self.has_metadata, actual.has_metadata),
+            ("uses_iterators", self.uses_iterators, actual.uses_iterators),
+            ("uses_pe_module", self.uses_pe_module, actual.uses_pe_module),
+            (
+                "uses_elf_module",
+                self.uses_elf_module,
+                actual.uses_elf_module,
+            ),
+            (
+                "uses_dotnet_module",
+                self.uses_dotnet_module,
+                actual.uses_dotnet_module,
+            ),
+            ("uses_filesize", self.uses_filesize, actual.uses_filesize),
+            ("uses_entropy", self.uses_entropy, actual.uses_entropy),
+        ];
+        for (feature, expected, actual) in flags {
+            check(feature, expected.map(|v| v.to_string()), actual.to_string());
+        }
+
+        check(
+            "string_count",
+            self.string_count.map(|v| v.to_string()),
+            actual.string_count.to_string(),
+        );
+
+        let missing: Vec<&str> = self
+            .imported_modules
+            .iter()
+            .filter(|module| !actual.imported_modules.contains(module))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            check(
+                "imported_modules",
+                Some(format!("[{}]", self.imported_modules.join(", "))),
+                format!("[{}]", actual.imported_modules.join(", ")),
+            );
+        }
+
+        mismatches
+    }
+}
+
+/// An expected feature that differs from the one detected in the rule
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct FeatureMismatch {
+    /// Name of the feature, matching the [`RuleFeatures`] field
+    pub feature: String,
+    /// Expected value
+    pub expected: String,
+    /// Detected value
+    pub actual: String,
+}
+
+impl std::fmt::Display for FeatureMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.feature, self.expected, self.actual
+        )
+    }
+}
+
+/// Name of the suite in JUnit XML reports
+const JUNIT_SUITE_NAME: &str = "yara_rule_validation";
+
+impl TestSuiteResult {
+    /// Renders the results as a JUnit XML report
+    ///
+    /// The report contains one `<testsuite>` with a `<testcase>` per YARA
+    /// test. Failed tests carry a `<failure>` element with the error message
+    /// and any compilation errors, and each case is timed by the rule's
+    /// compilation time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraTestCases;
+    ///
+    /// let results = YaraTestCases::new().run_all_tests()?;
+    /// let xml = results.to_junit_xml();
+    ///
+    /// assert!(xml.starts_with("<?xml"));
+    /// assert!(xml.contains(r#"<testcase classname="yara_rule_validation" name="basic_001""#));
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[must_use]
+    pub fn to_junit_xml(&self) -> String {
+        let seconds = |ms: u64| {
+            #[allow(clippy::cast_precision_loss)]
+            {
+                ms as f64 / 1000.0
+            }
+        };
+        let total_ms: u64 = self
+            .test_results
+            .iter()
+            .map(|test| test.validation_result.metrics.compilation_time_ms)
+            .sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuites tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            self.total_tests,
+            self.failed_tests,
+            seconds(total_ms)
+        ));
+        xml.push_str(&format!(
+            "  <testsuite name=\"{JUNIT_SUITE_NAME}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\" time=\"{:.3}\">\n",
+            self.total_tests,
+            self.failed_tests,
+            seconds(total_ms)
+        ));
+
+        for test in &self.test_results {
+            let attributes = format!(
+                "classname=\"{JUNIT_SUITE_NAME}\" name=\"{}\" time=\"{:.3}\"",
+                escape_xml(&test.test_id),
+                seconds(test.validation_result.metrics.compilation_time_ms)
+            );
+            if test.passed {
+                xml.push_str(&format!("    <testcase {attributes}/>\n"));
+                continue;
+            }
+
+            let message = test.error_message.as_deref().unwrap_or("Test case failed");
+            let details: Vec<String> = std::iter::once(test.test_name.clone())
+                .chain(
+                    test.validation_result
+                        .errors
+                        .iter()
+                        .map(ToString::to_string),
+                )
+                .collect();
+            xml.push_str(&format!("    <testcase {attributes}>\n"));
+            xml.push_str(&format!(
+                "      <failure message=\"{}\" type=\"AssertionError\">{}</failure>\n",
+                escape_xml(message),
+                escape_xml(&details.join("\n"))
+            ));
+            xml.push_str("    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n</testsuites>\n");
+        xml
+    }
+}
+
+/// Escapes the characters that are not allowed verbatim in XML text and
+/// attribute values
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c if c.is_control() && !matches!(c, '\n' | '\t' | '\r') => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
 }
 
 /// Test case manager for YARA rule validation
@@ -64,6 +367,8 @@ pub struct TestCaseResult {
 pub struct YaraTestCases {
     /// The YARA validator instance used for testing
     validator: YaraValidator,
+    /// Test cases run after the predefined ones
+    custom_cases: Vec<YaraTestCase>,
 }
 
 impl YaraTestCases {
@@ -80,15 +385,30 @@ impl YaraTestCases {
     pub fn new() -> Self {
         Self {
             validator: YaraValidator::new(),
+            custom_cases: Vec::new(),
         }
     }
 
+    /// Adds a test case that runs after the predefined ones
+    #[allow(dead_code)]
+    #[must_use]
+    pub fn with_test_case(mut self, test_case: YaraTestCase) -> Self {
+        self.custom_cases.push(test_case);
+        self
+    }
+
     /// Executes all predefined test cases and returns aggregated results
     ///
     /// Runs a comprehensive test suite including:
     /// - Valid rules that should compile successfully
     /// - Invalid rules that should fail compilation
     /// - Rules with various features and complexity levels
+    /// - Test cases added with [`with_test_case`](Self::with_test_case)
+    ///
+    /// A test case passes when the rule's validity matches the expectation
+    /// and, if the case has [`ExpectedFeatures`], every expected feature was
+    /// detected. Unmet feature expectations are listed in
+    /// [`TestCaseResult::feature_mismatches`].
     ///
     /// # Returns
     ///
@@ -112,15 +432,16 @@ impl YaraTestCases {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn run_all_tests(&self) -> Result<TestSuiteResult> {
-        let test_cases = Self::get_test_cases();
+        let mut test_cases = Self::get_test_cases();
+        test_cases.extend(self.custom_cases.iter().cloned());
         let test_results = self.execute_test_cases(test_cases)?;
         Ok(Self::aggregate_results(test_results))
     }
 
     /// Returns the predefined test cases for validation
-    fn get_test_cases() -> Vec<(&'static str, &'static str, &'static str, bool)> {
+    fn get_test_cases() -> Vec<YaraTestCase> {
         vec![
-            (
+            YaraTestCase::new(
                 "basic_001",
                 "Simple String",
                 r#"
@@ -132,8 +453,14 @@ impl YaraTestCases {
                 }
             "#,
                 true,
-            ),
-            (
+            )
+            .with_expected_features(ExpectedFeatures {
+                has_strings: Some(true),
+                has_hex_patterns: Some(false),
+                string_count: Some(1),
+                ..ExpectedFeatures::default()
+            }),
+            YaraTestCase::new(
                 "hex_001",
                 "Hex Pattern",
                 r"
@@ -145,8 +472,12 @@ impl YaraTestCases {
                 }
             ",
                 true,
-            ),
-            (
+            )
+            .with_expected_features(ExpectedFeatures {
+                has_hex_patterns: Some(true),
+                ..ExpectedFeatures::default()
+            }),
+            YaraTestCase::new(
                 "invalid_001",
                 "Invalid Rule",
                 r#"
@@ -163,24 +494,43 @@ impl YaraTestCases {
     }
 
     /// Executes a list of test cases and returns individual results
-    fn execute_test_cases(
-        &self,
-        test_cases: Vec<(&str, &str, &str, bool)>,
-    ) -> Result<Vec<TestCaseResult>> {
+    fn execute_test_cases(&self, test_cases: Vec<YaraTestCase>) -> Result<Vec<TestCaseResult>> {
         test_cases
             .into_iter()
-            .map(|(id, name, rule, expected_valid)| {
-                let validation_result = self.validator.validate_rule(rule)?;
-                let passed = validation_result.is_valid == expected_valid;
-                let error_message =
-                    Self::get_error_message(passed, expected_valid, validation_result.is_valid);
+            .map(|test_case| {
+                let validation_result = self.validator.validate_rule(&test_case.rule)?;
+                let feature_mismatches = test_case
+                    .expected_features
+                    .as_ref()
+                    .map(|expected| expected.mismatches(&validation_result.features))
+                    .unwrap_or_default();
+                let valid_as_expected = validation_result.is_valid == test_case.expected_valid;
+                let passed = valid_as_expected && feature_mismatches.is_empty();
+
+                let mut error_message = Self::get_error_message(
+                    valid_as_expected,
+                    test_case.expected_valid,
+                    validation_result.is_valid,
+                );
+                if !feature_mismatches.is_empty() {
+                    let diff: Vec<String> = feature_mismatches
+                        .iter()
+                        .map(|mismatch| format!("  - {mismatch}"))
+                        .collect();
+                    let message = format!("Feature mismatch:\n{}", diff.join("\n"));
+                    error_message = Some(match error_message {
+                        Some(validity) => format!("{validity}\n{message}"),
+                        None => message,
+                    });
+                }
 
                 Ok(TestCaseResult {
-                    test_id: id.to_string(),
-                    test_name: name.to_string(),
+                    test_id: test_case.id,
+                    test_name: test_case.name,
                     passed,
                     validation_result,
                     error_message,
+                    feature_mismatches,
                 })
             })
             .collect()
@@ -410,6 +760,94 @@ mod tests {
         assert_eq!(result1.test_results.len(), result2.test_results.len());
     }
 
+    #[test]
+    fn test_expected_features_pass_and_fail() {
+        let rule = r#"
+            import "pe"
+            rule pe_regex {
+                strings:
+                    $re = /abc[0-9]+/
+                condition:
+                    pe.is_pe and $re
+            }
+        "#;
+        let matching = YaraTestCase::new("custom_ok", "Matching", rule, true)
+            .with_expected_features(ExpectedFeatures {
+                has_regex_patterns: Some(true),
+                uses_pe_module: Some(true),
+                imported_modules: vec!["pe".to_string()],
+                ..ExpectedFeatures::default()
+            });
+        let mismatched = YaraTestCase::new("custom_bad", "Mismatched", rule, true)
+            .with_expected_features(ExpectedFeatures {
+                has_regex_patterns: Some(true),
+                uses_elf_module: Some(true),
+                string_count: Some(2),
+                imported_modules: vec!["elf".to_string()],
+                ..ExpectedFeatures::default()
+            });
+
+        let result = YaraTestCases::new()
+            .with_test_case(matching)
+            .with_test_case(mismatched)
+            .run_all_tests()
+            .unwrap();
+        assert_eq!(result.total_tests, 5);
+
+        let ok = &result.test_results[3];
+        assert!(ok.passed, "{:?}", ok.error_message);
+        assert!(ok.feature_mismatches.is_empty());
+
+        let bad = &result.test_results[4];
+        assert!(!bad.passed);
+        assert!(bad.validation_result.is_valid);
+        let features: Vec<&str> = bad
+            .feature_mismatches
+            .iter()
+            .map(|mismatch| mismatch.feature.as_str())
+            .collect();
+        assert_eq!(
+            features,
+            vec!["uses_elf_module", "string_count", "imported_modules"]
+        );
+        assert_eq!(
+            bad.error_message.as_deref(),
+            Some(
+                "Feature mismatch:\n  - uses_elf_module: expected true, got false\n  - string_count: expected 2, got 1\n  - imported_modules: expected [elf], got [pe]"
+            )
+        );
+    }
+
+    #[test]
+    fn test_junit_xml_report() {
+        let mut result = YaraTestCases::new().run_all_tests().unwrap();
+        let failing = &mut result.test_results[0];
+        failing.passed = false;
+        failing.test_id = "case<&>\"1\"".to_string();
+        failing.error_message = Some("Expected valid: true, got: false".to_string());
+        result.passed_tests -= 1;
+        result.failed_tests += 1;
+
+        let xml = result.to_junit_xml();
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites"));
+        assert!(xml.contains(&format!(
+            "<testsuite name=\"yara_rule_validation\" tests=\"{}\" failures=\"1\"",
+            result.total_tests
+        )));
+        assert_eq!(xml.matches("<testcase ").count(), result.total_tests);
+        assert!(xml.contains("name=\"case&lt;&amp;&gt;&quot;1&quot;\""));
+        assert!(xml.contains("<failure message=\"Expected valid: true, got: false\""));
+        assert_eq!(xml.matches("<failure").count(), 1);
+        assert!(xml.contains(r#"name="hex_001" time=""#));
+        assert!(xml.trim_end().ends_with("</testsuites>"));
+    }
+
+    #[test]
+    fn test_escape_xml() {
+        assert_eq!(escape_xml("a<b & 'c'\u{1}"), "a&lt;b &amp; &apos;c&apos;");
+    }
+
     #[test]
     fn test_feature_detection_in_tests() {
         let test_cases = YaraTestCases::new();
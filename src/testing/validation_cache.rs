@@ -0,0 +1,138 @@
+//! # YARA Validation Cache
+//!
+//! A thread-safe, least-recently-used cache of validation results and
+//! compiled rules, keyed by a hash of the rule source. A
+//! [`YaraValidator`](crate::testing::YaraValidator) built with
+//! [`with_cache`](crate::testing::YaraValidator::with_cache) consults it
+//! before compiling, so validating or scanning with the same rule again
+//! skips compilation entirely.
+
+use crate::testing::yara_validator::ValidationResult;
+use indexmap::IndexMap;
+use serde::{Deserialize as De, Serialize as Ser};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use yara_x::Rules;
+
+/// Hit and miss counters of a [`ValidationCache`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Ser, De)]
+pub struct CacheStats {
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that required compiling the rule
+    pub misses: u64,
+    /// Number of rules currently cached
+    pub entries: usize,
+    /// Maximum number of rules kept in the cache
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    /// Fraction of lookups answered from the cache (0.0-1.0)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss, dead_code)]
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// A cached validation outcome
+#[derive(Clone)]
+pub(crate) struct CachedRule {
+    /// Validation result of the rule
+    pub(crate) result: ValidationResult,
+    /// Compiled rules, present when the rule compiled
+    pub(crate) rules: Option<Arc<Rules>>,
+}
+
+/// Entries and counters guarded by the cache lock
+#[derive(Default)]
+struct CacheState {
+    /// Cached rules ordered from least to most recently used
+    entries: IndexMap<u64, CachedRule>,
+    /// Lookups answered from the cache
+    hits: u64,
+    /// Lookups that required compiling the rule
+    misses: u64,
+}
+
+/// Thread-safe LRU cache of YARA validation results
+pub struct ValidationCache {
+    /// Maximum number of cached rules
+    capacity: usize,
+    /// Cached entries and counters
+    state: Mutex<CacheState>,
+}
+
+impl ValidationCache {
+    /// Creates a cache holding at most `capacity` rules
+    ///
+    /// A capacity of 0 is raised to 1.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Returns the current hit and miss counters
+    #[must_use]
+    pub fn stats(&self) -> CacheStats {
+        let state = self.lock();
+        CacheStats {
+            hits: state.hits,
+            misses: state.misses,
+            entries: state.entries.len(),
+            capacity: self.capacity,
+        }
+    }
+
+    /// Removes every cached rule, keeping the counters
+    #[allow(dead_code)]
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+    }
+
+    /// Looks up a rule, marking it as most recently used and counting the hit
+    /// or miss
+    pub(crate) fn get(&self, key: u64) -> Option<CachedRule> {
+        let mut state = self.lock();
+        if let Some(entry) = state.entries.shift_remove(&key) {
+            state.entries.insert(key, entry.clone());
+            state.hits += 1;
+            Some(entry)
+        } else {
+            state.misses += 1;
+            None
+        }
+    }
+
+    /// Stores a rule, evicting the least recently used one when full
+    pub(crate) fn insert(&self, key: u64, entry: CachedRule) {
+        let mut state = self.lock();
+        state.entries.shift_remove(&key);
+        while state.entries.len() >= self.capacity {
+            state.entries.shift_remove_index(0);
+        }
+        state.entries.insert(key, entry);
+    }
+
+    /// Locks the cache state, recovering it if a holder panicked
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl std::fmt::Debug for ValidationCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidationCache")
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
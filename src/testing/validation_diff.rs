@@ -0,0 +1,301 @@
+//! # YARA Validation Diffs
+//!
+//! Compares two [`ValidationResult`]s, typically of a rule before and after
+//! an edit, and reports what changed: validity, compilation errors, metrics
+//! and detected features.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use openai_rust_sdk::testing::YaraValidator;
+//!
+//! let validator = YaraValidator::new();
+//! let before = validator.validate_rule("rule r {\n strings:\n  $a = \"x\"\n condition:\n  $a\n}")?;
+//! let after = validator.validate_rule("rule r {\n strings:\n  $a = /x+/\n condition:\n  $a\n}")?;
+//!
+//! let diff = before.diff(&after);
+//! assert!(!diff.validity_changed());
+//! assert_eq!(diff.added_features, vec!["regex patterns".to_string()]);
+//! println!("{diff}");
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use crate::testing::yara_validator::ValidationResult;
+use serde::{Deserialize as De, Serialize as Ser};
+use std::collections::BTreeSet;
+
+/// Smallest change in compilation time, in milliseconds, that is reported
+const TIMING_TOLERANCE_MS: u64 = 10;
+
+/// Change of a single numeric metric between two validation results
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct MetricDelta {
+    /// Name of the metric, such as `rule_size_bytes`
+    pub metric: String,
+    /// Value in the original result
+    pub before: u64,
+    /// Value in the new result
+    pub after: u64,
+}
+
+impl MetricDelta {
+    /// Signed difference from `before` to `after`
+    #[must_use]
+    pub fn delta(&self) -> i128 {
+        i128::from(self.after) - i128::from(self.before)
+    }
+}
+
+/// Differences between two validation results
+#[derive(Debug, Clone, PartialEq, Eq, Ser, De)]
+pub struct ValidationDiff {
+    /// Whether the original rule compiled
+    pub was_valid: bool,
+    /// Whether the new rule compiles
+    pub is_valid: bool,
+    /// Errors only present in the new result
+    pub added_errors: Vec<String>,
+    /// Errors only present in the original result
+    pub removed_errors: Vec<String>,
+    /// Metrics whose value changed, excluding timing
+    pub metric_deltas: Vec<MetricDelta>,
+    /// Change in compilation time, if larger than the timing tolerance
+    pub compilation_time: Option<MetricDelta>,
+    /// Features only detected in the new rule
+    pub added_features: Vec<String>,
+    /// Features only detected in the original rule
+    pub removed_features: Vec<String>,
+}
+
+impl ValidationDiff {
+    /// Returns true if the rule went from valid to invalid or back
+    #[must_use]
+    pub fn validity_changed(&self) -> bool {
+        self.was_valid != self.is_valid
+    }
+
+    /// Returns true if nothing differs between the two results
+    ///
+    /// Compilation time only counts when it moved by more than the timing
+    /// tolerance.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        !self.validity_changed()
+            && self.compilation_time.is_none()
+            && self.added_errors.is_empty()
+            && self.removed_errors.is_empty()
+            && self.metric_deltas.is_empty()
+            && self.added_features.is_empty()
+            && self.removed_features.is_empty()
+    }
+}
+
+impl ValidationResult {
+    /// Compares this result with a newer one
+    ///
+    /// `self` is treated as the original and `other` as the new result, so
+    /// "added" means present in `other` only. Features are compared by the
+    /// labels of [`RuleFeatures::detected_feature_labels`], which include
+    /// counts such as the number of strings.
+    ///
+    /// [`RuleFeatures::detected_feature_labels`]: crate::testing::yara_validator::RuleFeatures::detected_feature_labels
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> ValidationDiff {
+        let errors = |result: &Self| -> Vec<String> {
+            result.errors.iter().map(ToString::to_string).collect()
+        };
+        let (added_errors, removed_errors) = set_difference(errors(self), errors(other));
+        let (added_features, removed_features) = set_difference(
+            self.features.detected_feature_labels(),
+            other.features.detected_feature_labels(),
+        );
+
+        let metrics = |result: &Self| {
+            [
+                ("rule_size_bytes", result.metrics.rule_size_bytes as u64),
+                ("pattern_count", result.metrics.pattern_count as u64),
+                ("string_count", result.features.string_count as u64),
+                (
+                    "complexity_score",
+                    u64::from(result.features.complexity_score),
+                ),
+                (
+                    "condition_complexity",
+                    result.features.condition_complexity as u64,
+                ),
+            ]
+        };
+        let metric_deltas = metrics(self)
+            .into_iter()
+            .zip(metrics(other))
+            .filter(|((_, before), (_, after))| before != after)
+            .map(|((metric, before), (_, after))| MetricDelta {
+                metric: metric.to_string(),
+                before,
+                after,
+            })
+            .collect();
+        let (before, after) = (
+            self.metrics.compilation_time_ms,
+            other.metrics.compilation_time_ms,
+        );
+        let compilation_time =
+            (before.abs_diff(after) > TIMING_TOLERANCE_MS).then(|| MetricDelta {
+                metric: "compilation_time_ms".to_string(),
+                before,
+                after,
+            });
+
+        ValidationDiff {
+            was_valid: self.is_valid,
+            is_valid: other.is_valid,
+            added_errors,
+            removed_errors,
+            metric_deltas,
+            compilation_time,
+            added_features,
+            removed_features,
+        }
+    }
+}
+
+/// Splits two lists into the items only in `after` and only in `before`,
+/// each in sorted order
+fn set_difference(before: Vec<String>, after: Vec<String>) -> (Vec<String>, Vec<String>) {
+    let before: BTreeSet<String> = before.into_iter().collect();
+    let after: BTreeSet<String> = after.into_iter().collect();
+    (
+        after.difference(&before).cloned().collect(),
+        before.difference(&after).cloned().collect(),
+    )
+}
+
+impl std::fmt::Display for ValidationDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No changes");
+        }
+
+        let status = |valid: bool| if valid { "VALID" } else { "INVALID" };
+        let mut lines = Vec::new();
+        if self.validity_changed() {
+            lines.push(format!(
+                "Validity: {} -> {}",
+                status(self.was_valid),
+                status(self.is_valid)
+            ));
+        } else {
+            lines.push(format!("Validity: {} (unchanged)", status(self.is_valid)));
+        }
+
+        let mut section = |title: &str, marker: char, items: &[String]| {
+            if !items.is_empty() {
+                lines.push(format!("{title}:"));
+                lines.extend(items.iter().map(|item| format!("  {marker} {item}")));
+            }
+        };
+        section("Errors added", '+', &self.added_errors);
+        section("Errors removed", '-', &self.removed_errors);
+        section("Features added", '+', &self.added_features);
+        section("Features removed", '-', &self.removed_features);
+
+        if let Some(timing) = &self.compilation_time {
+            lines.push(format!(
+                "Compilation time: {} ms -> {} ms ({:+})",
+                timing.before,
+                timing.after,
+                timing.delta()
+            ));
+        }
+
+        if !self.metric_deltas.is_empty() {
+            lines.push("Metrics:".to_string());
+            lines.extend(self.metric_deltas.iter().map(|delta| {
+                format!(
+                    "  {}: {} -> {} ({:+})",
+                    delta.metric,
+                    delta.before,
+                    delta.after,
+                    delta.delta()
+                )
+            }));
+        }
+
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::YaraValidator;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_identical_results_have_empty_diff() {
+        let validator = YaraValidator::new();
+        let first = validator
+            .validate_rule("rule same { condition: true }")
+            .unwrap();
+        let mut second = validator
+            .validate_rule("rule same { condition: true }")
+            .unwrap();
+
+        assert!(first.diff(&second).is_empty());
+        assert_eq!(first.diff(&second).to_string(), "No changes");
+
+        second.metrics.compilation_time_ms = first.metrics.compilation_time_ms + 5;
+        assert_eq!(first.diff(&second).compilation_time, None);
+        assert!(first.diff(&second).is_empty());
+
+        second.metrics.compilation_time_ms = first.metrics.compilation_time_ms + 50;
+        let diff = first.diff(&second);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.to_string(),
+            format!(
+                "Validity: VALID (unchanged)\nCompilation time: {} ms -> {} ms (+50)",
+                first.metrics.compilation_time_ms, second.metrics.compilation_time_ms
+            )
+        );
+        assert_eq!(diff.compilation_time.unwrap().delta(), 50);
+    }
+
+    #[test]
+    fn test_diff_reports_validity_errors_features_and_metrics() {
+        let validator = YaraValidator::new();
+        let before = validator
+            .validate_rule("rule r {\n strings:\n  $h = { 4D 5A }\n condition:\n  $h\n}")
+            .unwrap();
+        let after = validator
+            .validate_rule("rule r {\n strings:\n  $h = /MZ+/\n condition:\n  nope(\n}")
+            .unwrap();
+
+        let diff = before.diff(&after);
+
+        assert!(diff.validity_changed());
+        assert_eq!(diff.added_errors.len(), 1);
+        assert!(diff.removed_errors.is_empty());
+        assert_eq!(diff.added_features, vec!["regex patterns"]);
+        assert_eq!(diff.removed_features, vec!["hex patterns"]);
+        assert_eq!(
+            diff.metric_deltas,
+            vec![MetricDelta {
+                metric: "rule_size_bytes".to_string(),
+                before: before.metrics.rule_size_bytes as u64,
+                after: after.metrics.rule_size_bytes as u64,
+            }]
+        );
+        assert_eq!(diff.metric_deltas[0].delta(), -1);
+
+        let rendered = diff.to_string();
+        assert!(rendered.starts_with("Validity: VALID -> INVALID\nErrors added:\n  + "));
+        assert!(rendered.contains("Features added:\n  + regex patterns"));
+        assert!(rendered.contains("Features removed:\n  - hex patterns"));
+        assert!(rendered.ends_with("  rule_size_bytes: 54 -> 53 (-1)"));
+
+        let reverse = after.diff(&before);
+        assert_eq!(reverse.removed_errors, diff.added_errors);
+        assert_eq!(reverse.metric_deltas[0].delta(), 1);
+    }
+}
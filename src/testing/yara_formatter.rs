@@ -0,0 +1,169 @@
+//! Canonical layout for YARA rule sources
+//!
+//! The formatter works line by line: it normalizes line endings, hoists and
+//! sorts `import` statements, re-indents every line from the brace structure
+//! of the rule and collapses runs of blank lines. The code within a line is
+//! kept as written, so comments and the rule's semantics are preserved.
+
+/// Indentation used for one nesting level
+const INDENT: &str = "    ";
+
+/// Section headers that are indented one level inside a rule body
+const SECTIONS: [&str; 3] = ["meta:", "strings:", "condition:"];
+
+/// Formats a YARA source into the canonical layout
+pub(crate) fn format_source(source: &str) -> String {
+    let source = source.replace("\r\n", "\n").replace('\r', "\n");
+
+    let mut imports = Vec::new();
+    let mut body = Vec::new();
+    let mut depth = 0usize;
+    let mut in_comment = false;
+
+    for raw in source.lines() {
+        let line = raw.trim();
+
+        if in_comment {
+            body.push(raw.trim_end().to_string());
+            in_comment = !line.contains("*/");
+            continue;
+        }
+
+        if depth == 0 && line.starts_with("import ") {
+            imports.push(line.to_string());
+            continue;
+        }
+
+        if line.is_empty() {
+            body.push(String::new());
+            continue;
+        }
+
+        let (opened, closed) = count_braces(line);
+        let level = line_level(line, depth);
+        body.push(format!("{}{line}", INDENT.repeat(level)));
+
+        depth = (depth + opened).saturating_sub(closed);
+        in_comment = opens_block_comment(line);
+    }
+
+    imports.sort();
+    imports.dedup();
+
+    let mut output = String::new();
+    for import in &imports {
+        output.push_str(import);
+        output.push('\n');
+    }
+    if !imports.is_empty() {
+        output.push('\n');
+    }
+
+    let mut previous_blank = true;
+    for line in body {
+        let blank = line.is_empty();
+        if blank && previous_blank {
+            continue;
+        }
+        output.push_str(&line);
+        output.push('\n');
+        previous_blank = blank;
+    }
+
+    let trimmed = output.trim_end_matches('\n').len();
+    output.truncate(trimmed);
+    output.push('\n');
+    output
+}
+
+/// Indentation level of a line that starts at the given brace depth
+fn line_level(line: &str, depth: usize) -> usize {
+    if line.starts_with('}') {
+        // A closing brace lines up with the line that opened the block
+        return match depth.saturating_sub(1) {
+            0 => 0,
+            outer => outer + 1,
+        };
+    }
+    match depth {
+        0 => 0,
+        1 if SECTIONS.iter().any(|section| line.starts_with(section)) => 1,
+        _ => depth + 1,
+    }
+}
+
+/// Counts the braces on a line that are not inside strings or comments
+fn count_braces(line: &str) -> (usize, usize) {
+    let mut opened = 0;
+    let mut closed = 0;
+    let mut in_string = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '/' if !in_string && matches!(chars.peek(), Some('/' | '*')) => break,
+            '{' if !in_string => opened += 1,
+            '}' if !in_string => closed += 1,
+            _ => {}
+        }
+    }
+
+    (opened, closed)
+}
+
+/// Whether a line leaves a `/* ... */` comment open
+fn opens_block_comment(line: &str) -> bool {
+    line.rfind("/*")
+        .is_some_and(|start| !line[start..].contains("*/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_reindents_and_sorts_imports() {
+        let source = "import \"pe\"\r\nimport \"math\"\r\nrule a {\r\n  meta:\r\n author = \"x\"   \r\n\r\n\r\n strings:\r\n$h = {\r\n4D 5A\r\n}\r\n  condition:\r\n      $h and pe.is_pe   // entry {\r\n}\r\n";
+
+        assert_eq!(
+            format_source(source),
+            r#"import "math"
+import "pe"
+
+rule a {
+    meta:
+        author = "x"
+
+    strings:
+        $h = {
+            4D 5A
+        }
+    condition:
+        $h and pe.is_pe   // entry {
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_preserves_block_comments_and_one_line_rules() {
+        let source = "\n\n/*\n   Header\n*/\nrule a { condition: true }\n\n\n\nrule b {\ncondition:\n\"}\" == \"}\"\n}\n\n";
+
+        assert_eq!(
+            format_source(source),
+            "/*\n   Header\n*/\nrule a { condition: true }\n\nrule b {\n    condition:\n        \"}\" == \"}\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_is_idempotent() {
+        let source = "import \"pe\"\nrule a {\n strings:\n  $s = \"x\"\n condition:\n  $s\n}\n";
+        let once = format_source(source);
+        assert_eq!(format_source(&once), once);
+    }
+}
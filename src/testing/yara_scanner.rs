@@ -0,0 +1,326 @@
+//! # YARA File Scanning
+//!
+//! This module compiles one or more YARA rules and matches them against files
+//! or whole directory trees using the yara-x engine.
+//!
+//! ## Features
+//!
+//! - **Multi-Rule Compilation**: Combine several rule sources into one rule set
+//! - **File Scanning**: Report the rules matching each scanned file
+//! - **Directory Walking**: Optional recursive traversal with a file-name glob filter
+//!
+//! ## Example
+//!
+//! ```rust
+//! use openai_rust_sdk::testing::YaraScanner;
+//!
+//! let scanner = YaraScanner::from_source(r#"
+//! rule detect_pe {
+//!     strings:
+//!         $mz = { 4D 5A }
+//!     condition:
+//!         $mz at 0
+//! }
+//! "#)?;
+//!
+//! let matches = scanner.scan_bytes(b"MZ\x90\x00")?;
+//! assert_eq!(matches, vec!["detect_pe".to_string()]);
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use anyhow::{Context, Result};
+use serde::{Deserialize as De, Serialize as Ser};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use yara_x::{Compiler, Rules, Scanner};
+
+/// Options controlling how a target path is scanned
+#[derive(Debug, Clone, Default)]
+pub struct ScanOptions {
+    /// Descend into subdirectories when the target is a directory
+    pub recursive: bool,
+    /// Only scan files whose name matches this glob (`*` and `?` wildcards)
+    pub glob: Option<String>,
+}
+
+impl ScanOptions {
+    /// Creates scan options with recursion disabled and no glob filter
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables recursive directory scanning
+    #[must_use]
+    pub fn with_recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Restricts scanning to file names matching the given glob
+    #[must_use]
+    pub fn with_glob(mut self, glob: impl Into<String>) -> Self {
+        self.glob = Some(glob.into());
+        self
+    }
+}
+
+/// Matches found when scanning a single file
+#[derive(Debug, Clone, Ser, De)]
+pub struct FileScanResult {
+    /// Path of the scanned file
+    pub path: String,
+    /// Identifiers of the rules that matched the file
+    pub matched_rules: Vec<String>,
+    /// Error message if the file could not be scanned
+    pub error: Option<String>,
+}
+
+impl FileScanResult {
+    /// Returns true if at least one rule matched the file
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        !self.matched_rules.is_empty()
+    }
+}
+
+/// Compiled YARA rule set used to scan files and buffers
+pub struct YaraScanner {
+    /// The compiled rules, shared with the validation cache
+    rules: Arc<Rules>,
+}
+
+impl YaraScanner {
+    /// Compiles a single YARA rule source into a scanner
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rule source fails to compile
+    #[allow(dead_code)]
+    pub fn from_source(rule_source: &str) -> Result<Self> {
+        Self::from_sources(&[rule_source])
+    }
+
+    /// Compiles several YARA rule sources into a single scanner
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the rule sources fails to compile
+    pub fn from_sources(rule_sources: &[&str]) -> Result<Self> {
+        let mut compiler = Compiler::new();
+        for source in rule_sources {
+            compiler
+                .add_source(*source)
+                .context("Failed to add rule source")?;
+        }
+        Ok(Self::from_rules(Arc::new(compiler.build())))
+    }
+
+    /// Wraps rules that are already compiled
+    pub(crate) fn from_rules(rules: Arc<Rules>) -> Self {
+        Self { rules }
+    }
+
+    /// Reads and compiles the YARA rules stored in the given files
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file cannot be read or its rules fail to compile
+    pub fn from_files(rule_files: &[PathBuf]) -> Result<Self> {
+        let sources = rule_files
+            .iter()
+            .map(|path| {
+                std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read rule file {}", path.display()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let sources: Vec<&str> = sources.iter().map(String::as_str).collect();
+        Self::from_sources(&sources)
+    }
+
+    /// Scans an in-memory buffer and returns the identifiers of matching rules
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scan fails
+    #[allow(dead_code)]
+    pub fn scan_bytes(&self, data: &[u8]) -> Result<Vec<String>> {
+        let mut scanner = Scanner::new(&self.rules);
+        let results = scanner.scan(data).context("Failed to scan data")?;
+        Ok(results
+            .matching_rules()
+            .map(|rule| rule.identifier().to_string())
+            .collect())
+    }
+
+    /// Scans a single file
+    ///
+    /// Scan failures are recorded in the returned result rather than
+    /// aborting, so one unreadable file does not stop a directory scan.
+    #[must_use]
+    pub fn scan_file(&self, path: &Path) -> FileScanResult {
+        let mut scanner = Scanner::new(&self.rules);
+        let (matched_rules, error) = match scanner.scan_file(path) {
+            Ok(results) => (
+                results
+                    .matching_rules()
+                    .map(|rule| rule.identifier().to_string())
+                    .collect(),
+                None,
+            ),
+            Err(e) => (Vec::new(), Some(e.to_string())),
+        };
+
+        FileScanResult {
+            path: path.display().to_string(),
+            matched_rules,
+            error,
+        }
+    }
+
+    /// Scans a file or directory according to the given options
+    ///
+    /// When `target` is a directory, its files are scanned in sorted order,
+    /// descending into subdirectories only if `options.recursive` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the target or one of its directories cannot be read
+    pub fn scan_path(&self, target: &Path, options: &ScanOptions) -> Result<Vec<FileScanResult>> {
+        if target.is_file() {
+            return Ok(vec![self.scan_file(target)]);
+        }
+
+        let mut files = Vec::new();
+        collect_files(target, options, &mut files)?;
+        Ok(files.iter().map(|path| self.scan_file(path)).collect())
+    }
+}
+
+/// Collects the files under `dir` that pass the glob filter
+pub(crate) fn collect_files(
+    dir: &Path,
+    options: &ScanOptions,
+    files: &mut Vec<PathBuf>,
+) -> Result<()> {
+    let mut entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory {}", dir.display()))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            if options.recursive {
+                collect_files(&path, options, files)?;
+            }
+        } else if matches_glob(&path, options.glob.as_deref()) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Checks a path's file name against an optional glob
+fn matches_glob(path: &Path, glob: Option<&str>) -> bool {
+    let Some(pattern) = glob else {
+        return true;
+    };
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| wildcard_match(pattern, name))
+}
+
+/// Matches `text` against a pattern supporting `*` and `?` wildcards
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    const PE_RULE: &str = r"
+        rule detect_pe {
+            strings:
+                $mz = { 4D 5A }
+            condition:
+                $mz at 0
+        }
+    ";
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.exe", "sample.exe"));
+        assert!(wildcard_match("sample?.bin", "sample1.bin"));
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("a*b*c", "axxbyyc"));
+        assert!(!wildcard_match("*.exe", "sample.dll"));
+        assert!(!wildcard_match("sample?.bin", "sample10.bin"));
+    }
+
+    #[test]
+    fn test_scan_bytes() {
+        let scanner = YaraScanner::from_source(PE_RULE).unwrap();
+        assert_eq!(
+            scanner.scan_bytes(b"MZ\x90\x00").unwrap(),
+            vec!["detect_pe"]
+        );
+        assert!(scanner.scan_bytes(b"plain text").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_rule_fails_to_compile() {
+        assert!(YaraScanner::from_source("rule broken { condition: nope( }").is_err());
+    }
+
+    #[test]
+    fn test_scan_directory_with_glob_and_recursion() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.exe"), b"MZ\x90\x00").unwrap();
+        std::fs::write(dir.path().join("b.txt"), b"MZ\x90\x00").unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("nested").join("c.exe"), b"hello").unwrap();
+
+        let scanner = YaraScanner::from_source(PE_RULE).unwrap();
+
+        let flat = scanner
+            .scan_path(dir.path(), &ScanOptions::new().with_glob("*.exe"))
+            .unwrap();
+        assert_eq!(flat.len(), 1);
+        assert!(flat[0].is_match());
+
+        let recursive = scanner
+            .scan_path(
+                dir.path(),
+                &ScanOptions::new().with_recursive(true).with_glob("*.exe"),
+            )
+            .unwrap();
+        assert_eq!(recursive.len(), 2);
+        assert!(!recursive[1].is_match());
+        assert!(recursive[1].error.is_none());
+    }
+}
@@ -12,6 +12,7 @@
 //! - **Feature Detection**: Analyzes rule features like hex patterns, regex, metadata
 //! - **Performance Metrics**: Measures compilation time and resource usage
 //! - **Pattern Testing**: Tests patterns against sample data
+//! - **Rule Formatting**: Normalizes rule layout for tidy, diff-friendly repositories
 //! - **Error Reporting**: Detailed error messages and validation results
 //!
 //! ## Example
@@ -35,9 +36,15 @@
 //! # Ok::<(), anyhow::Error>(())
 //! ```
 
+use crate::testing::validation_cache::{CacheStats, CachedRule, ValidationCache};
+use crate::testing::yara_formatter::format_source;
+use crate::testing::yara_scanner::YaraScanner;
 use anyhow::{Context, Result};
 use serde::{Deserialize as De, Serialize as Ser};
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 use thiserror::Error;
 use yara_x::{Compiler, Rules, Scanner};
 
@@ -92,6 +99,28 @@ pub struct RuleFeatures {
     pub string_count: usize,
     /// Complexity score from 1-10 based on rule features
     pub complexity_score: u8,
+    /// Names of the modules imported by the rule, in import order
+    #[serde(default)]
+    pub imported_modules: Vec<String>,
+    /// Whether the rule imports the `pe` module
+    #[serde(default)]
+    pub uses_pe_module: bool,
+    /// Whether the rule imports the `elf` module
+    #[serde(default)]
+    pub uses_elf_module: bool,
+    /// Whether the rule imports the `dotnet` module
+    #[serde(default)]
+    pub uses_dotnet_module: bool,
+    /// Whether the condition refers to `filesize`
+    #[serde(default)]
+    pub uses_filesize: bool,
+    /// Whether the condition computes an entropy, such as `math.entropy`
+    #[serde(default)]
+    pub uses_entropy: bool,
+    /// Number of terms in the condition: one plus every `and`, `or`, `not`
+    /// and quantifier (`of`, `for`), or 0 when there is no condition
+    #[serde(default)]
+    pub condition_complexity: usize,
 }
 
 /// Performance metrics for rule validation
@@ -99,12 +128,26 @@ pub struct RuleFeatures {
 pub struct ValidationMetrics {
     /// Time taken to compile the rule in milliseconds
     pub compilation_time_ms: u64,
+    /// Time taken to compile the rule in microseconds
+    ///
+    /// Most rules compile in well under a millisecond, so this is the figure
+    /// to compare rules by.
+    #[serde(default)]
+    pub compilation_time_us: u64,
     /// Size of the rule source code in bytes
     pub rule_size_bytes: usize,
     /// Number of patterns in the compiled rule
     pub pattern_count: usize,
 }
 
+impl ValidationMetrics {
+    /// Stores the compilation time in both milliseconds and microseconds
+    fn record_compilation_time(&mut self, elapsed: std::time::Duration) {
+        self.compilation_time_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        self.compilation_time_us = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+    }
+}
+
 /// Result of testing a pattern against sample data
 #[derive(Debug, Clone, Ser, De)]
 pub struct PatternTestResult {
@@ -118,6 +161,267 @@ pub struct PatternTestResult {
     pub match_details: Option<String>,
 }
 
+/// Value of an external variable defined at compile time
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExternalValue {
+    /// String value
+    String(String),
+    /// Integer value
+    Integer(i64),
+    /// Boolean value
+    Boolean(bool),
+    /// Floating point value
+    Float(f64),
+}
+
+impl From<&str> for ExternalValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for ExternalValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<i64> for ExternalValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<i32> for ExternalValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(i64::from(value))
+    }
+}
+
+impl From<bool> for ExternalValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<f64> for ExternalValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+/// ANSI escape sequence for green text
+const ANSI_GREEN: &str = "\x1b[32m";
+/// ANSI escape sequence for red text
+const ANSI_RED: &str = "\x1b[31m";
+/// ANSI escape sequence for yellow text
+const ANSI_YELLOW: &str = "\x1b[33m";
+/// ANSI escape sequence for bold text
+const ANSI_BOLD: &str = "\x1b[1m";
+/// ANSI escape sequence that resets all text attributes
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Keywords and built-in identifiers that may appear bare in a condition
+const CONDITION_KEYWORDS: [&str; 54] = [
+    "all",
+    "and",
+    "any",
+    "ascii",
+    "at",
+    "base64",
+    "base64wide",
+    "contains",
+    "defined",
+    "endswith",
+    "entrypoint",
+    "false",
+    "filesize",
+    "for",
+    "fullword",
+    "icontains",
+    "iendswith",
+    "iequals",
+    "in",
+    "int16",
+    "int16be",
+    "int32",
+    "int32be",
+    "int8",
+    "int8be",
+    "istartswith",
+    "kb",
+    "matches",
+    "mb",
+    "nocase",
+    "none",
+    "not",
+    "of",
+    "or",
+    "private",
+    "global",
+    "rule",
+    "startswith",
+    "them",
+    "true",
+    "uint16",
+    "uint16be",
+    "uint32",
+    "uint32be",
+    "uint8",
+    "uint8be",
+    "wide",
+    "with",
+    "xor",
+    "condition",
+    "strings",
+    "meta",
+    "import",
+    "include",
+];
+
+/// Returns the byte offset of the first occurrence of `needle` in `haystack`
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+impl ValidationResult {
+    /// Renders a human-readable report of the validation result
+    ///
+    /// The report lists the rule name, validity, compilation time, detected
+    /// features, errors and warnings. When `colored` is true, ANSI escape
+    /// sequences are used to highlight the status, errors and warnings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraValidator;
+    ///
+    /// let validator = YaraValidator::new();
+    /// let result = validator.validate_rule("rule test { condition: true }")?;
+    ///
+    /// let report = result.to_report_string(false);
+    /// assert!(report.contains("Rule: test"));
+    /// assert!(report.contains("Status: VALID"));
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[must_use]
+    pub fn to_report_string(&self, colored: bool) -> String {
+        let paint = |color: &str, text: &str| {
+            if colored {
+                format!("{color}{text}{ANSI_RESET}")
+            } else {
+                text.to_string()
+            }
+        };
+
+        let mut lines = Vec::new();
+        let name = self.rule_name.as_deref().unwrap_or("<unknown>");
+        lines.push(format!("Rule: {}", paint(ANSI_BOLD, name)));
+
+        let status = if self.is_valid {
+            paint(ANSI_GREEN, "VALID")
+        } else {
+            paint(ANSI_RED, "INVALID")
+        };
+        lines.push(format!("Status: {status}"));
+        lines.push(format!(
+            "Compilation Time: {}ms",
+            self.metrics.compilation_time_ms
+        ));
+        lines.push(format!("Rule Size: {} bytes", self.metrics.rule_size_bytes));
+
+        let detected = self.features.detected_feature_labels();
+        if detected.is_empty() {
+            lines.push("Features: none".to_string());
+        } else {
+            lines.push("Features:".to_string());
+            lines.extend(detected.iter().map(|label| format!("  - {label}")));
+        }
+        lines.push(format!("Complexity: {}/10", self.features.complexity_score));
+
+        if !self.errors.is_empty() {
+            lines.push(paint(ANSI_RED, "Errors:"));
+            lines.extend(self.errors.iter().map(|error| format!("  - {error}")));
+        }
+
+        if !self.warnings.is_empty() {
+            lines.push(paint(ANSI_YELLOW, "Warnings:"));
+            lines.extend(self.warnings.iter().map(|warning| format!("  - {warning}")));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl std::fmt::Display for ValidationResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_report_string(false))
+    }
+}
+
+impl RuleFeatures {
+    /// Returns short labels for every feature detected in the rule
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraValidator;
+    ///
+    /// let validator = YaraValidator::new();
+    /// let features = validator.analyze_features(
+    ///     r#"import "pe" rule t { condition: pe.is_pe and filesize < 1MB }"#,
+    /// );
+    /// assert_eq!(
+    ///     features.detected_feature_labels(),
+    ///     vec!["module imports (pe)", "filesize", "condition terms (2)"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn detected_feature_labels(&self) -> Vec<String> {
+        let mut labels = Vec::new();
+        if self.has_strings {
+            labels.push(format!("strings ({})", self.string_count));
+        }
+        if self.has_hex_patterns {
+            labels.push("hex patterns".to_string());
+        }
+        if self.has_regex_patterns {
+            labels.push("regex patterns".to_string());
+        }
+        if self.has_metadata {
+            labels.push("metadata".to_string());
+        }
+        if self.has_imports {
+            if self.imported_modules.is_empty() {
+                labels.push("module imports".to_string());
+            } else {
+                labels.push(format!(
+                    "module imports ({})",
+                    self.imported_modules.join(", ")
+                ));
+            }
+        }
+        if self.uses_external_vars {
+            labels.push("external variables".to_string());
+        }
+        if self.uses_iterators {
+            labels.push("iterators".to_string());
+        }
+        if self.uses_filesize {
+            labels.push("filesize".to_string());
+        }
+        if self.uses_entropy {
+            labels.push("entropy".to_string());
+        }
+        if self.condition_complexity > 1 {
+            labels.push(format!("condition terms ({})", self.condition_complexity));
+        }
+        labels
+    }
+}
+
 /// Result of analyzing string patterns in a YARA rule
 #[derive(Debug, Default)]
 struct StringAnalysisResult {
@@ -139,6 +443,10 @@ struct StringAnalysisResult {
 pub struct YaraValidator {
     /// Sample data for testing patterns
     test_samples: HashMap<String, Vec<u8>>,
+    /// External variables defined when compiling rules
+    externals: BTreeMap<String, ExternalValue>,
+    /// Cache of validation results and compiled rules
+    cache: Option<Arc<ValidationCache>>,
 }
 
 impl Default for YaraValidator {
@@ -166,7 +474,89 @@ impl YaraValidator {
         let mut test_samples = HashMap::new();
         test_samples.insert("pe_sample".to_string(), b"MZ\x90\x00PE\x00\x00".to_vec());
         test_samples.insert("text_sample".to_string(), b"email@example.com".to_vec());
-        Self { test_samples }
+        Self {
+            test_samples,
+            externals: BTreeMap::new(),
+            cache: None,
+        }
+    }
+
+    /// Caches up to `capacity` validation results and compiled rules
+    ///
+    /// Validating a rule whose source (and external variables) was seen
+    /// before returns the cached [`ValidationResult`], including the
+    /// compilation time measured the first time, without compiling again.
+    /// The least recently used rule is evicted when the cache is full.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraValidator;
+    ///
+    /// let validator = YaraValidator::new().with_cache(128);
+    /// for _ in 0..3 {
+    ///     validator.validate_rule("rule test { condition: true }")?;
+    /// }
+    ///
+    /// let stats = validator.cache_stats().unwrap();
+    /// assert_eq!((stats.hits, stats.misses), (2, 1));
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[allow(dead_code)]
+    #[must_use]
+    pub fn with_cache(self, capacity: usize) -> Self {
+        self.with_shared_cache(Arc::new(ValidationCache::new(capacity)))
+    }
+
+    /// Uses a cache shared with other validators
+    ///
+    /// The cache is thread-safe, so validators running on different threads
+    /// can share compiled rules. Entries are keyed by the rule source and
+    /// the validator's external variables, so validators with different
+    /// externals never see each other's results.
+    #[allow(dead_code)]
+    #[must_use]
+    pub fn with_shared_cache(mut self, cache: Arc<ValidationCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Returns the cache hit and miss counters, if a cache is configured
+    #[allow(dead_code)]
+    #[must_use]
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Defines an external variable available to the rules being validated
+    ///
+    /// Rules that reference external variables only compile when those
+    /// variables are defined. Defining the same name again replaces its value.
+    /// Strings, integers, booleans and floats are supported.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraValidator;
+    ///
+    /// let rule = "rule big { condition: filesize > min_size and verbose }";
+    ///
+    /// let validator = YaraValidator::new()
+    ///     .with_external("min_size", 1024)
+    ///     .with_external("verbose", true);
+    /// assert!(validator.validate_rule(rule)?.is_valid);
+    /// assert!(!YaraValidator::new().validate_rule(rule)?.is_valid);
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[allow(dead_code)]
+    #[must_use]
+    pub fn with_external(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<ExternalValue>,
+    ) -> Self {
+        self.externals.insert(name.into(), value.into());
+        self
     }
 
     /// Get the test samples (for testing)
@@ -210,6 +600,85 @@ impl YaraValidator {
     /// # Ok::<(), anyhow::Error>(())
     /// ```
     pub fn validate_rule(&self, rule_source: &str) -> Result<ValidationResult> {
+        Ok(self.validate_cached(rule_source)?.result)
+    }
+
+    /// Compiles a rule into a scanner for matching files and buffers
+    ///
+    /// With a cache configured, the compiled rules are shared with
+    /// [`validate_rule`](Self::validate_rule), so a rule that was already
+    /// validated is not compiled again.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rule does not compile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraValidator;
+    ///
+    /// let validator = YaraValidator::new().with_cache(16);
+    /// let rule = "rule mz { strings: $mz = { 4D 5A } condition: $mz at 0 }";
+    ///
+    /// assert!(validator.validate_rule(rule)?.is_valid);
+    /// let scanner = validator.scanner(rule)?;
+    /// assert_eq!(scanner.scan_bytes(b"MZ\x90\x00")?, vec!["mz".to_string()]);
+    ///
+    /// let stats = validator.cache_stats().unwrap();
+    /// assert_eq!((stats.hits, stats.misses), (1, 1));
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[allow(dead_code)]
+    pub fn scanner(&self, rule_source: &str) -> Result<YaraScanner> {
+        let entry = self.validate_cached(rule_source)?;
+        match entry.rules {
+            Some(rules) => Ok(YaraScanner::from_rules(rules)),
+            None => {
+                let errors: Vec<String> = entry
+                    .result
+                    .errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect();
+                anyhow::bail!("{}", errors.join("; "))
+            }
+        }
+    }
+
+    /// Validates a rule, consulting the cache when one is configured
+    fn validate_cached(&self, rule_source: &str) -> Result<CachedRule> {
+        let Some(cache) = &self.cache else {
+            return self.validate_uncached(rule_source);
+        };
+
+        let key = self.cache_key(rule_source);
+        if let Some(entry) = cache.get(key) {
+            return Ok(entry);
+        }
+        let entry = self.validate_uncached(rule_source)?;
+        cache.insert(key, entry.clone());
+        Ok(entry)
+    }
+
+    /// Hashes the rule source together with the external variables
+    fn cache_key(&self, rule_source: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        rule_source.hash(&mut hasher);
+        for (name, value) in &self.externals {
+            name.hash(&mut hasher);
+            match value {
+                ExternalValue::String(value) => (0u8, value).hash(&mut hasher),
+                ExternalValue::Integer(value) => (1u8, value).hash(&mut hasher),
+                ExternalValue::Boolean(value) => (2u8, value).hash(&mut hasher),
+                ExternalValue::Float(value) => (3u8, value.to_bits()).hash(&mut hasher),
+            }
+        }
+        hasher.finish()
+    }
+
+    /// Compiles and analyzes a rule without consulting the cache
+    fn validate_uncached(&self, rule_source: &str) -> Result<CachedRule> {
         let start_time = std::time::Instant::now();
 
         let mut result = ValidationResult {
@@ -220,27 +689,62 @@ impl YaraValidator {
             features: self.analyze_features(rule_source),
             metrics: ValidationMetrics {
                 compilation_time_ms: 0,
+                compilation_time_us: 0,
                 rule_size_bytes: rule_source.len(),
                 pattern_count: 0,
             },
             pattern_tests: Vec::new(),
         };
 
-        match self.compile_rule(rule_source) {
+        let rules = match self.compile_rule(rule_source) {
             Ok(rules) => {
                 result.is_valid = true;
-                result.metrics.compilation_time_ms = start_time.elapsed().as_millis() as u64;
+                result.metrics.record_compilation_time(start_time.elapsed());
                 result.pattern_tests = self.test_patterns(&rules)?;
+                Some(Arc::new(rules))
             }
             Err(e) => {
                 result.errors.push(ValidationError::CompilationError {
                     message: e.to_string(),
                 });
-                result.metrics.compilation_time_ms = start_time.elapsed().as_millis() as u64;
+                result.metrics.record_compilation_time(start_time.elapsed());
+                None
             }
-        }
+        };
 
-        Ok(result)
+        Ok(CachedRule { result, rules })
+    }
+
+    /// Formats a YARA rule into a canonical, diff-friendly layout
+    ///
+    /// The rule must compile. Line endings are normalized to `\n`, `import`
+    /// statements are moved to the top, sorted and deduplicated, section
+    /// headers are indented one level and their contents two, trailing
+    /// whitespace is removed and runs of blank lines are collapsed. The code
+    /// within each line, including comments, is kept as written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rule does not compile.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraValidator;
+    ///
+    /// let validator = YaraValidator::new();
+    /// let rule = "rule test {\r\n  strings:\r\n$a = \"abc\"\r\ncondition:\r\n      $a\r\n}";
+    ///
+    /// assert_eq!(
+    ///     validator.format_rule(rule)?,
+    ///     "rule test {\n    strings:\n        $a = \"abc\"\n    condition:\n        $a\n}\n"
+    /// );
+    /// # Ok::<(), anyhow::Error>(())
+    /// ```
+    #[allow(dead_code)]
+    pub fn format_rule(&self, rule_source: &str) -> Result<String> {
+        self.compile_rule(rule_source)?;
+        Ok(format_source(rule_source))
     }
 
     /// Compiles a YARA rule using the yara-x compiler
@@ -252,9 +756,17 @@ impl YaraValidator {
     /// # Returns
     ///
     /// Returns compiled `Rules` on success or an error if compilation fails
-    #[allow(clippy::unused_self)]
     fn compile_rule(&self, rule_source: &str) -> Result<Rules> {
         let mut compiler = Compiler::new();
+        for (name, value) in &self.externals {
+            match value {
+                ExternalValue::String(value) => compiler.define_global(name, value.as_str()),
+                ExternalValue::Integer(value) => compiler.define_global(name, *value),
+                ExternalValue::Boolean(value) => compiler.define_global(name, *value),
+                ExternalValue::Float(value) => compiler.define_global(name, *value),
+            }
+            .with_context(|| format!("Failed to define external variable {name}"))?;
+        }
         compiler
             .add_source(rule_source)
             .context("Failed to add rule source")?;
@@ -293,9 +805,14 @@ impl YaraValidator {
     /// Performs static analysis of the rule source to detect:
     /// - String patterns and types
     /// - Metadata presence
-    /// - External variable usage
+    /// - Imported modules (`pe`, `elf`, `dotnet`, ...)
+    /// - External variable, `filesize` and entropy usage
     /// - Iterator usage
-    /// - Complexity scoring
+    /// - Complexity scoring of the rule and its condition
+    ///
+    /// The rule does not need to compile; this is the same analysis
+    /// [`validate_rule`](Self::validate_rule) stores in
+    /// [`ValidationResult::features`].
     ///
     /// # Arguments
     ///
@@ -304,12 +821,39 @@ impl YaraValidator {
     /// # Returns
     ///
     /// Returns a `RuleFeatures` struct with detailed feature analysis
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use openai_rust_sdk::testing::YaraValidator;
+    ///
+    /// let validator = YaraValidator::new();
+    /// let features = validator.analyze_features(
+    ///     r#"
+    ///     import "math"
+    ///     rule packed {
+    ///         strings:
+    ///             $upx = /UPX[0-9]/
+    ///         condition:
+    ///             $upx and math.entropy(0, filesize) > 7.0
+    ///     }
+    ///     "#,
+    /// );
+    ///
+    /// assert!(features.has_regex_patterns);
+    /// assert!(features.uses_entropy);
+    /// assert_eq!(features.imported_modules, vec!["math"]);
+    /// assert_eq!(features.condition_complexity, 2);
+    /// ```
     #[allow(clippy::unused_self)]
-    fn analyze_features(&self, rule_source: &str) -> RuleFeatures {
+    #[must_use]
+    pub fn analyze_features(&self, rule_source: &str) -> RuleFeatures {
         let mut features = RuleFeatures::default();
         let source_lower = rule_source.to_lowercase();
 
         self.analyze_basic_features(&mut features, &source_lower);
+        self.analyze_modules(&mut features, rule_source);
+        self.analyze_condition(&mut features, &source_lower);
         let string_analysis = self.analyze_string_patterns(rule_source);
 
         features.string_count = string_analysis.count;
@@ -320,13 +864,166 @@ impl YaraValidator {
         features
     }
 
+    /// Records the modules imported by the rule
+    #[allow(clippy::unused_self)]
+    fn analyze_modules(&self, features: &mut RuleFeatures, rule_source: &str) {
+        for line in rule_source.lines() {
+            let Some(rest) = line.trim().strip_prefix("import") else {
+                continue;
+            };
+            let Some((module, _)) = rest
+                .trim_start()
+                .strip_prefix('"')
+                .and_then(|r| r.split_once('"'))
+            else {
+                continue;
+            };
+            if !module.is_empty() && !features.imported_modules.iter().any(|m| m == module) {
+                features.imported_modules.push(module.to_string());
+            }
+        }
+
+        let imports = |name: &str| features.imported_modules.iter().any(|m| m == name);
+        features.uses_pe_module = imports("pe");
+        features.uses_elf_module = imports("elf");
+        features.uses_dotnet_module = imports("dotnet");
+    }
+
+    /// Analyzes the condition sections of the rule
+    #[allow(clippy::unused_self)]
+    fn analyze_condition(&self, features: &mut RuleFeatures, source_lower: &str) {
+        let condition = self.condition_text(source_lower);
+        let words: Vec<&str> = condition
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .filter(|word| !word.is_empty())
+            .collect();
+
+        features.uses_filesize = words.contains(&"filesize");
+        features.uses_external_vars = self.references_external(&condition, source_lower);
+        features.uses_entropy = words.contains(&"entropy");
+        features.condition_complexity = if words.is_empty() {
+            0
+        } else {
+            1 + words
+                .iter()
+                .filter(|word| matches!(**word, "and" | "or" | "not" | "of" | "for"))
+                .count()
+        };
+    }
+
+    /// Checks whether a condition names an identifier that can only be an
+    /// external variable
+    ///
+    /// Bare identifiers that are not keywords, rule names, loop variables or
+    /// `with` declarations are externals. String references (`$a`, `#a`,
+    /// `@a`, `!a`), module fields, function calls and the contents of text
+    /// and regex literals are skipped.
+    #[allow(clippy::unused_self)]
+    fn references_external(&self, condition: &str, source_lower: &str) -> bool {
+        let words: Vec<&str> = source_lower.split_whitespace().collect();
+        let mut declared: Vec<&str> = words
+            .windows(2)
+            .filter(|pair| pair[0] == "rule")
+            .map(|pair| pair[1].trim_end_matches(['{', ':']))
+            .collect();
+        let mut identifiers = Vec::new();
+        let mut loop_vars = false;
+        let mut previous_word = "";
+        let bytes = condition.as_bytes();
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let c = bytes[i];
+            if c == b'"' || (c == b'/' && previous_word == "matches") {
+                i += 1;
+                while i < bytes.len() && bytes[i] != c {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                i += 1;
+                if c == b'/' {
+                    // Regex modifiers such as `/abc/is`
+                    while i < bytes.len() && matches!(bytes[i], b'i' | b's') {
+                        i += 1;
+                    }
+                }
+                continue;
+            }
+            // Only bytes are compared here: `i` may sit inside a multi-byte
+            // character, so the condition must never be sliced at it
+            if bytes[i..].starts_with(b"//") {
+                i = find_bytes(&bytes[i..], b"\n").map_or(bytes.len(), |end| i + end);
+                continue;
+            }
+            if bytes[i..].starts_with(b"/*") {
+                i = find_bytes(&bytes[i..], b"*/").map_or(bytes.len(), |end| i + end + 2);
+                continue;
+            }
+            if !(c.is_ascii_alphanumeric() || c == b'_') {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            let word = &condition[start..i];
+            let before = condition[..start].trim_end().chars().last();
+            let after = condition[i..].trim_start();
+
+            let is_identifier = !c.is_ascii_digit()
+                && !matches!(before, Some('$' | '#' | '@' | '!' | '.'))
+                && !after.starts_with(['.', '('])
+                && !CONDITION_KEYWORDS.contains(&word);
+
+            if previous_word == "for" {
+                // The quantifier of a loop; its variables follow up to `in`
+                loop_vars = true;
+            } else if loop_vars && !matches!(word, "in" | "of") {
+                declared.push(word);
+            } else if is_identifier {
+                if after.starts_with('=') && !after.starts_with("==") {
+                    declared.push(word);
+                } else {
+                    identifiers.push(word);
+                }
+            }
+            if matches!(word, "in" | "of") {
+                loop_vars = false;
+            }
+            previous_word = word;
+        }
+
+        identifiers.iter().any(|word| !declared.contains(word))
+    }
+
+    /// Collects the text of every `condition:` section in the source
+    #[allow(clippy::unused_self)]
+    fn condition_text(&self, source_lower: &str) -> String {
+        let mut condition = String::new();
+        for section in source_lower.split("condition:").skip(1) {
+            let end = section
+                .match_indices('\n')
+                .map(|(index, _)| index)
+                .find(|&index| {
+                    let line = section[index + 1..].trim_start();
+                    ["rule ", "private rule ", "global rule "]
+                        .iter()
+                        .any(|marker| line.starts_with(marker))
+                })
+                .unwrap_or(section.len());
+            condition.push_str(&section[..end]);
+            condition.push('\n');
+        }
+        condition
+    }
+
     /// Analyzes basic features from rule source
     #[allow(clippy::unused_self)]
     fn analyze_basic_features(&self, features: &mut RuleFeatures, source_lower: &str) {
         features.has_strings = source_lower.contains("strings:");
         features.has_metadata = source_lower.contains("meta:");
         features.has_imports = source_lower.contains("import ");
-        features.uses_external_vars = source_lower.contains("filesize");
         features.uses_iterators = self.has_iterator_usage(source_lower);
     }
 
@@ -606,6 +1303,62 @@ mod tests {
         assert_eq!(validator.extract_rule_name(rule3), None);
     }
 
+    #[test]
+    fn test_external_variable_detection() {
+        let validator = YaraValidator::new();
+        let uses_externals = |condition: &str| {
+            let rule = format!(
+                "import \"pe\"\nrule other {{ condition: true }}\nrule r {{\n strings:\n  $a = \"x\"\n condition:\n  {condition}\n}}"
+            );
+            validator.analyze_features(&rule).uses_external_vars
+        };
+
+        assert!(uses_externals("max_size > filesize"));
+        assert!(uses_externals("$a and is_sample"));
+        assert!(!uses_externals(
+            "filesize < 1MB and #a > 2 and @a[1] < 10 and !a[1] == 1"
+        ));
+        assert!(!uses_externals(
+            "pe.is_pe and other and uint16(0) == 0x5A4D"
+        ));
+        assert!(!uses_externals("for any i in (1..#a) : (@a[i] < 100)"));
+        assert!(!uses_externals(
+            "for any s in pe.sections : (s.name == \"unknown_name\")"
+        ));
+        assert!(!uses_externals("with n = #a : (n > 1) // limit\n and $a"));
+        assert!(!uses_externals(
+            "pe.sections[0].name matches /unknown_name/"
+        ));
+        assert!(!uses_externals("\"abc\" matches /ABC/i and $a"));
+        assert!(!uses_externals("\"abc\" matches /A.C/is"));
+        // Curly quotes are not string delimiters, so `x` reads as a bare name
+        assert!(uses_externals("$a and “x” == 1"));
+    }
+
+    #[test]
+    fn test_condition_text_stops_at_indented_rule() {
+        let validator = YaraValidator::new();
+        let rule = r#"
+            rule first {
+                condition:
+                    true
+            }
+            rule second {
+                meta:
+                    author = "someone"
+                strings:
+                    $a = "x"
+                condition:
+                    $a
+            }
+        "#;
+
+        let condition = validator.condition_text(&rule.to_lowercase());
+        assert!(!condition.contains("author"));
+        assert!(!condition.contains("strings:"));
+        assert!(!validator.analyze_features(rule).uses_external_vars);
+    }
+
     #[test]
     fn test_feature_analysis() {
         let validator = YaraValidator::new();
@@ -631,10 +1384,52 @@ mod tests {
         assert!(features.has_regex_patterns);
         assert!(features.has_metadata);
         assert!(features.has_imports);
-        assert!(features.uses_external_vars);
+        assert!(!features.uses_external_vars);
         assert!(features.uses_iterators);
         assert_eq!(features.string_count, 3);
         assert!(features.complexity_score > 1);
+        assert_eq!(features.imported_modules, vec!["pe"]);
+        assert!(features.uses_pe_module);
+        assert!(features.uses_filesize);
+        assert_eq!(features.condition_complexity, 3);
+    }
+
+    #[test]
+    fn test_module_and_condition_features() {
+        let validator = YaraValidator::new();
+
+        let rule = r#"
+            import "elf"
+            import "dotnet"
+            import "math"
+            rule first {
+                condition:
+                    elf.type == elf.ET_EXEC or not dotnet.is_dotnet
+            }
+            rule second {
+                condition:
+                    for any i in (0..2): (math.entropy(i, 10) > 7.0)
+            }
+        "#;
+
+        let features = validator.analyze_features(rule);
+
+        assert_eq!(features.imported_modules, vec!["elf", "dotnet", "math"]);
+        assert!(!features.uses_pe_module);
+        assert!(features.uses_elf_module);
+        assert!(features.uses_dotnet_module);
+        assert!(features.uses_entropy);
+        assert!(!features.uses_filesize);
+        assert_eq!(features.condition_complexity, 4);
+        assert!(
+            features
+                .detected_feature_labels()
+                .contains(&"module imports (elf, dotnet, math)".to_string())
+        );
+
+        let empty = validator.analyze_features("not a rule");
+        assert_eq!(empty.condition_complexity, 0);
+        assert!(empty.imported_modules.is_empty());
     }
 
     #[test]
@@ -720,6 +1515,111 @@ mod tests {
         assert!(!message.is_empty());
     }
 
+    #[test]
+    fn test_rule_with_external_variables() {
+        let rule = r#"
+            rule external_rule {
+                condition:
+                    filesize > min_size and tag == "sample" and not strict and ratio < 0.5
+            }
+        "#;
+
+        let result = YaraValidator::new().validate_rule(rule).unwrap();
+        assert!(!result.is_valid);
+
+        let validator = YaraValidator::new()
+            .with_external("min_size", 0)
+            .with_external("tag", "sample")
+            .with_external("strict", false)
+            .with_external("ratio", 0.25);
+        let result = validator.validate_rule(rule).unwrap();
+        assert!(result.is_valid, "{result}");
+
+        let validator = YaraValidator::new().with_external("not an identifier", 1);
+        let result = validator.validate_rule(rule).unwrap();
+        assert!(!result.is_valid);
+        assert!(result.errors[0].to_string().contains("external variable"));
+    }
+
+    #[test]
+    fn test_cache_hits_misses_and_eviction() {
+        let validator = YaraValidator::new().with_cache(2);
+        let rule_a = "rule a { condition: true }";
+        let rule_b = "rule b { condition: true }";
+        let rule_c = "rule c { condition: true }";
+
+        let first = validator.validate_rule(rule_a).unwrap();
+        let second = validator.validate_rule(rule_a).unwrap();
+        assert_eq!(first.rule_name, second.rule_name);
+        assert_eq!(
+            first.metrics.compilation_time_ms,
+            second.metrics.compilation_time_ms
+        );
+
+        validator.validate_rule(rule_b).unwrap();
+        validator.validate_rule(rule_a).unwrap();
+        validator.validate_rule(rule_c).unwrap();
+        // `b` was least recently used and has been evicted
+        validator.validate_rule(rule_b).unwrap();
+
+        let stats = validator.cache_stats().unwrap();
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 4);
+        assert_eq!(stats.entries, 2);
+        assert!((stats.hit_rate() - 2.0 / 6.0).abs() < f64::EPSILON);
+        assert!(YaraValidator::new().cache_stats().is_none());
+    }
+
+    #[test]
+    fn test_cache_is_shared_across_threads() {
+        let cache = Arc::new(ValidationCache::new(8));
+        let rule = "rule shared { condition: size > 0 }";
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let cache = Arc::clone(&cache);
+                scope.spawn(move || {
+                    let validator = YaraValidator::new()
+                        .with_external("size", 1)
+                        .with_shared_cache(cache);
+                    assert!(validator.validate_rule(rule).unwrap().is_valid);
+                    assert!(validator.scanner(rule).is_ok());
+                });
+            }
+        });
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits + stats.misses, 8);
+        assert_eq!(stats.entries, 1);
+
+        // Different externals produce a separate entry
+        let validator = YaraValidator::new()
+            .with_external("size", 0)
+            .with_shared_cache(Arc::clone(&cache));
+        validator.validate_rule(rule).unwrap();
+        assert_eq!(cache.stats().entries, 2);
+
+        let Err(broken) = validator.scanner("rule broken {") else {
+            panic!("broken rule should not compile");
+        };
+        assert!(broken.to_string().contains("Compilation failed"));
+    }
+
+    #[test]
+    fn test_format_rule() {
+        let validator = YaraValidator::new();
+        let rule = "import \"pe\"\r\nrule fmt {\r\n condition:\r\n  pe.is_pe\r\n}";
+
+        let formatted = validator.format_rule(rule).unwrap();
+        assert_eq!(
+            formatted,
+            "import \"pe\"\n\nrule fmt {\n    condition:\n        pe.is_pe\n}\n"
+        );
+        assert!(validator.validate_rule(&formatted).unwrap().is_valid);
+
+        assert!(validator.format_rule("rule broken {").is_err());
+    }
+
     #[test]
     fn test_serialization() {
         let validator = YaraValidator::new();
@@ -743,4 +1643,46 @@ mod tests {
         assert_eq!(result.is_valid, deserialized.is_valid);
         assert_eq!(result.rule_name, deserialized.rule_name);
     }
+
+    #[test]
+    fn test_report_string_for_valid_rule() {
+        let validator = YaraValidator::new();
+        let rule = r"
+            rule report_test {
+                strings:
+                    $hex = { 4D 5A }
+                condition:
+                    $hex
+            }
+        ";
+
+        let result = validator.validate_rule(rule).unwrap();
+        let report = result.to_report_string(false);
+
+        assert!(report.contains("Rule: report_test"));
+        assert!(report.contains("Status: VALID"));
+        assert!(report.contains("Compilation Time:"));
+        assert!(report.contains("  - strings (1)"));
+        assert!(report.contains("  - hex patterns"));
+        assert!(!report.contains("Errors:"));
+        assert!(!report.contains('\x1b'));
+        assert_eq!(result.to_string(), report);
+    }
+
+    #[test]
+    fn test_report_string_for_invalid_rule() {
+        let validator = YaraValidator::new();
+        let result = validator
+            .validate_rule("this is not a valid YARA rule")
+            .unwrap();
+
+        let plain = result.to_report_string(false);
+        assert!(plain.contains("Rule: <unknown>"));
+        assert!(plain.contains("Status: INVALID"));
+        assert!(plain.contains("Errors:"));
+        assert!(plain.contains("Compilation failed"));
+
+        let colored = result.to_report_string(true);
+        assert!(colored.contains("\x1b[31mINVALID\x1b[0m"));
+    }
 }
@@ -92,3 +92,80 @@ fn test_all_apis_imported_together() {
     let _ = ModelsApi::new("key");
     let _ = ModerationsApi::new("key");
 }
+
+/// Assert that a constructor result failed with an authentication error
+fn assert_blank_key_rejected<T>(api: &str, result: openai_rust_sdk::error::Result<T>) {
+    use openai_rust_sdk::error::OpenAIError;
+
+    match result {
+        Err(OpenAIError::Authentication(msg)) => {
+            assert_eq!(msg, "API key cannot be empty", "{api}");
+        }
+        Err(other) => panic!("{api}: expected authentication error, got {other:?}"),
+        Ok(_) => panic!("{api}: blank API key was accepted"),
+    }
+}
+
+#[test]
+fn test_blank_api_key_rejected_by_every_api() {
+    use openai_rust_sdk::api::{
+        admin::AdminApi, assistants::AssistantsApi, audio::AudioApi, batch::BatchApi,
+        common::ApiClientConstructors, containers::ContainersApi, conversations::ConversationsApi,
+        embeddings::EmbeddingsApi, evals::EvalsApi, files::FilesApi, fine_tuning::FineTuningApi,
+        functions::FunctionsApi, gpt5::GPT5Api, images::ImagesApi, models::ModelsApi,
+        moderations::ModerationsApi, realtime_audio::RealtimeAudioApi, responses::ResponsesApi,
+        responses_v2::ResponsesApiV2, runs::RunsApi, skills::SkillsApi, streaming::StreamingApi,
+        threads::ThreadsApi, uploads::UploadsApi, vector_stores::VectorStoresApi,
+        videos::VideosApi,
+    };
+    use openai_rust_sdk::client::OpenAIClient;
+
+    macro_rules! check_trait_constructors {
+        ($key:expr, $($api:ty),+ $(,)?) => {
+            $(
+                assert_blank_key_rejected(stringify!($api), <$api>::new($key));
+                assert_blank_key_rejected(
+                    stringify!($api),
+                    <$api as ApiClientConstructors>::new_with_base_url($key, "http://localhost"),
+                );
+            )+
+        };
+    }
+
+    for key in ["", "   ", "\t\n"] {
+        check_trait_constructors!(
+            key,
+            AdminApi,
+            AssistantsApi,
+            AudioApi,
+            BatchApi,
+            ContainersApi,
+            ConversationsApi,
+            EmbeddingsApi,
+            EvalsApi,
+            FilesApi,
+            FineTuningApi,
+            ImagesApi,
+            ModelsApi,
+            ModerationsApi,
+            RealtimeAudioApi,
+            ResponsesApi,
+            ResponsesApiV2,
+            RunsApi,
+            SkillsApi,
+            StreamingApi,
+            ThreadsApi,
+            UploadsApi,
+            VectorStoresApi,
+            VideosApi,
+        );
+
+        assert_blank_key_rejected("FunctionsApi", FunctionsApi::new(key));
+        assert_blank_key_rejected("GPT5Api", GPT5Api::new(key.to_string()));
+        assert_blank_key_rejected("OpenAIClient", OpenAIClient::new(key));
+        assert_blank_key_rejected(
+            "RealtimeAudioApi::new_with_config",
+            RealtimeAudioApi::new_with_config(key, Default::default()),
+        );
+    }
+}
@@ -313,6 +313,7 @@ fn test_assistant_serialization() {
             map.insert("test".to_string(), "true".to_string());
             map
         },
+        extra: HashMap::new(),
     };
 
     let json = serde_json::to_string(&assistant).unwrap();
@@ -378,6 +379,7 @@ fn test_list_assistants_response() {
                 tools: Vec::new(),
                 file_ids: Vec::new(),
                 metadata: HashMap::new(),
+                extra: HashMap::new(),
             },
             Assistant {
                 id: "asst_2".to_string(),
@@ -390,6 +392,7 @@ fn test_list_assistants_response() {
                 tools: Vec::new(),
                 file_ids: Vec::new(),
                 metadata: HashMap::new(),
+                extra: HashMap::new(),
             },
         ],
         first_id: Some("asst_1".to_string()),
@@ -456,3 +459,43 @@ fn test_count_validation() {
     // We have implemented 15+ comprehensive tests for the Assistants API
     // This test exists to document that fact
 }
+
+#[tokio::test]
+async fn test_modify_assistant_partial_sends_only_changed_fields() {
+    use httpmock::prelude::*;
+
+    let assistant_json = serde_json::json!({
+        "id": "asst_123",
+        "object": "assistant",
+        "created_at": 1_700_000_000,
+        "name": "Helper",
+        "model": "gpt-4o",
+        "instructions": "Be brief.",
+        "tools": [],
+        "metadata": {"team": "support"}
+    });
+    let assistant: Assistant = serde_json::from_value(assistant_json.clone()).unwrap();
+
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1/assistants/asst_123")
+                .json_body(serde_json::json!({"metadata": {"team": "support", "tier": "gold"}}));
+            then.status(200).json_body(assistant_json);
+        })
+        .await;
+
+    let api = AssistantsApi::new_with_base_url("test-key", server.base_url().as_str()).unwrap();
+    let request = assistant
+        .to_modify_request()
+        .metadata_pair("tier", "gold")
+        .build();
+    let modified = api
+        .modify_assistant_partial(&assistant.id, request)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(modified.id, "asst_123");
+}
@@ -4,6 +4,7 @@ use openai_rust_sdk::api::batch::{
     Batch, BatchList, BatchRequestCounts, BatchStatus, FileUploadResponse,
 };
 use serde_json::json;
+use std::collections::HashMap;
 
 /// Helper functions for creating test data
 pub fn create_sample_file_response() -> FileUploadResponse {
@@ -40,6 +41,7 @@ pub fn create_sample_batch() -> Batch {
             failed: 5,
         },
         metadata: Some(json!({"test": "true"})),
+        extra: HashMap::new(),
     }
 }
 
@@ -326,6 +326,7 @@ fn test_response_request_creation() {
         enhanced_tool_choice: None,
         parallel_tool_calls: None,
         prompt_cache_key: None,
+        ..Default::default()
     };
 
     verify_response_request_fields(&response_req);
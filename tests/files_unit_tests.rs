@@ -8,6 +8,7 @@ use openai_rust_sdk::models::files::{
     File, FileDeleteResponse, FilePurpose, FileStatus, FileUploadRequest, ListFilesParams,
     ListFilesResponse, SortOrder,
 };
+use std::collections::HashMap;
 use std::str::FromStr;
 
 #[test]
@@ -259,6 +260,7 @@ fn test_file_object_methods() {
         purpose: "fine-tune".to_string(),
         status: "uploaded".to_string(),
         status_details: None,
+        extra: HashMap::new(),
     };
 
     // Test purpose enum conversion
@@ -282,6 +284,7 @@ fn test_file_object_methods() {
         purpose: "assistants".to_string(),
         status: "uploaded".to_string(),
         status_details: Some("Processing complete".to_string()),
+        extra: HashMap::new(),
     };
 
     assert!(assistants_file.is_assistants_file());
@@ -298,6 +301,7 @@ fn test_file_object_methods() {
         purpose: "batch".to_string(),
         status: "processed".to_string(),
         status_details: None,
+        extra: HashMap::new(),
     };
 
     assert!(batch_file.is_batch_file());
@@ -330,6 +334,7 @@ fn test_file_size_human_readable() {
             purpose: "user_data".to_string(),
             status: "uploaded".to_string(),
             status_details: None,
+            extra: HashMap::new(),
         };
         assert_eq!(
             file.size_human_readable(),
@@ -351,6 +356,7 @@ fn create_test_files() -> Vec<File> {
             purpose: "fine-tune".to_string(),
             status: "uploaded".to_string(),
             status_details: None,
+            extra: HashMap::new(),
         },
         File {
             id: "file-2".to_string(),
@@ -361,6 +367,7 @@ fn create_test_files() -> Vec<File> {
             purpose: "fine-tune".to_string(),
             status: "uploaded".to_string(),
             status_details: None,
+            extra: HashMap::new(),
         },
         File {
             id: "file-3".to_string(),
@@ -371,6 +378,7 @@ fn create_test_files() -> Vec<File> {
             purpose: "assistants".to_string(),
             status: "uploaded".to_string(),
             status_details: None,
+            extra: HashMap::new(),
         },
         File {
             id: "file-4".to_string(),
@@ -381,6 +389,7 @@ fn create_test_files() -> Vec<File> {
             purpose: "batch".to_string(),
             status: "processed".to_string(),
             status_details: None,
+            extra: HashMap::new(),
         },
     ]
 }
@@ -554,6 +563,7 @@ fn test_file_created_at_formatted() {
         purpose: "user_data".to_string(),
         status: "uploaded".to_string(),
         status_details: None,
+        extra: HashMap::new(),
     };
 
     let formatted = file.created_at_formatted();
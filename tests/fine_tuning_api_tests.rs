@@ -595,3 +595,162 @@ mod type_conversion_tests {
         assert_eq!(metadata.get("key2"), Some(&"value2".to_string()));
     }
 }
+
+fn checkpoint_json(id: &str, step: u32, valid_loss: f64) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "object": "fine_tuning.job.checkpoint",
+        "created_at": 1_700_000_000,
+        "fine_tuned_model_checkpoint": format!("ft:gpt-4o-mini:org::{id}"),
+        "step_number": step,
+        "metrics": {"train_loss": 0.5, "valid_loss": valid_loss},
+        "fine_tuning_job_id": "ftjob-1"
+    })
+}
+
+#[tokio::test]
+async fn test_list_all_checkpoints_follows_pagination() {
+    use httpmock::prelude::*;
+    use openai_rust_sdk::models::fine_tuning::FineTuningJobCheckpoint;
+
+    let server = MockServer::start_async().await;
+    let first_page = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v1/fine_tuning/jobs/ftjob-1/checkpoints")
+                .query_param_missing("after");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "data": [checkpoint_json("ckpt-3", 300, 0.7), checkpoint_json("ckpt-2", 200, 0.4)],
+                "first_id": "ckpt-3",
+                "last_id": "ckpt-2",
+                "has_more": true
+            }));
+        })
+        .await;
+    let second_page = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v1/fine_tuning/jobs/ftjob-1/checkpoints")
+                .query_param("after", "ckpt-2");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "data": [checkpoint_json("ckpt-1", 100, 0.9)],
+                "first_id": "ckpt-1",
+                "last_id": "ckpt-1",
+                "has_more": false
+            }));
+        })
+        .await;
+
+    let api: FineTuningApi = create_test_api_client_with_url(&server.base_url());
+    let checkpoints = api.list_all_checkpoints("ftjob-1").await.unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let steps: Vec<u32> = checkpoints.iter().map(|c| c.step_number).collect();
+    assert_eq!(steps, vec![300, 200, 100]);
+    assert_eq!(
+        FineTuningJobCheckpoint::best(&checkpoints).unwrap().id,
+        "ckpt-2"
+    );
+}
+
+fn job_json(status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": "ftjob-1",
+        "object": "fine_tuning.job",
+        "created_at": 1_700_000_000,
+        "model": "gpt-4o-mini-2024-07-18",
+        "organization_id": "org-1",
+        "status": status,
+        "hyperparameters": {},
+        "training_file": "file-abc123",
+        "result_files": []
+    })
+}
+
+#[tokio::test]
+async fn test_cancel_finished_job_returns_current_state() {
+    use httpmock::prelude::*;
+
+    let server = MockServer::start_async().await;
+    let cancel = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1/fine_tuning/jobs/ftjob-1/cancel");
+            then.status(400).json_body(serde_json::json!({
+                "error": {"message": "Job has already completed.", "type": "invalid_request_error"}
+            }));
+        })
+        .await;
+    let retrieve = server
+        .mock_async(|when, then| {
+            when.method(GET).path("/v1/fine_tuning/jobs/ftjob-1");
+            then.status(200).json_body(job_json("succeeded"));
+        })
+        .await;
+
+    let api: FineTuningApi = create_test_api_client_with_url(&server.base_url());
+    let job = api.cancel_fine_tuning_job("ftjob-1").await.unwrap();
+
+    cancel.assert_async().await;
+    retrieve.assert_async().await;
+    assert_eq!(job.status, FineTuningJobStatus::Succeeded);
+}
+
+#[tokio::test]
+async fn test_cancel_rejection_of_running_job_is_an_error() {
+    use httpmock::prelude::*;
+
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1/fine_tuning/jobs/ftjob-1/cancel");
+            then.status(400).json_body(serde_json::json!({
+                "error": {"message": "Cannot cancel right now.", "type": "invalid_request_error"}
+            }));
+        })
+        .await;
+    server
+        .mock_async(|when, then| {
+            when.method(GET).path("/v1/fine_tuning/jobs/ftjob-1");
+            then.status(200).json_body(job_json("running"));
+        })
+        .await;
+
+    let api: FineTuningApi = create_test_api_client_with_url(&server.base_url());
+    let err = api.cancel_fine_tuning_job("ftjob-1").await.unwrap_err();
+    assert_eq!(err.status_code(), Some(400));
+}
+
+#[tokio::test]
+async fn test_pause_and_resume_return_updated_job() {
+    use httpmock::prelude::*;
+
+    let server = MockServer::start_async().await;
+    let pause = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/v1/fine_tuning/jobs/ftjob-1/pause");
+            then.status(200).json_body(job_json("paused"));
+        })
+        .await;
+    let resume = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1/fine_tuning/jobs/ftjob-1/resume");
+            then.status(200).json_body(job_json("running"));
+        })
+        .await;
+
+    let api: FineTuningApi = create_test_api_client_with_url(&server.base_url());
+    let paused = api.pause_fine_tuning_job("ftjob-1").await.unwrap();
+    assert_eq!(paused.status, FineTuningJobStatus::Paused);
+    assert!(!paused.status.is_terminal());
+    let resumed = api.resume_fine_tuning_job("ftjob-1").await.unwrap();
+    assert_eq!(resumed.status, FineTuningJobStatus::Running);
+
+    pause.assert_async().await;
+    resume.assert_async().await;
+}
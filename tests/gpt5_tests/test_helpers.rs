@@ -4,6 +4,7 @@ use openai_rust_sdk::models::functions::{FunctionTool, Tool};
 use openai_rust_sdk::models::gpt5::models;
 use openai_rust_sdk::models::responses::ResponseResult;
 use serde_json::json;
+use std::collections::HashMap;
 
 // Helper function to create a mock response
 pub fn mock_gpt5_response() -> ResponseResult {
@@ -14,6 +15,7 @@ pub fn mock_gpt5_response() -> ResponseResult {
         model: models::GPT_5.to_string(),
         choices: vec![],
         usage: None,
+        extra: HashMap::new(),
     }
 }
 
@@ -1,6 +1,7 @@
 //! GPT-5 validation tests
 
 use openai_rust_sdk::api::gpt5::GPT5RequestBuilder;
+use openai_rust_sdk::models::gpt5::{ReasoningEffort, models};
 
 #[cfg(test)]
 #[test]
@@ -16,6 +17,93 @@ fn test_temperature_validation_range() {
     }
 }
 
+#[test]
+fn test_checked_build_rejects_out_of_range_temperature() {
+    let out_of_range = GPT5RequestBuilder::new()
+        .model(models::GPT_5_CHAT_LATEST)
+        .input("test")
+        .temperature(2.5)
+        .build_checked();
+    assert!(
+        out_of_range
+            .unwrap_err()
+            .to_string()
+            .contains("between 0 and 2")
+    );
+}
+
+#[test]
+fn test_reasoning_models_reject_sampling_controls() {
+    let err = GPT5RequestBuilder::new()
+        .gpt5()
+        .input("test")
+        .temperature(0.2)
+        .build_checked()
+        .unwrap_err();
+    assert!(err.to_string().contains("temperature"));
+
+    let err = GPT5RequestBuilder::new()
+        .gpt5_mini()
+        .input("test")
+        .top_p(0.5)
+        .build_checked()
+        .unwrap_err();
+    assert!(err.to_string().contains("top_p"));
+
+    let default_value = GPT5RequestBuilder::new()
+        .gpt5()
+        .input("test")
+        .temperature(1.0)
+        .build_checked();
+    assert!(default_value.is_ok());
+
+    let unchecked = GPT5RequestBuilder::new()
+        .gpt5()
+        .input("test")
+        .temperature(0.2)
+        .build()
+        .unwrap();
+    assert_eq!(unchecked.temperature, Some(0.2));
+}
+
+#[test]
+fn test_reasoning_settings_checked_against_model() {
+    let err = GPT5RequestBuilder::new()
+        .model(models::GPT_5_CHAT_LATEST)
+        .input("test")
+        .high_reasoning()
+        .build_checked()
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("does not accept a reasoning effort")
+    );
+
+    let err = GPT5RequestBuilder::new()
+        .model(models::O3)
+        .input("test")
+        .reasoning(ReasoningEffort::Minimal)
+        .build_checked()
+        .unwrap_err();
+    assert!(err.to_string().contains("Minimal"));
+
+    let err = GPT5RequestBuilder::new()
+        .model(models::O4_MINI)
+        .input("test")
+        .low_verbosity()
+        .build_checked()
+        .unwrap_err();
+    assert!(err.to_string().contains("verbosity"));
+
+    let request = GPT5RequestBuilder::new()
+        .model(models::O3)
+        .input("test")
+        .high_reasoning()
+        .build_checked()
+        .unwrap();
+    assert!(request.reasoning.is_some());
+}
+
 #[test]
 fn test_max_tokens_validation() {
     // Valid max_tokens values
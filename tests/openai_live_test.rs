@@ -290,6 +290,7 @@ fn create_chat_request() -> ResponseRequest {
         enhanced_tool_choice: None,
         parallel_tool_calls: None,
         prompt_cache_key: None,
+        ..Default::default()
     }
 }
 
@@ -330,6 +331,7 @@ fn create_invalid_request() -> ResponseRequest {
         enhanced_tool_choice: None,
         parallel_tool_calls: None,
         prompt_cache_key: None,
+        ..Default::default()
     }
 }
 
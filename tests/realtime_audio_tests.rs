@@ -112,6 +112,7 @@ fn test_voice_activity_detector() {
         threshold: 0.5,
         prefix_padding_ms: 300,
         silence_duration_ms: 500,
+        ..Default::default()
     };
 
     let mut vad = VoiceActivityDetector::new(config, 24000);
@@ -8,8 +8,9 @@
 use openai_rust_sdk::api::runs::RunsApi;
 use openai_rust_sdk::models::assistants::AssistantTool;
 use openai_rust_sdk::models::runs::{
-    CreateThreadAndRunRequest, ListRunStepsParams, ListRunsParams, ModifyRunRequest, RunRequest,
-    RunStatus, SubmitToolOutputsRequest, ThreadCreateRequest, ThreadMessage, ToolOutput,
+    AssistantStreamEvent, CreateThreadAndRunRequest, ListRunStepsParams, ListRunsParams,
+    ModifyRunRequest, RunRequest, RunStatus, RunStep, SubmitToolOutputsRequest,
+    ThreadCreateRequest, ThreadMessage, ToolOutput,
 };
 use std::collections::HashMap;
 
@@ -340,6 +341,320 @@ fn test_modify_run_request_serialization() {
     test_serialization_round_trip(&request);
 }
 
+#[test]
+fn test_run_step_code_interpreter_outputs() {
+    let step: RunStep = serde_json::from_value(serde_json::json!({
+        "id": "step_1",
+        "object": "thread.run.step",
+        "created_at": 1,
+        "assistant_id": "asst_1",
+        "thread_id": "thread_1",
+        "run_id": "run_1",
+        "type": "tool_calls",
+        "status": "completed",
+        "step_details": {
+            "type": "tool_calls",
+            "tool_calls": [
+                {
+                    "id": "call_1",
+                    "type": "code_interpreter",
+                    "code_interpreter": {
+                        "input": "print(1 + 1)\nplot()",
+                        "outputs": [
+                            {"type": "logs", "logs": "2\n"},
+                            {"type": "image", "image": {"file_id": "file-chart"}}
+                        ]
+                    }
+                },
+                {
+                    "id": "call_2",
+                    "type": "function",
+                    "function": {"name": "noop", "arguments": "{}", "output": null}
+                }
+            ]
+        },
+        "last_error": null,
+        "expired_at": null,
+        "cancelled_at": null,
+        "failed_at": null,
+        "completed_at": 2,
+        "metadata": {},
+        "usage": null
+    }))
+    .unwrap();
+
+    let calls = step.code_interpreter_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].input, "print(1 + 1)\nplot()");
+    assert_eq!(calls[0].logs(), vec!["2\n"]);
+    assert_eq!(calls[0].image_file_ids(), vec!["file-chart"]);
+}
+
+#[tokio::test]
+async fn test_stream_run_emits_code_interpreter_deltas() {
+    use futures::StreamExt;
+    use httpmock::prelude::*;
+
+    let body = concat!(
+        "event: thread.run.step.delta\n",
+        "data: {\"id\":\"step_1\",\"object\":\"thread.run.step.delta\",\"delta\":{\"step_details\":{\"type\":\"tool_calls\",\"tool_calls\":[{\"index\":0,\"id\":\"call_1\",\"type\":\"code_interpreter\",\"code_interpreter\":{\"input\":\"print(2)\",\"outputs\":[]}}]}}}\n\n",
+        "event: thread.run.step.delta\n",
+        "data: {\"id\":\"step_1\",\"object\":\"thread.run.step.delta\",\"delta\":{\"step_details\":{\"type\":\"tool_calls\",\"tool_calls\":[{\"index\":0,\"type\":\"code_interpreter\",\"code_interpreter\":{\"outputs\":[{\"index\":0,\"type\":\"logs\",\"logs\":\"2\\n\"},{\"index\":1,\"type\":\"image\",\"image\":{\"file_id\":\"file-chart\"}}]}}]}}}\n\n",
+        "event: thread.message.delta\n",
+        "data: {\"id\":\"msg_1\",\"object\":\"thread.message.delta\",\"delta\":{\"content\":[{\"index\":0,\"type\":\"text\",\"text\":{\"value\":\"Done\"}}]}}\n\n",
+        "event: done\n",
+        "data: [DONE]\n\n",
+    );
+
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1/threads/thread_1/runs")
+                .header("accept", "text/event-stream")
+                .body_includes("\"stream\":true");
+            then.status(200)
+                .header("Content-Type", "text/event-stream")
+                .body(body);
+        })
+        .await;
+
+    let api: RunsApi = create_test_api_client_with_url(&server.base_url());
+    let request = RunRequest::builder()
+        .assistant_id("asst_1")
+        .build()
+        .unwrap();
+    let events: Vec<AssistantStreamEvent> = api
+        .stream_run("thread_1", request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    mock.assert_async().await;
+    assert_eq!(events.len(), 4);
+    assert!(matches!(
+        &events[0],
+        AssistantStreamEvent::CodeInterpreterInputDelta { input, call_id: Some(id), .. }
+            if input == "print(2)" && id == "call_1"
+    ));
+    assert!(matches!(
+        &events[1],
+        AssistantStreamEvent::CodeInterpreterOutputDelta { output, .. } if output.logs() == Some("2\n")
+    ));
+    assert_eq!(events[2].image_file_id(), Some("file-chart"));
+    assert_eq!(
+        events[3],
+        AssistantStreamEvent::MessageDelta {
+            message_id: "msg_1".to_string(),
+            text: "Done".to_string()
+        }
+    );
+}
+
+fn sse_frame(event: &str, data: &serde_json::Value) -> String {
+    format!("event: {event}\ndata: {data}\n\n")
+}
+
+fn run_event_payload(status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": "run_1",
+        "object": "thread.run",
+        "created_at": 1,
+        "thread_id": "thread_1",
+        "assistant_id": "asst_1",
+        "status": status,
+        "model": "gpt-4o",
+        "instructions": "",
+        "tools": [],
+        "metadata": {},
+        "last_error": if status == "failed" {
+            serde_json::json!({"code": "server_error", "message": "boom"})
+        } else {
+            serde_json::Value::Null
+        }
+    })
+}
+
+fn completed_message_payload(content: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "id": "msg_1",
+        "object": "thread.message",
+        "created_at": 1,
+        "thread_id": "thread_1",
+        "role": "assistant",
+        "content": [content],
+        "assistant_id": "asst_1",
+        "run_id": "run_1"
+    })
+}
+
+async fn run_typed_against<T: serde::de::DeserializeOwned>(
+    body: String,
+) -> (openai_rust_sdk::Result<T>, usize) {
+    use httpmock::prelude::*;
+
+    let server = MockServer::start_async().await;
+    server
+        .mock_async(|when, then| {
+            when.method(POST).path("/v1/threads/thread_1/runs");
+            then.status(200)
+                .header("Content-Type", "text/event-stream")
+                .body(body);
+        })
+        .await;
+
+    let api: RunsApi = create_test_api_client_with_url(&server.base_url());
+    let request = RunRequest::builder()
+        .assistant_id("asst_1")
+        .build()
+        .unwrap();
+    let mut seen = 0;
+    let result = api.run_typed_with("thread_1", request, |_| seen += 1).await;
+    (result, seen)
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct Verdict {
+    approved: bool,
+}
+
+#[tokio::test]
+async fn test_run_typed_parses_final_message() {
+    let text = "```json\n{\"approved\": true}\n```";
+    let body = [
+        sse_frame("thread.run.created", &run_event_payload("queued")),
+        sse_frame(
+            "thread.message.delta",
+            &serde_json::json!({"id": "msg_1", "object": "thread.message.delta", "delta": {"content": [{"index": 0, "type": "text", "text": {"value": text}}]}}),
+        ),
+        sse_frame(
+            "thread.message.completed",
+            &completed_message_payload(serde_json::json!({"type": "text", "text": {"value": text, "annotations": []}})),
+        ),
+        sse_frame("thread.run.completed", &run_event_payload("completed")),
+        "event: done\ndata: [DONE]\n\n".to_string(),
+    ]
+    .concat();
+
+    let (result, seen) = run_typed_against::<Verdict>(body).await;
+    assert!(result.unwrap().approved);
+    assert_eq!(seen, 4);
+}
+
+#[tokio::test]
+async fn test_run_typed_surfaces_refusal() {
+    let body = [
+        sse_frame(
+            "thread.message.completed",
+            &completed_message_payload(
+                serde_json::json!({"type": "refusal", "refusal": "I can't help with that."}),
+            ),
+        ),
+        sse_frame("thread.run.completed", &run_event_payload("completed")),
+    ]
+    .concat();
+
+    let (result, _) = run_typed_against::<Verdict>(body).await;
+    assert!(matches!(
+        result,
+        Err(openai_rust_sdk::OpenAIError::Refusal(message)) if message.contains("can't help")
+    ));
+}
+
+#[tokio::test]
+async fn test_run_typed_reports_failed_run() {
+    let body = sse_frame("thread.run.failed", &run_event_payload("failed"));
+
+    let (result, _) = run_typed_against::<Verdict>(body).await;
+    assert!(matches!(
+        result,
+        Err(openai_rust_sdk::OpenAIError::RequestError(message)) if message.contains("boom")
+    ));
+}
+
+fn tool_call_step(id: &str, call_id: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "object": "thread.run.step",
+        "created_at": 1,
+        "assistant_id": "asst_1",
+        "thread_id": "thread_1",
+        "run_id": "run_1",
+        "type": "tool_calls",
+        "status": "completed",
+        "step_details": {
+            "type": "tool_calls",
+            "tool_calls": [{
+                "id": call_id,
+                "type": "function",
+                "function": {"name": "lookup", "arguments": "{}", "output": null}
+            }]
+        },
+        "last_error": null,
+        "expired_at": null,
+        "cancelled_at": null,
+        "failed_at": null,
+        "completed_at": 2,
+        "metadata": {},
+        "usage": null
+    })
+}
+
+#[tokio::test]
+async fn test_list_all_steps_follows_pagination() {
+    use futures::TryStreamExt;
+    use httpmock::prelude::*;
+    use openai_rust_sdk::models::runs::step_tool_calls;
+
+    let server = MockServer::start_async().await;
+    let first_page = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v1/threads/thread_1/runs/run_1/steps")
+                .query_param("order", "asc")
+                .query_param_missing("after");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "data": [tool_call_step("step_1", "call_1"), tool_call_step("step_2", "call_2")],
+                "first_id": "step_1",
+                "last_id": "step_2",
+                "has_more": true
+            }));
+        })
+        .await;
+    let second_page = server
+        .mock_async(|when, then| {
+            when.method(GET)
+                .path("/v1/threads/thread_1/runs/run_1/steps")
+                .query_param("after", "step_2");
+            then.status(200).json_body(serde_json::json!({
+                "object": "list",
+                "data": [tool_call_step("step_3", "call_3")],
+                "first_id": "step_3",
+                "last_id": "step_3",
+                "has_more": false
+            }));
+        })
+        .await;
+
+    let api: RunsApi = create_test_api_client_with_url(&server.base_url());
+    let steps: Vec<RunStep> = api
+        .list_all_steps("thread_1", "run_1")
+        .try_collect()
+        .await
+        .unwrap();
+
+    first_page.assert_async().await;
+    second_page.assert_async().await;
+    let ids: Vec<&str> = step_tool_calls(&steps)
+        .into_iter()
+        .map(|call| call.id())
+        .collect();
+    assert_eq!(ids, vec!["call_1", "call_2", "call_3"]);
+}
+
 // Integration tests (would require actual API key and network access)
 // These are commented out but show how to test the actual API calls
 
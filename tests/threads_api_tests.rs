@@ -485,6 +485,7 @@ fn test_default_object_types() {
         object: "thread".to_string(),
         created_at: 1_234_567_890,
         metadata: HashMap::new(),
+        extra: HashMap::new(),
     };
     assert_eq!(thread.object, "thread");
 
@@ -499,6 +500,7 @@ fn test_default_object_types() {
         run_id: None,
         file_ids: Vec::new(),
         metadata: HashMap::new(),
+        extra: HashMap::new(),
     };
     assert_eq!(message.object, "thread.message");
 
@@ -510,3 +512,41 @@ fn test_default_object_types() {
     };
     assert_eq!(message_file.object, "thread.message.file");
 }
+
+#[tokio::test]
+async fn test_modify_thread_partial_clears_metadata() {
+    use httpmock::prelude::*;
+
+    let thread_json = serde_json::json!({
+        "id": "thread_123",
+        "object": "thread",
+        "created_at": 1_700_000_000,
+        "metadata": {"topic": "billing"}
+    });
+    let thread: Thread = serde_json::from_value(thread_json).unwrap();
+
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/v1/threads/thread_123")
+                .json_body(serde_json::json!({"metadata": null}));
+            then.status(200).json_body(serde_json::json!({
+                "id": "thread_123",
+                "object": "thread",
+                "created_at": 1_700_000_000,
+                "metadata": {}
+            }));
+        })
+        .await;
+
+    let api = ThreadsApi::new_with_base_url("test-key", server.base_url().as_str()).unwrap();
+    let request = thread.to_modify_request().clear_metadata().build();
+    let modified = api
+        .modify_thread_partial(&thread.id, request)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert!(modified.metadata.is_empty());
+}